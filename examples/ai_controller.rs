@@ -0,0 +1,86 @@
+//! Bots can be driven by the same `ActionState` as players,
+//! so that every gameplay system works for both without any special-casing.
+//!
+//! Press Space to jump and hold Enter to charge an attack: the bot will follow along on its own.
+
+use bevy::prelude::*;
+use bevy::utils::Duration;
+use leafwing_input_manager::prelude::*;
+use leafwing_input_manager::virtual_controller::VirtualController;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .add_startup_system(spawn_player)
+        .add_startup_system(spawn_bot)
+        // The bot's brain only decides which inputs to schedule
+        .add_system(think)
+        // These systems don't care who is in control
+        .add_system(jump)
+        .add_system(charge_attack)
+        .run();
+}
+
+#[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug)]
+enum Action {
+    Jump,
+    ChargeAttack,
+}
+
+#[derive(Component)]
+struct Bot;
+
+fn spawn_player(mut commands: Commands) {
+    commands
+        .spawn(InputManagerBundle::<Action> {
+            action_state: ActionState::default(),
+            input_map: InputMap::new([
+                (KeyCode::Space, Action::Jump),
+                (KeyCode::Return, Action::ChargeAttack),
+            ]),
+        })
+        .insert(Name::new("Player"));
+}
+
+fn spawn_bot(mut commands: Commands) {
+    // Bots don't need an `InputMap`: their actions are pressed by the `VirtualController` instead
+    commands.spawn((
+        ActionState::<Action>::default(),
+        VirtualController::<Action>::default(),
+        Name::new("Bot"),
+        Bot,
+    ));
+}
+
+fn think(mut query: Query<&mut VirtualController<Action>, With<Bot>>) {
+    let mut controller = query.single_mut();
+
+    // Once the previous plan has played out, jump, then charge up an attack
+    if controller.is_idle() {
+        controller.tap(Action::Jump);
+        controller.hold_after(
+            Action::ChargeAttack,
+            Duration::from_millis(500),
+            Duration::from_secs(1),
+        );
+        controller.hold_after(Action::Jump, Duration::from_secs(3), Duration::ZERO);
+    }
+}
+
+fn jump(query: Query<(&ActionState<Action>, &Name)>) {
+    for (action_state, name) in query.iter() {
+        if action_state.just_pressed(Action::Jump) {
+            println!("{name} jumped!");
+        }
+    }
+}
+
+fn charge_attack(query: Query<(&ActionState<Action>, &Name)>) {
+    for (action_state, name) in query.iter() {
+        if action_state.just_released(Action::ChargeAttack) {
+            let charge = action_state.previous_duration(Action::ChargeAttack);
+            println!("{name} attacked after charging for {charge:?}!");
+        }
+    }
+}
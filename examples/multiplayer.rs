@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use leafwing_input_manager::prelude::*;
+use leafwing_input_manager::split_keyboard::{validate_split_keyboard, KeyboardRegion};
 
 fn main() {
     App::new()
@@ -17,7 +18,7 @@ enum Action {
     Jump,
 }
 
-#[derive(Component)]
+#[derive(Component, Clone, Copy)]
 enum Player {
     One,
     Two,
@@ -32,27 +33,25 @@ struct PlayerBundle {
 
 impl PlayerBundle {
     fn input_map(player: Player) -> InputMap<Action> {
-        let mut input_map = match player {
-            Player::One => InputMap::new([
-                (KeyCode::A, Action::Left),
-                (KeyCode::D, Action::Right),
-                (KeyCode::W, Action::Jump),
-            ])
-            // This is a quick and hacky solution:
-            // you should coordinate with the `Gamepads` resource to determine the correct gamepad for each player
-            // and gracefully handle disconnects
-            // Note that this step is not required:
-            // if it is skipped all input maps will read from all connected gamepads
-            .set_gamepad(Gamepad { id: 0 })
-            .build(),
-            Player::Two => InputMap::new([
-                (KeyCode::Left, Action::Left),
-                (KeyCode::Right, Action::Right),
-                (KeyCode::Up, Action::Jump),
-            ])
-            .set_gamepad(Gamepad { id: 1 })
-            .build(),
+        // Both players share the keyboard, so each uses one half of it
+        let (region, gamepad) = match player {
+            Player::One => (KeyboardRegion::Wasd, Gamepad { id: 0 }),
+            Player::Two => (KeyboardRegion::Arrows, Gamepad { id: 1 }),
         };
+        let dpad = region.dpad();
+
+        let mut input_map = InputMap::new([
+            (dpad.left, Action::Left),
+            (dpad.right, Action::Right),
+            (dpad.up, Action::Jump),
+        ]);
+
+        // This is a quick and hacky solution:
+        // you should coordinate with the `Gamepads` resource to determine the correct gamepad for each player
+        // and gracefully handle disconnects
+        // Note that this step is not required:
+        // if it is skipped all input maps will read from all connected gamepads
+        input_map.set_gamepad(gamepad);
 
         // Each player will use the same gamepad controls, but on seperate gamepads
         input_map.insert_multiple([
@@ -67,19 +66,22 @@ impl PlayerBundle {
 }
 
 fn spawn_players(mut commands: Commands) {
-    commands.spawn(PlayerBundle {
-        player: Player::One,
-        input_manager: InputManagerBundle {
-            input_map: PlayerBundle::input_map(Player::One),
-            ..Default::default()
-        },
-    });
+    let players =
+        [Player::One, Player::Two].map(|player| (player, PlayerBundle::input_map(player)));
 
-    commands.spawn(PlayerBundle {
-        player: Player::Two,
-        input_manager: InputManagerBundle {
-            input_map: PlayerBundle::input_map(Player::Two),
-            ..Default::default()
-        },
-    });
+    // Catch any keys that would be shared between the players
+    debug_assert_eq!(
+        validate_split_keyboard(players.iter().map(|(_, input_map)| input_map)),
+        Ok(())
+    );
+
+    for (player, input_map) in players {
+        commands.spawn(PlayerBundle {
+            player,
+            input_manager: InputManagerBundle {
+                input_map,
+                ..Default::default()
+            },
+        });
+    }
 }
@@ -65,10 +65,7 @@ fn spawn_ui(mut commands: Commands, player_query: Query<Entity, With<Player>>) {
             ..Default::default()
         })
         // This component links the button to the entity with the `ActionState` component
-        .insert(ActionStateDriver {
-            action: Action::Left,
-            entity: player_entity,
-        })
+        .insert(ActionStateDriver::new(Action::Left, player_entity))
         .id();
 
     // Right
@@ -81,10 +78,7 @@ fn spawn_ui(mut commands: Commands, player_query: Query<Entity, With<Player>>) {
             background_color: Color::BLUE.into(),
             ..Default::default()
         })
-        .insert(ActionStateDriver {
-            action: Action::Right,
-            entity: player_entity,
-        })
+        .insert(ActionStateDriver::new(Action::Right, player_entity))
         .id();
 
     // Container for layout
@@ -2,7 +2,7 @@ use proc_macro2::Span;
 use proc_macro2::TokenStream;
 use proc_macro_crate::{crate_name, FoundCrate};
 use quote::quote;
-use syn::{Data, DeriveInput, Ident};
+use syn::{Attribute, Data, DeriveInput, Ident, Lit, Meta, NestedMeta};
 
 /// This approach and implementation is inspired by the `strum` crate,
 /// Copyright (c) 2019 Peter Glotfelty
@@ -42,6 +42,7 @@ pub(crate) fn actionlike_inner(ast: &DeriveInput) -> TokenStream {
     // Populate the array
     let mut get_at_match_items = Vec::new();
     let mut index_match_items = Vec::new();
+    let mut metadata_match_items = Vec::new();
 
     for (index, variant) in variants.iter().enumerate() {
         // The name of the enum variant
@@ -92,15 +93,37 @@ pub(crate) fn actionlike_inner(ast: &DeriveInput) -> TokenStream {
         index_match_items.push(quote! {
             #enum_name::#variant_identifier #index_params => #index,
         });
+
+        let metadata = match VariantMetadata::parse(&variant.attrs) {
+            Ok(metadata) => metadata,
+            Err(error) => return error.to_compile_error(),
+        };
+        let name = metadata
+            .name
+            .unwrap_or_else(|| variant_identifier.to_string());
+        let description = option_tokens(metadata.description);
+        let category = option_tokens(metadata.category);
+        let fixed = metadata.fixed;
+        let identifier = variant_identifier.to_string();
+        let aliases = metadata.aliases;
+
+        metadata_match_items.push(quote! {
+            #enum_name::#variant_identifier #index_params => #crate_path::ActionMetadata {
+                name: #name,
+                identifier: #identifier,
+                aliases: &[#(#aliases),*],
+                description: #description,
+                category: #category,
+                fixed: #fixed,
+            },
+        });
     }
 
     let n_variants = variants.iter().len();
 
     quote! {
         impl #impl_generics #crate_path::Actionlike for #enum_name #type_generics #where_clause {
-            fn n_variants() -> usize {
-                #n_variants
-            }
+            const N_VARIANTS: usize = #n_variants;
 
             fn get_at(index: usize) -> Option<Self> {
                 match index {
@@ -115,6 +138,103 @@ pub(crate) fn actionlike_inner(ast: &DeriveInput) -> TokenStream {
                     _ => unreachable!()
                 }
             }
+
+            fn metadata(&self) -> #crate_path::ActionMetadata {
+                match self {
+                    #(#metadata_match_items)*
+                    _ => unreachable!()
+                }
+            }
         }
     }
 }
+
+/// The values supplied by `#[actionlike(...)]` attributes on a single variant
+#[derive(Default)]
+struct VariantMetadata {
+    name: Option<String>,
+    description: Option<String>,
+    category: Option<String>,
+    aliases: Vec<String>,
+    fixed: bool,
+}
+
+impl VariantMetadata {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut metadata = VariantMetadata::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("actionlike")) {
+            let list = match attr.parse_meta()? {
+                Meta::List(list) => list,
+                meta => {
+                    return Err(syn::Error::new_spanned(
+                        meta,
+                        "expected `#[actionlike(...)]`",
+                    ))
+                }
+            };
+
+            for nested in list.nested {
+                let name_value = match nested {
+                    NestedMeta::Meta(Meta::NameValue(name_value)) => name_value,
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("fixed") => {
+                        if metadata.fixed {
+                            return Err(syn::Error::new_spanned(
+                                path,
+                                "duplicate `actionlike` attribute",
+                            ));
+                        }
+
+                        metadata.fixed = true;
+                        continue;
+                    }
+                    other => {
+                        return Err(syn::Error::new_spanned(
+                            other,
+                            "expected `fixed` or `key = \"value\"`",
+                        ))
+                    }
+                };
+
+                let value = match &name_value.lit {
+                    Lit::Str(value) => value.value(),
+                    lit => return Err(syn::Error::new_spanned(lit, "expected a string literal")),
+                };
+
+                if name_value.path.is_ident("alias") {
+                    metadata.aliases.push(value);
+                    continue;
+                }
+
+                let slot = if name_value.path.is_ident("name") {
+                    &mut metadata.name
+                } else if name_value.path.is_ident("description") {
+                    &mut metadata.description
+                } else if name_value.path.is_ident("category") {
+                    &mut metadata.category
+                } else {
+                    return Err(syn::Error::new_spanned(
+                        name_value.path,
+                        "unknown `actionlike` attribute; expected `name`, `alias`, `description` or `category`",
+                    ));
+                };
+
+                if slot.replace(value).is_some() {
+                    return Err(syn::Error::new_spanned(
+                        name_value.path,
+                        "duplicate `actionlike` attribute",
+                    ));
+                }
+            }
+        }
+
+        Ok(metadata)
+    }
+}
+
+fn option_tokens(value: Option<String>) -> TokenStream {
+    match value {
+        Some(value) => quote!(::core::option::Option::Some(#value)),
+        None => quote!(::core::option::Option::None),
+    }
+}
@@ -0,0 +1,213 @@
+//! This module contains [`ActionAliases`] and its supporting methods and impls.
+//!
+//! The [`ActionAliases`] is an optional addition to an [`InputManagerBundle`](crate::InputManagerBundle),
+//! which makes some actions mirror others.
+//! This keeps legacy action names working while a project is refactored,
+//! and lets UI code listen to a single "umbrella" action that is fed by several concrete ones.
+
+use bevy::ecs::prelude::*;
+
+use crate::action_state::ActionData;
+use crate::axislike::DualAxisData;
+use crate::buttonlike::ButtonState;
+use crate::Actionlike;
+
+/// Forwards the presses of one action to another
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActionAlias<A: Actionlike> {
+    /// The action whose presses are forwarded
+    pub source: A,
+    /// The action that mirrors the [`ActionAlias::source`]
+    pub target: A,
+    /// The factor applied to the value and axis pair of the [`ActionAlias::source`] before they are forwarded
+    ///
+    /// Use `-1.0` to invert the source.
+    pub scale: f32,
+}
+
+impl<A: Actionlike> ActionAlias<A> {
+    /// Creates an [`ActionAlias`] that forwards the presses of `source` to `target` unchanged
+    #[must_use]
+    pub fn new(source: A, target: A) -> Self {
+        Self::scaled(source, target, 1.0)
+    }
+
+    /// Creates an [`ActionAlias`] that forwards the presses of `source` to `target`, multiplying their values by `scale`
+    #[must_use]
+    pub fn scaled(source: A, target: A, scale: f32) -> Self {
+        Self {
+            source,
+            target,
+            scale,
+        }
+    }
+
+    fn apply(&self, action_data: &mut [ActionData]) {
+        let source = action_data[self.source.index()].clone();
+        if !source.state.pressed() {
+            return;
+        }
+
+        let target = &mut action_data[self.target.index()];
+        if !target.state.pressed() {
+            target.state = ButtonState::JustPressed;
+        }
+        target.value += source.value * self.scale;
+
+        if let Some(axis_pair) = source.axis_pair {
+            let axis_pair = DualAxisData::from_xy(axis_pair.xy() * self.scale);
+            target.axis_pair = Some(match target.axis_pair {
+                Some(current) => current.merged_with(axis_pair),
+                None => {
+                    target.axis_semantics = source.axis_semantics;
+                    axis_pair
+                }
+            });
+        }
+
+        if target.triggering_input.is_none() {
+            target.triggering_input = source.triggering_input;
+        }
+        for device in source.sources {
+            if !target.sources.contains(&device) {
+                target.sources.push(device);
+            }
+        }
+    }
+}
+
+/// Makes actions mirror the presses of other actions
+///
+/// As a resource, this affects the [`ActionState`](crate::action_state::ActionState) resource,
+/// and as a component, the [`ActionState`](crate::action_state::ActionState) of the same entity.
+///
+/// Each [`ActionAlias`] is applied by [`update_action_state`](crate::systems::update_action_state),
+/// before the pressed actions reach the [`ActionState`](crate::action_state::ActionState).
+/// The target of an alias is pressed whenever its source is, in addition to its own bindings,
+/// and the scaled value and axis pair of the source are added to its own.
+/// This is the same way the values of several bindings of one action are combined.
+///
+/// Aliases are applied in order, so an alias can forward presses that were forwarded by an earlier one.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::action_aliases::{ActionAlias, ActionAliases};
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     LightAttack,
+///     HeavyAttack,
+///     // Pressed by either attack, so that the UI can flash the weapon icon
+///     AnyAttack,
+///     // The old name of `LightAttack`, kept until every system has been migrated
+///     Attack,
+/// }
+///
+/// let mut world = World::new();
+/// world
+///     .spawn(InputManagerBundle {
+///         input_map: InputMap::new([
+///             (MouseButton::Left, Action::LightAttack),
+///             (MouseButton::Right, Action::HeavyAttack),
+///         ]),
+///         ..Default::default()
+///     })
+///     .insert(ActionAliases::new([
+///         ActionAlias::new(Action::LightAttack, Action::AnyAttack),
+///         ActionAlias::new(Action::HeavyAttack, Action::AnyAttack),
+///         ActionAlias::new(Action::LightAttack, Action::Attack),
+///     ]));
+/// ```
+#[derive(Resource, Component, Debug, Clone, PartialEq)]
+pub struct ActionAliases<A: Actionlike> {
+    /// The aliases, applied in order
+    pub aliases: Vec<ActionAlias<A>>,
+}
+
+impl<A: Actionlike> Default for ActionAliases<A> {
+    fn default() -> Self {
+        Self {
+            aliases: Vec::new(),
+        }
+    }
+}
+
+impl<A: Actionlike> ActionAliases<A> {
+    /// Creates [`ActionAliases`] from the provided `aliases`
+    #[must_use]
+    pub fn new(aliases: impl IntoIterator<Item = ActionAlias<A>>) -> Self {
+        Self {
+            aliases: aliases.into_iter().collect(),
+        }
+    }
+
+    /// Adds an [`ActionAlias`]
+    #[must_use]
+    pub fn with(mut self, alias: ActionAlias<A>) -> Self {
+        self.aliases.push(alias);
+        self
+    }
+
+    /// Forwards the presses of each [`ActionAlias`] to its target
+    ///
+    /// The `action_data` should be collected from the inputs once per update,
+    /// as by [`InputMap::which_pressed`](crate::input_map::InputMap::which_pressed).
+    pub fn apply(&self, action_data: &mut [ActionData]) {
+        for alias in &self.aliases {
+            alias.apply(action_data);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as leafwing_input_manager;
+
+    #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    enum Action {
+        Forward,
+        Back,
+        Throttle,
+    }
+
+    fn pressed(value: f32) -> ActionData {
+        ActionData {
+            state: ButtonState::JustPressed,
+            value,
+            axis_pair: Some(DualAxisData::new(0.0, value)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn aliases_forward_scaled_values() {
+        let aliases = ActionAliases::default()
+            .with(ActionAlias::new(Action::Forward, Action::Throttle))
+            .with(ActionAlias::scaled(Action::Back, Action::Throttle, -1.0));
+
+        let mut action_data = vec![ActionData::default(); Action::N_VARIANTS];
+        action_data[Action::Back.index()] = pressed(0.5);
+        aliases.apply(&mut action_data);
+        let throttle = &action_data[Action::Throttle.index()];
+        assert!(throttle.state.pressed());
+        assert_eq!(throttle.value, -0.5);
+        assert_eq!(throttle.axis_pair, Some(DualAxisData::new(0.0, -0.5)));
+
+        action_data[Action::Forward.index()] = pressed(1.0);
+        action_data[Action::Throttle.index()] = ActionData::default();
+        aliases.apply(&mut action_data);
+        assert_eq!(action_data[Action::Throttle.index()].value, 0.5);
+    }
+
+    #[test]
+    fn released_sources_are_not_forwarded() {
+        let aliases = ActionAliases::new([ActionAlias::new(Action::Forward, Action::Throttle)]);
+
+        let mut action_data = vec![ActionData::default(); Action::N_VARIANTS];
+        aliases.apply(&mut action_data);
+        assert!(!action_data[Action::Throttle.index()].state.pressed());
+    }
+}
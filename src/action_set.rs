@@ -0,0 +1,221 @@
+//! This module contains [`ActionSet`] and its supporting methods and impls.
+//!
+//! An [`ActionSet`] stores one bit per action, keyed by [`Actionlike::index`].
+//! This makes bulk queries and comparisons across every action cheap,
+//! and makes it inexpensive to keep a history of which actions were pressed on each frame.
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use fixedbitset::FixedBitSet;
+
+use crate::Actionlike;
+
+/// A set of actions, stored as a bitset with [`Actionlike::N_VARIANTS`] bits
+///
+/// The [`ActionState`](crate::action_state::ActionState) returns these from
+/// [`pressed_set`](crate::action_state::ActionState::pressed_set),
+/// [`just_pressed_set`](crate::action_state::ActionState::just_pressed_set) and
+/// [`just_released_set`](crate::action_state::ActionState::just_released_set).
+///
+/// # Example
+/// ```rust
+/// use leafwing_input_manager::action_set::ActionSet;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Left,
+///     Right,
+///     Jump,
+/// }
+///
+/// let mut action_state = ActionState::<Action>::default();
+/// action_state.press(Action::Left);
+/// let previous = action_state.pressed_set();
+///
+/// action_state.release(Action::Left);
+/// action_state.press(Action::Jump);
+///
+/// // The actions whose pressed state has changed
+/// let changed = previous.symmetric_difference(&action_state.pressed_set());
+/// assert_eq!(changed.iter().collect::<Vec<_>>(), [Action::Left, Action::Jump]);
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct ActionSet<A: Actionlike> {
+    /// Whether each action is in the set, indexed by [`Actionlike::index`]
+    bitset: FixedBitSet,
+    _phantom: PhantomData<A>,
+}
+
+impl<A: Actionlike> Default for ActionSet<A> {
+    fn default() -> Self {
+        Self {
+            bitset: FixedBitSet::with_capacity(A::N_VARIANTS),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A: Actionlike> ActionSet<A> {
+    /// Creates a set containing every action
+    #[must_use]
+    pub fn all() -> Self {
+        let mut set = Self::default();
+        set.bitset.insert_range(..);
+        set
+    }
+
+    /// Adds the `action` to the set
+    ///
+    /// Returns `true` if the `action` was not already in the set.
+    pub fn insert(&mut self, action: A) -> bool {
+        !self.bitset.put(action.index())
+    }
+
+    /// Removes the `action` from the set
+    ///
+    /// Returns `true` if the `action` was in the set.
+    pub fn remove(&mut self, action: A) -> bool {
+        let index = action.index();
+        let contained = self.bitset.contains(index);
+        self.bitset.set(index, false);
+        contained
+    }
+
+    /// Adds the `action` to the set if `contained` is `true`, or removes it otherwise
+    pub(crate) fn set(&mut self, action: A, contained: bool) {
+        self.bitset.set(action.index(), contained);
+    }
+
+    /// Is the `action` in the set?
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, action: A) -> bool {
+        self.bitset.contains(action.index())
+    }
+
+    /// The number of actions in the set
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.bitset.count_ones(..)
+    }
+
+    /// Is the set empty?
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.bitset.is_clear()
+    }
+
+    /// Removes every action from the set
+    pub fn clear(&mut self) {
+        self.bitset.clear();
+    }
+
+    /// Iterates over the actions in the set, in the order of their [`Actionlike::index`]
+    pub fn iter(&self) -> impl Iterator<Item = A> + '_ {
+        self.bitset.ones().filter_map(A::get_at)
+    }
+
+    /// The actions that are in either set
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        self.combine(other, |bitset, other| bitset.union_with(other))
+    }
+
+    /// The actions that are in both sets
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.combine(other, |bitset, other| bitset.intersect_with(other))
+    }
+
+    /// The actions that are in this set, but not in `other`
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        self.combine(other, |bitset, other| bitset.difference_with(other))
+    }
+
+    /// The actions that are in exactly one of the sets
+    ///
+    /// Comparing the [`pressed_set`](crate::action_state::ActionState::pressed_set)s of two frames this way
+    /// gives the actions that were pressed or released between them.
+    #[must_use]
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        self.combine(other, |bitset, other| {
+            bitset.symmetric_difference_with(other)
+        })
+    }
+
+    /// Copies this set, and combines its bits with those of `other` in place
+    fn combine(&self, other: &Self, operation: fn(&mut FixedBitSet, &FixedBitSet)) -> Self {
+        let mut combined = self.clone();
+        operation(&mut combined.bitset, &other.bitset);
+        combined
+    }
+}
+
+impl<A: Actionlike + Debug> Debug for ActionSet<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<A: Actionlike> FromIterator<A> for ActionSet<A> {
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let mut set = Self::default();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<A: Actionlike> Extend<A> for ActionSet<A> {
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        for action in iter {
+            self.insert(action);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ActionSet;
+    use crate as leafwing_input_manager;
+    use crate::Actionlike;
+
+    #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    enum Action {
+        Run,
+        Jump,
+        Hide,
+    }
+
+    #[test]
+    fn set_operations() {
+        let mut set = ActionSet::<Action>::default();
+        assert!(set.is_empty());
+        assert!(set.insert(Action::Jump));
+        assert!(!set.insert(Action::Jump));
+        assert!(set.contains(Action::Jump));
+        assert!(!set.contains(Action::Run));
+        assert_eq!(set.len(), 1);
+
+        let other: ActionSet<Action> = [Action::Run, Action::Jump].into_iter().collect();
+        assert_eq!(set.union(&other), other);
+        assert_eq!(set.intersection(&other), set);
+        assert_eq!(
+            other.difference(&set).iter().collect::<Vec<_>>(),
+            [Action::Run]
+        );
+        assert_eq!(
+            ActionSet::all()
+                .symmetric_difference(&other)
+                .iter()
+                .collect::<Vec<_>>(),
+            [Action::Hide]
+        );
+
+        assert!(set.remove(Action::Jump));
+        assert!(!set.remove(Action::Jump));
+        assert!(set.is_empty());
+    }
+}
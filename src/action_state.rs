@@ -1,13 +1,25 @@
 //! This module contains [`ActionState`] and its supporting methods and impls.
 
 use crate::Actionlike;
-use crate::{axislike::DualAxisData, buttonlike::ButtonState};
+use crate::{
+    action_set::ActionSet,
+    axislike::{AxisSemantics, DualAxisData},
+    buttonlike::ButtonState,
+    clashing_inputs::ClashStrategy,
+    errors::InputManagerError,
+    inhibit_input::InputDevice,
+    input_map::InputMap,
+    input_streams::InputStreams,
+    user_input::UserInput,
+};
 
 use bevy::ecs::{component::Component, entity::Entity};
+use bevy::input::gamepad::Gamepad;
 use bevy::prelude::Resource;
-use bevy::reflect::{FromReflect, Reflect};
-use bevy::utils::{Duration, Instant};
-use serde::{Deserialize, Serialize};
+use bevy::reflect::{FromReflect, Reflect, ReflectRef};
+use bevy::utils::{Duration, FloatOrd, Instant};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
+use std::hash::Hash;
 use std::marker::PhantomData;
 
 /// Metadata about an [`Actionlike`] action
@@ -24,17 +36,85 @@ pub struct ActionData {
     /// **Warning:** this value may not be bounded as you might expect.
     /// Consider clamping this to account for multiple triggering inputs.
     pub value: f32,
+    /// The [`ActionData::value`] during the previous update.
+    ///
+    /// Used to compute [`ActionState::value_interpolated()`].
+    pub previous_value: f32,
     /// The [`AxisPair`] of the binding that triggered the action.
     ///
     /// See [`ActionState::action_axis_pair()`] for more details.
     pub axis_pair: Option<DualAxisData>,
+    /// The [`DualAxisData`] of the binding that triggered the action during the previous update.
+    ///
+    /// Used to compute [`ActionState::axis_delta()`] for [`AxisSemantics::Absolute`] inputs.
+    pub previous_axis_pair: Option<DualAxisData>,
+    /// Does the [`DualAxisData`] of this action report a position or a change in position?
+    ///
+    /// See [`ActionState::axis_semantics()`] for more details.
+    pub axis_semantics: AxisSemantics,
     /// When was the button pressed / released, and how long has it been held for?
     pub timing: Timing,
+    /// How long the action must be held before it is pressed, while it is only held by [`BindingTrigger::Hold`](crate::input_map::BindingTrigger::Hold) bindings
+    ///
+    /// See [`ActionState::hold_progress()`] for more details.
+    pub hold_requirement: Option<Duration>,
+    /// How long the action has been held towards its [`ActionData::hold_requirement`]
+    pub hold_elapsed: Duration,
+    /// How often the action is pressed, while it is only held by [`BindingTrigger::Turbo`](crate::input_map::BindingTrigger::Turbo) bindings
+    pub turbo_period: Option<Duration>,
+    /// How long it has been since the last press towards the [`ActionData::turbo_period`]
+    pub turbo_elapsed: Duration,
     /// Was this action consumed by [`ActionState::consume`]?
     ///
-    /// Actions that are consumed cannot be pressed again until they are explicitly released.
+    /// Actions that are consumed cannot be pressed again until they are explicitly released,
+    /// or as otherwise specified by their [`ActionData::consumed_until`].
     /// This ensures that consumed actions are not immediately re-pressed by continued inputs.
     pub consumed: bool,
+    /// When the current consumption of this action ends
+    ///
+    /// This is set by [`ActionState::consume`] and [`ActionState::consume_with`], and is only meaningful while [`ActionData::consumed`] is `true`.
+    pub consumed_until: ConsumeRelease,
+    /// How long this action has been consumed for, used by [`ConsumeRelease::For`]
+    pub consumed_elapsed: Duration,
+    /// When this action can be pressed again after being consumed by [`ActionState::consume`]
+    ///
+    /// See [`ActionState::set_consume_release`] for more details.
+    pub consume_release: ConsumeRelease,
+    /// The order in which this action was pressed, relative to the other actions pressed since the last tick
+    ///
+    /// See [`ActionState::press_order()`] for more details.
+    pub press_order: Option<usize>,
+    /// The first binding that pressed this action during the most recent update, with any [`Wildcard`](crate::user_input::Wildcard)s resolved
+    ///
+    /// See [`ActionState::triggering_input()`] for more details.
+    #[reflect(ignore)]
+    pub triggering_input: Option<UserInput>,
+    /// The devices that held this action pressed during the most recent update
+    ///
+    /// See [`ActionState::sources()`] for more details.
+    #[reflect(ignore)]
+    pub sources: Vec<(InputDevice, Option<Gamepad>)>,
+}
+
+/// When an action that was [consumed](ActionState::consume) can be pressed again
+///
+/// Different layering schemes need different semantics: a menu that closes on `Escape` wants the key
+/// to stay consumed until it is let go, while a system that merely peeks at an action may only want to hide it for the rest of the frame.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Reflect, FromReflect,
+)]
+pub enum ConsumeRelease {
+    /// The action stays consumed until it is released, typically once its inputs are physically released
+    #[default]
+    UntilReleased,
+    /// The action stays consumed until the next [tick](ActionState::tick)
+    ///
+    /// If its inputs are still held, the next update presses it again, and it becomes [`just_pressed`](ActionState::just_pressed) once more.
+    EndOfFrame,
+    /// The action stays consumed for the given duration of ticked time
+    ///
+    /// Releasing the action does not end the consumption early, so inputs pressed again during this time are ignored.
+    For(Duration),
 }
 
 /// Stores the canonical input-method-agnostic representation of the inputs received
@@ -81,14 +161,37 @@ pub struct ActionData {
 /// assert!(action_state.released(Action::Jump));
 /// assert!(!action_state.just_released(Action::Jump));
 /// ```
-#[derive(
-    Resource, Component, Clone, Debug, PartialEq, Serialize, Deserialize, Reflect, FromReflect,
-)]
+#[derive(Resource, Component, Clone, Debug, PartialEq, Serialize, Reflect)]
 pub struct ActionState<A: Actionlike> {
     /// The [`ActionData`] of each action
     ///
     /// The position in this vector corresponds to [`Actionlike::index`].
     action_data: Vec<ActionData>,
+    /// The actions that are currently pressed
+    ///
+    /// This and the following sets mirror the [`ButtonState`] of each [`ActionData`],
+    /// and are kept up to date by every method that changes those states,
+    /// so that bulk queries such as [`ActionState::any_pressed`] do not need to check every action.
+    #[serde(skip)]
+    #[reflect(ignore)]
+    pressed: ActionSet<A>,
+    /// The actions that were pressed since the last tick
+    #[serde(skip)]
+    #[reflect(ignore)]
+    just_pressed: ActionSet<A>,
+    /// The actions that were released since the last tick
+    #[serde(skip)]
+    #[reflect(ignore)]
+    just_released: ActionSet<A>,
+    /// The number of actions that have been pressed since the last tick
+    ///
+    /// Used to assign each [`ActionData::press_order`].
+    presses_this_tick: usize,
+    /// The number of times [`ActionState::tick`] has been called
+    tick_count: u64,
+    /// How many ticks late the inputs of this [`ActionState`] arrive, such as for remote players over a network
+    #[serde(default)]
+    latency_ticks: u64,
     #[reflect(ignore)]
     _phantom: PhantomData<A>,
 }
@@ -98,20 +201,92 @@ impl<A: Actionlike> ActionState<A> {
     ///
     /// The `action_data` is typically constructed from [`InputMap::which_pressed`](crate::input_map::InputMap),
     /// which reads from the assorted [`Input`](bevy::input::Input) resources.
+    ///
+    /// Actions with a [`ActionData::hold_requirement`] stay released until they have been held long enough,
+    /// and actions with a [`ActionData::turbo_period`] are pressed for a single update once per period.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `action_data` does not contain exactly [`Actionlike::N_VARIANTS`] entries.
+    /// Use [`ActionState::try_update`] to handle this case instead.
     pub fn update(&mut self, action_data: Vec<ActionData>) {
-        assert_eq!(action_data.len(), A::n_variants());
+        assert_eq!(action_data.len(), A::N_VARIANTS);
 
         for (i, action) in A::variants().enumerate() {
-            match action_data[i].state {
-                ButtonState::JustPressed => self.press(action),
-                ButtonState::Pressed => self.press(action),
-                ButtonState::JustReleased => self.release(action),
-                ButtonState::Released => self.release(action),
+            let held = action_data[i].state.pressed();
+            let mut pending = false;
+            match action_data[i].hold_requirement.filter(|_| held) {
+                Some(required) => {
+                    // A new hold starts from scratch
+                    if self.action_data[i].hold_requirement.is_none() {
+                        self.action_data[i].hold_elapsed = Duration::ZERO;
+                    }
+                    self.action_data[i].hold_requirement = Some(required);
+                    pending = self.action_data[i].hold_elapsed < required;
+                }
+                None => {
+                    self.action_data[i].hold_requirement = None;
+                    self.action_data[i].hold_elapsed = Duration::ZERO;
+                }
+            }
+
+            match action_data[i].turbo_period.filter(|_| held) {
+                Some(period) => {
+                    let turbo = &mut self.action_data[i];
+                    let was_pressed = turbo.state.pressed();
+                    // The first pulse happens as soon as the action is held
+                    if turbo.turbo_period.is_none() {
+                        turbo.turbo_elapsed = period;
+                    }
+                    turbo.turbo_period = Some(period);
+
+                    // Each pulse lasts a single update, so that the next one is a fresh press
+                    pending = was_pressed || turbo.turbo_elapsed < period;
+                    if !pending {
+                        turbo.turbo_elapsed = match period.as_nanos() {
+                            0 => Duration::ZERO,
+                            nanos => Duration::from_nanos(
+                                (turbo.turbo_elapsed.as_nanos() % nanos) as u64,
+                            ),
+                        };
+                    }
+                }
+                None => {
+                    self.action_data[i].turbo_period = None;
+                    self.action_data[i].turbo_elapsed = Duration::ZERO;
+                }
+            }
+
+            if held && !pending {
+                self.press(action);
+            } else {
+                self.release(action);
             }
 
+            self.action_data[i].previous_axis_pair = self.action_data[i].axis_pair;
             self.action_data[i].axis_pair = action_data[i].axis_pair;
-            self.action_data[i].value = action_data[i].value;
+            self.action_data[i].axis_semantics = action_data[i].axis_semantics;
+            self.action_data[i].previous_value = self.action_data[i].value;
+            self.action_data[i].value = if pending { 0.0 } else { action_data[i].value };
+            self.action_data[i].triggering_input = action_data[i].triggering_input.clone();
+            self.action_data[i].sources = action_data[i].sources.clone();
+        }
+    }
+
+    /// Attempts to update the [`ActionState`] based on a vector of [`ActionData`], ordered by [`Actionlike::id`](Actionlike)
+    ///
+    /// Behaves like [`ActionState::update`], but returns [`InputManagerError::ActionCountMismatch`]
+    /// and leaves the [`ActionState`] unchanged if `action_data` does not contain exactly one entry per action.
+    pub fn try_update(&mut self, action_data: Vec<ActionData>) -> Result<(), InputManagerError> {
+        if action_data.len() != A::N_VARIANTS {
+            return Err(InputManagerError::ActionCountMismatch {
+                expected: A::N_VARIANTS,
+                found: action_data.len(),
+            });
         }
+
+        self.update(action_data);
+        Ok(())
     }
 
     /// Advances the time for all actions
@@ -158,16 +333,75 @@ impl<A: Actionlike> ActionState<A> {
     /// assert!(!action_state.just_pressed(Action::Jump));
     /// ```
     pub fn tick(&mut self, current_instant: Instant, previous_instant: Instant) {
+        self.advance(current_instant, previous_instant, false);
+    }
+
+    /// Advances the [`ButtonState`]s like [`ActionState::tick`], but holds every duration in place
+    ///
+    /// Pressed actions stay pressed, and their timers resume from where they left off
+    /// once [`ActionState::tick`] is called again.
+    /// This is used while [`PauseTiming<A>`](crate::plugin::PauseTiming) is paused.
+    pub fn tick_paused(&mut self, current_instant: Instant, previous_instant: Instant) {
+        self.advance(current_instant, previous_instant, true);
+    }
+
+    /// Shared implementation of [`ActionState::tick`] and [`ActionState::tick_paused`]
+    fn advance(&mut self, current_instant: Instant, previous_instant: Instant, paused: bool) {
         // Advanced the ButtonState
         self.action_data.iter_mut().for_each(|ad| ad.state.tick());
+        self.just_pressed.clear();
+        self.just_released.clear();
+
+        // Presses in the next tick are ordered from scratch
+        self.presses_this_tick = 0;
+        self.action_data
+            .iter_mut()
+            .for_each(|ad| ad.press_order = None);
 
         // Advance the Timings
         self.action_data.iter_mut().for_each(|ad| {
-            // Durations should not advance while actions are consumed
-            if !ad.consumed {
+            let changed = ad.timing.instant_started.is_none();
+
+            // Durations should not advance while actions are consumed or timing is paused
+            if paused {
+                ad.timing.pause(current_instant, previous_instant);
+            } else if !ad.consumed {
                 ad.timing.tick(current_instant, previous_instant);
             }
+
+            if !paused {
+                let delta = current_instant.saturating_duration_since(previous_instant);
+                if ad.hold_requirement.is_some() {
+                    ad.hold_elapsed += delta;
+                }
+                if ad.turbo_period.is_some() {
+                    ad.turbo_elapsed += delta;
+                }
+                if ad.consumed {
+                    ad.consumed_elapsed += delta;
+                }
+            }
+
+            ad.consumed &= match ad.consumed_until {
+                ConsumeRelease::UntilReleased => true,
+                ConsumeRelease::EndOfFrame => false,
+                ConsumeRelease::For(duration) => ad.consumed_elapsed < duration,
+            };
+
+            // Transitions are timestamped with the start of the frame in which they occurred,
+            // matching `Timing::instant_started`
+            if changed {
+                if ad.state.pressed() {
+                    if ad.timing.last_pressed_tick.is_some() {
+                        ad.timing.last_pressed_instant = Some(previous_instant);
+                    }
+                } else if ad.timing.last_released_tick.is_some() {
+                    ad.timing.last_released_instant = Some(previous_instant);
+                }
+            }
         });
+
+        self.tick_count += 1;
     }
 
     /// A reference to the [`ActionData`] of the corresponding `action`
@@ -200,6 +434,10 @@ impl<A: Actionlike> ActionState<A> {
     /// Generally, it'll be clearer to call `pressed` or so on directly on the [`ActionState`].
     /// However, accessing the raw data directly allows you to examine detailed metadata holistically.
     ///
+    /// The [`ActionData::state`] should not be modified through this reference:
+    /// use [`ActionState::press`], [`ActionState::release`] or [`ActionState::set_action_data`] instead,
+    /// which keep the sets of pressed actions up to date.
+    ///
     /// # Example
     /// ```rust
     /// use leafwing_input_manager::prelude::*;
@@ -221,6 +459,37 @@ impl<A: Actionlike> ActionState<A> {
         &mut self.action_data[action.index()]
     }
 
+    /// The [`ActionData`] of every action, ordered by [`Actionlike::index`]
+    ///
+    /// This allows advanced consumers, such as netcode or AI controllers, to inspect or snapshot the complete state at once.
+    /// Individual entries can be restored with [`ActionState::set_action_data`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Run,
+    ///     Jump,
+    /// }
+    /// let mut action_state = ActionState::<Action>::default();
+    /// action_state.press(Action::Jump);
+    ///
+    /// let snapshot = action_state.all_action_data().to_vec();
+    /// action_state.release(Action::Jump);
+    ///
+    /// for (action, action_data) in Action::variants().zip(snapshot) {
+    ///     action_state.set_action_data(action, action_data);
+    /// }
+    /// assert!(action_state.pressed(Action::Jump));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn all_action_data(&self) -> &[ActionData] {
+        &self.action_data
+    }
+
     /// Get the value associated with the corresponding `action`
     ///
     /// Different kinds of bindings have different ways of calculating the value:
@@ -233,6 +502,8 @@ impl<A: Actionlike> ActionState<A> {
     /// triggers which may be tracked as buttons or axes. Examples of these include the Xbox LT/RT
     /// triggers and the Playstation L2/R2 triggers. See also the `axis_inputs` example in the
     /// repository.
+    /// - Pressure-sensitive face buttons, where the gamepad backend reports them, return how far they are pressed.
+    /// On digital-only gamepads, they fall back to `0.0` and `1.0`.
     /// - Dual axis inputs will return the magnitude of its [`AxisPair`] and will be in the range
     /// `0.0..=1.0`.
     /// - Chord inputs will return the value of its first input.
@@ -280,6 +551,79 @@ impl<A: Actionlike> ActionState<A> {
             .map(|pair| DualAxisData::new(pair.x().clamp(-1.0, 1.0), pair.y().clamp(-1.0, 1.0)))
     }
 
+    /// Does the [`axis_pair`](Self::axis_pair) of the corresponding `action` report a position or a change in position?
+    ///
+    /// Actions are [`AxisSemantics::Relative`] when every binding that provides an axis pair is relative,
+    /// such as [`DualAxis::mouse_motion`](crate::axislike::DualAxis::mouse_motion).
+    /// Otherwise, they are [`AxisSemantics::Absolute`].
+    ///
+    /// For relative actions, [`axis_pair`](Self::axis_pair) returns the movement since the last update,
+    /// and must be integrated to recover a position.
+    /// Use [`axis_delta`](Self::axis_delta) to read a change in position regardless of the bound inputs.
+    pub fn axis_semantics(&self, action: A) -> AxisSemantics {
+        self.action_data(action).axis_semantics
+    }
+
+    /// Get the change in the [`DualAxisData`] of the corresponding `action` since the last update.
+    ///
+    /// For [`AxisSemantics::Relative`] actions (such as mouse motion), this is the same as [`axis_pair`](Self::axis_pair).
+    /// For [`AxisSemantics::Absolute`] actions (such as gamepad sticks and the cursor position),
+    /// this is the difference between the current and previous axis pair.
+    ///
+    /// Returns [`None`] if the action has no axis pair.
+    pub fn axis_delta(&self, action: A) -> Option<DualAxisData> {
+        let action_data = self.action_data(action);
+        let current = action_data.axis_pair?;
+
+        match action_data.axis_semantics {
+            AxisSemantics::Relative => Some(current),
+            AxisSemantics::Absolute => {
+                let previous = action_data.previous_axis_pair.unwrap_or_default();
+                Some(DualAxisData::from_xy(current.xy() - previous.xy()))
+            }
+        }
+    }
+
+    /// Get the value of the corresponding `action`, blended between the previous and the current update.
+    ///
+    /// When input is processed in a fixed timestep but read by systems that run every frame, such as cameras,
+    /// reading [`value`](Self::value) directly makes motion judder whenever the two rates don't line up.
+    /// Pass the fraction of a timestep that has elapsed since the last fixed update
+    /// (as reported by [`FixedTimestepState::overstep_percentage`](bevy::time::FixedTimestepState::overstep_percentage)):
+    /// `0.0` returns the previous value and `1.0` the current one.
+    /// Fractions above `1.0` extrapolate past the current value, trading latency for the risk of overshooting.
+    #[must_use]
+    pub fn value_interpolated(&self, action: A, overstep_fraction: f32) -> f32 {
+        let action_data = self.action_data(action);
+        lerp(
+            action_data.previous_value,
+            action_data.value,
+            overstep_fraction,
+        )
+    }
+
+    /// Get the [`DualAxisData`] of the corresponding `action`, blended between the previous and the current update.
+    ///
+    /// See [`value_interpolated`](Self::value_interpolated) for the meaning of `overstep_fraction`.
+    /// If the action had no axis pair during the previous update, it is treated as centered.
+    ///
+    /// Returns [`None`] if the action has no axis pair.
+    #[must_use]
+    pub fn axis_pair_interpolated(
+        &self,
+        action: A,
+        overstep_fraction: f32,
+    ) -> Option<DualAxisData> {
+        let action_data = self.action_data(action);
+        let current = action_data.axis_pair?;
+        let previous = action_data.previous_axis_pair.unwrap_or_default();
+
+        Some(DualAxisData::new(
+            lerp(previous.x(), current.x(), overstep_fraction),
+            lerp(previous.y(), current.y(), overstep_fraction),
+        ))
+    }
+
     /// Manually sets the [`ActionData`] of the corresponding `action`
     ///
     /// You should almost always use more direct methods, as they are simpler and less error-prone.
@@ -316,6 +660,22 @@ impl<A: Actionlike> ActionState<A> {
     #[inline]
     pub fn set_action_data(&mut self, action: A, data: ActionData) {
         self.action_data[action.index()] = data;
+        self.sync_action_sets(action);
+    }
+
+    /// Updates the sets of pressed, just pressed and just released actions to match the [`ButtonState`] of the `action`
+    fn sync_action_sets(&mut self, action: A) {
+        let state = self.action_data[action.index()].state;
+        self.pressed.set(action.clone(), state.pressed());
+        self.just_pressed.set(action.clone(), state.just_pressed());
+        self.just_released.set(action, state.just_released());
+    }
+
+    /// Rebuilds the sets of pressed, just pressed and just released actions from the [`ButtonState`] of every action
+    fn sync_all_action_sets(&mut self) {
+        for action in A::variants() {
+            self.sync_action_sets(action);
+        }
     }
 
     /// Press the `action`
@@ -330,11 +690,15 @@ impl<A: Actionlike> ActionState<A> {
             return;
         }
 
-        if self.released(action) {
+        if self.released(action.clone()) {
             self.action_data[index].timing.flip();
+            self.action_data[index].press_order = Some(self.presses_this_tick);
+            self.action_data[index].timing.last_pressed_tick = Some(self.tick_count);
+            self.presses_this_tick += 1;
         }
 
         self.action_data[index].state.press();
+        self.sync_action_sets(action);
     }
 
     /// Release the `action`
@@ -345,13 +709,17 @@ impl<A: Actionlike> ActionState<A> {
     pub fn release(&mut self, action: A) {
         let index = action.index();
         // Once released, consumed actions can be pressed again
-        self.action_data[index].consumed = false;
+        if self.action_data[index].consumed_until == ConsumeRelease::UntilReleased {
+            self.action_data[index].consumed = false;
+        }
 
-        if self.pressed(action) {
+        if self.pressed(action.clone()) {
             self.action_data[index].timing.flip();
+            self.action_data[index].timing.last_released_tick = Some(self.tick_count);
         }
 
         self.action_data[index].state.release();
+        self.sync_action_sets(action);
     }
 
     /// Consumes the `action`
@@ -359,6 +727,8 @@ impl<A: Actionlike> ActionState<A> {
     /// The action will be released, and will not be able to be pressed again
     /// until it would have otherwise been released by [`ActionState::release`],
     /// [`ActionState::release_all`] or [`ActionState::update`].
+    /// This can be changed for each action with [`ActionState::set_consume_release`],
+    /// or for a single call with [`ActionState::consume_with`].
     ///
     /// No initial instant will be recorded
     /// Instead, this is set through [`ActionState::tick()`]
@@ -394,20 +764,365 @@ impl<A: Actionlike> ActionState<A> {
     /// ```
     #[inline]
     pub fn consume(&mut self, action: A) {
+        let consume_release = self.action_data[action.index()].consume_release;
+        self.consume_with(action, consume_release);
+    }
+
+    /// Consumes the `action`, choosing when it can be pressed again with `consume_release`
+    ///
+    /// This overrides the [`ConsumeRelease`] set by [`ActionState::set_consume_release`] for this call only.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::utils::Instant;
+    /// use leafwing_input_manager::action_state::ConsumeRelease;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Confirm,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// action_state.press(Action::Confirm);
+    ///
+    /// // Hide the press from the systems that run later this frame
+    /// action_state.consume_with(Action::Confirm, ConsumeRelease::EndOfFrame);
+    /// action_state.press(Action::Confirm);
+    /// assert!(action_state.released(Action::Confirm));
+    ///
+    /// // Once ticked, the held input presses the action again
+    /// let now = Instant::now();
+    /// action_state.tick(now, now);
+    /// action_state.press(Action::Confirm);
+    /// assert!(action_state.just_pressed(Action::Confirm));
+    /// ```
+    pub fn consume_with(&mut self, action: A, consume_release: ConsumeRelease) {
         let index = action.index();
         // This is the only difference from action_state.release(action)
         self.action_data[index].consumed = true;
+        self.action_data[index].consumed_until = consume_release;
+        self.action_data[index].consumed_elapsed = Duration::ZERO;
         self.action_data[index].state.release();
         self.action_data[index].timing.flip();
+        self.action_data[index].timing.last_released_tick = Some(self.tick_count);
+        self.sync_action_sets(action);
+    }
+
+    /// Sets when the `action` can be pressed again after it is consumed by [`ActionState::consume`]
+    ///
+    /// Defaults to [`ConsumeRelease::UntilReleased`].
+    /// This setting is kept by [`ActionState::restore`].
+    pub fn set_consume_release(&mut self, action: A, consume_release: ConsumeRelease) {
+        self.action_data[action.index()].consume_release = consume_release;
+    }
+
+    /// When the `action` can be pressed again after it is consumed by [`ActionState::consume`]
+    #[must_use]
+    pub fn consume_release(&self, action: A) -> ConsumeRelease {
+        self.action_data[action.index()].consume_release
+    }
+
+    /// The order in which the `action` was pressed, relative to the other actions that were just pressed
+    ///
+    /// The first action to be pressed since the last [`ActionState::tick`] has a press order of `0`,
+    /// the next `1` and so on, which allows simultaneous actions (such as blocking and attacking) to be resolved deterministically.
+    /// Actions pressed by [`ActionState::update`] are ordered by their [`Actionlike::index`],
+    /// followed by any actions that are pressed manually afterwards.
+    ///
+    /// Returns [`None`] if the action was not just pressed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Attack,
+    ///     Block,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// action_state.press(Action::Block);
+    /// action_state.press(Action::Attack);
+    ///
+    /// assert_eq!(action_state.press_order(Action::Block), Some(0));
+    /// assert_eq!(action_state.press_order(Action::Attack), Some(1));
+    /// ```
+    #[must_use]
+    pub fn press_order(&self, action: A) -> Option<usize> {
+        if self.just_pressed(action.clone()) {
+            self.action_data[action.index()].press_order
+        } else {
+            None
+        }
+    }
+
+    /// The input that pressed the `action` during the most recent [`ActionState::update`]
+    ///
+    /// If several bindings of the `action` were pressed, the first one in the [`InputMap`](crate::input_map::InputMap) is reported.
+    /// Any [`Wildcard`](crate::user_input::Wildcard)s in that binding are replaced with the concrete inputs that matched them,
+    /// so that (for example) a binding to [`Wildcard::AnyKey`](crate::user_input::Wildcard::AnyKey) reports which key was pressed.
+    ///
+    /// Returns [`None`] if the action is released, or was only pressed manually.
+    #[must_use]
+    pub fn triggering_input(&self, action: A) -> Option<&UserInput> {
+        if self.pressed(action.clone()) {
+            self.action_data[action.index()].triggering_input.as_ref()
+        } else {
+            None
+        }
+    }
+
+    /// The devices that held the `action` pressed during the most recent [`ActionState::update`]
+    ///
+    /// Every pressed binding of the `action` contributes the devices of its pressed inputs,
+    /// so an action bound to both `Space` and a gamepad's `South` button reports both devices while both are held.
+    /// Each device is paired with the specific [`Gamepad`] its inputs were read from, or [`None`] for the keyboard and mouse.
+    ///
+    /// This is useful for restricting cross-play matchmaking by input method, or for recording which devices players use.
+    /// Returns an empty slice if the action is released, or was only pressed manually.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::input::InputPlugin;
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::input_streams::InputStreams;
+    /// use leafwing_input_manager::prelude::*;
+    /// use leafwing_input_manager::inhibit_input::InputDevice;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Jump,
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugin(InputPlugin);
+    /// app.send_input(KeyCode::Space);
+    /// app.update();
+    ///
+    /// let input_map = InputMap::new([(KeyCode::Space, Action::Jump)]);
+    /// let input_streams = InputStreams::from_world(&app.world, None);
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// action_state.update(input_map.which_pressed(&input_streams, ClashStrategy::PressAll));
+    /// assert_eq!(action_state.sources(Action::Jump), [(InputDevice::Keyboard, None)]);
+    /// ```
+    #[must_use]
+    pub fn sources(&self, action: A) -> &[(InputDevice, Option<Gamepad>)] {
+        if self.pressed(action.clone()) {
+            &self.action_data[action.index()].sources
+        } else {
+            &[]
+        }
+    }
+
+    /// Computes the [`ActionDiff`]s that transform the `previous` [`ActionState`] into this one
+    ///
+    /// An [`ActionDiff::Pressed`] is generated for each action that is pressed now but was released in `previous`,
+    /// and an [`ActionDiff::Released`] for each action that was pressed in `previous` but is released now.
+    /// These are followed by an [`ActionDiff::ValueChanged`] for each action whose [`value`](Self::value) differs,
+    /// and an [`ActionDiff::AxisPairChanged`] for each action whose [`axis_pair`](Self::axis_pair) differs.
+    /// Each diff is tagged with the provided `id`.
+    ///
+    /// Applying the resulting diffs to `previous` with [`ActionState::apply_diff`]
+    /// will cause its actions to be pressed and released exactly as in this [`ActionState`],
+    /// with the same values and axis pairs.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Run,
+    ///     Jump,
+    /// }
+    ///
+    /// #[derive(Component, Clone, PartialEq, Eq, Debug)]
+    /// struct PlayerId(u32);
+    ///
+    /// let previous = ActionState::<Action>::default();
+    /// let mut current = previous.clone();
+    /// current.press(Action::Jump);
+    ///
+    /// let mut replica = previous.clone();
+    /// for diff in current.diff_since(&previous, &PlayerId(0)) {
+    ///     replica.apply_diff(&diff);
+    /// }
+    ///
+    /// assert!(replica.pressed(Action::Jump));
+    /// assert!(replica.released(Action::Run));
+    /// ```
+    #[must_use]
+    pub fn diff_since<ID: Eq + Clone + Component>(
+        &self,
+        previous: &ActionState<A>,
+        id: &ID,
+    ) -> Vec<ActionDiff<A, ID>> {
+        let pressed = self.pressed_set();
+
+        let mut diffs: Vec<ActionDiff<A, ID>> = pressed
+            .symmetric_difference(&previous.pressed_set())
+            .iter()
+            .map(|action| {
+                if pressed.contains(action.clone()) {
+                    ActionDiff::Pressed {
+                        action,
+                        id: id.clone(),
+                    }
+                } else {
+                    ActionDiff::Released {
+                        action,
+                        id: id.clone(),
+                    }
+                }
+            })
+            .collect();
+
+        for action in A::variants() {
+            let action_data = self.action_data(action.clone());
+            let previous_data = previous.action_data(action.clone());
+
+            if FloatOrd(action_data.value) != FloatOrd(previous_data.value) {
+                diffs.push(ActionDiff::ValueChanged {
+                    action: action.clone(),
+                    id: id.clone(),
+                    value: action_data.value,
+                });
+            }
+
+            if action_data.axis_pair != previous_data.axis_pair {
+                diffs.push(ActionDiff::AxisPairChanged {
+                    action,
+                    id: id.clone(),
+                    axis_pair: action_data.axis_pair,
+                });
+            }
+        }
+
+        diffs
+    }
+
+    /// Applies the provided [`ActionDiff`] to this [`ActionState`], pressing or releasing the corresponding action,
+    /// or setting its value or axis pair
+    ///
+    /// The `id` of the diff is ignored: check it before applying diffs if they might belong to another entity.
+    /// This is the inverse of [`ActionState::diff_since`].
+    pub fn apply_diff<ID: Eq + Clone + Component>(&mut self, diff: &ActionDiff<A, ID>) {
+        match diff {
+            ActionDiff::Pressed { action, .. } => self.press(action.clone()),
+            ActionDiff::Released { action, .. } => self.release(action.clone()),
+            ActionDiff::ValueChanged { action, value, .. } => {
+                self.action_data[action.index()].value = *value;
+            }
+            ActionDiff::AxisPairChanged {
+                action, axis_pair, ..
+            } => {
+                self.action_data[action.index()].axis_pair = *axis_pair;
+            }
+        }
     }
 
     /// Releases all actions
+    ///
+    /// Actions that were pressed become [`just_released`](ActionState::just_released),
+    /// while actions that were already released are left untouched.
+    /// As with [`ActionState::release`], consumed actions can be pressed again afterwards.
+    ///
+    /// This is useful when opening a pause menu or when the player dies,
+    /// so that no action remains held through the transition.
     pub fn release_all(&mut self) {
         for action in A::variants() {
             self.release(action);
         }
     }
 
+    /// Reconciles the held actions with a `new_map` that is replacing the previous [`InputMap`]
+    ///
+    /// Call this when swapping the [`InputMap`] at runtime, such as when switching control schemes or entering a vehicle:
+    /// - actions that are still bound to a physically held input stay pressed, without becoming [`just_pressed`](ActionState::just_pressed) again
+    /// - actions that are no longer held under the `new_map` become [`just_released`](ActionState::just_released)
+    /// - actions that are only held because the `new_map` binds them to an input that was already down are [consumed](ActionState::consume),
+    ///   so that holding a key through the swap does not fire a newly bound action until it is pressed again
+    ///
+    /// The `new_map`'s associated gamepad and window are respected,
+    /// and clashes are resolved with the default [`ClashStrategy`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::input::InputPlugin;
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::input_streams::InputStreams;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Walk,
+    ///     Drive,
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugin(InputPlugin);
+    /// app.send_input(KeyCode::W);
+    /// app.update();
+    /// let input_streams = InputStreams::from_world(&app.world, None);
+    ///
+    /// let on_foot = InputMap::new([(KeyCode::W, Action::Walk)]);
+    /// let mut action_state = ActionState::<Action>::default();
+    /// action_state.update(on_foot.which_pressed(&input_streams, ClashStrategy::PressAll));
+    ///
+    /// // Entering a vehicle while holding W does not start driving until W is pressed again
+    /// let in_vehicle = InputMap::new([(KeyCode::W, Action::Drive)]);
+    /// action_state.rebind_reconcile(&in_vehicle, &input_streams);
+    /// assert!(action_state.just_released(Action::Walk));
+    /// assert!(action_state.released(Action::Drive));
+    /// ```
+    pub fn rebind_reconcile(&mut self, new_map: &InputMap<A>, input_streams: &InputStreams) {
+        let action_data = new_map.process_data(input_streams, ClashStrategy::default());
+
+        for action in A::variants() {
+            let index = action.index();
+            if action_data[index].state.pressed() && self.released(action) {
+                self.action_data[index].consumed = true;
+                self.action_data[index].consumed_until = ConsumeRelease::UntilReleased;
+            }
+        }
+
+        self.update(action_data);
+    }
+
+    /// Presses all actions
+    ///
+    /// Actions that were released become [`just_pressed`](ActionState::just_pressed),
+    /// while actions that were already pressed are left untouched.
+    /// As with [`ActionState::press`], consumed actions remain released.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Run,
+    ///     Jump,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// action_state.press_all();
+    /// assert_eq!(action_state.get_just_pressed(), [Action::Run, Action::Jump]);
+    ///
+    /// action_state.release_all();
+    /// assert_eq!(action_state.get_just_released(), [Action::Run, Action::Jump]);
+    /// ```
+    pub fn press_all(&mut self) {
+        for action in A::variants() {
+            self.press(action);
+        }
+    }
+
     /// Is this `action` currently pressed?
     #[inline]
     #[must_use]
@@ -438,22 +1153,74 @@ impl<A: Actionlike> ActionState<A> {
         self.action_data[action.index()].state.just_released()
     }
 
+    /// Is any action currently pressed?
     #[must_use]
-    /// Which actions are currently pressed?
-    pub fn get_pressed(&self) -> Vec<A> {
-        A::variants().filter(|a| self.pressed(a.clone())).collect()
+    pub fn any_pressed(&self) -> bool {
+        !self.pressed.is_empty()
     }
 
+    /// Are all actions currently released?
+    ///
+    /// This is the opposite of [`ActionState::any_pressed`].
     #[must_use]
-    /// Which actions were just pressed?
-    pub fn get_just_pressed(&self) -> Vec<A> {
-        A::variants()
-            .filter(|a| self.just_pressed(a.clone()))
-            .collect()
+    pub fn none_pressed(&self) -> bool {
+        !self.any_pressed()
     }
 
+    /// The number of actions that are currently pressed
     #[must_use]
-    /// Which actions are currently released?
+    pub fn active_count(&self) -> usize {
+        self.pressed.len()
+    }
+
+    /// Was any action pressed since the last time [tick](ActionState::tick) was called?
+    #[must_use]
+    pub fn any_just_pressed(&self) -> bool {
+        !self.just_pressed.is_empty()
+    }
+
+    /// Was any action released since the last time [tick](ActionState::tick) was called?
+    #[must_use]
+    pub fn any_just_released(&self) -> bool {
+        !self.just_released.is_empty()
+    }
+
+    /// The set of actions that are currently pressed
+    ///
+    /// Unlike [`ActionState::get_pressed`], this does not allocate a [`Vec`] of actions,
+    /// and is cheap to clone and compare, such as to keep a history of inputs for rollback.
+    /// Use [`ActionSet::symmetric_difference`] to find the actions that changed between two sets.
+    #[must_use]
+    pub fn pressed_set(&self) -> ActionSet<A> {
+        self.pressed.clone()
+    }
+
+    /// The set of actions that were pressed since the last time [tick](ActionState::tick) was called
+    #[must_use]
+    pub fn just_pressed_set(&self) -> ActionSet<A> {
+        self.just_pressed.clone()
+    }
+
+    /// The set of actions that were released since the last time [tick](ActionState::tick) was called
+    #[must_use]
+    pub fn just_released_set(&self) -> ActionSet<A> {
+        self.just_released.clone()
+    }
+
+    #[must_use]
+    /// Which actions are currently pressed?
+    pub fn get_pressed(&self) -> Vec<A> {
+        self.pressed.iter().collect()
+    }
+
+    #[must_use]
+    /// Which actions were just pressed?
+    pub fn get_just_pressed(&self) -> Vec<A> {
+        self.just_pressed.iter().collect()
+    }
+
+    #[must_use]
+    /// Which actions are currently released?
     pub fn get_released(&self) -> Vec<A> {
         A::variants().filter(|a| self.released(a.clone())).collect()
     }
@@ -461,9 +1228,7 @@ impl<A: Actionlike> ActionState<A> {
     #[must_use]
     /// Which actions were just released?
     pub fn get_just_released(&self) -> Vec<A> {
-        A::variants()
-            .filter(|a| self.just_released(a.clone()))
-            .collect()
+        self.just_released.iter().collect()
     }
 
     /// The [`Instant`] that the action was last pressed or released
@@ -476,6 +1241,82 @@ impl<A: Actionlike> ActionState<A> {
         self.action_data[action.index()].timing.instant_started
     }
 
+    /// The [`Instant`] at which the `action` was last pressed
+    ///
+    /// Like [`ActionState::instant_started`], this is recorded as the start of the frame in which the action was pressed
+    /// (the `previous_instant` passed to the [`ActionState::tick`] that follows the press),
+    /// and will be [`None`] until [`ActionState::tick`] has been called following the first press.
+    #[must_use]
+    pub fn last_pressed(&self, action: A) -> Option<Instant> {
+        self.action_data[action.index()].timing.last_pressed_instant
+    }
+
+    /// The [`Instant`] at which the `action` was last released
+    ///
+    /// Like [`ActionState::instant_started`], this is recorded as the start of the frame in which the action was released
+    /// (the `previous_instant` passed to the [`ActionState::tick`] that follows the release),
+    /// and will be [`None`] until [`ActionState::tick`] has been called following the first release.
+    #[must_use]
+    pub fn last_released(&self, action: A) -> Option<Instant> {
+        self.action_data[action.index()]
+            .timing
+            .last_released_instant
+    }
+
+    /// The [`tick_count`](ActionState::tick_count) at which the `action` was last pressed, if it has ever been pressed
+    #[must_use]
+    pub fn last_pressed_tick(&self, action: A) -> Option<u64> {
+        self.action_data[action.index()].timing.last_pressed_tick
+    }
+
+    /// The [`tick_count`](ActionState::tick_count) at which the `action` was last released, if it has ever been released
+    #[must_use]
+    pub fn last_released_tick(&self, action: A) -> Option<u64> {
+        self.action_data[action.index()].timing.last_released_tick
+    }
+
+    /// The number of times [`ActionState::tick`] has been called on this [`ActionState`]
+    ///
+    /// When driven by the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin), this is the number of frames that have elapsed.
+    #[must_use]
+    pub fn tick_count(&self) -> u64 {
+        self.tick_count
+    }
+
+    /// How many ticks late the inputs of this [`ActionState`] arrive
+    ///
+    /// This is `0` for local players. For remote players, it should be set to their measured input delay,
+    /// so that [`ActionState::effective_pressed_tick`] reports when they actually pressed each action.
+    #[must_use]
+    pub fn latency_ticks(&self) -> u64 {
+        self.latency_ticks
+    }
+
+    /// Records that the inputs of this [`ActionState`] arrive `latency_ticks` late
+    ///
+    /// Presses and releases are still applied as soon as they arrive: this only changes the effective ticks that are reported for them.
+    pub fn set_latency_ticks(&mut self, latency_ticks: u64) {
+        self.latency_ticks = latency_ticks;
+    }
+
+    /// The [`tick_count`](ActionState::tick_count) at which the `action` was actually last pressed,
+    /// accounting for the [`latency_ticks`](ActionState::latency_ticks) of this [`ActionState`]
+    ///
+    /// Use this to rewind the simulation to the tick the press was intended for, when compensating for lag.
+    #[must_use]
+    pub fn effective_pressed_tick(&self, action: A) -> Option<u64> {
+        self.last_pressed_tick(action)
+            .map(|tick| tick.saturating_sub(self.latency_ticks))
+    }
+
+    /// The [`tick_count`](ActionState::tick_count) at which the `action` was actually last released,
+    /// accounting for the [`latency_ticks`](ActionState::latency_ticks) of this [`ActionState`]
+    #[must_use]
+    pub fn effective_released_tick(&self, action: A) -> Option<u64> {
+        self.last_released_tick(action)
+            .map(|tick| tick.saturating_sub(self.latency_ticks))
+    }
+
     /// The [`Duration`] for which the action has been held or released
     pub fn current_duration(&self, action: A) -> Duration {
         self.action_data[action.index()].timing.current_duration
@@ -488,17 +1329,273 @@ impl<A: Actionlike> ActionState<A> {
     pub fn previous_duration(&self, action: A) -> Duration {
         self.action_data[action.index()].timing.previous_duration
     }
+
+    /// How far the `action` is towards being pressed by a [`BindingTrigger::Hold`](crate::input_map::BindingTrigger::Hold) binding, from `0.0` to `1.0`
+    ///
+    /// This is intended for progress rings and bars on hold-to-confirm prompts.
+    /// Actions that are not being held by such a binding report `1.0` while pressed and `0.0` otherwise.
+    #[must_use]
+    pub fn hold_progress(&self, action: A) -> f32 {
+        let action_data = &self.action_data[action.index()];
+        match action_data.hold_requirement {
+            Some(required) if !required.is_zero() => {
+                (action_data.hold_elapsed.as_secs_f32() / required.as_secs_f32()).min(1.0)
+            }
+            _ if self.pressed(action) => 1.0,
+            _ => 0.0,
+        }
+    }
+
+    /// Captures the persistent state of every action, for save states, rewind mechanics and tool-assisted replays
+    ///
+    /// See [`ActionStateSnapshot`] for what is captured.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Charge,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// action_state.press(Action::Charge);
+    /// let snapshot = action_state.snapshot();
+    ///
+    /// action_state.release(Action::Charge);
+    /// action_state.restore(&snapshot);
+    /// assert!(action_state.pressed(Action::Charge));
+    /// // Restoring does not replay the press
+    /// assert!(!action_state.just_pressed(Action::Charge));
+    /// ```
+    #[must_use]
+    pub fn snapshot(&self) -> ActionStateSnapshot {
+        ActionStateSnapshot {
+            actions: self
+                .action_data
+                .iter()
+                .map(|action_data| ActionSnapshot {
+                    pressed: action_data.state.pressed(),
+                    value: action_data.value,
+                    axis_pair: action_data.axis_pair,
+                    current_duration: action_data.timing.current_duration,
+                    previous_duration: action_data.timing.previous_duration,
+                    consumed: action_data.consumed,
+                })
+                .collect(),
+            tick_count: self.tick_count,
+        }
+    }
+
+    /// Returns every action to the state captured by [`ActionState::snapshot`]
+    ///
+    /// Pressed actions are restored as [`ButtonState::Pressed`], and released actions as [`ButtonState::Released`],
+    /// so systems that wait for [`just_pressed`](Self::just_pressed) do not fire again after loading.
+    /// Everything that is not captured by the [`ActionStateSnapshot`] is reset,
+    /// and is refilled by the next update.
+    ///
+    /// Actions are matched by [`Actionlike::index`].
+    /// Actions missing from the snapshot, such as those added to the end of `A` after it was saved, are released,
+    /// and surplus actions in the snapshot are ignored.
+    pub fn restore(&mut self, snapshot: &ActionStateSnapshot) {
+        for (index, action_data) in self.action_data.iter_mut().enumerate() {
+            let action = snapshot.actions.get(index).cloned().unwrap_or_default();
+            *action_data = ActionData {
+                state: if action.pressed {
+                    ButtonState::Pressed
+                } else {
+                    ButtonState::Released
+                },
+                value: action.value,
+                previous_value: action.value,
+                axis_pair: action.axis_pair,
+                previous_axis_pair: action.axis_pair,
+                timing: Timing {
+                    current_duration: action.current_duration,
+                    previous_duration: action.previous_duration,
+                    ..Default::default()
+                },
+                consumed: action.consumed,
+                consume_release: action_data.consume_release,
+                ..Default::default()
+            };
+        }
+
+        self.sync_all_action_sets();
+        self.presses_this_tick = 0;
+        self.tick_count = snapshot.tick_count;
+    }
+}
+
+/// The persistent state of an [`ActionState`], captured by [`ActionState::snapshot`] and applied by [`ActionState::restore`]
+///
+/// Only the state that matters from one frame to the next is kept: whether each action is pressed,
+/// its value and axis pair, how long it has been in its current state, and whether it was consumed.
+/// Transient flags that only last for a single frame, such as [`ActionState::just_pressed`],
+/// and bookkeeping that is rebuilt by the next update, such as [`ActionState::triggering_input`], are left out.
+///
+/// The serialized format is stable across versions of this crate:
+/// new fields are only ever added with defaults, so older snapshots can always be restored.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ActionStateSnapshot {
+    /// The state of each action, indexed by [`Actionlike::index`]
+    pub actions: Vec<ActionSnapshot>,
+    /// The [`ActionState::tick_count`] when the snapshot was taken
+    pub tick_count: u64,
+}
+
+/// The persistent state of a single action, stored in an [`ActionStateSnapshot`]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ActionSnapshot {
+    /// Was the action pressed?
+    pub pressed: bool,
+    /// The [`ActionState::value`] of the action
+    pub value: f32,
+    /// The [`ActionState::axis_pair`] of the action
+    pub axis_pair: Option<DualAxisData>,
+    /// The [`ActionState::current_duration`] of the action
+    pub current_duration: Duration,
+    /// The [`ActionState::previous_duration`] of the action
+    pub previous_duration: Duration,
+    /// Was the action [consumed](ActionState::consume)?
+    pub consumed: bool,
+}
+
+/// Advances the `action_state` from `previous_instant` to `current_instant`, then updates it from the `input_streams`
+///
+/// This is the per-entity work that [`InputManagerPlugin`](crate::plugin::InputManagerPlugin) performs each frame
+/// in [`tick_action_state`](crate::systems::tick_action_state) and [`update_action_state`](crate::systems::update_action_state),
+/// exposed for custom runners, editor embeddings and other schedules that cannot rely on the plugin's stage placement.
+/// As with the plugin, the `action_state` is ticked before it is updated, so that inputs held since the previous call
+/// stay pressed without being [`just_pressed`](ActionState::just_pressed) again.
+///
+/// Only the bindings of the `input_map` are evaluated, with clashes resolved according to the `clash_strategy`.
+/// As with [`InputMap::process`], the associated gamepad and window of the `input_map` take precedence over those of the `input_streams`.
+/// Optional components handled by the plugin's systems, such as an [`InputMapOverlay`](crate::input_map::InputMapOverlay)
+/// or [`ComboButtons`](crate::combo_buttons::ComboButtons), must be applied by the caller.
+///
+/// # Example
+/// ```rust
+/// use bevy::input::InputPlugin;
+/// use bevy::prelude::*;
+/// use bevy::utils::{Duration, Instant};
+/// use leafwing_input_manager::action_state::tick_action_state;
+/// use leafwing_input_manager::input_streams::InputStreams;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Jump,
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugin(InputPlugin);
+/// app.send_input(KeyCode::Space);
+/// app.update();
+///
+/// let input_map = InputMap::new([(KeyCode::Space, Action::Jump)]);
+/// let mut action_state = ActionState::<Action>::default();
+/// let input_streams = InputStreams::from_world(&app.world, None);
+///
+/// let t0 = Instant::now();
+/// let t1 = t0 + Duration::from_millis(16);
+/// tick_action_state(&mut action_state, &input_map, &input_streams, ClashStrategy::default(), t1, t0);
+/// assert!(action_state.just_pressed(Action::Jump));
+///
+/// let t2 = t1 + Duration::from_millis(16);
+/// tick_action_state(&mut action_state, &input_map, &input_streams, ClashStrategy::default(), t2, t1);
+/// assert!(action_state.pressed(Action::Jump));
+/// assert!(!action_state.just_pressed(Action::Jump));
+/// ```
+pub fn tick_action_state<A: Actionlike>(
+    action_state: &mut ActionState<A>,
+    input_map: &InputMap<A>,
+    input_streams: &InputStreams,
+    clash_strategy: ClashStrategy,
+    current_instant: Instant,
+    previous_instant: Instant,
+) {
+    action_state.tick(current_instant, previous_instant);
+    action_state.update(input_map.process_data(input_streams, clash_strategy));
+}
+
+/// Linearly interpolates from `start` to `end`, extrapolating for fractions outside of `0.0..=1.0`
+fn lerp(start: f32, end: f32, fraction: f32) -> f32 {
+    start + (end - start) * fraction
 }
 
 impl<A: Actionlike> Default for ActionState<A> {
     fn default() -> ActionState<A> {
         ActionState {
             action_data: A::variants().map(|_| ActionData::default()).collect(),
+            pressed: ActionSet::default(),
+            just_pressed: ActionSet::default(),
+            just_released: ActionSet::default(),
+            presses_this_tick: 0,
+            tick_count: 0,
+            latency_ticks: 0,
             _phantom: PhantomData::default(),
         }
     }
 }
 
+/// The serialized fields of an [`ActionState`], from which its sets of actions are rebuilt
+#[derive(Deserialize)]
+#[serde(rename = "ActionState")]
+struct SerializedActionState {
+    action_data: Vec<ActionData>,
+    presses_this_tick: usize,
+    tick_count: u64,
+    #[serde(default)]
+    latency_ticks: u64,
+}
+
+impl<'de, A: Actionlike> Deserialize<'de> for ActionState<A> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let serialized = SerializedActionState::deserialize(deserializer)?;
+        if serialized.action_data.len() != A::N_VARIANTS {
+            return Err(D::Error::invalid_length(
+                serialized.action_data.len(),
+                &"one entry of action data per action",
+            ));
+        }
+
+        let mut action_state = ActionState {
+            action_data: serialized.action_data,
+            presses_this_tick: serialized.presses_this_tick,
+            tick_count: serialized.tick_count,
+            latency_ticks: serialized.latency_ticks,
+            ..Default::default()
+        };
+        action_state.sync_all_action_sets();
+
+        Ok(action_state)
+    }
+}
+
+// Deriving FromReflect would leave the reflect-ignored sets of actions empty
+impl<A: Actionlike> FromReflect for ActionState<A> {
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        let ReflectRef::Struct(reflect) = reflect.reflect_ref() else {
+            return None;
+        };
+
+        let mut action_state = ActionState {
+            action_data: FromReflect::from_reflect(reflect.field("action_data")?)?,
+            presses_this_tick: FromReflect::from_reflect(reflect.field("presses_this_tick")?)?,
+            tick_count: FromReflect::from_reflect(reflect.field("tick_count")?)?,
+            latency_ticks: FromReflect::from_reflect(reflect.field("latency_ticks")?)?,
+            ..Default::default()
+        };
+        action_state.sync_all_action_sets();
+
+        Some(action_state)
+    }
+}
+
 /// A component that allows the attached entity to drive the [`ActionState`] of the associated entity
 ///
 /// # Examples
@@ -529,24 +1626,258 @@ impl<A: Actionlike> Default for ActionState<A> {
 /// world
 ///     .spawn(ButtonBundle::default())
 ///     // This component links the button to the entity with the `ActionState` component
-///     .insert(ActionStateDriver {
-///         action: DanceDance::Left,
-///         entity: dance_tracker,
-///     });
+///     .insert(ActionStateDriver::new(DanceDance::Left, dance_tracker));
+///```
+///
+/// Drivers can also press a second action while the button is hovered,
+/// and forward a value or axis pair to their action, such as the position of a slider:
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy)]
+/// enum ShipAction {
+///     Throttle,
+///     HighlightThrottle,
+/// }
+///
+/// let mut world = World::new();
+/// let ship = world.spawn(ActionState::<ShipAction>::default()).id();
+///
+/// // Your slider logic should update the forwarded value as the slider moves
+/// world.spawn(ButtonBundle::default()).insert(
+///     ActionStateDriver::new(ShipAction::Throttle, ship)
+///         .with_hovered_action(ShipAction::HighlightThrottle)
+///         .with_value(0.5),
+/// );
 ///```
 ///
+/// To drive several actions of the same type from one entity, use [`ActionStateDrivers`] instead.
+///
 /// Writing your own systems that use the [`ActionStateDriver`] component is easy,
 /// although this should be reserved for cases where the entity whose value you want to check
 /// is distinct from the entity whose [`ActionState`] you want to set.
 /// Check the source code of [`update_action_state_from_interaction`](crate::systems::update_action_state_from_interaction) for an example of how this is done.
-#[derive(Component, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// Drivers on entities with a [`DisabledDriver`] component are ignored.
+#[derive(Component, Clone, Copy, PartialEq, Debug)]
 pub struct ActionStateDriver<A: Actionlike> {
     /// The action triggered by this entity
+    ///
+    /// By default, this action is pressed while the [`Interaction`](bevy::ui::Interaction) of this entity is `Clicked`.
+    /// This can be changed with the `activation`.
     pub action: A,
+    /// The action pressed while this entity is `Hovered`, if any
+    pub hovered_action: Option<A>,
+    /// The value forwarded to `action` each frame, if any
+    ///
+    /// When this is set, `action` is also pressed whenever the value is non-zero, regardless of the [`Interaction`](bevy::ui::Interaction).
+    pub value: Option<f32>,
+    /// The [`DualAxisData`] forwarded to `action` each frame, if any
+    ///
+    /// When this is set, `action` is also pressed whenever the axis pair is non-zero, regardless of the [`Interaction`](bevy::ui::Interaction).
+    pub axis_pair: Option<DualAxisData>,
+    /// When a clicked entity should press its `action`
+    pub press_mode: DriverPressMode,
+    /// Which [`Interaction`](bevy::ui::Interaction)s of this entity press its `action`
+    pub activation: DriverActivation,
     /// The entity whose action state should be updated
     pub entity: Entity,
 }
 
+/// Prevents the [`ActionStateDriver`]s and [`ActionStateDrivers`] of this entity from driving any actions
+///
+/// While this component is present, the drivers do not press any actions or forward any values.
+/// This is useful for widgets that can be disabled, or that should only drive actions in some states.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct DisabledDriver;
+
+/// Controls which [`Interaction`](bevy::ui::Interaction)s of an [`ActionStateDriver`] press its action
+///
+/// By default, only clicks press the action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DriverActivation {
+    /// Is the action pressed while the entity is `Clicked`?
+    ///
+    /// The [`DriverPressMode`] of the driver further controls whether a clicked entity presses the action.
+    pub clicked: bool,
+    /// Is the action pressed while the entity is `Hovered`?
+    pub hovered: bool,
+    /// Is the action pressed while the entity is not interacted with?
+    pub none: bool,
+}
+
+impl DriverActivation {
+    /// The action is pressed while the entity is clicked
+    pub const CLICKED: DriverActivation = DriverActivation {
+        clicked: true,
+        hovered: false,
+        none: false,
+    };
+
+    /// The action is pressed while the entity is hovered, but not clicked
+    pub const HOVERED: DriverActivation = DriverActivation {
+        clicked: false,
+        hovered: true,
+        none: false,
+    };
+
+    /// The action is pressed while the entity is either hovered or clicked
+    pub const CLICKED_OR_HOVERED: DriverActivation = DriverActivation {
+        clicked: true,
+        hovered: true,
+        none: false,
+    };
+}
+
+impl Default for DriverActivation {
+    fn default() -> Self {
+        Self::CLICKED
+    }
+}
+
+/// Controls when a clicked [`ActionStateDriver`] presses its action
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DriverPressMode {
+    /// The action is pressed for as long as the [`Interaction`](bevy::ui::Interaction) is `Clicked`
+    ///
+    /// Like `bevy_ui` itself, this keeps the action pressed while the pointer is held down,
+    /// even if the pointer is dragged off the node.
+    #[default]
+    WhileClicked,
+    /// The action is only pressed while the node is clicked and the pointer remains over the node
+    ///
+    /// Dragging the pointer off a clicked node releases the action, while dragging it back on presses it again.
+    /// Nodes without a [`Node`](bevy::ui::Node) and [`GlobalTransform`](bevy::transform::components::GlobalTransform) behave as in [`DriverPressMode::WhileClicked`].
+    WhilePointerOver,
+}
+
+impl<A: Actionlike> ActionStateDriver<A> {
+    /// Creates a driver that presses `action` in the [`ActionState`] of `entity` when clicked
+    #[must_use]
+    pub fn new(action: A, entity: Entity) -> Self {
+        Self {
+            action,
+            hovered_action: None,
+            value: None,
+            axis_pair: None,
+            press_mode: DriverPressMode::default(),
+            activation: DriverActivation::default(),
+            entity,
+        }
+    }
+
+    /// Presses `hovered_action` while this entity is hovered
+    #[must_use]
+    pub fn with_hovered_action(mut self, hovered_action: A) -> Self {
+        self.hovered_action = Some(hovered_action);
+        self
+    }
+
+    /// Forwards `value` to the driven action each frame
+    #[must_use]
+    pub fn with_value(mut self, value: f32) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Forwards `axis_pair` to the driven action each frame
+    #[must_use]
+    pub fn with_axis_pair(mut self, axis_pair: DualAxisData) -> Self {
+        self.axis_pair = Some(axis_pair);
+        self
+    }
+
+    /// Sets when a click presses the driven action
+    #[must_use]
+    pub fn with_press_mode(mut self, press_mode: DriverPressMode) -> Self {
+        self.press_mode = press_mode;
+        self
+    }
+
+    /// Sets which [`Interaction`](bevy::ui::Interaction)s press the driven action
+    #[must_use]
+    pub fn with_activation(mut self, activation: DriverActivation) -> Self {
+        self.activation = activation;
+        self
+    }
+}
+
+/// Several [`ActionStateDriver`]s of the same [`Actionlike`] type on a single entity
+///
+/// An entity can only hold one [`ActionStateDriver<A>`] component per action type.
+/// Drivers for different action types can be inserted side by side,
+/// so a button can drive both menu actions and gameplay actions with an `ActionStateDriver` for each.
+/// Use this component instead when one entity should drive several actions of the same type,
+/// or the [`ActionState`]s of several entities.
+///
+/// Each driver behaves exactly as if it were an [`ActionStateDriver`] on its own,
+/// and both components can be used on the same entity.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::action_state::ActionStateDrivers;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy)]
+/// enum GameAction {
+///     Attack,
+///     Target,
+/// }
+///
+/// let mut world = World::new();
+/// let player = world.spawn(ActionState::<GameAction>::default()).id();
+/// let companion = world.spawn(ActionState::<GameAction>::default()).id();
+///
+/// // Clicking the enemy makes both the player and their companion attack, and hovering it targets it
+/// world.spawn(ButtonBundle::default()).insert(ActionStateDrivers::new([
+///     ActionStateDriver::new(GameAction::Attack, player).with_hovered_action(GameAction::Target),
+///     ActionStateDriver::new(GameAction::Attack, companion),
+/// ]));
+/// ```
+#[derive(Component, Clone, PartialEq, Debug)]
+pub struct ActionStateDrivers<A: Actionlike> {
+    /// The drivers of this entity, which are evaluated in order
+    pub drivers: Vec<ActionStateDriver<A>>,
+}
+
+impl<A: Actionlike> Default for ActionStateDrivers<A> {
+    fn default() -> Self {
+        Self {
+            drivers: Vec::new(),
+        }
+    }
+}
+
+impl<A: Actionlike> ActionStateDrivers<A> {
+    /// Creates a component from the provided `drivers`
+    #[must_use]
+    pub fn new(drivers: impl IntoIterator<Item = ActionStateDriver<A>>) -> Self {
+        Self {
+            drivers: drivers.into_iter().collect(),
+        }
+    }
+
+    /// Adds a `driver` to this entity
+    #[must_use]
+    pub fn with(mut self, driver: ActionStateDriver<A>) -> Self {
+        self.push(driver);
+        self
+    }
+
+    /// Adds a `driver` to this entity
+    pub fn push(&mut self, driver: ActionStateDriver<A>) {
+        self.drivers.push(driver);
+    }
+
+    /// Iterates over the drivers of this entity
+    pub fn iter(&self) -> impl Iterator<Item = &ActionStateDriver<A>> {
+        self.drivers.iter()
+    }
+}
+
 /// Stores information about when an action was pressed or released
 ///
 /// This struct is principally used as a field on [`ActionData`],
@@ -554,7 +1885,7 @@ pub struct ActionStateDriver<A: Actionlike> {
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, Reflect, FromReflect)]
 pub struct Timing {
     /// The [`Instant`] at which the button was pressed or released
-    /// Recorded as the [`Time`](bevy::core::Time) at the start of the tick after the state last changed.
+    /// Recorded as the [`Time`](bevy::core::Time) at the start of the tick in which the state last changed.
     /// If this is none, [`Timing::tick`] has not been called yet.
     #[serde(skip)]
     pub instant_started: Option<Instant>,
@@ -564,6 +1895,20 @@ pub struct Timing {
     pub current_duration: Duration,
     /// The [`Duration`] for which the button was pressed or released before the state last changed.
     pub previous_duration: Duration,
+    /// The [`Instant`] at which the button was last pressed
+    ///
+    /// Like `instant_started`, this is recorded as the start of the tick in which the button was pressed.
+    #[serde(skip)]
+    pub last_pressed_instant: Option<Instant>,
+    /// The [`Instant`] at which the button was last released
+    ///
+    /// Like `instant_started`, this is recorded as the start of the tick in which the button was released.
+    #[serde(skip)]
+    pub last_released_instant: Option<Instant>,
+    /// The [`ActionState::tick_count`] during which the button was last pressed
+    pub last_pressed_tick: Option<u64>,
+    /// The [`ActionState::tick_count`] during which the button was last released
+    pub last_released_tick: Option<u64>,
 }
 
 impl PartialOrd for Timing {
@@ -586,6 +1931,17 @@ impl Timing {
         }
     }
 
+    /// Holds the `current_duration` of this timer in place while time passes from `previous_instant` to `current_instant`
+    ///
+    /// The `instant_started` is shifted forward by the elapsed time, so that the timer resumes where it left off.
+    pub fn pause(&mut self, current_instant: Instant, previous_instant: Instant) {
+        if let Some(instant_started) = self.instant_started {
+            self.instant_started = Some(instant_started + (current_instant - previous_instant));
+        } else {
+            self.instant_started = Some(current_instant);
+        }
+    }
+
     /// Flips the metaphorical hourglass, storing `current_duration` in `previous_duration` and resetting `instant_started`
     ///
     /// This method is called whenever actions are pressed or released
@@ -596,14 +1952,14 @@ impl Timing {
     }
 }
 
-/// Stores presses and releases of buttons without timing information
+/// Stores presses and releases of buttons, and changes to the values and axis pairs of actions, without timing information
 ///
 /// These are typically accessed using the `Events<ActionDiff>` resource.
 /// Uses a minimal storage format, in order to facilitate transport over the network.
 ///
 /// `ID` should be a component type that stores a unique stable identifier for the entity
 /// that stores the corresponding [`ActionState`].
-#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum ActionDiff<A: Actionlike, ID: Eq + Clone + Component> {
     /// The action was pressed
     Pressed {
@@ -619,6 +1975,78 @@ pub enum ActionDiff<A: Actionlike, ID: Eq + Clone + Component> {
         /// The stable identifier of the entity
         id: ID,
     },
+    /// The [`value`](ActionState::value) of the action changed
+    ValueChanged {
+        /// The value of the action
+        action: A,
+        /// The stable identifier of the entity
+        id: ID,
+        /// The new value of the action
+        value: f32,
+    },
+    /// The [`axis_pair`](ActionState::axis_pair) of the action changed
+    AxisPairChanged {
+        /// The value of the action
+        action: A,
+        /// The stable identifier of the entity
+        id: ID,
+        /// The new axis pair of the action
+        axis_pair: Option<DualAxisData>,
+    },
+}
+
+impl<A: Actionlike, ID: Eq + Clone + Component> ActionDiff<A, ID> {
+    /// The action that this diff applies to
+    #[must_use]
+    pub fn action(&self) -> &A {
+        match self {
+            ActionDiff::Pressed { action, .. }
+            | ActionDiff::Released { action, .. }
+            | ActionDiff::ValueChanged { action, .. }
+            | ActionDiff::AxisPairChanged { action, .. } => action,
+        }
+    }
+
+    /// The stable identifier of the entity that this diff applies to
+    #[must_use]
+    pub fn id(&self) -> &ID {
+        match self {
+            ActionDiff::Pressed { id, .. }
+            | ActionDiff::Released { id, .. }
+            | ActionDiff::ValueChanged { id, .. }
+            | ActionDiff::AxisPairChanged { id, .. } => id,
+        }
+    }
+
+    /// The floating point data carried by this diff, in a form that can be compared and hashed
+    fn float_data(&self) -> [Option<FloatOrd>; 2] {
+        match self {
+            ActionDiff::Pressed { .. } | ActionDiff::Released { .. } => [None, None],
+            ActionDiff::ValueChanged { value, .. } => [Some(FloatOrd(*value)), None],
+            ActionDiff::AxisPairChanged { axis_pair, .. } => axis_pair
+                .map_or([None, None], |axis_pair| {
+                    [Some(FloatOrd(axis_pair.x())), Some(FloatOrd(axis_pair.y()))]
+                }),
+        }
+    }
+}
+
+impl<A: Actionlike + PartialEq, ID: Eq + Clone + Component> PartialEq for ActionDiff<A, ID> {
+    fn eq(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+            && self.action() == other.action()
+            && self.id() == other.id()
+            && self.float_data() == other.float_data()
+    }
+}
+impl<A: Actionlike + Eq, ID: Eq + Clone + Component> Eq for ActionDiff<A, ID> {}
+impl<A: Actionlike + Hash, ID: Eq + Clone + Component + Hash> Hash for ActionDiff<A, ID> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        self.action().hash(state);
+        self.id().hash(state);
+        self.float_data().hash(state);
+    }
 }
 
 #[cfg(test)]
@@ -775,4 +2203,586 @@ mod tests {
         assert_eq!(action_state.current_duration(Action::Jump), Duration::ZERO);
         assert_eq!(action_state.previous_duration(Action::Jump), t2 - t0);
     }
+
+    #[test]
+    fn press_order() {
+        use crate::action_state::ActionState;
+        use bevy::utils::{Duration, Instant};
+
+        let mut action_state = ActionState::<Action>::default();
+
+        // Simultaneous presses are ordered by when they were pressed
+        action_state.press(Action::Hide);
+        action_state.press(Action::Run);
+        assert_eq!(action_state.press_order(Action::Hide), Some(0));
+        assert_eq!(action_state.press_order(Action::Run), Some(1));
+        assert_eq!(action_state.press_order(Action::Jump), None);
+
+        // Holding an action does not change its order
+        action_state.press(Action::Hide);
+        assert_eq!(action_state.press_order(Action::Hide), Some(0));
+
+        // Ticking resets the ordering
+        action_state.tick(Instant::now(), Instant::now() - Duration::from_micros(1));
+        assert_eq!(action_state.press_order(Action::Hide), None);
+        action_state.press(Action::Jump);
+        assert_eq!(action_state.press_order(Action::Jump), Some(0));
+    }
+
+    #[test]
+    fn transition_timestamps() {
+        use crate::action_state::ActionState;
+        use bevy::utils::{Duration, Instant};
+
+        let mut action_state = ActionState::<Action>::default();
+        let t0 = Instant::now();
+        let t1 = t0 + Duration::from_secs(1);
+        let t2 = t1 + Duration::from_secs(1);
+
+        // Actions that have never changed have no timestamps
+        action_state.tick(t0, t0);
+        assert_eq!(action_state.tick_count(), 1);
+        assert_eq!(action_state.last_pressed(Action::Run), None);
+        assert_eq!(action_state.last_released(Action::Run), None);
+        assert_eq!(action_state.last_released_tick(Action::Run), None);
+
+        action_state.press(Action::Run);
+        assert_eq!(action_state.last_pressed_tick(Action::Run), Some(1));
+        assert_eq!(action_state.last_pressed(Action::Run), None);
+
+        // The instant is only known once the following frame starts,
+        // and is the start of the frame in which the press occurred
+        action_state.tick(t1, t0);
+        assert_eq!(action_state.last_pressed(Action::Run), Some(t0));
+
+        action_state.release(Action::Run);
+        action_state.tick(t2, t1);
+        assert_eq!(action_state.last_released_tick(Action::Run), Some(2));
+        assert_eq!(action_state.last_released(Action::Run), Some(t1));
+        assert_eq!(action_state.last_pressed(Action::Run), Some(t0));
+        assert_eq!(action_state.tick_count(), 3);
+    }
+
+    #[test]
+    fn latency_offsets_effective_ticks() {
+        use crate::action_state::ActionState;
+        use bevy::utils::Instant;
+
+        let mut action_state = ActionState::<Action>::default();
+        let now = Instant::now();
+        for _ in 0..5 {
+            action_state.tick(now, now);
+        }
+
+        // The remote player pressed this two ticks before the press arrived
+        action_state.set_latency_ticks(2);
+        action_state.press(Action::Run);
+        assert_eq!(action_state.last_pressed_tick(Action::Run), Some(5));
+        assert_eq!(action_state.effective_pressed_tick(Action::Run), Some(3));
+        assert_eq!(action_state.effective_released_tick(Action::Run), None);
+
+        // Effective ticks never go below zero
+        action_state.set_latency_ticks(10);
+        assert_eq!(action_state.effective_pressed_tick(Action::Run), Some(0));
+    }
+
+    #[test]
+    fn try_update_rejects_mismatched_data() {
+        use crate::action_state::{ActionData, ActionState};
+        use crate::buttonlike::ButtonState;
+        use crate::errors::InputManagerError;
+        use crate::Actionlike;
+
+        let mut action_state = ActionState::<Action>::default();
+        let pressed = ActionData {
+            state: ButtonState::JustPressed,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            action_state.try_update(vec![pressed.clone()]),
+            Err(InputManagerError::ActionCountMismatch {
+                expected: Action::N_VARIANTS,
+                found: 1,
+            })
+        );
+        assert!(action_state.get_pressed().is_empty());
+
+        let mut action_data = vec![ActionData::default(); Action::N_VARIANTS];
+        action_data[Action::Jump.index()] = pressed;
+        assert_eq!(action_state.try_update(action_data), Ok(()));
+        assert!(action_state.pressed(Action::Jump));
+    }
+
+    #[test]
+    fn consume_release_semantics() {
+        use crate::action_state::{ActionState, ConsumeRelease};
+        use bevy::utils::{Duration, Instant};
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.set_consume_release(Action::Jump, ConsumeRelease::For(Duration::from_secs(1)));
+        let t0 = Instant::now();
+
+        // Consumed for a duration: releasing the action does not end the consumption early
+        action_state.press(Action::Jump);
+        action_state.consume(Action::Jump);
+        action_state.release(Action::Jump);
+        action_state.tick(t0 + Duration::from_millis(500), t0);
+        action_state.press(Action::Jump);
+        assert!(action_state.released(Action::Jump));
+
+        action_state.tick(
+            t0 + Duration::from_millis(1000),
+            t0 + Duration::from_millis(500),
+        );
+        action_state.press(Action::Jump);
+        assert!(action_state.just_pressed(Action::Jump));
+
+        // Consumed until released: ticking does not end the consumption
+        action_state.press(Action::Run);
+        action_state.consume(Action::Run);
+        action_state.tick(t0 + Duration::from_secs(5), t0);
+        action_state.press(Action::Run);
+        assert!(action_state.released(Action::Run));
+
+        action_state.release(Action::Run);
+        action_state.press(Action::Run);
+        assert!(action_state.pressed(Action::Run));
+    }
+
+    #[test]
+    fn action_sets() {
+        use crate::action_state::ActionState;
+        use crate::buttonlike::ButtonState;
+        use bevy::utils::Instant;
+
+        let mut action_state = ActionState::<Action>::default();
+        assert!(!action_state.any_pressed());
+        assert!(action_state.none_pressed());
+        assert_eq!(action_state.active_count(), 0);
+        assert!(action_state.pressed_set().is_empty());
+
+        action_state.press(Action::Jump);
+        action_state.press(Action::Hide);
+        assert!(action_state.any_pressed());
+        assert!(!action_state.none_pressed());
+        assert_eq!(action_state.active_count(), 2);
+        assert!(action_state.any_just_pressed());
+        assert_eq!(
+            action_state.just_pressed_set().iter().collect::<Vec<_>>(),
+            [Action::Jump, Action::Hide]
+        );
+
+        let now = Instant::now();
+        action_state.tick(now, now);
+        action_state.release(Action::Hide);
+        assert!(!action_state.any_just_pressed());
+        assert!(action_state.any_just_released());
+        assert_eq!(
+            action_state.just_released_set().iter().collect::<Vec<_>>(),
+            [Action::Hide]
+        );
+        assert_eq!(action_state.get_pressed(), [Action::Jump]);
+
+        // The sets follow every change to the state of an action
+        action_state.consume(Action::Jump);
+        let mut run_data = action_state.action_data(Action::Run).clone();
+        run_data.state = ButtonState::JustPressed;
+        action_state.set_action_data(Action::Run, run_data);
+        for action in Action::variants() {
+            assert_eq!(
+                action_state.pressed_set().contains(action),
+                action_state.pressed(action)
+            );
+            assert_eq!(
+                action_state.just_pressed_set().contains(action),
+                action_state.just_pressed(action)
+            );
+            assert_eq!(
+                action_state.just_released_set().contains(action),
+                action_state.just_released(action)
+            );
+        }
+        assert_eq!(action_state.get_pressed(), [Action::Run]);
+        assert_eq!(
+            action_state.get_just_released(),
+            [Action::Jump, Action::Hide]
+        );
+    }
+
+    #[test]
+    fn bulk_transitions() {
+        use crate::action_state::ActionState;
+        use bevy::utils::Instant;
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.press(Action::Run);
+        let now = Instant::now();
+        action_state.tick(now, now);
+
+        // Only the actions that change state are just pressed
+        action_state.consume(Action::Hide);
+        action_state.press_all();
+        assert_eq!(action_state.get_just_pressed(), [Action::Jump]);
+        assert!(action_state.pressed(Action::Run));
+        assert!(action_state.released(Action::Hide));
+
+        action_state.tick(now, now);
+        action_state.release_all();
+        assert_eq!(
+            action_state.get_just_released(),
+            [Action::Run, Action::Jump]
+        );
+        assert!(action_state.get_pressed().is_empty());
+
+        // Consumed actions can be pressed again once everything is released
+        action_state.press(Action::Hide);
+        assert!(action_state.just_pressed(Action::Hide));
+    }
+
+    #[test]
+    fn diff_round_trip() {
+        use crate::action_state::{ActionDiff, ActionState};
+        use bevy::prelude::Component;
+
+        #[derive(Component, Clone, PartialEq, Eq, Debug)]
+        struct Id;
+
+        let mut previous = ActionState::<Action>::default();
+        previous.press(Action::Run);
+        previous.press(Action::Hide);
+
+        let mut current = previous.clone();
+        current.release(Action::Run);
+        current.press(Action::Jump);
+
+        let diffs = current.diff_since(&previous, &Id);
+        assert_eq!(
+            diffs,
+            vec![
+                ActionDiff::Released {
+                    action: Action::Run,
+                    id: Id
+                },
+                ActionDiff::Pressed {
+                    action: Action::Jump,
+                    id: Id
+                },
+            ]
+        );
+
+        for diff in &diffs {
+            previous.apply_diff(diff);
+        }
+        assert_eq!(previous.get_pressed(), current.get_pressed());
+        assert!(current.diff_since(&previous, &Id).is_empty());
+    }
+
+    #[test]
+    fn diff_round_trip_with_axis_pair() {
+        use crate::action_state::{ActionDiff, ActionState};
+        use crate::axislike::DualAxisData;
+        use bevy::prelude::Component;
+
+        #[derive(Component, Clone, PartialEq, Eq, Debug)]
+        struct Id;
+
+        let mut previous = ActionState::<Action>::default();
+        let mut current = previous.clone();
+        current.press(Action::Run);
+        current.action_data_mut(Action::Run).value = 0.5;
+        current.action_data_mut(Action::Run).axis_pair = Some(DualAxisData::new(0.3, 0.4));
+
+        let diffs = current.diff_since(&previous, &Id);
+        assert_eq!(
+            diffs,
+            vec![
+                ActionDiff::Pressed {
+                    action: Action::Run,
+                    id: Id
+                },
+                ActionDiff::ValueChanged {
+                    action: Action::Run,
+                    id: Id,
+                    value: 0.5
+                },
+                ActionDiff::AxisPairChanged {
+                    action: Action::Run,
+                    id: Id,
+                    axis_pair: Some(DualAxisData::new(0.3, 0.4))
+                },
+            ]
+        );
+
+        for diff in &diffs {
+            previous.apply_diff(diff);
+        }
+        assert!(previous.pressed(Action::Run));
+        assert_eq!(previous.value(Action::Run), 0.5);
+        assert_eq!(
+            previous.axis_pair(Action::Run),
+            Some(DualAxisData::new(0.3, 0.4))
+        );
+        assert!(current.diff_since(&previous, &Id).is_empty());
+
+        // Releasing the stick clears its axis pair on the replica too
+        current.release(Action::Run);
+        current.action_data_mut(Action::Run).value = 0.0;
+        current.action_data_mut(Action::Run).axis_pair = None;
+        for diff in &current.diff_since(&previous, &Id) {
+            previous.apply_diff(diff);
+        }
+        assert!(previous.released(Action::Run));
+        assert_eq!(previous.value(Action::Run), 0.0);
+        assert_eq!(previous.axis_pair(Action::Run), None);
+    }
+
+    #[test]
+    fn rebinding_reconciles_held_actions() {
+        use crate::action_state::ActionState;
+        use crate::clashing_inputs::ClashStrategy;
+        use crate::input_map::InputMap;
+        use crate::input_streams::InputStreams;
+        use bevy::input::InputPlugin;
+        use bevy::prelude::*;
+        use bevy::utils::Instant;
+
+        let mut app = App::new();
+        app.add_plugin(InputPlugin);
+        app.send_input(KeyCode::R);
+        app.send_input(KeyCode::Space);
+        app.update();
+        let input_streams = InputStreams::from_world(&app.world, None);
+
+        let old_map = InputMap::new([(KeyCode::R, Action::Run), (KeyCode::Space, Action::Hide)]);
+        let mut action_state = ActionState::<Action>::default();
+        action_state.update(old_map.which_pressed(&input_streams, ClashStrategy::PressAll));
+        action_state.tick(Instant::now(), Instant::now());
+
+        let new_map = InputMap::new([(KeyCode::R, Action::Run), (KeyCode::Space, Action::Jump)]);
+        action_state.rebind_reconcile(&new_map, &input_streams);
+
+        // Still bound and held
+        assert!(action_state.pressed(Action::Run));
+        assert!(!action_state.just_pressed(Action::Run));
+        // No longer bound
+        assert!(action_state.just_released(Action::Hide));
+        // Newly bound to a key that was already held
+        assert!(action_state.released(Action::Jump));
+        action_state.update(new_map.which_pressed(&input_streams, ClashStrategy::PressAll));
+        assert!(action_state.released(Action::Jump));
+
+        // Pressing the key again triggers the new binding
+        app.release_input(KeyCode::Space);
+        app.update();
+        let input_streams = InputStreams::from_world(&app.world, None);
+        action_state.update(new_map.which_pressed(&input_streams, ClashStrategy::PressAll));
+        app.send_input(KeyCode::Space);
+        app.update();
+        let input_streams = InputStreams::from_world(&app.world, None);
+        action_state.update(new_map.which_pressed(&input_streams, ClashStrategy::PressAll));
+        assert!(action_state.just_pressed(Action::Jump));
+    }
+
+    #[test]
+    fn hold_bindings_report_progress() {
+        use crate::action_state::ActionState;
+        use crate::clashing_inputs::ClashStrategy;
+        use crate::input_map::{BindingTrigger, InputMap};
+        use crate::input_streams::InputStreams;
+        use bevy::input::InputPlugin;
+        use bevy::prelude::*;
+        use bevy::utils::{Duration, Instant};
+
+        let mut app = App::new();
+        app.add_plugin(InputPlugin);
+        let mut input_map = InputMap::default();
+        input_map
+            .insert_with_trigger(
+                KeyCode::Delete,
+                Action::Hide,
+                BindingTrigger::Hold(Duration::from_secs(2)),
+            )
+            .insert(KeyCode::R, Action::Run);
+        let mut action_state = ActionState::<Action>::default();
+
+        let start = Instant::now();
+        // Ticks and then updates the action state, as the plugin does each frame
+        let update = |app: &mut App, action_state: &mut ActionState<Action>, seconds| {
+            action_state.tick(
+                start + Duration::from_secs(seconds + 1),
+                start + Duration::from_secs(seconds),
+            );
+            let input_streams = InputStreams::from_world(&app.world, None);
+            action_state.update(input_map.which_pressed(&input_streams, ClashStrategy::PressAll));
+        };
+
+        app.send_input(KeyCode::Delete);
+        app.send_input(KeyCode::R);
+        app.update();
+        update(&mut app, &mut action_state, 0);
+        assert!(action_state.released(Action::Hide));
+        assert_eq!(action_state.hold_progress(Action::Hide), 0.0);
+        assert_eq!(action_state.hold_progress(Action::Run), 1.0);
+
+        update(&mut app, &mut action_state, 1);
+        assert!(action_state.released(Action::Hide));
+        assert_eq!(action_state.hold_progress(Action::Hide), 0.5);
+
+        update(&mut app, &mut action_state, 2);
+        assert!(action_state.just_pressed(Action::Hide));
+        assert_eq!(action_state.hold_progress(Action::Hide), 1.0);
+
+        // Letting go resets the progress
+        app.release_input(KeyCode::Delete);
+        app.update();
+        update(&mut app, &mut action_state, 3);
+        assert!(action_state.just_released(Action::Hide));
+        assert_eq!(action_state.hold_progress(Action::Hide), 0.0);
+    }
+
+    #[test]
+    fn turbo_bindings_pulse() {
+        use crate::action_state::ActionState;
+        use crate::clashing_inputs::ClashStrategy;
+        use crate::input_map::{BindingTrigger, InputMap};
+        use crate::input_streams::InputStreams;
+        use bevy::input::InputPlugin;
+        use bevy::prelude::*;
+        use bevy::utils::{Duration, Instant};
+
+        let mut app = App::new();
+        app.add_plugin(InputPlugin);
+        let mut input_map = InputMap::default();
+        input_map.insert_with_trigger(
+            KeyCode::Z,
+            Action::Jump,
+            BindingTrigger::Turbo(Duration::from_secs(2)),
+        );
+        let mut action_state = ActionState::<Action>::default();
+
+        let start = Instant::now();
+        let update = |app: &mut App, action_state: &mut ActionState<Action>, seconds| {
+            action_state.tick(
+                start + Duration::from_secs(seconds + 1),
+                start + Duration::from_secs(seconds),
+            );
+            let input_streams = InputStreams::from_world(&app.world, None);
+            action_state.update(input_map.which_pressed(&input_streams, ClashStrategy::PressAll));
+        };
+
+        app.send_input(KeyCode::Z);
+        app.update();
+        let mut pulses = Vec::new();
+        for seconds in 0..6 {
+            update(&mut app, &mut action_state, seconds);
+            pulses.push(action_state.just_pressed(Action::Jump));
+        }
+        assert_eq!(pulses, [true, false, true, false, true, false]);
+
+        app.release_input(KeyCode::Z);
+        app.update();
+        update(&mut app, &mut action_state, 6);
+        assert!(action_state.released(Action::Jump));
+    }
+
+    #[test]
+    fn values_are_interpolated_between_updates() {
+        use crate::action_state::{ActionData, ActionState};
+        use crate::axislike::DualAxisData;
+        use crate::buttonlike::ButtonState;
+        use crate::Actionlike;
+
+        let mut action_state = ActionState::<Action>::default();
+        let update = |action_state: &mut ActionState<Action>, value: f32| {
+            let mut action_data = vec![ActionData::default(); Action::N_VARIANTS];
+            action_data[Action::Run.index()] = ActionData {
+                state: ButtonState::JustPressed,
+                value,
+                axis_pair: Some(DualAxisData::new(value, -value)),
+                ..Default::default()
+            };
+            action_state.update(action_data);
+        };
+
+        update(&mut action_state, 0.2);
+        update(&mut action_state, 0.6);
+        assert_eq!(action_state.value_interpolated(Action::Run, 0.0), 0.2);
+        assert_eq!(action_state.value_interpolated(Action::Run, 1.0), 0.6);
+        assert!((action_state.value_interpolated(Action::Run, 0.5) - 0.4).abs() < 1e-6);
+        // Fractions past the current update extrapolate
+        assert!((action_state.value_interpolated(Action::Run, 1.5) - 0.8).abs() < 1e-6);
+
+        let axis_pair = action_state
+            .axis_pair_interpolated(Action::Run, 0.5)
+            .unwrap();
+        assert!((axis_pair.x() - 0.4).abs() < 1e-6);
+        assert!((axis_pair.y() + 0.4).abs() < 1e-6);
+        assert_eq!(action_state.axis_pair_interpolated(Action::Jump, 0.5), None);
+    }
+
+    #[test]
+    fn snapshots_round_trip() {
+        use crate::action_state::{ActionState, ActionStateSnapshot};
+        use crate::axislike::DualAxisData;
+        use bevy::utils::{Duration, Instant};
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.press(Action::Run);
+        action_state.action_data_mut(Action::Run).axis_pair = Some(DualAxisData::new(1.0, 0.0));
+        action_state.press(Action::Jump);
+        action_state.consume(Action::Jump);
+        let start = Instant::now();
+        action_state.tick(start + Duration::from_secs(2), start);
+
+        let snapshot: ActionStateSnapshot = action_state.snapshot();
+
+        let mut restored = ActionState::<Action>::default();
+        restored.restore(&snapshot);
+        assert!(restored.pressed(Action::Run));
+        assert!(!restored.just_pressed(Action::Run));
+        assert_eq!(
+            restored.axis_pair(Action::Run),
+            Some(DualAxisData::new(1.0, 0.0))
+        );
+        assert_eq!(
+            restored.current_duration(Action::Run),
+            action_state.current_duration(Action::Run)
+        );
+        assert!(restored.action_data(Action::Jump).consumed);
+        assert!(restored.released(Action::Hide));
+        assert_eq!(restored.tick_count(), action_state.tick_count());
+
+        // Snapshots taken before an action was added leave it released
+        let mut truncated = snapshot;
+        truncated.actions.pop();
+        restored.press(Action::Hide);
+        restored.restore(&truncated);
+        assert!(restored.released(Action::Hide));
+    }
+
+    #[test]
+    fn restoring_updates_bulk_queries() {
+        use crate::action_state::ActionState;
+        use bevy::reflect::FromReflect;
+
+        let mut action_state = ActionState::<Action>::default();
+        let snapshot = action_state.snapshot();
+        action_state.press(Action::Run);
+        assert_eq!(action_state.get_pressed(), [Action::Run]);
+
+        action_state.restore(&snapshot);
+        assert!(action_state.released(Action::Run));
+        assert!(!action_state.any_pressed());
+        assert!(action_state.get_pressed().is_empty());
+        assert!(action_state.get_just_pressed().is_empty());
+        assert!(action_state.get_just_released().is_empty());
+
+        // The sets of actions are not reflected, and are rebuilt instead
+        action_state.press(Action::Jump);
+        let reflected = ActionState::<Action>::from_reflect(&action_state).unwrap();
+        assert_eq!(reflected.get_pressed(), [Action::Jump]);
+        assert_eq!(reflected.get_just_pressed(), [Action::Jump]);
+        assert_eq!(reflected, action_state);
+    }
 }
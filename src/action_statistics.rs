@@ -0,0 +1,193 @@
+//! This module contains [`ActionStatistics`] and its supporting methods and impls.
+//!
+//! The [`ActionStatistics`] is an optional addition to an [`InputManagerBundle`](crate::InputManagerBundle),
+//! which counts how often each action has been pressed and how long it has been held for in total.
+//! This is useful for analytics, achievements ("jump 1000 times") and playtest instrumentation.
+
+use bevy::ecs::prelude::*;
+use bevy::utils::Duration;
+use std::marker::PhantomData;
+
+use crate::action_state::ActionState;
+use crate::Actionlike;
+
+/// Counts the presses and total hold time of each action
+///
+/// Hold times are measured with the durations of the [`ActionState`],
+/// and so respect [`PauseTiming`](crate::plugin::PauseTiming) and [`TimeSource`](crate::plugin::TimeSource).
+///
+/// This is updated by [`update_action_statistics`](crate::systems::update_action_statistics),
+/// as part of [`InputManagerSystem::Activity`](crate::plugin::InputManagerSystem::Activity).
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::action_statistics::ActionStatistics;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Jump,
+/// }
+///
+/// fn jump_achievement(query: Query<&ActionStatistics<Action>>) {
+///     for statistics in query.iter() {
+///         if statistics.presses(Action::Jump) == 1000 {
+///             println!("Achievement unlocked: Frog");
+///         }
+///     }
+/// }
+///
+/// let mut world = World::new();
+/// world
+///     .spawn(InputManagerBundle {
+///         input_map: InputMap::new([(KeyCode::Space, Action::Jump)]),
+///         ..Default::default()
+///     })
+///     .insert(ActionStatistics::<Action>::default());
+/// ```
+#[derive(Resource, Component, Debug, Clone, PartialEq)]
+pub struct ActionStatistics<A: Actionlike> {
+    /// How many times each action has been pressed
+    presses: Vec<u64>,
+    /// How long each action has been held for in total
+    hold_times: Vec<Duration>,
+    /// How much of the current hold of each action has already been added to its hold time
+    counted_durations: Vec<Duration>,
+    _phantom: PhantomData<A>,
+}
+
+impl<A: Actionlike> Default for ActionStatistics<A> {
+    fn default() -> Self {
+        Self {
+            presses: vec![0; A::N_VARIANTS],
+            hold_times: vec![Duration::ZERO; A::N_VARIANTS],
+            counted_durations: vec![Duration::ZERO; A::N_VARIANTS],
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A: Actionlike> ActionStatistics<A> {
+    /// Records the presses and hold times of the `action_state` since the previous call
+    ///
+    /// This should be called once per frame, after the `action_state` has been updated.
+    pub fn record(&mut self, action_state: &ActionState<A>) {
+        for action in A::variants() {
+            let index = action.index();
+
+            if action_state.just_pressed(action.clone()) {
+                self.presses[index] += 1;
+                self.counted_durations[index] = Duration::ZERO;
+            }
+
+            // Releasing an action moves its final duration into the previous duration
+            let just_released = action_state.just_released(action.clone());
+            let held = if action_state.pressed(action.clone()) {
+                action_state.current_duration(action)
+            } else if just_released {
+                action_state.previous_duration(action)
+            } else {
+                continue;
+            };
+
+            self.hold_times[index] += held.saturating_sub(self.counted_durations[index]);
+            self.counted_durations[index] = if just_released { Duration::ZERO } else { held };
+        }
+    }
+
+    /// How many times the `action` has been pressed since the last reset
+    #[must_use]
+    pub fn presses(&self, action: A) -> u64 {
+        self.presses[action.index()]
+    }
+
+    /// How many times any action has been pressed since the last reset
+    #[must_use]
+    pub fn total_presses(&self) -> u64 {
+        self.presses.iter().sum()
+    }
+
+    /// How long the `action` has been held for in total since the last reset
+    #[must_use]
+    pub fn hold_time(&self, action: A) -> Duration {
+        self.hold_times[action.index()]
+    }
+
+    /// Resets the press count and hold time of the `action`
+    ///
+    /// If the `action` is currently held, only the time it is held for from now on is counted.
+    pub fn reset_action(&mut self, action: A) {
+        let index = action.index();
+        self.presses[index] = 0;
+        self.hold_times[index] = Duration::ZERO;
+    }
+
+    /// Resets the press counts and hold times of every action
+    ///
+    /// Actions that are currently held only count the time they are held for from now on.
+    pub fn reset(&mut self) {
+        for action in A::variants() {
+            self.reset_action(action);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ActionStatistics;
+    use crate as leafwing_input_manager;
+    use crate::action_state::ActionState;
+    use crate::Actionlike;
+    use bevy::utils::{Duration, Instant};
+
+    #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    enum Action {
+        Jump,
+        Crouch,
+    }
+
+    #[test]
+    fn presses_and_hold_times_are_counted() {
+        let mut action_state = ActionState::<Action>::default();
+        let mut statistics = ActionStatistics::<Action>::default();
+        let frame = Duration::from_millis(100);
+        let start = Instant::now();
+        let mut n_frames = 0;
+
+        let mut step = |action_state: &mut ActionState<Action>, pressed: bool| {
+            let previous = start + frame * n_frames;
+            n_frames += 1;
+            action_state.tick(previous + frame, previous);
+            if pressed {
+                action_state.press(Action::Jump);
+            } else {
+                action_state.release(Action::Jump);
+            }
+            statistics.record(action_state);
+            statistics.clone()
+        };
+
+        // Held for three frames, then released
+        step(&mut action_state, true);
+        step(&mut action_state, true);
+        step(&mut action_state, true);
+        let released = step(&mut action_state, false);
+        assert_eq!(released.presses(Action::Jump), 1);
+        assert_eq!(released.hold_time(Action::Jump), frame * 3);
+
+        // Idle frames are not counted
+        step(&mut action_state, false);
+        step(&mut action_state, true);
+        step(&mut action_state, true);
+        let mut statistics = step(&mut action_state, false);
+        assert_eq!(statistics.presses(Action::Jump), 2);
+        assert_eq!(statistics.hold_time(Action::Jump), frame * 5);
+        assert_eq!(statistics.total_presses(), 2);
+        assert_eq!(statistics.presses(Action::Crouch), 0);
+
+        statistics.reset();
+        assert_eq!(statistics.presses(Action::Jump), 0);
+        assert_eq!(statistics.hold_time(Action::Jump), Duration::ZERO);
+    }
+}
@@ -0,0 +1,233 @@
+//! This module contains [`ActionUsage`] and its supporting methods and impls.
+//!
+//! The [`ActionUsage`] is an optional addition to an [`InputManagerBundle`](crate::InputManagerBundle),
+//! which remembers which actions the player has triggered and which inputs they used to do so.
+//! Tutorial systems can use this to prompt the player with the binding they are most likely to use,
+//! and to dismiss the prompt once the action has been performed.
+
+use bevy::ecs::prelude::*;
+use std::marker::PhantomData;
+
+use crate::action_state::ActionState;
+use crate::inhibit_input::InputDevice;
+use crate::input_map::InputMap;
+use crate::user_input::UserInput;
+use crate::Actionlike;
+
+/// Records which actions have been triggered, and with which inputs
+///
+/// This is updated by [`update_action_usage`](crate::systems::update_action_usage),
+/// as part of [`InputManagerSystem::Activity`](crate::plugin::InputManagerSystem::Activity).
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::action_usage::ActionUsage;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Jump,
+/// }
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// fn jump_tutorial(query: Query<(&ActionUsage<Action>, &InputMap<Action>), With<Player>>) {
+///     let (action_usage, input_map) = query.single();
+///
+///     if !action_usage.has_triggered(Action::Jump) {
+///         if let Some(input) = action_usage.prompt_input(Action::Jump, input_map) {
+///             println!("Press {input} to jump!");
+///         }
+///     }
+/// }
+///
+/// let mut world = World::new();
+/// world
+///     .spawn(InputManagerBundle {
+///         input_map: InputMap::new([(KeyCode::Space, Action::Jump)]),
+///         ..Default::default()
+///     })
+///     .insert((ActionUsage::<Action>::default(), Player));
+/// ```
+#[derive(Resource, Component, Debug, Clone, PartialEq)]
+pub struct ActionUsage<A: Actionlike> {
+    /// Has each action been triggered since the last reset?
+    triggered: Vec<bool>,
+    /// The input that most recently triggered each action
+    last_inputs: Vec<Option<UserInput>>,
+    /// The [`InputDevice`] of the input that most recently triggered any action
+    last_device: Option<InputDevice>,
+    _phantom: PhantomData<A>,
+}
+
+impl<A: Actionlike> Default for ActionUsage<A> {
+    fn default() -> Self {
+        Self {
+            triggered: vec![false; A::N_VARIANTS],
+            last_inputs: vec![None; A::N_VARIANTS],
+            last_device: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A: Actionlike> ActionUsage<A> {
+    /// Records the actions that were just pressed in the `action_state`
+    pub fn record(&mut self, action_state: &ActionState<A>) {
+        for action in action_state.get_just_pressed() {
+            let index = action.index();
+            self.triggered[index] = true;
+
+            if let Some(input) = action_state.triggering_input(action) {
+                self.last_device = input.device().or(self.last_device);
+                self.last_inputs[index] = Some(input.clone());
+            }
+        }
+    }
+
+    /// Has the `action` been triggered since the last reset?
+    #[must_use]
+    pub fn has_triggered(&self, action: A) -> bool {
+        self.triggered[action.index()]
+    }
+
+    /// The actions that have bindings in the `input_map`, but have not been triggered since the last reset
+    ///
+    /// Use [`InputMap::unbound_actions`] to find the actions that cannot be triggered at all.
+    #[must_use]
+    pub fn unused_actions(&self, input_map: &InputMap<A>) -> Vec<A> {
+        A::variants()
+            .filter(|action| {
+                !self.triggered[action.index()] && !input_map.get(action.clone()).is_empty()
+            })
+            .collect()
+    }
+
+    /// The input that most recently triggered the `action`, if any
+    #[must_use]
+    pub fn last_input(&self, action: A) -> Option<&UserInput> {
+        self.last_inputs[action.index()].as_ref()
+    }
+
+    /// The [`InputDevice`] that the player most recently used to trigger any action
+    #[must_use]
+    pub fn last_device(&self) -> Option<InputDevice> {
+        self.last_device
+    }
+
+    /// The binding of the `action` in the `input_map` that should be shown to the player
+    ///
+    /// This is the input that last triggered the `action`, as long as it is still bound and is read from
+    /// a device [used with](InputDevice::used_with) the [`last_device`](Self::last_device) of the player.
+    /// Otherwise, the first binding read from those devices is chosen,
+    /// falling back to the first binding of the `action`.
+    ///
+    /// Returns [`None`] if the `action` is unbound.
+    #[must_use]
+    pub fn prompt_input<'a>(&self, action: A, input_map: &'a InputMap<A>) -> Option<&'a UserInput> {
+        let bindings = input_map.get(action.clone());
+        let matches_device = |input: &UserInput| match self.last_device {
+            Some(last_device) => input
+                .device()
+                .is_some_and(|device| last_device.used_with().contains(&device)),
+            None => true,
+        };
+
+        self.last_input(action)
+            .filter(|input| matches_device(input))
+            .and_then(|last_input| bindings.iter().find(|input| *input == last_input))
+            .or_else(|| bindings.iter().find(|input| matches_device(input)))
+            .or_else(|| bindings.iter().next())
+    }
+
+    /// Forgets every action that has been triggered, and every input used to do so
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ActionUsage;
+    use crate as leafwing_input_manager;
+    use crate::action_state::{ActionData, ActionState};
+    use crate::buttonlike::ButtonState;
+    use crate::inhibit_input::InputDevice;
+    use crate::input_map::InputMap;
+    use crate::user_input::{InputKind, UserInput};
+    use crate::Actionlike;
+    use bevy::input::gamepad::GamepadButtonType;
+    use bevy::input::keyboard::KeyCode;
+
+    #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    enum Action {
+        Jump,
+        Dash,
+        Interact,
+    }
+
+    fn press(action_state: &mut ActionState<Action>, action: Action, input: InputKind) {
+        let mut action_data = vec![ActionData::default(); Action::n_variants()];
+        action_data[action.index()] = ActionData {
+            state: ButtonState::JustPressed,
+            triggering_input: Some(UserInput::Single(input)),
+            ..Default::default()
+        };
+        action_state.update(action_data);
+    }
+
+    #[test]
+    fn prompts_follow_the_last_device() {
+        let input_map = InputMap::new([
+            (InputKind::Keyboard(KeyCode::Space), Action::Jump),
+            (
+                InputKind::GamepadButton(GamepadButtonType::South),
+                Action::Jump,
+            ),
+            (InputKind::Keyboard(KeyCode::LShift), Action::Dash),
+            (
+                InputKind::GamepadButton(GamepadButtonType::East),
+                Action::Dash,
+            ),
+        ]);
+        let mut action_state = ActionState::<Action>::default();
+        let mut action_usage = ActionUsage::<Action>::default();
+
+        assert_eq!(
+            action_usage.unused_actions(&input_map),
+            [Action::Jump, Action::Dash]
+        );
+        assert_eq!(input_map.unbound_actions(), [Action::Interact]);
+        assert_eq!(
+            action_usage.prompt_input(Action::Dash, &input_map),
+            Some(&KeyCode::LShift.into())
+        );
+        assert_eq!(
+            action_usage.prompt_input(Action::Interact, &input_map),
+            None
+        );
+
+        press(
+            &mut action_state,
+            Action::Jump,
+            InputKind::GamepadButton(GamepadButtonType::South),
+        );
+        action_usage.record(&action_state);
+
+        assert!(action_usage.has_triggered(Action::Jump));
+        assert!(!action_usage.has_triggered(Action::Dash));
+        assert_eq!(action_usage.unused_actions(&input_map), [Action::Dash]);
+        assert_eq!(action_usage.last_device(), Some(InputDevice::Gamepad));
+        // The gamepad binding is shown, since the player is using a gamepad
+        assert_eq!(
+            action_usage.prompt_input(Action::Dash, &input_map),
+            Some(&GamepadButtonType::East.into())
+        );
+
+        action_usage.reset();
+        assert!(!action_usage.has_triggered(Action::Jump));
+        assert_eq!(action_usage.last_device(), None);
+    }
+}
@@ -0,0 +1,230 @@
+//! Tracks how long it has been since any bound input was active, for attract modes, AFK detection and auto-pausing.
+//!
+//! The [`InputActivity`] resource is added and updated by every [`InputManagerPlugin`](crate::plugin::InputManagerPlugin).
+//! Once no action of any registered type has been active for [`InputActivity::idle_timeout`],
+//! an [`InputIdle`] event is sent.
+
+use bevy::ecs::entity::Entities;
+use bevy::ecs::prelude::*;
+use bevy::math::Vec2;
+use bevy::time::Time;
+use bevy::utils::{Duration, HashMap, Instant};
+
+use crate::action_state::ActionState;
+use crate::Actionlike;
+
+/// Tracks when the actions of each player, and of the app as a whole, were last active
+///
+/// An action counts as active when it is pressed.
+/// Actions with an [`axis_pair`](ActionState::axis_pair) instead count when they are just pressed or their axis pair changed this update,
+/// so that a stick held in place or an unmoving cursor will not keep the app awake.
+///
+/// Players are the entities with an [`ActionState`] component.
+/// An [`ActionState`] resource only contributes to the global activity.
+#[derive(Resource, Debug, Clone)]
+pub struct InputActivity {
+    /// How long the app or a player must be inactive before an [`InputIdle`] event is sent
+    ///
+    /// Defaults to [`InputActivity::DEFAULT_IDLE_TIMEOUT`].
+    pub idle_timeout: Duration,
+    now: Option<Instant>,
+    global: ActivityRecord,
+    players: HashMap<Entity, ActivityRecord>,
+}
+
+/// When a player (or the app as a whole) was last active, and whether an [`InputIdle`] event has already been sent
+#[derive(Debug, Clone, Copy, Default)]
+struct ActivityRecord {
+    last_active: Option<Instant>,
+    idle: bool,
+}
+
+impl ActivityRecord {
+    fn since(&self, now: Option<Instant>) -> Duration {
+        match (self.last_active, now) {
+            (Some(last_active), Some(now)) => now.saturating_duration_since(last_active),
+            _ => Duration::ZERO,
+        }
+    }
+}
+
+impl Default for InputActivity {
+    fn default() -> Self {
+        Self {
+            idle_timeout: Self::DEFAULT_IDLE_TIMEOUT,
+            now: None,
+            global: ActivityRecord::default(),
+            players: HashMap::default(),
+        }
+    }
+}
+
+impl InputActivity {
+    /// The default value of [`InputActivity::idle_timeout`]
+    pub const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+
+    /// Creates a new [`InputActivity`] with the provided `idle_timeout`
+    #[must_use]
+    pub fn new(idle_timeout: Duration) -> Self {
+        Self {
+            idle_timeout,
+            ..Default::default()
+        }
+    }
+
+    /// How long it has been since any action of any player was active
+    ///
+    /// If no input has ever been active, this is measured from the start of the app.
+    #[must_use]
+    pub fn time_since_active(&self) -> Duration {
+        self.global.since(self.now)
+    }
+
+    /// How long it has been since any action of the `player` entity was active
+    ///
+    /// If the player has never been active, this is measured from when it was first seen.
+    /// Returns [`None`] if `player` has no [`ActionState`] component.
+    #[must_use]
+    pub fn player_time_since_active(&self, player: Entity) -> Option<Duration> {
+        self.players
+            .get(&player)
+            .map(|record| record.since(self.now))
+    }
+
+    /// Has the app as a whole been inactive for at least [`InputActivity::idle_timeout`]?
+    #[must_use]
+    pub fn is_idle(&self) -> bool {
+        self.global.idle
+    }
+
+    /// Has the `player` entity been inactive for at least [`InputActivity::idle_timeout`]?
+    ///
+    /// Returns `false` if `player` has no [`ActionState`] component.
+    #[must_use]
+    pub fn is_player_idle(&self, player: Entity) -> bool {
+        self.players.get(&player).is_some_and(|record| record.idle)
+    }
+
+    /// Records activity at `instant`, for the `player` entity if provided and for the app as a whole
+    ///
+    /// This is called automatically for every active action,
+    /// but can be used to keep the app awake from inputs that are not handled by this crate.
+    pub fn mark_active(&mut self, player: Option<Entity>, instant: Instant) {
+        self.global = ActivityRecord {
+            last_active: Some(instant),
+            idle: false,
+        };
+
+        if let Some(player) = player {
+            self.players.insert(
+                player,
+                ActivityRecord {
+                    last_active: Some(instant),
+                    idle: false,
+                },
+            );
+        }
+    }
+
+    /// Starts tracking the `player` entity from `instant`, if it was not already tracked
+    fn track_player(&mut self, player: Entity, instant: Instant) {
+        self.players.entry(player).or_insert(ActivityRecord {
+            last_active: Some(instant),
+            idle: false,
+        });
+    }
+
+    /// Advances the current time to `now`, returning the [`InputIdle`] events that should be sent
+    ///
+    /// `startup` is used as the last active time if no input has ever been active,
+    /// while `is_alive` is used to stop tracking players that have been despawned.
+    fn advance(
+        &mut self,
+        now: Instant,
+        startup: Instant,
+        is_alive: impl Fn(Entity) -> bool,
+    ) -> Vec<InputIdle> {
+        self.now = Some(now);
+        self.global.last_active.get_or_insert(startup);
+        self.players.retain(|&player, _| is_alive(player));
+
+        let mut events = Vec::new();
+        let idle_timeout = self.idle_timeout;
+
+        for (&player, record) in self.players.iter_mut() {
+            if !record.idle && record.since(Some(now)) >= idle_timeout {
+                record.idle = true;
+                events.push(InputIdle {
+                    player: Some(player),
+                });
+            }
+        }
+
+        if !self.global.idle && self.global.since(Some(now)) >= idle_timeout {
+            self.global.idle = true;
+            events.push(InputIdle { player: None });
+        }
+
+        events
+    }
+}
+
+/// Sent once when the app or a single player has been inactive for [`InputActivity::idle_timeout`]
+///
+/// Another event will only be sent after the app or player has become active again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InputIdle {
+    /// The player entity that became idle, or [`None`] if every player has become idle
+    pub player: Option<Entity>,
+}
+
+/// Is any action of the `action_state` active, as defined by [`InputActivity`]?
+///
+/// Actions with an axis pair only count when they are just pressed or their axis pair moved,
+/// as absolute inputs like the cursor position are pressed for as long as they are non-zero.
+fn any_action_active<A: Actionlike>(action_state: &ActionState<A>) -> bool {
+    A::variants().any(|action| match action_state.axis_delta(action.clone()) {
+        Some(delta) => action_state.just_pressed(action) || delta.xy() != Vec2::ZERO,
+        None => action_state.pressed(action),
+    })
+}
+
+/// Records the activity of the [`ActionState<A>`] resource and components in the [`InputActivity`] resource
+///
+/// Labeled [`InputManagerSystem::Activity`](crate::plugin::InputManagerSystem::Activity).
+pub fn update_input_activity<A: Actionlike>(
+    mut activity: ResMut<InputActivity>,
+    action_state: Option<Res<ActionState<A>>>,
+    query: Query<(Entity, &ActionState<A>)>,
+    time: Res<Time>,
+) {
+    let now = time.last_update().unwrap_or_else(|| time.startup());
+
+    if let Some(action_state) = action_state {
+        if any_action_active(&action_state) {
+            activity.mark_active(None, now);
+        }
+    }
+
+    for (player, action_state) in query.iter() {
+        if any_action_active(action_state) {
+            activity.mark_active(Some(player), now);
+        } else {
+            activity.track_player(player, now);
+        }
+    }
+}
+
+/// Sends an [`InputIdle`] event when the app or one of its players crosses the [`InputActivity::idle_timeout`]
+///
+/// Runs once per update, after [`update_input_activity`] has run for every action type.
+pub fn send_input_idle_events(
+    mut activity: ResMut<InputActivity>,
+    entities: &Entities,
+    time: Res<Time>,
+    mut events: EventWriter<InputIdle>,
+) {
+    let now = time.last_update().unwrap_or_else(|| time.startup());
+    let idle_events = activity.advance(now, time.startup(), |player| entities.contains(player));
+    events.send_batch(idle_events);
+}
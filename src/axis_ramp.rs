@@ -0,0 +1,211 @@
+//! This module contains [`AxisRamp`] and its supporting methods and impls.
+//!
+//! The [`AxisRamp`] is an optional addition to an [`InputManagerBundle`](crate::InputManagerBundle),
+//! which smooths the values of selected actions over time.
+//! This pairs well with [`VirtualAxis`](crate::axislike::VirtualAxis),
+//! turning two digital buttons into an analog value that accelerates and decelerates like a stick would.
+//! Separate [attack and release speeds](RampSpeed) let a button bound to an analog action, such as a throttle,
+//! build up gradually but cut off quickly.
+
+use bevy::ecs::prelude::*;
+use bevy::utils::Duration;
+use std::marker::PhantomData;
+
+use crate::action_state::ActionState;
+use crate::Actionlike;
+
+/// Ramps the [`ActionState::value`] of selected actions towards the value of their inputs at a fixed speed
+///
+/// Without ramping, a [`VirtualAxis`](crate::axislike::VirtualAxis) bound to two keys snaps instantly between -1.0, 0.0 and 1.0.
+/// With a ramp speed of `4.0`, the value instead takes a quarter of a second to move from 0.0 to 1.0.
+///
+/// Only the value is ramped: whether an action is pressed still depends only on its inputs.
+/// Actions without a ramp speed are left untouched.
+/// For the opposite adapter, which only presses an action once its analog value is large enough,
+/// see [`InputMap::set_press_threshold`](crate::input_map::InputMap::set_press_threshold).
+///
+/// This is applied by [`apply_axis_ramp`](crate::systems::apply_axis_ramp),
+/// as part of [`InputManagerSystem::ManualControl`](crate::plugin::InputManagerSystem::ManualControl).
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::axis_ramp::AxisRamp;
+/// use leafwing_input_manager::axislike::VirtualAxis;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Steer,
+/// }
+///
+/// let mut world = World::new();
+/// world.spawn(InputManagerBundle {
+///     input_map: InputMap::new([(VirtualAxis::ad(), Action::Steer)]),
+///     ..Default::default()
+/// })
+/// .insert(AxisRamp::default().with_speed(Action::Steer, 4.0));
+/// ```
+#[derive(Resource, Component, Debug, Clone, PartialEq)]
+pub struct AxisRamp<A: Actionlike> {
+    /// How quickly the value of each action moves towards its target
+    speeds: Vec<Option<RampSpeed>>,
+    /// The current ramped value of each action
+    values: Vec<f32>,
+    _phantom: PhantomData<A>,
+}
+
+impl<A: Actionlike> Default for AxisRamp<A> {
+    fn default() -> Self {
+        Self {
+            speeds: vec![None; A::N_VARIANTS],
+            values: vec![0.0; A::N_VARIANTS],
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// How quickly an [`AxisRamp`] moves the value of an action, in units per second
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RampSpeed {
+    /// The speed at which the value moves away from zero, as its inputs are pressed harder
+    pub attack: f32,
+    /// The speed at which the value moves towards zero, as its inputs are let go
+    pub release: f32,
+}
+
+impl RampSpeed {
+    /// Creates a [`RampSpeed`] that moves at the same `speed` in both directions
+    #[must_use]
+    pub fn uniform(speed: f32) -> RampSpeed {
+        RampSpeed {
+            attack: speed,
+            release: speed,
+        }
+    }
+
+    /// The speed at which a value moves from `current` towards `target`
+    #[must_use]
+    pub fn towards(&self, current: f32, target: f32) -> f32 {
+        let moving_outwards = target.abs() > current.abs() && target * current >= 0.0;
+        if moving_outwards {
+            self.attack
+        } else {
+            self.release
+        }
+    }
+}
+
+impl<A: Actionlike> AxisRamp<A> {
+    /// Ramps the value of the `action` at `speed` units per second
+    #[must_use]
+    pub fn with_speed(mut self, action: A, speed: f32) -> Self {
+        self.set_speed(action, Some(RampSpeed::uniform(speed)));
+        self
+    }
+
+    /// Ramps the value of the `action` away from zero at `attack` units per second, and back towards zero at `release` units per second
+    ///
+    /// This turns a digital button into an analog value with a configurable attack and release,
+    /// so that the same action can be driven by a trigger on a gamepad and by a key on a keyboard.
+    #[must_use]
+    pub fn with_attack_release(mut self, action: A, attack: f32, release: f32) -> Self {
+        self.set_speed(action, Some(RampSpeed { attack, release }));
+        self
+    }
+
+    /// Sets the speed at which the value of the `action` is ramped
+    ///
+    /// If `speed` is [`None`], the value of the `action` is no longer ramped.
+    pub fn set_speed(&mut self, action: A, speed: Option<RampSpeed>) {
+        self.speeds[action.index()] = speed;
+    }
+
+    /// The speed at which the value of the `action` is ramped
+    #[must_use]
+    pub fn speed(&self, action: A) -> Option<RampSpeed> {
+        self.speeds[action.index()]
+    }
+
+    /// Moves the values of the ramped actions of the `action_state` towards their targets, advancing time by `delta`
+    ///
+    /// This should be called once per frame, after the `action_state` has been updated from inputs.
+    pub fn apply(&mut self, action_state: &mut ActionState<A>, delta: Duration) {
+        for action in A::variants() {
+            let index = action.index();
+            let Some(speed) = self.speeds[index] else {
+                continue;
+            };
+
+            let target = action_state.value(action.clone());
+            let current = &mut self.values[index];
+            let max_step = speed.towards(*current, target) * delta.as_secs_f32();
+            *current += (target - *current).clamp(-max_step, max_step);
+
+            action_state.action_data_mut(action).value = *current;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AxisRamp;
+    use crate as leafwing_input_manager;
+    use crate::action_state::ActionState;
+    use crate::Actionlike;
+    use bevy::utils::Duration;
+
+    #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    enum Action {
+        Steer,
+        Jump,
+    }
+
+    #[test]
+    fn values_ramp_towards_their_targets() {
+        let mut action_state = ActionState::<Action>::default();
+        let mut axis_ramp = AxisRamp::default().with_speed(Action::Steer, 4.0);
+        let frame = Duration::from_millis(100);
+
+        let mut step = |action_state: &mut ActionState<Action>, target: f32| {
+            action_state.action_data_mut(Action::Steer).value = target;
+            action_state.action_data_mut(Action::Jump).value = target;
+            axis_ramp.apply(action_state, frame);
+            action_state.value(Action::Steer)
+        };
+
+        assert!((step(&mut action_state, 1.0) - 0.4).abs() < 1e-5);
+        assert!((step(&mut action_state, 1.0) - 0.8).abs() < 1e-5);
+        assert_eq!(step(&mut action_state, 1.0), 1.0);
+        assert_eq!(step(&mut action_state, 1.0), 1.0);
+
+        // Reversing direction ramps back through zero
+        assert!((step(&mut action_state, -1.0) - 0.6).abs() < 1e-5);
+
+        // Actions without a ramp speed are unaffected
+        assert_eq!(action_state.value(Action::Jump), -1.0);
+    }
+
+    #[test]
+    fn attack_and_release_speeds_differ() {
+        let mut action_state = ActionState::<Action>::default();
+        let mut axis_ramp = AxisRamp::default().with_attack_release(Action::Steer, 2.0, 10.0);
+        let frame = Duration::from_millis(100);
+
+        let mut step = |action_state: &mut ActionState<Action>, target: f32| {
+            action_state.action_data_mut(Action::Steer).value = target;
+            axis_ramp.apply(action_state, frame);
+            action_state.value(Action::Steer)
+        };
+
+        // Pressing the button builds up slowly
+        assert!((step(&mut action_state, 1.0) - 0.2).abs() < 1e-5);
+        assert!((step(&mut action_state, 1.0) - 0.4).abs() < 1e-5);
+
+        // Releasing it cuts off quickly
+        assert_eq!(step(&mut action_state, 0.0), 0.0);
+
+        // Reversing direction releases before attacking
+        assert!((step(&mut action_state, -1.0) + 0.2).abs() < 1e-5);
+    }
+}
@@ -3,9 +3,11 @@
 use crate::buttonlike::{MouseMotionDirection, MouseWheelDirection};
 use crate::orientation::{Direction, Rotation};
 use crate::user_input::InputKind;
+use bevy::ecs::system::Resource;
 use bevy::input::{
     gamepad::{GamepadAxisType, GamepadButtonType},
     keyboard::KeyCode,
+    mouse::{MouseScrollUnit, MouseWheel},
 };
 use bevy::math::Vec2;
 use bevy::reflect::{FromReflect, Reflect};
@@ -31,6 +33,17 @@ pub struct SingleAxis {
     ///
     /// WARNING: this field is ignored for the sake of [`Eq`] and [`Hash`](std::hash::Hash)
     pub value: Option<f32>,
+    /// The range of values actually reported by the hardware, which is remapped onto the full range of the axis
+    ///
+    /// The thresholds are compared against the remapped value.
+    /// If this is [`None`], values are used as reported.
+    #[serde(default)]
+    pub range: Option<AxisRange>,
+    /// The value reported by the axis while at rest, which is subtracted from each reported value before it is remapped
+    ///
+    /// This corrects sticks that drift, and is set by [`InputMap::calibrate`](crate::input_map::InputMap::calibrate).
+    #[serde(default)]
+    pub offset: f32,
 }
 
 impl SingleAxis {
@@ -42,6 +55,8 @@ impl SingleAxis {
             positive_low: threshold,
             negative_low: -threshold,
             value: None,
+            range: None,
+            offset: 0.0,
         }
     }
 
@@ -56,6 +71,8 @@ impl SingleAxis {
             positive_low: 0.0,
             negative_low: 0.0,
             value: Some(value),
+            range: None,
+            offset: 0.0,
         }
     }
 
@@ -67,6 +84,8 @@ impl SingleAxis {
             positive_low: 0.,
             negative_low: 0.,
             value: None,
+            range: None,
+            offset: 0.0,
         }
     }
 
@@ -78,6 +97,8 @@ impl SingleAxis {
             positive_low: 0.,
             negative_low: 0.,
             value: None,
+            range: None,
+            offset: 0.0,
         }
     }
 
@@ -89,6 +110,8 @@ impl SingleAxis {
             positive_low: 0.,
             negative_low: 0.,
             value: None,
+            range: None,
+            offset: 0.0,
         }
     }
 
@@ -100,9 +123,46 @@ impl SingleAxis {
             positive_low: 0.,
             negative_low: 0.,
             value: None,
+            range: None,
+            offset: 0.0,
+        }
+    }
+
+    /// Creates a [`SingleAxis`] corresponding to the horizontal position of the cursor, in the given [`CursorSpace`]
+    #[must_use]
+    pub const fn cursor_x(space: CursorSpace) -> SingleAxis {
+        SingleAxis {
+            axis_type: AxisType::CursorPosition(CursorPositionAxisType::X(space)),
+            positive_low: 0.,
+            negative_low: 0.,
+            value: None,
+            range: None,
+            offset: 0.0,
+        }
+    }
+
+    /// Creates a [`SingleAxis`] corresponding to the vertical position of the cursor, in the given [`CursorSpace`]
+    #[must_use]
+    pub const fn cursor_y(space: CursorSpace) -> SingleAxis {
+        SingleAxis {
+            axis_type: AxisType::CursorPosition(CursorPositionAxisType::Y(space)),
+            positive_low: 0.,
+            negative_low: 0.,
+            value: None,
+            range: None,
+            offset: 0.0,
         }
     }
 
+    /// Creates a [`SingleAxis`] that is triggered once the analog `button_type` is pressed further than `threshold`
+    ///
+    /// Unlike [`InputKind::GamepadButton`], which respects the [`GamepadButtonSettings`](crate::buttonlike::GamepadButtonSettings),
+    /// this overrides the press threshold for a single binding.
+    #[must_use]
+    pub fn gamepad_button(button_type: GamepadButtonType, threshold: f32) -> SingleAxis {
+        SingleAxis::positive_only(button_type, threshold)
+    }
+
     /// Creates a [`SingleAxis`] with the `axis_type` and `negative_low` set to `threshold`.
     ///
     /// Positive values will not trigger the input.
@@ -112,6 +172,8 @@ impl SingleAxis {
             negative_low: threshold,
             positive_low: f32::MAX,
             value: None,
+            range: None,
+            offset: 0.0,
         }
     }
 
@@ -124,6 +186,8 @@ impl SingleAxis {
             negative_low: f32::MIN,
             positive_low: threshold,
             value: None,
+            range: None,
+            offset: 0.0,
         }
     }
 
@@ -134,6 +198,50 @@ impl SingleAxis {
         self.positive_low = deadzone;
         self
     }
+
+    /// Returns this [`SingleAxis`] with the values reported in the `range` remapped onto the full range of the axis
+    ///
+    /// See [`AxisRange`] for how values are remapped.
+    #[must_use]
+    pub fn with_range(mut self, range: AxisRange) -> SingleAxis {
+        self.range = Some(range);
+        self
+    }
+
+    /// Remaps a raw `value` reported by the hardware according to the [`offset`](Self::offset) and [`range`](Self::range) of this axis
+    #[must_use]
+    pub fn remap(&self, value: f32) -> f32 {
+        let value = value - self.offset;
+        match self.range {
+            Some(range) => range.remap(value),
+            None => value,
+        }
+    }
+
+    /// Rescales a `value` that lies outside of the thresholds of this axis,
+    /// so that it starts at 0.0 at the threshold and still reaches ±1.0 at full tilt
+    ///
+    /// Values within the thresholds are zeroed.
+    /// Only [`AxisType::Gamepad`] axes, whose values lie within `-1.0..=1.0`, are rescaled:
+    /// the values of unbounded axes such as mouse motion are returned unchanged.
+    #[must_use]
+    pub fn rescale(&self, value: f32) -> f32 {
+        if value <= self.positive_low && value >= self.negative_low {
+            return 0.0;
+        }
+
+        if !matches!(self.axis_type, AxisType::Gamepad(_)) {
+            return value;
+        }
+
+        if value > self.positive_low && (0.0..1.0).contains(&self.positive_low) {
+            (value - self.positive_low) / (1.0 - self.positive_low)
+        } else if value < self.negative_low && (0.0..1.0).contains(&-self.negative_low) {
+            (value - self.negative_low) / (1.0 + self.negative_low)
+        } else {
+            value
+        }
+    }
 }
 
 impl PartialEq for SingleAxis {
@@ -141,6 +249,8 @@ impl PartialEq for SingleAxis {
         self.axis_type == other.axis_type
             && FloatOrd(self.positive_low) == FloatOrd(other.positive_low)
             && FloatOrd(self.negative_low) == FloatOrd(other.negative_low)
+            && self.range == other.range
+            && FloatOrd(self.offset) == FloatOrd(other.offset)
     }
 }
 impl Eq for SingleAxis {}
@@ -149,6 +259,124 @@ impl std::hash::Hash for SingleAxis {
         self.axis_type.hash(state);
         FloatOrd(self.positive_low).hash(state);
         FloatOrd(self.negative_low).hash(state);
+        self.range.hash(state);
+        FloatOrd(self.offset).hash(state);
+    }
+}
+
+/// The range of values that an axis actually reports, such as `0.05..0.92` for the triggers of some third-party controllers
+///
+/// Values are remapped so that this range spans the full range of the axis:
+/// - if `min` is at least zero, as for triggers, `min..max` is remapped onto `0..1`
+/// - otherwise, as for sticks, `min..0` is remapped onto `-1..0` and `0..max` onto `0..1`, so that the resting position is preserved
+///
+/// Remapped values are clamped to the full range of the axis.
+/// Use an [`AxisRangeLearner`](crate::calibration::AxisRangeLearner) to measure the range of a controller.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct AxisRange {
+    /// The lowest value reported by the axis
+    pub min: f32,
+    /// The highest value reported by the axis
+    pub max: f32,
+}
+
+impl AxisRange {
+    /// Creates an [`AxisRange`] spanning from `min` to `max`
+    #[must_use]
+    pub fn new(min: f32, max: f32) -> AxisRange {
+        AxisRange { min, max }
+    }
+
+    /// Remaps a `value` within this range onto the full range of the axis
+    #[must_use]
+    pub fn remap(&self, value: f32) -> f32 {
+        // Guards against empty ranges, which would otherwise divide by zero
+        let scale = |value: f32, extent: f32| {
+            if extent.abs() > f32::EPSILON {
+                (value / extent).clamp(0.0, 1.0)
+            } else {
+                0.0
+            }
+        };
+
+        if self.min >= 0.0 {
+            scale(value - self.min, self.max - self.min)
+        } else if value < 0.0 {
+            -scale(-value, -self.min)
+        } else {
+            scale(value, self.max)
+        }
+    }
+}
+
+impl PartialEq for AxisRange {
+    fn eq(&self, other: &Self) -> bool {
+        FloatOrd(self.min) == FloatOrd(other.min) && FloatOrd(self.max) == FloatOrd(other.max)
+    }
+}
+impl Eq for AxisRange {}
+impl std::hash::Hash for AxisRange {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        FloatOrd(self.min).hash(state);
+        FloatOrd(self.max).hash(state);
+    }
+}
+
+/// The range of values that an action reports, such as `0..1` for a throttle or `-30..30` for a steering angle
+///
+/// The value of the action is remapped from the nominal range of its inputs onto this range, mirroring [`AxisRange`]:
+/// - if `min` is at least zero, `0..1` is remapped onto `min..max`, and negative values are treated as zero
+/// - otherwise, `-1..0` is remapped onto `min..0` and `0..1` onto `0..max`, so that the resting position is preserved
+///
+/// Values beyond the nominal range of the inputs are clamped first, so the result always lies within `min..max`.
+/// Set this with [`InputMap::set_output_range`](crate::input_map::InputMap::set_output_range).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct OutputRange {
+    /// The value reported at the lowest position of the inputs
+    pub min: f32,
+    /// The value reported at the highest position of the inputs
+    pub max: f32,
+}
+
+impl OutputRange {
+    /// The `-1..1` range of sticks and steering
+    pub const SIGNED: OutputRange = OutputRange {
+        min: -1.0,
+        max: 1.0,
+    };
+
+    /// The `0..1` range of triggers and throttles
+    pub const UNSIGNED: OutputRange = OutputRange { min: 0.0, max: 1.0 };
+
+    /// Creates an [`OutputRange`] spanning from `min` to `max`
+    #[must_use]
+    pub fn new(min: f32, max: f32) -> OutputRange {
+        OutputRange { min, max }
+    }
+
+    /// Remaps a `value` from the nominal range of the inputs onto this range
+    #[must_use]
+    pub fn remap(&self, value: f32) -> f32 {
+        if self.min >= 0.0 {
+            self.min + (self.max - self.min) * value.clamp(0.0, 1.0)
+        } else if value < 0.0 {
+            -self.min * value.max(-1.0)
+        } else {
+            self.max * value.min(1.0)
+        }
+    }
+}
+
+impl PartialEq for OutputRange {
+    fn eq(&self, other: &Self) -> bool {
+        FloatOrd(self.min) == FloatOrd(other.min) && FloatOrd(self.max) == FloatOrd(other.max)
+    }
+}
+impl Eq for OutputRange {}
+impl std::hash::Hash for OutputRange {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        FloatOrd(self.min).hash(state);
+        FloatOrd(self.max).hash(state);
     }
 }
 
@@ -168,6 +396,175 @@ pub struct DualAxis {
     pub x: SingleAxis,
     /// The axis representing vertical movement.
     pub y: SingleAxis,
+    /// The shape of the deadzone applied to the pair of axes.
+    ///
+    /// With the default [`DeadZoneShape::Cross`], the thresholds of `x` and `y` are used.
+    #[serde(default)]
+    pub deadzone_shape: DeadZoneShape,
+    /// How the length of the resulting [`DualAxisData`] is limited, after the deadzone has been applied.
+    #[serde(default)]
+    pub magnitude: MagnitudeLimit,
+}
+
+/// How the length of the [`DualAxisData`] produced by a [`DualAxis`] is limited
+///
+/// Gamepad sticks often report slightly more than 1.0 along the diagonals,
+/// and other inputs (such as mouse motion) are not bounded at all.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum MagnitudeLimit {
+    /// Values are passed through unchanged.
+    #[default]
+    Raw,
+    /// Values longer than 1.0 are shortened to a length of 1.0, keeping their direction.
+    ClampToUnit,
+    /// Values longer than `threshold` are scaled to a length of exactly 1.0, keeping their direction.
+    ///
+    /// Shorter values are passed through unchanged.
+    /// This is useful for games where pushing a stick past a point should always mean full speed.
+    NormalizeAbove {
+        /// The length above which values are normalized
+        threshold: f32,
+    },
+}
+
+impl MagnitudeLimit {
+    /// Limits the length of `xy` according to this [`MagnitudeLimit`]
+    #[must_use]
+    pub fn apply(&self, xy: Vec2) -> Vec2 {
+        match *self {
+            MagnitudeLimit::Raw => xy,
+            MagnitudeLimit::ClampToUnit => xy.clamp_length_max(1.0),
+            MagnitudeLimit::NormalizeAbove { threshold } => {
+                if xy.length() > threshold {
+                    xy.normalize_or_zero()
+                } else {
+                    xy
+                }
+            }
+        }
+    }
+}
+
+impl PartialEq for MagnitudeLimit {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (MagnitudeLimit::Raw, MagnitudeLimit::Raw) => true,
+            (MagnitudeLimit::ClampToUnit, MagnitudeLimit::ClampToUnit) => true,
+            (
+                MagnitudeLimit::NormalizeAbove { threshold: a },
+                MagnitudeLimit::NormalizeAbove { threshold: b },
+            ) => FloatOrd(*a) == FloatOrd(*b),
+            _ => false,
+        }
+    }
+}
+impl Eq for MagnitudeLimit {}
+impl std::hash::Hash for MagnitudeLimit {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        if let MagnitudeLimit::NormalizeAbove { threshold } = self {
+            FloatOrd(*threshold).hash(state);
+        }
+    }
+}
+
+/// The shape of the deadzone of a [`DualAxis`]
+///
+/// Checking each axis against its own threshold produces a cross-shaped deadzone,
+/// which snaps inputs close to an axis onto it and distorts diagonal movement.
+/// The other shapes consider both axes at once, rescaling values outside of the deadzone
+/// so that the output still starts at 0.0 at the edge of the deadzone and reaches 1.0 at full tilt.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum DeadZoneShape {
+    /// Each axis is zeroed using the thresholds of its own [`SingleAxis`].
+    ///
+    /// Values outside of the thresholds are rescaled independently for each axis, using [`SingleAxis::rescale`].
+    #[default]
+    Cross,
+    /// Inputs whose length is smaller than `radius` are zeroed.
+    ///
+    /// The thresholds of the [`SingleAxis`] are ignored.
+    /// Use [`DeadZoneShape::circle`] to ensure that the `radius` is valid.
+    Circle {
+        /// The radius of the deadzone
+        radius: f32,
+    },
+    /// Inputs where the absolute values of both axes are smaller than `half_size` are zeroed.
+    ///
+    /// The thresholds of the [`SingleAxis`] are ignored.
+    /// Use [`DeadZoneShape::square`] to ensure that the `half_size` is valid.
+    Square {
+        /// Half of the side length of the deadzone
+        half_size: f32,
+    },
+}
+
+impl DeadZoneShape {
+    /// The largest size of a [`DeadZoneShape::Circle`] or [`DeadZoneShape::Square`] deadzone
+    ///
+    /// Deadzones must be smaller than the full range of the axes, or no input could ever escape them.
+    pub const MAX_SIZE: f32 = 1.0 - f32::EPSILON;
+
+    /// Creates a [`DeadZoneShape::Circle`] with the provided `radius`, clamped to `0.0..=DeadZoneShape::MAX_SIZE`
+    #[must_use]
+    pub fn circle(radius: f32) -> DeadZoneShape {
+        DeadZoneShape::Circle {
+            radius: radius.clamp(0.0, Self::MAX_SIZE),
+        }
+    }
+
+    /// Creates a [`DeadZoneShape::Square`] with the provided `half_size`, clamped to `0.0..=DeadZoneShape::MAX_SIZE`
+    #[must_use]
+    pub fn square(half_size: f32) -> DeadZoneShape {
+        DeadZoneShape::Square {
+            half_size: half_size.clamp(0.0, Self::MAX_SIZE),
+        }
+    }
+
+    /// Applies this deadzone to the raw `xy` values of a pair of axes, rescaling the values outside of it
+    ///
+    /// [`DeadZoneShape::Cross`] is applied by each [`SingleAxis`] instead, and so returns `xy` unchanged.
+    /// Deadzones that cover the full range of the axes (a size of 1.0 or more) zero every input.
+    #[must_use]
+    pub fn apply(&self, xy: Vec2) -> Vec2 {
+        let (size, deadzone) = match *self {
+            DeadZoneShape::Cross => return xy,
+            DeadZoneShape::Circle { radius } => (xy.length(), radius),
+            DeadZoneShape::Square { half_size } => (xy.x.abs().max(xy.y.abs()), half_size),
+        };
+
+        if size <= deadzone || deadzone >= 1.0 {
+            Vec2::ZERO
+        } else {
+            xy * ((size - deadzone) / (1.0 - deadzone) / size)
+        }
+    }
+}
+
+impl PartialEq for DeadZoneShape {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (DeadZoneShape::Cross, DeadZoneShape::Cross) => true,
+            (DeadZoneShape::Circle { radius: a }, DeadZoneShape::Circle { radius: b }) => {
+                FloatOrd(*a) == FloatOrd(*b)
+            }
+            (DeadZoneShape::Square { half_size: a }, DeadZoneShape::Square { half_size: b }) => {
+                FloatOrd(*a) == FloatOrd(*b)
+            }
+            _ => false,
+        }
+    }
+}
+impl Eq for DeadZoneShape {}
+impl std::hash::Hash for DeadZoneShape {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            DeadZoneShape::Cross => (),
+            DeadZoneShape::Circle { radius } => FloatOrd(*radius).hash(state),
+            DeadZoneShape::Square { half_size } => FloatOrd(*half_size).hash(state),
+        }
+    }
 }
 
 impl DualAxis {
@@ -186,6 +583,8 @@ impl DualAxis {
         DualAxis {
             x: SingleAxis::symmetric(x_axis_type, threshold),
             y: SingleAxis::symmetric(y_axis_type, threshold),
+            deadzone_shape: DeadZoneShape::Cross,
+            magnitude: MagnitudeLimit::Raw,
         }
     }
 
@@ -203,6 +602,8 @@ impl DualAxis {
         DualAxis {
             x: SingleAxis::from_value(x_axis_type, x_value),
             y: SingleAxis::from_value(y_axis_type, y_value),
+            deadzone_shape: DeadZoneShape::Cross,
+            magnitude: MagnitudeLimit::Raw,
         }
     }
 
@@ -226,11 +627,26 @@ impl DualAxis {
         )
     }
 
+    /// Creates a [`DualAxis`] for the position of a finger on the touchpad of the gamepad, without a deadzone
+    ///
+    /// See the [`touchpad`](crate::touchpad) module for how touchpads are read.
+    #[cfg(feature = "touchpad")]
+    #[must_use]
+    pub fn touchpad() -> DualAxis {
+        DualAxis::symmetric(
+            crate::touchpad::TOUCHPAD_X,
+            crate::touchpad::TOUCHPAD_Y,
+            0.0,
+        )
+    }
+
     /// Creates a [`DualAxis`] corresponding to horizontal and vertical [`MouseWheel`](bevy::input::mouse::MouseWheel) movement
     pub const fn mouse_wheel() -> DualAxis {
         DualAxis {
             x: SingleAxis::mouse_wheel_x(),
             y: SingleAxis::mouse_wheel_y(),
+            deadzone_shape: DeadZoneShape::Cross,
+            magnitude: MagnitudeLimit::Raw,
         }
     }
 
@@ -239,6 +655,24 @@ impl DualAxis {
         DualAxis {
             x: SingleAxis::mouse_motion_x(),
             y: SingleAxis::mouse_motion_y(),
+            deadzone_shape: DeadZoneShape::Cross,
+            magnitude: MagnitudeLimit::Raw,
+        }
+    }
+
+    /// Creates a [`DualAxis`] corresponding to the position of the cursor in the primary window, in the given [`CursorSpace`]
+    ///
+    /// Unlike other axes, this reports an absolute position rather than a displacement from a neutral origin.
+    /// As a result, actions bound to this input are pressed whenever the cursor is inside the window
+    /// and not exactly at the origin of the chosen space.
+    ///
+    /// This is typically used for "aim at the cursor" controls, in combination with a [`DualAxis`] for a gamepad stick.
+    pub const fn cursor_position(space: CursorSpace) -> DualAxis {
+        DualAxis {
+            x: SingleAxis::cursor_x(space),
+            y: SingleAxis::cursor_y(space),
+            deadzone_shape: DeadZoneShape::Cross,
+            magnitude: MagnitudeLimit::Raw,
         }
     }
 
@@ -249,6 +683,20 @@ impl DualAxis {
         self.y = self.y.with_deadzone(deadzone);
         self
     }
+
+    /// Returns this [`DualAxis`] with the [`MagnitudeLimit`] set to the specified value
+    #[must_use]
+    pub fn with_magnitude_limit(mut self, magnitude: MagnitudeLimit) -> DualAxis {
+        self.magnitude = magnitude;
+        self
+    }
+
+    /// Returns this [`DualAxis`] with the [`DeadZoneShape`] set to the specified value
+    #[must_use]
+    pub fn with_deadzone_shape(mut self, deadzone_shape: DeadZoneShape) -> DualAxis {
+        self.deadzone_shape = deadzone_shape;
+        self
+    }
 }
 
 #[allow(clippy::doc_markdown)] // False alarm because it thinks DPad is an un-quoted item
@@ -258,6 +706,41 @@ impl DualAxis {
 /// even though it can be stored as an [`InputKind`].
 ///
 /// Instead, use it directly as [`InputKind::DualAxis`]!
+///
+/// The most common layouts are available as presets, such as [`VirtualDPad::wasd`], [`VirtualDPad::arrow_keys`]
+/// and [`VirtualDPad::dpad`], so that each layout can be bound to a dual-axis action in a single binding.
+///
+/// # Example
+/// ```rust
+/// use bevy::input::InputPlugin;
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::axislike::DualAxisData;
+/// use leafwing_input_manager::input_streams::InputStreams;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Move,
+/// }
+///
+/// let input_map = InputMap::new([
+///     (VirtualDPad::wasd(), Action::Move),
+///     (VirtualDPad::arrow_keys(), Action::Move),
+///     (VirtualDPad::dpad(), Action::Move),
+/// ]);
+///
+/// let mut app = App::new();
+/// app.add_plugin(InputPlugin);
+/// app.send_input(KeyCode::D);
+/// app.update();
+///
+/// let input_streams = InputStreams::from_world(&app.world, None);
+/// let action_state = input_map.process_all(&input_streams, ClashStrategy::PressAll);
+/// assert_eq!(
+///     action_state.axis_pair(Action::Move),
+///     Some(DualAxisData::new(1.0, 0.0))
+/// );
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct VirtualDPad {
     /// The input that represents the up direction in this virtual DPad
@@ -302,6 +785,47 @@ impl VirtualDPad {
         }
     }
 
+    /// Generates a [`VirtualDPad`] corresponding to an analog stick on a gamepad
+    ///
+    /// Each direction is pressed once the stick is pushed past `threshold` along the `x_axis` or `y_axis`,
+    /// which allows the stick to drive four button-like directional actions, as is typical for menu navigation.
+    /// Combine this with a [`DirectionalRepeat`](crate::directional_repeat::DirectionalRepeat)
+    /// to repeat held directions and filter out diagonals.
+    pub fn gamepad_stick(
+        x_axis: GamepadAxisType,
+        y_axis: GamepadAxisType,
+        threshold: f32,
+    ) -> VirtualDPad {
+        VirtualDPad {
+            up: InputKind::SingleAxis(SingleAxis::positive_only(y_axis, threshold)),
+            down: InputKind::SingleAxis(SingleAxis::negative_only(y_axis, -threshold)),
+            left: InputKind::SingleAxis(SingleAxis::negative_only(x_axis, -threshold)),
+            right: InputKind::SingleAxis(SingleAxis::positive_only(x_axis, threshold)),
+        }
+    }
+
+    /// Generates a [`VirtualDPad`] corresponding to the left analog stick on a gamepad
+    ///
+    /// See [`VirtualDPad::gamepad_stick`] for more details.
+    pub fn left_stick(threshold: f32) -> VirtualDPad {
+        VirtualDPad::gamepad_stick(
+            GamepadAxisType::LeftStickX,
+            GamepadAxisType::LeftStickY,
+            threshold,
+        )
+    }
+
+    /// Generates a [`VirtualDPad`] corresponding to the right analog stick on a gamepad
+    ///
+    /// See [`VirtualDPad::gamepad_stick`] for more details.
+    pub fn right_stick(threshold: f32) -> VirtualDPad {
+        VirtualDPad::gamepad_stick(
+            GamepadAxisType::RightStickX,
+            GamepadAxisType::RightStickY,
+            threshold,
+        )
+    }
+
     /// Generates a [`VirtualDPad`] corresponding to the face buttons on a gamepad
     ///
     /// North corresponds to up, west corresponds to left, east corresponds to right, south corresponds to down
@@ -350,6 +874,26 @@ pub struct VirtualAxis {
 }
 
 impl VirtualAxis {
+    /// Creates a [`VirtualAxis`] from the inputs for its `negative` and `positive` directions
+    ///
+    /// Buttons contribute a value of 1.0 when pressed,
+    /// while analog inputs (such as triggers or a [`SingleAxis::positive_only`] stick direction) contribute their magnitude.
+    #[must_use]
+    pub fn new(negative: impl Into<InputKind>, positive: impl Into<InputKind>) -> VirtualAxis {
+        VirtualAxis {
+            negative: negative.into(),
+            positive: positive.into(),
+        }
+    }
+
+    /// Generates a [`VirtualAxis`] corresponding to the analog left (negative) and right (positive) triggers of a gamepad
+    pub fn gamepad_triggers() -> VirtualAxis {
+        VirtualAxis {
+            negative: InputKind::GamepadButton(GamepadButtonType::LeftTrigger2),
+            positive: InputKind::GamepadButton(GamepadButtonType::RightTrigger2),
+        }
+    }
+
     /// Generates a [`VirtualAxis`] corresponding to the horizontal arrow keyboard keycodes
     pub fn horizontal_arrow_keys() -> VirtualAxis {
         VirtualAxis {
@@ -394,6 +938,10 @@ pub enum AxisType {
     MouseWheel(MouseWheelAxisType),
     /// Input associated with movement of the mouse
     MouseMotion(MouseMotionAxisType),
+    /// Input associated with the position of the cursor in the primary window
+    CursorPosition(CursorPositionAxisType),
+    /// An analog gamepad button, such as a trigger, which reports values from 0.0 to 1.0
+    GamepadButton(GamepadButtonType),
 }
 
 /// The direction of motion of the mouse wheel.
@@ -411,6 +959,49 @@ pub enum MouseWheelAxisType {
     Y,
 }
 
+/// Controls how raw [`MouseWheel`] events are converted into axis values
+///
+/// Mouse wheels typically report movement in lines ([`MouseScrollUnit::Line`]),
+/// while touchpads and some precision mice report movement in pixels ([`MouseScrollUnit::Pixel`]).
+/// Each unit is scaled separately, so that both kinds of devices produce comparable values.
+/// The `x` and `y` components of each scale are applied to horizontal and vertical scrolling respectively;
+/// use negative values to invert a direction.
+///
+/// Insert this as a resource to configure scrolling for all [`InputMap`](crate::input_map::InputMap)s.
+/// If it does not exist, the default settings are used.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct MouseWheelSettings {
+    /// The multiplier applied to [`MouseScrollUnit::Line`] events
+    pub line_scale: Vec2,
+    /// The multiplier applied to [`MouseScrollUnit::Pixel`] events
+    pub pixel_scale: Vec2,
+}
+
+impl MouseWheelSettings {
+    /// The number of pixels treated as equivalent to a single line by [`MouseWheelSettings::default`]
+    pub const DEFAULT_PIXELS_PER_LINE: f32 = 16.0;
+
+    /// Converts a raw [`MouseWheel`] event into a scaled movement
+    #[must_use]
+    pub fn scaled_delta(&self, event: &MouseWheel) -> Vec2 {
+        let scale = match event.unit {
+            MouseScrollUnit::Line => self.line_scale,
+            MouseScrollUnit::Pixel => self.pixel_scale,
+        };
+
+        Vec2::new(event.x, event.y) * scale
+    }
+}
+
+impl Default for MouseWheelSettings {
+    fn default() -> Self {
+        MouseWheelSettings {
+            line_scale: Vec2::ONE,
+            pixel_scale: Vec2::splat(1.0 / Self::DEFAULT_PIXELS_PER_LINE),
+        }
+    }
+}
+
 /// The direction of motion of the mouse.
 ///
 /// Stored in the [`AxisType`] enum.
@@ -422,6 +1013,61 @@ pub enum MouseMotionAxisType {
     Y,
 }
 
+/// Whether the values reported by an axis are positions or changes in position
+///
+/// Absolute inputs (such as gamepad sticks and the cursor position) report where the input currently is,
+/// while relative inputs (such as mouse motion and the mouse wheel) report how far the input moved since the last update.
+/// Relative values must be integrated over time to recover a position.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, Reflect, FromReflect,
+)]
+pub enum AxisSemantics {
+    /// The axis reports its current position
+    #[default]
+    Absolute,
+    /// The axis reports its change in position since the last update
+    Relative,
+}
+
+impl AxisType {
+    /// Does this axis report positions or changes in position?
+    #[must_use]
+    pub fn semantics(&self) -> AxisSemantics {
+        match self {
+            AxisType::Gamepad(_) | AxisType::GamepadButton(_) | AxisType::CursorPosition(_) => {
+                AxisSemantics::Absolute
+            }
+            AxisType::MouseWheel(_) | AxisType::MouseMotion(_) => AxisSemantics::Relative,
+        }
+    }
+}
+
+/// One axis of the cursor position.
+///
+/// Stored in the [`AxisType`] enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CursorPositionAxisType {
+    /// Horizontal position.
+    X(CursorSpace),
+    /// Vertical position.
+    Y(CursorSpace),
+}
+
+/// The coordinate space in which the cursor position is reported.
+///
+/// Used in [`CursorPositionAxisType`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CursorSpace {
+    /// Logical pixels, measured from the bottom-left corner of the window.
+    ///
+    /// This matches [`Window::cursor_position`](bevy::window::Window::cursor_position).
+    Screen,
+    /// Normalized device coordinates, ranging from -1.0 to 1.0 along each axis.
+    ///
+    /// The origin is at the center of the window, with `y` increasing upwards.
+    Ndc,
+}
+
 impl From<GamepadAxisType> for AxisType {
     fn from(axis_type: GamepadAxisType) -> Self {
         AxisType::Gamepad(axis_type)
@@ -440,6 +1086,18 @@ impl From<MouseMotionAxisType> for AxisType {
     }
 }
 
+impl From<CursorPositionAxisType> for AxisType {
+    fn from(axis_type: CursorPositionAxisType) -> Self {
+        AxisType::CursorPosition(axis_type)
+    }
+}
+
+impl From<GamepadButtonType> for AxisType {
+    fn from(button_type: GamepadButtonType) -> Self {
+        AxisType::GamepadButton(button_type)
+    }
+}
+
 impl TryFrom<AxisType> for GamepadAxisType {
     type Error = AxisConversionError;
 
@@ -473,6 +1131,17 @@ impl TryFrom<AxisType> for MouseMotionAxisType {
     }
 }
 
+impl TryFrom<AxisType> for CursorPositionAxisType {
+    type Error = AxisConversionError;
+
+    fn try_from(axis_type: AxisType) -> Result<Self, AxisConversionError> {
+        match axis_type {
+            AxisType::CursorPosition(inner) => Ok(inner),
+            _ => Err(AxisConversionError),
+        }
+    }
+}
+
 /// An [`AxisType`] could not be converted into a more specialized variant
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub struct AxisConversionError;
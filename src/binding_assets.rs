@@ -0,0 +1,262 @@
+//! Loads [`InputMap`]s from asset files, and updates the bindings of a running game whenever those files change.
+//!
+//! This module is only available with the `asset` feature.
+//! Add an [`InputMapAssetPlugin`] to register the [`InputMap`] asset type and its loader,
+//! such as [`InputMapAssetPlugin::ron`] for files written in [RON](https://github.com/ron-rs/ron),
+//! then add a [`Handle<InputMap<A>>`] next to the [`InputMap`] components that should follow the file.
+//! To reload the bindings as soon as the file is saved, enable the `filesystem_watcher` feature of Bevy
+//! and set [`AssetPlugin::watch_for_changes`](bevy::asset::AssetPlugin::watch_for_changes).
+
+use bevy::app::{App, CoreStage, Plugin};
+use bevy::asset::{
+    AddAsset, AssetEvent, AssetLoader, Assets, BoxedFuture, Error, Handle, LoadContext, LoadedAsset,
+};
+use bevy::ecs::prelude::*;
+use bevy::ecs::query::ChangeTrackers;
+use bevy::input::InputSystem;
+use bevy::reflect::TypeUuid;
+use serde::de::DeserializeOwned;
+
+use crate::input_map::InputMap;
+use crate::plugin::InputManagerSystem;
+use crate::Actionlike;
+
+/// Parses the contents of an asset file into an [`InputMap`]
+///
+/// Any format supported by [`serde`] can be used, such as JSON with `|bytes| Ok(serde_json::from_slice(bytes)?)`.
+/// RON is supported out of the box by [`InputMapAssetPlugin::ron`].
+pub type ParseInputMap<A> = fn(&[u8]) -> Result<InputMap<A>, Error>;
+
+/// An [`AssetLoader`] for [`InputMap`]s, registered by the [`InputMapAssetPlugin`]
+pub struct InputMapLoader<A: Actionlike + TypeUuid> {
+    /// The file extensions that this loader is used for
+    extensions: &'static [&'static str],
+    /// Turns the contents of the file into an [`InputMap`]
+    parse: ParseInputMap<A>,
+}
+
+impl<A: Actionlike + TypeUuid> AssetLoader for InputMapLoader<A> {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), Error>> {
+        Box::pin(async move {
+            let input_map = (self.parse)(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(input_map));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        self.extensions
+    }
+}
+
+/// A [`Plugin`] that loads [`InputMap<A>`]s from asset files, and keeps the bindings of entities up to date with them
+///
+/// Entities with both a [`Handle<InputMap<A>>`] and an [`InputMap<A>`] component have their bindings replaced
+/// whenever the asset is loaded or modified, and whenever their handle changes.
+/// The associated gamepad, associated window and [`BindingPolicy`](crate::input_map::BindingPolicy) of their [`InputMap`] are preserved.
+///
+/// This requires the [`AssetPlugin`](bevy::asset::AssetPlugin), which is part of Bevy's `DefaultPlugins`.
+///
+/// Like every asset type, the action type must implement [`TypeUuid`]:
+/// the uuid of each [`InputMap<A>`] is derived from that of `A`,
+/// so that the [`InputMap`]s of different action types can be told apart.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy::reflect::TypeUuid;
+/// use leafwing_input_manager::binding_assets::InputMapAssetPlugin;
+/// use leafwing_input_manager::prelude::*;
+/// use serde::Deserialize;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Deserialize, TypeUuid)]
+/// #[uuid = "4f3d2a3e-1c5b-4d8e-9a60-2b7c1e9f0d42"]
+/// enum Action {
+///     Jump,
+/// }
+///
+/// fn spawn_player(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands
+///         .spawn(InputManagerBundle::<Action>::default())
+///         .insert(asset_server.load::<InputMap<Action>, _>("player.bindings"));
+/// }
+///
+/// App::new()
+///     .add_plugins(MinimalPlugins)
+///     .add_plugin(AssetPlugin::default())
+///     .add_plugin(InputManagerPlugin::<Action>::default())
+///     .add_plugin(InputMapAssetPlugin::<Action>::ron(&["bindings"]))
+///     .add_startup_system(spawn_player);
+/// ```
+pub struct InputMapAssetPlugin<A: Actionlike + TypeUuid> {
+    /// The file extensions that are loaded as [`InputMap`]s
+    extensions: &'static [&'static str],
+    /// Turns the contents of each file into an [`InputMap`]
+    parse: ParseInputMap<A>,
+}
+
+impl<A: Actionlike + TypeUuid> InputMapAssetPlugin<A> {
+    /// Loads files with any of the `extensions` as [`InputMap`]s, using the `parse` function
+    ///
+    /// Extensions are given without their leading dot, and may contain dots themselves, such as `"bindings.ron"`.
+    #[must_use]
+    pub fn new(extensions: &'static [&'static str], parse: ParseInputMap<A>) -> Self {
+        Self { extensions, parse }
+    }
+
+    /// Loads files with any of the `extensions` as [`InputMap`]s written in [RON](https://github.com/ron-rs/ron)
+    ///
+    /// The files use the same representation as the [`Serialize`](serde::Serialize) implementation of [`InputMap`].
+    #[must_use]
+    pub fn ron(extensions: &'static [&'static str]) -> Self
+    where
+        A: DeserializeOwned + Eq + std::hash::Hash,
+    {
+        Self::new(extensions, |bytes| Ok(ron::de::from_bytes(bytes)?))
+    }
+}
+
+impl<A: Actionlike + TypeUuid> Plugin for InputMapAssetPlugin<A> {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<InputMap<A>>()
+            .add_asset_loader(InputMapLoader {
+                extensions: self.extensions,
+                parse: self.parse,
+            })
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                reload_input_maps::<A>
+                    .after(InputSystem)
+                    .before(InputManagerSystem::Update),
+            );
+    }
+}
+
+/// Replaces the bindings of each [`InputMap<A>`] component with those of its [`Handle<InputMap<A>>`],
+/// whenever the asset is loaded or modified, or the handle changes
+pub fn reload_input_maps<A: Actionlike + TypeUuid>(
+    mut asset_events: EventReader<AssetEvent<InputMap<A>>>,
+    assets: Res<Assets<InputMap<A>>>,
+    mut query: Query<(
+        &Handle<InputMap<A>>,
+        ChangeTrackers<Handle<InputMap<A>>>,
+        &mut InputMap<A>,
+    )>,
+) {
+    let modified: Vec<&Handle<InputMap<A>>> = asset_events
+        .iter()
+        .filter_map(|event| match event {
+            AssetEvent::Created { handle } | AssetEvent::Modified { handle } => Some(handle),
+            AssetEvent::Removed { .. } => None,
+        })
+        .collect();
+
+    for (handle, handle_tracker, mut input_map) in query.iter_mut() {
+        if !handle_tracker.is_changed() && !modified.contains(&handle) {
+            continue;
+        }
+
+        if let Some(asset) = assets.get(handle) {
+            input_map.replace_bindings(asset.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InputMapAssetPlugin;
+    use crate as leafwing_input_manager;
+    use crate::input_map::InputMap;
+    use crate::plugin::InputManagerPlugin;
+    use crate::Actionlike;
+    use bevy::asset::{AssetPlugin, Assets};
+    use bevy::input::gamepad::Gamepad;
+    use bevy::input::keyboard::KeyCode;
+    use bevy::input::InputPlugin;
+    use bevy::prelude::*;
+    use bevy::reflect::TypeUuid;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(
+        Actionlike,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        Debug,
+        Serialize,
+        Deserialize,
+        TypeUuid,
+    )]
+    #[uuid = "9b1c6f0e-7d2a-4e3b-8c5f-0a1d2e3f4b5c"]
+    enum Action {
+        Jump,
+    }
+
+    #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug, TypeUuid)]
+    #[uuid = "0c2d4e6f-8a1b-4c3d-9e5f-7a8b9c0d1e2f"]
+    enum MenuAction {
+        Select,
+    }
+
+    #[test]
+    fn input_maps_of_each_action_type_are_distinct_assets() {
+        assert_ne!(
+            InputMap::<Action>::TYPE_UUID,
+            InputMap::<MenuAction>::TYPE_UUID
+        );
+        assert_ne!(InputMap::<Action>::TYPE_UUID, Action::TYPE_UUID);
+    }
+
+    #[test]
+    fn ron_files_are_parsed() {
+        let input_map = InputMap::new([(KeyCode::Space, Action::Jump)]);
+        let contents = ron::ser::to_string(&input_map).unwrap();
+
+        let plugin = InputMapAssetPlugin::<Action>::ron(&["bindings.ron"]);
+        assert_eq!((plugin.parse)(contents.as_bytes()).unwrap(), input_map);
+        assert!((plugin.parse)(b"not an input map").is_err());
+    }
+
+    #[test]
+    fn input_maps_follow_their_asset() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugin(AssetPlugin::default())
+            .add_plugin(InputPlugin)
+            .add_plugin(InputManagerPlugin::<Action>::default())
+            .add_plugin(InputMapAssetPlugin::<Action>::new(&["bindings"], |_| {
+                Ok(InputMap::default())
+            }));
+
+        let handle = app
+            .world
+            .resource_mut::<Assets<InputMap<Action>>>()
+            .add(InputMap::new([(KeyCode::Space, Action::Jump)]));
+        let mut input_map = InputMap::<Action>::default();
+        input_map.set_gamepad(Gamepad { id: 0 });
+        let entity = app.world.spawn((input_map, handle.clone())).id();
+
+        app.update();
+        let input_map = app.world.get::<InputMap<Action>>(entity).unwrap();
+        assert_eq!(input_map.get(Action::Jump).len(), 1);
+        assert_eq!(input_map.gamepad(), Some(Gamepad { id: 0 }));
+
+        // Editing the asset updates the bindings
+        app.world
+            .resource_mut::<Assets<InputMap<Action>>>()
+            .get_mut(&handle)
+            .unwrap()
+            .insert(KeyCode::W, Action::Jump);
+        app.update();
+        let input_map = app.world.get::<InputMap<Action>>(entity).unwrap();
+        assert_eq!(input_map.get(Action::Jump).len(), 2);
+    }
+}
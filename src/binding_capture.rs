@@ -0,0 +1,352 @@
+//! This module contains [`BindingCapture`] and its supporting methods and impls.
+//!
+//! The [`BindingCapture`] is an optional resource that records the next input pressed by the player,
+//! so that rebinding screens can offer a "press a key" prompt.
+//! Captured inputs are filtered and sanitized first, so that stray mouse movement, held keys
+//! and the distinction between left and right modifiers do not end up in the player's bindings.
+
+use bevy::ecs::prelude::*;
+use bevy::input::keyboard::KeyCode;
+use bevy::math::Vec2;
+
+use crate::buttonlike::{MouseMotionDirection, MouseWheelDirection};
+use crate::inhibit_input::InputDevice;
+use crate::user_input::{InputKind, Modifier};
+
+/// Records the next input pressed by the player, to be used as a new binding
+///
+/// Insert this as a resource, and [`start`](Self::start) it when the player selects a binding to replace.
+/// While active, [`capture_binding`](crate::systems::capture_binding) offers each newly pressed key, mouse button,
+/// gamepad button, mouse wheel movement and mouse movement to the capture,
+/// and the first one that passes its filters becomes the [`captured`](Self::captured) input.
+/// The capture then stops by itself.
+///
+/// By default:
+/// - mouse movement is ignored, as it is almost always accidental (see [`with_mouse_motion`](Self::with_mouse_motion))
+/// - `Escape` is ignored, so that it can be used to cancel the prompt (see [`with_rejected_keys`](Self::with_rejected_keys))
+/// - inputs from every device are accepted (see [`with_required_device`](Self::with_required_device))
+/// - left and right modifier keys are collapsed into a single [`Modifier`] (see [`with_collapsed_modifiers`](Self::with_collapsed_modifiers))
+/// - key repeats, and keys that were already held when the capture started, are ignored (see [`with_key_repeats`](Self::with_key_repeats))
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::binding_capture::BindingCapture;
+/// use leafwing_input_manager::inhibit_input::InputDevice;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Jump,
+/// }
+///
+/// fn rebind_jump(mut capture: ResMut<BindingCapture>, mut input_map: ResMut<InputMap<Action>>) {
+///     if let Some(input) = capture.take_captured() {
+///         input_map.clear_action(Action::Jump);
+///         input_map.insert(input, Action::Jump);
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugins(MinimalPlugins)
+///     .add_plugin(InputManagerPlugin::<Action>::default())
+///     .init_resource::<InputMap<Action>>()
+///     // Only gamepad buttons can be captured on the gamepad settings screen
+///     .insert_resource(BindingCapture::default().with_required_device(Some(InputDevice::Gamepad)))
+///     .add_system(rebind_jump);
+/// ```
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct BindingCapture {
+    /// Is the next input currently being captured?
+    active: bool,
+    /// The input captured since the capture was last started
+    captured: Option<InputKind>,
+    /// Can mouse movement be captured?
+    mouse_motion: bool,
+    /// The keys that are never captured
+    rejected_keys: Vec<KeyCode>,
+    /// The only device whose inputs can be captured, if any
+    required_device: Option<InputDevice>,
+    /// Are left and right modifier keys captured as a [`Modifier`]?
+    collapsed_modifiers: bool,
+    /// Can repeated key presses be captured?
+    key_repeats: bool,
+    /// The keys that are currently held, used to detect key repeats
+    held_keys: Vec<KeyCode>,
+}
+
+impl Default for BindingCapture {
+    fn default() -> Self {
+        Self {
+            active: false,
+            captured: None,
+            mouse_motion: false,
+            rejected_keys: vec![KeyCode::Escape],
+            required_device: None,
+            collapsed_modifiers: true,
+            key_repeats: false,
+            held_keys: Vec::new(),
+        }
+    }
+}
+
+impl BindingCapture {
+    /// The distance the mouse must move in a single frame for the movement to be captured
+    pub const MOUSE_MOTION_THRESHOLD: f32 = 10.0;
+
+    /// Sets whether mouse movement can be captured, as a [`MouseMotionDirection`]
+    ///
+    /// By default, mouse movement is ignored.
+    #[must_use]
+    pub fn with_mouse_motion(mut self, mouse_motion: bool) -> Self {
+        self.mouse_motion = mouse_motion;
+        self
+    }
+
+    /// Replaces the keys that are never captured
+    ///
+    /// By default, this is only `Escape`.
+    #[must_use]
+    pub fn with_rejected_keys(mut self, keys: impl IntoIterator<Item = KeyCode>) -> Self {
+        self.rejected_keys = keys.into_iter().collect();
+        self
+    }
+
+    /// Only captures inputs from the `device`, or from every device if this is [`None`]
+    ///
+    /// By default, inputs from every device are captured.
+    #[must_use]
+    pub fn with_required_device(mut self, device: Option<InputDevice>) -> Self {
+        self.required_device = device;
+        self
+    }
+
+    /// Sets whether left and right modifier keys (such as `LShift` and `RShift`) are captured as a single [`Modifier`]
+    ///
+    /// By default, modifier keys are collapsed.
+    #[must_use]
+    pub fn with_collapsed_modifiers(mut self, collapsed_modifiers: bool) -> Self {
+        self.collapsed_modifiers = collapsed_modifiers;
+        self
+    }
+
+    /// Sets whether the repeated presses sent while a key is held can be captured
+    ///
+    /// By default, key repeats are ignored, as are keys that were already held when the capture started.
+    #[must_use]
+    pub fn with_key_repeats(mut self, key_repeats: bool) -> Self {
+        self.key_repeats = key_repeats;
+        self
+    }
+
+    /// Starts capturing the next input, discarding any previously captured input
+    pub fn start(&mut self) {
+        self.active = true;
+        self.captured = None;
+    }
+
+    /// Stops capturing without recording an input
+    pub fn cancel(&mut self) {
+        self.active = false;
+    }
+
+    /// Is the next input currently being captured?
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// The input captured since the capture was last [started](Self::start), if any
+    #[must_use]
+    pub fn captured(&self) -> Option<InputKind> {
+        self.captured
+    }
+
+    /// Returns the captured input, leaving nothing captured
+    pub fn take_captured(&mut self) -> Option<InputKind> {
+        self.captured.take()
+    }
+
+    /// Does this capture accept the `input`, once [sanitized](Self::sanitize)?
+    #[must_use]
+    pub fn accepts(&self, input: InputKind) -> bool {
+        let input = self.sanitize(input);
+        if let Some(device) = self.required_device {
+            if input.device() != Some(device) {
+                return false;
+            }
+        }
+
+        match input {
+            InputKind::Keyboard(key) => !self.rejected_keys.contains(&key),
+            InputKind::Modifier(modifier) => !modifier
+                .key_codes()
+                .iter()
+                .any(|key| self.rejected_keys.contains(key)),
+            InputKind::MouseMotion(_) => self.mouse_motion,
+            _ => true,
+        }
+    }
+
+    /// Converts the `input` into the form in which it is captured
+    ///
+    /// Unless [`with_collapsed_modifiers`](Self::with_collapsed_modifiers) disabled this,
+    /// left and right modifier keys are converted into the corresponding [`Modifier`].
+    #[must_use]
+    pub fn sanitize(&self, input: InputKind) -> InputKind {
+        let InputKind::Keyboard(key) = input else {
+            return input;
+        };
+        if !self.collapsed_modifiers {
+            return input;
+        }
+
+        [
+            Modifier::Alt,
+            Modifier::Control,
+            Modifier::Shift,
+            Modifier::Win,
+        ]
+        .into_iter()
+        .find(|modifier| modifier.key_codes().contains(&key))
+        .map_or(input, InputKind::Modifier)
+    }
+
+    /// Captures the `input` if the capture is active and [accepts](Self::accepts) it
+    ///
+    /// Returns `true` if the input was captured, which stops the capture.
+    pub fn receive(&mut self, input: InputKind) -> bool {
+        if !self.active || !self.accepts(input) {
+            return false;
+        }
+
+        self.captured = Some(self.sanitize(input));
+        self.active = false;
+        true
+    }
+
+    /// Records that the `key` was pressed or released, capturing it if it was newly pressed
+    ///
+    /// Key presses are tracked even while the capture is inactive, so that keys held when it starts count as repeats.
+    /// Returns `true` if the key was captured.
+    pub fn receive_key(&mut self, key: KeyCode, pressed: bool) -> bool {
+        if !pressed {
+            self.held_keys.retain(|held| *held != key);
+            return false;
+        }
+
+        let repeat = self.held_keys.contains(&key);
+        if !repeat {
+            self.held_keys.push(key);
+        }
+        if repeat && !self.key_repeats {
+            return false;
+        }
+        self.receive(InputKind::Keyboard(key))
+    }
+
+    /// Captures the mouse `motion` of a single frame, if it is large enough and mouse movement is accepted
+    ///
+    /// The motion is captured as the [`MouseMotionDirection`] of its largest component.
+    /// Returns `true` if the motion was captured.
+    pub fn receive_mouse_motion(&mut self, motion: Vec2) -> bool {
+        if motion.abs().max_element() < Self::MOUSE_MOTION_THRESHOLD {
+            return false;
+        }
+
+        let direction = if motion.x.abs() >= motion.y.abs() {
+            if motion.x > 0.0 {
+                MouseMotionDirection::Right
+            } else {
+                MouseMotionDirection::Left
+            }
+        } else if motion.y > 0.0 {
+            MouseMotionDirection::Up
+        } else {
+            MouseMotionDirection::Down
+        };
+        self.receive(InputKind::MouseMotion(direction))
+    }
+
+    /// Captures the mouse wheel `scroll` of a single event, as the [`MouseWheelDirection`] of its largest component
+    ///
+    /// Returns `true` if the scroll was captured.
+    pub fn receive_mouse_wheel(&mut self, scroll: Vec2) -> bool {
+        if scroll == Vec2::ZERO {
+            return false;
+        }
+
+        let direction = if scroll.x.abs() >= scroll.y.abs() {
+            if scroll.x > 0.0 {
+                MouseWheelDirection::Right
+            } else {
+                MouseWheelDirection::Left
+            }
+        } else if scroll.y > 0.0 {
+            MouseWheelDirection::Up
+        } else {
+            MouseWheelDirection::Down
+        };
+        self.receive(InputKind::MouseWheel(direction))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BindingCapture;
+    use crate::inhibit_input::InputDevice;
+    use crate::user_input::{InputKind, Modifier};
+    use bevy::input::gamepad::GamepadButtonType;
+    use bevy::input::keyboard::KeyCode;
+    use bevy::input::mouse::MouseButton;
+    use bevy::math::Vec2;
+
+    #[test]
+    fn inputs_are_filtered_and_sanitized() {
+        let mut capture = BindingCapture::default();
+        assert!(!capture.receive_key(KeyCode::A, true));
+        capture.receive_key(KeyCode::A, false);
+
+        capture.start();
+        assert!(!capture.receive_key(KeyCode::Escape, true));
+        assert!(!capture.receive_mouse_motion(Vec2::new(50.0, 0.0)));
+        assert!(capture.receive_key(KeyCode::RShift, true));
+        assert_eq!(
+            capture.captured(),
+            Some(InputKind::Modifier(Modifier::Shift))
+        );
+        assert!(!capture.is_active());
+
+        capture.start();
+        assert_eq!(capture.captured(), None);
+        assert!(capture.receive(InputKind::Mouse(MouseButton::Left)));
+        assert_eq!(
+            capture.take_captured(),
+            Some(InputKind::Mouse(MouseButton::Left))
+        );
+        assert_eq!(capture.captured(), None);
+    }
+
+    #[test]
+    fn key_repeats_are_ignored() {
+        let mut capture = BindingCapture::default();
+        // Held down to open the prompt
+        capture.receive_key(KeyCode::Return, true);
+
+        capture.start();
+        assert!(!capture.receive_key(KeyCode::Return, true));
+        capture.receive_key(KeyCode::Return, false);
+        assert!(capture.receive_key(KeyCode::Return, true));
+    }
+
+    #[test]
+    fn required_device_is_respected() {
+        let mut capture =
+            BindingCapture::default().with_required_device(Some(InputDevice::Gamepad));
+
+        capture.start();
+        assert!(!capture.receive_key(KeyCode::Space, true));
+        assert!(!capture.receive(InputKind::Mouse(MouseButton::Right)));
+        assert!(capture.receive(InputKind::GamepadButton(GamepadButtonType::South)));
+    }
+}
@@ -1,5 +1,6 @@
 //! Tools for working with button-like user inputs (mouse clicks, gamepad button, keyboard inputs and so on)
 //!
+use bevy::ecs::system::Resource;
 use bevy::reflect::{FromReflect, Reflect};
 use serde::{Deserialize, Serialize};
 
@@ -118,3 +119,31 @@ pub enum MouseMotionDirection {
     /// Corresponds to `-x`
     Left,
 }
+
+/// Controls when analog [`GamepadButton`](bevy::input::gamepad::GamepadButton)s, such as triggers, are considered pressed
+///
+/// By default, [`InputKind::GamepadButton`](crate::user_input::InputKind::GamepadButton) inputs respect the
+/// [`ButtonSettings`](bevy::input::gamepad::ButtonSettings) stored in Bevy's [`GamepadSettings`](bevy::input::gamepad::GamepadSettings).
+/// Set [`press_threshold`](Self::press_threshold) to use a single threshold for every analog button instead.
+/// Individual bindings can override this using [`SingleAxis::gamepad_button`](crate::axislike::SingleAxis::gamepad_button).
+///
+/// Insert this as a resource to configure gamepad buttons for all [`InputMap`](crate::input_map::InputMap)s.
+/// If it does not exist, the default settings are used.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Default)]
+pub struct GamepadButtonSettings {
+    /// The analog value above which a gamepad button is considered pressed
+    ///
+    /// If this is [`None`], Bevy's [`GamepadSettings`](bevy::input::gamepad::GamepadSettings) are used.
+    /// Buttons that do not report an analog value always use Bevy's digital button state.
+    pub press_threshold: Option<f32>,
+}
+
+impl GamepadButtonSettings {
+    /// Creates a [`GamepadButtonSettings`] that treats analog buttons as pressed above `press_threshold`
+    #[must_use]
+    pub fn with_threshold(press_threshold: f32) -> Self {
+        Self {
+            press_threshold: Some(press_threshold),
+        }
+    }
+}
@@ -0,0 +1,432 @@
+//! Tools for calibrating analog sticks that drift while at rest.
+//!
+//! An [`AxisCalibrator`] samples the value of a resting axis over a short period,
+//! producing an [`AxisCalibration`] that can be applied to the bindings of an [`InputMap`](crate::input_map::InputMap)
+//! with [`InputMap::calibrate`](crate::input_map::InputMap::calibrate).
+//! As the calibration is stored in the offset and thresholds of each [`SingleAxis`],
+//! it is saved and loaded along with the rest of the [`InputMap`](crate::input_map::InputMap).
+//!
+//! Similarly, an [`AxisRangeLearner`] observes the range of values that an axis actually reports,
+//! which can be remapped onto the full range of the axis with [`InputMap::set_axis_range`](crate::input_map::InputMap::set_axis_range).
+
+use bevy::input::gamepad::{GamepadAxis, GamepadAxisType, GamepadButton};
+use serde::{Deserialize, Serialize};
+
+use crate::axislike::{AxisRange, AxisType, DualAxis, SingleAxis, VirtualAxis, VirtualDPad};
+use crate::input_streams::InputStreams;
+use crate::user_input::{InputKind, UserInput};
+
+/// The resting bias and recommended deadzone of a single axis, as measured by an [`AxisCalibrator`]
+///
+/// When applied to a [`SingleAxis`], the `bias` becomes its [`offset`](SingleAxis::offset),
+/// so that reported values are centered on the resting position,
+/// and its thresholds are widened to at least the `deadzone`, so that the resting axis no longer triggers the input.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct AxisCalibration {
+    /// The average value reported by the axis while at rest
+    pub bias: f32,
+    /// How far from the `bias` the axis may wander while at rest
+    pub deadzone: f32,
+}
+
+impl AxisCalibration {
+    /// Returns the `axis` with this calibration applied
+    ///
+    /// Thresholds that are already further from the resting position than the `deadzone` are kept,
+    /// as are the unbounded thresholds of axes that only trigger in one direction,
+    /// such as [`SingleAxis::positive_only`] and [`SingleAxis::gamepad_button`].
+    #[must_use]
+    pub fn apply(&self, axis: SingleAxis) -> SingleAxis {
+        let negative_low = if axis.negative_low == f32::MIN {
+            axis.negative_low
+        } else {
+            axis.negative_low.min(-self.deadzone)
+        };
+        let positive_low = if axis.positive_low == f32::MAX {
+            axis.positive_low
+        } else {
+            axis.positive_low.max(self.deadzone)
+        };
+
+        SingleAxis {
+            negative_low,
+            positive_low,
+            offset: self.bias,
+            ..axis
+        }
+    }
+
+    /// Returns the `input` with this calibration applied to every [`SingleAxis`] that reads the gamepad `axis_type`
+    ///
+    /// This includes the axes of [`DualAxis`] inputs, and inputs nested in chords and virtual axes.
+    #[must_use]
+    pub fn apply_to_input(&self, axis_type: GamepadAxisType, input: &UserInput) -> UserInput {
+        map_single_axes(input, |axis| {
+            if axis.axis_type == AxisType::Gamepad(axis_type) {
+                self.apply(axis)
+            } else {
+                axis
+            }
+        })
+    }
+}
+
+/// Returns the `input` with every [`SingleAxis`] replaced by the result of `map`
+///
+/// This includes the axes of [`DualAxis`] inputs, and inputs nested in chords and virtual axes.
+pub(crate) fn map_single_axes(
+    input: &UserInput,
+    map: impl Fn(SingleAxis) -> SingleAxis,
+) -> UserInput {
+    let map_kind = |input_kind: InputKind| match input_kind {
+        InputKind::SingleAxis(axis) => InputKind::SingleAxis(map(axis)),
+        InputKind::DualAxis(dual_axis) => InputKind::DualAxis(DualAxis {
+            x: map(dual_axis.x),
+            y: map(dual_axis.y),
+            ..dual_axis
+        }),
+        _ => input_kind,
+    };
+
+    match input {
+        UserInput::Single(button) => UserInput::Single(map_kind(*button)),
+        UserInput::Chord(buttons) => {
+            UserInput::Chord(buttons.iter().map(|&button| map_kind(button)).collect())
+        }
+        UserInput::VirtualDPad(VirtualDPad {
+            up,
+            down,
+            left,
+            right,
+        }) => UserInput::VirtualDPad(VirtualDPad {
+            up: map_kind(*up),
+            down: map_kind(*down),
+            left: map_kind(*left),
+            right: map_kind(*right),
+        }),
+        UserInput::VirtualAxis(VirtualAxis { negative, positive }) => {
+            UserInput::VirtualAxis(VirtualAxis {
+                negative: map_kind(*negative),
+                positive: map_kind(*positive),
+            })
+        }
+    }
+}
+
+/// Measures the drift of an axis while the player leaves it at rest
+///
+/// Call [`sample`](Self::sample) (or [`sample_gamepad_axis`](Self::sample_gamepad_axis)) once per frame
+/// for a short period (typically around a second) while asking the player not to touch the stick,
+/// then read the result with [`calibration`](Self::calibration).
+///
+/// # Example
+/// ```rust
+/// use bevy::input::gamepad::GamepadAxisType;
+/// use leafwing_input_manager::calibration::AxisCalibrator;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Move,
+/// }
+///
+/// let mut calibrator = AxisCalibrator::default();
+/// for value in [0.08, 0.1, 0.12] {
+///     calibrator.sample(value);
+/// }
+///
+/// let calibration = calibrator.calibration().unwrap();
+/// let mut input_map = InputMap::new([(DualAxis::left_stick(), Action::Move)]);
+/// input_map.calibrate(GamepadAxisType::LeftStickX, calibration);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AxisCalibrator {
+    n_samples: usize,
+    sum: f32,
+    min: f32,
+    max: f32,
+}
+
+impl AxisCalibrator {
+    /// The recommended deadzone is this many times the largest deviation from the bias that was observed
+    pub const DEADZONE_MARGIN: f32 = 1.5;
+
+    /// The smallest deadzone that will be recommended, to account for noise that was not sampled
+    pub const MIN_DEADZONE: f32 = 0.02;
+
+    /// Records a `value` reported by the resting axis
+    pub fn sample(&mut self, value: f32) {
+        if self.n_samples == 0 {
+            self.min = value;
+            self.max = value;
+        } else {
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+        }
+
+        self.n_samples += 1;
+        self.sum += value;
+    }
+
+    /// Records the current value of the gamepad `axis_type` of the [guessed gamepad](InputStreams::guess_gamepad)
+    ///
+    /// Does nothing if no gamepad is connected.
+    pub fn sample_gamepad_axis(
+        &mut self,
+        input_streams: &InputStreams,
+        axis_type: GamepadAxisType,
+    ) {
+        let Some(gamepad) = input_streams.guess_gamepad() else {
+            return;
+        };
+
+        let value = input_streams
+            .gamepad_axes
+            .get(GamepadAxis { gamepad, axis_type })
+            .unwrap_or_default();
+        self.sample(value);
+    }
+
+    /// The number of values that have been sampled so far
+    #[must_use]
+    pub fn n_samples(&self) -> usize {
+        self.n_samples
+    }
+
+    /// The measured bias and recommended deadzone of the axis
+    ///
+    /// Returns [`None`] if no values have been sampled.
+    #[must_use]
+    pub fn calibration(&self) -> Option<AxisCalibration> {
+        if self.n_samples == 0 {
+            return None;
+        }
+
+        let bias = self.sum / self.n_samples as f32;
+        let deviation = (self.max - bias).max(bias - self.min);
+
+        Some(AxisCalibration {
+            bias,
+            deadzone: (deviation * Self::DEADZONE_MARGIN).max(Self::MIN_DEADZONE),
+        })
+    }
+}
+
+/// Learns the range of values that an axis reports, widening it whenever a value outside of it is observed
+///
+/// Cheap controllers often never quite reach the ends of their axes: triggers may report `0.05..0.92` rather than `0..1`.
+/// Call [`observe`](Self::observe) (or [`observe_axis`](Self::observe_axis)) every frame while the player uses the controller,
+/// and periodically apply the learned [`range`](Self::range) with [`InputMap::set_axis_range`](crate::input_map::InputMap::set_axis_range)
+/// so that values reliably span the full range of the axis.
+///
+/// # Example
+/// ```rust
+/// use bevy::input::gamepad::GamepadButtonType;
+/// use leafwing_input_manager::axislike::{AxisRange, AxisType};
+/// use leafwing_input_manager::calibration::AxisRangeLearner;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Accelerate,
+/// }
+///
+/// // Assume a nearly complete range, so that the first presses are not exaggerated
+/// let mut learner = AxisRangeLearner::new(AxisRange::new(0.1, 0.8));
+/// for value in [0.05, 0.4, 0.92] {
+///     learner.observe(value);
+/// }
+/// assert_eq!(learner.range(), AxisRange::new(0.05, 0.92));
+///
+/// let trigger = AxisType::GamepadButton(GamepadButtonType::RightTrigger2);
+/// let mut input_map = InputMap::new([(SingleAxis::gamepad_button(GamepadButtonType::RightTrigger2, 0.1), Action::Accelerate)]);
+/// input_map.set_axis_range(trigger, learner.range());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AxisRangeLearner {
+    range: AxisRange,
+}
+
+impl AxisRangeLearner {
+    /// Creates a learner that starts from the `initial` range, which is only ever widened
+    #[must_use]
+    pub fn new(initial: AxisRange) -> Self {
+        Self { range: initial }
+    }
+
+    /// Widens the learned range to include the `value`
+    ///
+    /// Returns `true` if the range was widened.
+    pub fn observe(&mut self, value: f32) -> bool {
+        if value < self.range.min {
+            self.range.min = value;
+            true
+        } else if value > self.range.max {
+            self.range.max = value;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Widens the learned range to include the current value of the `axis_type` on the [guessed gamepad](InputStreams::guess_gamepad)
+    ///
+    /// Only gamepad axes and analog gamepad buttons are observed; returns `false` for other axis types,
+    /// or if no gamepad is connected.
+    pub fn observe_axis(&mut self, input_streams: &InputStreams, axis_type: AxisType) -> bool {
+        let Some(gamepad) = input_streams.guess_gamepad() else {
+            return false;
+        };
+
+        let value = match axis_type {
+            AxisType::Gamepad(axis_type) => input_streams
+                .gamepad_axes
+                .get(GamepadAxis { gamepad, axis_type }),
+            AxisType::GamepadButton(button_type) => {
+                input_streams.gamepad_button_axes.get(GamepadButton {
+                    gamepad,
+                    button_type,
+                })
+            }
+            _ => None,
+        };
+
+        value.is_some_and(|value| self.observe(value))
+    }
+
+    /// The range of values that has been observed so far, including the initial range
+    #[must_use]
+    pub fn range(&self) -> AxisRange {
+        self.range
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AxisCalibration, AxisCalibrator, AxisRangeLearner};
+    use crate as leafwing_input_manager;
+    use crate::axislike::{AxisRange, DualAxis, SingleAxis};
+    use crate::input_map::InputMap;
+    use crate::user_input::{InputKind, UserInput};
+    use crate::Actionlike;
+    use bevy::input::gamepad::GamepadAxisType;
+
+    #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    enum Action {
+        Move,
+        Throttle,
+    }
+
+    #[test]
+    fn calibration_from_samples() {
+        let mut calibrator = AxisCalibrator::default();
+        assert_eq!(calibrator.calibration(), None);
+
+        for value in [0.1, 0.14, 0.12, 0.1, 0.14] {
+            calibrator.sample(value);
+        }
+        assert_eq!(calibrator.n_samples(), 5);
+
+        let calibration = calibrator.calibration().unwrap();
+        assert!((calibration.bias - 0.12).abs() < 1e-5);
+        assert!((calibration.deadzone - 0.03).abs() < 1e-5);
+
+        // A perfectly still axis still gets a small deadzone
+        let mut calibrator = AxisCalibrator::default();
+        calibrator.sample(0.0);
+        assert_eq!(
+            calibrator.calibration().unwrap().deadzone,
+            AxisCalibrator::MIN_DEADZONE
+        );
+    }
+
+    #[test]
+    fn calibration_is_stored_in_bindings() {
+        let mut input_map = InputMap::new([
+            (UserInput::from(DualAxis::left_stick()), Action::Move),
+            (
+                UserInput::from(SingleAxis::symmetric(GamepadAxisType::RightZ, 0.1)),
+                Action::Throttle,
+            ),
+        ]);
+        let calibration = AxisCalibration {
+            bias: 0.1,
+            deadzone: 0.05,
+        };
+        input_map.calibrate(GamepadAxisType::LeftStickX, calibration);
+
+        let Some(UserInput::Single(InputKind::DualAxis(dual_axis))) =
+            input_map.get(Action::Move).iter().next()
+        else {
+            panic!("the binding should still be a `DualAxis`");
+        };
+        assert_eq!(dual_axis.x.offset, 0.1);
+        // The default deadzone of the stick is larger than the calibrated deadzone, and so is kept
+        assert_eq!(dual_axis.x.negative_low, -DualAxis::DEFAULT_DEADZONE);
+        assert_eq!(dual_axis.x.positive_low, DualAxis::DEFAULT_DEADZONE);
+        assert!((dual_axis.x.remap(0.3) - 0.2).abs() < 1e-5);
+        // Other axes are unaffected
+        assert_eq!(dual_axis.y, DualAxis::left_stick().y);
+        assert_eq!(
+            input_map.get(Action::Throttle).iter().next(),
+            Some(&SingleAxis::symmetric(GamepadAxisType::RightZ, 0.1).into())
+        );
+    }
+
+    #[test]
+    fn calibration_keeps_one_sided_thresholds() {
+        let calibration = AxisCalibration {
+            bias: 0.1,
+            deadzone: 0.3,
+        };
+
+        let trigger = calibration.apply(SingleAxis::positive_only(GamepadAxisType::LeftZ, 0.5));
+        assert_eq!(trigger.negative_low, f32::MIN);
+        assert_eq!(trigger.positive_low, 0.5);
+
+        let stick_left =
+            calibration.apply(SingleAxis::negative_only(GamepadAxisType::LeftStickX, -0.1));
+        assert_eq!(stick_left.negative_low, -0.3);
+        assert_eq!(stick_left.positive_low, f32::MAX);
+    }
+
+    #[test]
+    fn ranges_are_remapped_and_learned() {
+        // Trigger-like ranges span 0..1
+        let trigger = AxisRange::new(0.05, 0.85);
+        assert_eq!(trigger.remap(0.05), 0.0);
+        assert!((trigger.remap(0.45) - 0.5).abs() < 1e-5);
+        assert_eq!(trigger.remap(0.9), 1.0);
+
+        // Stick-like ranges keep the resting position at zero
+        let stick = AxisRange::new(-0.8, 0.9);
+        assert_eq!(stick.remap(0.0), 0.0);
+        assert!((stick.remap(-0.4) + 0.5).abs() < 1e-5);
+        assert_eq!(stick.remap(0.9), 1.0);
+        assert_eq!(stick.remap(-1.0), -1.0);
+
+        // Empty ranges never report a value
+        assert_eq!(AxisRange::new(0.0, 0.0).remap(0.5), 0.0);
+
+        let mut learner = AxisRangeLearner::new(AxisRange::new(-0.5, 0.5));
+        assert!(!learner.observe(0.2));
+        assert!(learner.observe(-0.9));
+        assert!(learner.observe(0.7));
+        assert_eq!(learner.range(), AxisRange::new(-0.9, 0.7));
+    }
+
+    #[test]
+    fn ranges_are_stored_in_bindings() {
+        let mut input_map = InputMap::new([(DualAxis::left_stick(), Action::Move)]);
+        let range = AxisRange::new(-0.9, 0.9);
+        input_map.set_axis_range(GamepadAxisType::LeftStickY, range);
+
+        let Some(UserInput::Single(InputKind::DualAxis(dual_axis))) =
+            input_map.get(Action::Move).iter().next()
+        else {
+            panic!("the binding should still be a `DualAxis`");
+        };
+        assert_eq!(dual_axis.y.range, Some(range));
+        assert_eq!(dual_axis.x.range, None);
+    }
+}
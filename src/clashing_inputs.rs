@@ -36,9 +36,11 @@ pub enum ClashStrategy {
     ///
     /// This is the default strategy.
     PrioritizeLongest,
-    /// Use the order in which actions are defined in the enum to resolve clashing inputs
+    /// Use the [priority](InputMap::priority) of each action to resolve clashing inputs,
+    /// falling back to the order in which actions are defined in the enum
     ///
-    /// Uses the iteration order returned by [`Actionlike::variants()`],
+    /// Actions with higher priorities win. Actions with equal priorities,
+    /// as is the case for every action by default, are ordered by the iteration order returned by [`Actionlike::variants()`],
     /// which is generated in order of the enum items by the `#[derive(Actionlike)]` macro.
     UseActionOrder,
 }
@@ -49,6 +51,21 @@ impl Default for ClashStrategy {
     }
 }
 
+/// Whether the members of a chord may still press their own actions while the chord is held
+///
+/// This is set for individual chord bindings with [`InputMap::set_chord_exclusivity`],
+/// overriding the [`ClashStrategy`] for the clashes between that chord and its members.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, Serialize, Deserialize)]
+pub enum ChordExclusivity {
+    /// Clashes with the members of this chord are resolved by the [`ClashStrategy`]
+    #[default]
+    FollowStrategy,
+    /// The actions of the members of this chord are never pressed while the chord is held
+    Exclusive,
+    /// The actions of the members of this chord are pressed as usual while the chord is held
+    Passthrough,
+}
+
 impl UserInput {
     /// Does `self` clash with `other`?
     #[must_use]
@@ -96,12 +113,129 @@ impl<A: Actionlike> InputMap<A> {
     ) {
         for clash in self.get_clashes(action_data, input_streams) {
             // Remove the action in the pair that was overruled, if any
-            if let Some(culled_action) = resolve_clash(&clash, clash_strategy, input_streams) {
+            if let Some(culled_action) =
+                self.overruled_action(&clash, clash_strategy, input_streams)
+            {
                 action_data[culled_action.index()] = ActionData::default();
             }
         }
     }
 
+    /// Which of `action_a` and `action_b` would win the clash between them, given the currently pressed inputs?
+    ///
+    /// Returns [`None`] if the actions do not clash, or if both would remain pressed.
+    /// This is useful for debugging unexpected clash resolution,
+    /// particularly when [action priorities](InputMap::set_priority) are used.
+    #[must_use]
+    pub fn clash_winner(
+        &self,
+        action_a: A,
+        action_b: A,
+        input_streams: &InputStreams,
+        clash_strategy: ClashStrategy,
+    ) -> Option<A> {
+        let clash = self.possible_clash(action_a.clone(), action_b.clone())?;
+        let clash = check_clash(&clash, input_streams)?;
+        // Only the clashing inputs that are actually pressed are kept
+        if clash.inputs_a.is_empty() {
+            return None;
+        }
+
+        let overruled = self.overruled_action(&clash, clash_strategy, input_streams)?;
+
+        if overruled.index() == action_a.index() {
+            Some(action_b)
+        } else {
+            Some(action_a)
+        }
+    }
+
+    /// Which (if any) of the actions in the [`Clash`] should be discarded, accounting for action priorities?
+    #[must_use]
+    fn overruled_action(
+        &self,
+        clash: &Clash<A>,
+        clash_strategy: ClashStrategy,
+        input_streams: &InputStreams,
+    ) -> Option<A> {
+        let action_a = A::get_at(clash.index_a).unwrap();
+        let action_b = A::get_at(clash.index_b).unwrap();
+
+        let overruled = match self.overruled_by_chords(clash) {
+            Some(overruled) => overruled?,
+            None => {
+                let overruled = resolve_clash(clash, clash_strategy, input_streams)?;
+                if clash_strategy == ClashStrategy::UseActionOrder {
+                    match self
+                        .priority(action_a.clone())
+                        .cmp(&self.priority(action_b.clone()))
+                    {
+                        Ordering::Greater => action_b.clone(),
+                        Ordering::Less => action_a.clone(),
+                        Ordering::Equal => overruled,
+                    }
+                } else {
+                    overruled
+                }
+            }
+        };
+
+        // Actions that are also held by an input that does not clash with the winner stay pressed
+        let winner = if overruled.index() == action_a.index() {
+            action_b
+        } else {
+            action_a
+        };
+        if self.pressed_independently(overruled.clone(), winner, input_streams) {
+            return None;
+        }
+
+        Some(overruled)
+    }
+
+    /// The action overruled by the [`ChordExclusivity`] of the chords in the `clash`, if it overrides the [`ClashStrategy`]
+    ///
+    /// Any exclusive chord in the clash overrules the action of its members, returning `Some(Some(member_action))`.
+    /// If every clashing chord is a passthrough chord, both actions stay pressed, returning `Some(None)`.
+    #[must_use]
+    fn overruled_by_chords(&self, clash: &Clash<A>) -> Option<Option<A>> {
+        let action_a = A::get_at(clash.index_a).unwrap();
+        let action_b = A::get_at(clash.index_b).unwrap();
+
+        let mut all_passthrough = true;
+        for (input_a, input_b) in clash.inputs_a.iter().zip(clash.inputs_b.iter()) {
+            // Only the longer input of each pair can be a chord containing the other
+            let (exclusivity, member_action) = match input_a.len().cmp(&input_b.len()) {
+                Ordering::Greater => (self.chord_exclusivity(action_a.clone(), input_a), &action_b),
+                Ordering::Less => (self.chord_exclusivity(action_b.clone(), input_b), &action_a),
+                Ordering::Equal => (ChordExclusivity::FollowStrategy, &action_a),
+            };
+
+            match exclusivity {
+                ChordExclusivity::Exclusive => return Some(Some(member_action.clone())),
+                ChordExclusivity::Passthrough => (),
+                ChordExclusivity::FollowStrategy => all_passthrough = false,
+            }
+        }
+
+        all_passthrough.then_some(None)
+    }
+
+    /// Is `action` held by a pressed input that does not clash with any pressed input of the `rival` action?
+    #[must_use]
+    fn pressed_independently(&self, action: A, rival: A, input_streams: &InputStreams) -> bool {
+        let rival_inputs = self.get(rival);
+        let rival_inputs: Vec<&UserInput> = rival_inputs
+            .iter()
+            .filter(|input| input_streams.input_pressed(input))
+            .collect();
+
+        self.get(action)
+            .iter()
+            .filter(|input| input_streams.input_pressed(input))
+            .any(|input| rival_inputs.iter().all(|rival| !input.clashes(rival)))
+    }
+
     /// Updates the cache of possible input clashes
     pub(crate) fn possible_clashes(&self) -> Vec<Clash<A>> {
         let mut clashes = Vec::default();
@@ -328,7 +462,7 @@ fn check_clash<A: Actionlike>(clash: &Clash<A>, input_streams: &InputStreams) ->
         }
     }
 
-    if !clash.inputs_a.is_empty() {
+    if !actual_clash.inputs_a.is_empty() {
         Some(actual_clash)
     } else {
         None
@@ -641,6 +775,83 @@ mod tests {
             );
         }
 
+        #[test]
+        fn priorities_override_action_order() {
+            let mut app = App::new();
+            app.add_plugin(InputPlugin);
+
+            let mut input_map = test_input_map();
+            app.send_input(Key1);
+            app.send_input(LControl);
+            app.update();
+
+            let input_streams = InputStreams::from_world(&app.world, None);
+            let strategy = ClashStrategy::UseActionOrder;
+            assert_eq!(
+                input_map.clash_winner(One, CtrlOne, &input_streams, strategy),
+                Some(One)
+            );
+            // Actions that do not clash have no winner
+            assert_eq!(
+                input_map.clash_winner(One, Two, &input_streams, strategy),
+                None
+            );
+
+            input_map.set_priority(CtrlOne, 1);
+            assert_eq!(input_map.priority_order()[0], CtrlOne);
+            assert_eq!(
+                input_map.clash_winner(One, CtrlOne, &input_streams, strategy),
+                Some(CtrlOne)
+            );
+
+            let action_data = input_map.which_pressed(&input_streams, strategy);
+            assert!(action_data[CtrlOne.index()].state.pressed());
+            assert!(action_data[One.index()].state.released());
+        }
+
+        #[test]
+        fn chord_exclusivity_overrides_strategy() {
+            let mut app = App::new();
+            app.add_plugin(InputPlugin);
+
+            let mut input_map = test_input_map();
+            app.send_input(Key1);
+            app.send_input(LControl);
+            app.update();
+            let input_streams = InputStreams::from_world(&app.world, None);
+            let ctrl_one = UserInput::chord([LControl, Key1]);
+
+            // Passthrough chords let their members press their own actions
+            input_map.set_chord_exclusivity(
+                CtrlOne,
+                ctrl_one.clone(),
+                ChordExclusivity::Passthrough,
+            );
+            let action_data =
+                input_map.which_pressed(&input_streams, ClashStrategy::PrioritizeLongest);
+            assert!(action_data[CtrlOne.index()].state.pressed());
+            assert!(action_data[One.index()].state.pressed());
+
+            // Exclusive chords suppress their members, even when every action should be pressed
+            input_map.set_chord_exclusivity(CtrlOne, ctrl_one.clone(), ChordExclusivity::Exclusive);
+            let action_data = input_map.which_pressed(&input_streams, ClashStrategy::PressAll);
+            assert!(action_data[CtrlOne.index()].state.pressed());
+            assert!(action_data[One.index()].state.released());
+
+            // Only chords bound to the action can be configured
+            input_map.set_chord_exclusivity(One, Key1, ChordExclusivity::Exclusive);
+            assert_eq!(
+                input_map.chord_exclusivity(One, &Key1.into()),
+                ChordExclusivity::FollowStrategy
+            );
+
+            input_map.remove(CtrlOne, ctrl_one.clone());
+            assert_eq!(
+                input_map.chord_exclusivity(CtrlOne, &ctrl_one),
+                ChordExclusivity::FollowStrategy
+            );
+        }
+
         #[test]
         fn handle_clashes() {
             let mut app = App::new();
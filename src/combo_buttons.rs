@@ -0,0 +1,314 @@
+//! This module contains [`ComboButtons`] and its supporting methods and impls.
+//!
+//! The [`ComboButtons`] is an optional addition to an [`InputManagerBundle`](crate::InputManagerBundle),
+//! which turns a group of actions pressed at (almost) the same time into a distinct action.
+//! Fighting games commonly treat light punch and heavy punch pressed within a few frames of each other as their own button:
+//! pressing one of them briefly holds it back to see whether the rest of the group follows,
+//! and then reports either the combined action or the individual ones, but never both.
+
+use bevy::ecs::prelude::*;
+
+use crate::action_state::ActionData;
+use crate::buttonlike::ButtonState;
+use crate::Actionlike;
+
+/// A group of actions that presses a distinct action when they are all pressed within a short window
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComboButton<A: Actionlike> {
+    /// The actions that must all be pressed to trigger the [`ComboButton::combo`]
+    pub members: Vec<A>,
+    /// The action pressed in place of the [`ComboButton::members`]
+    ///
+    /// This action is pressed by the [`ComboButtons`], and should not be bound in the [`InputMap`](crate::input_map::InputMap).
+    pub combo: A,
+    /// How many updates may pass between pressing the first and the last of the [`ComboButton::members`]
+    ///
+    /// The individual members are held back for up to this many updates.
+    pub window: u32,
+    phase: ComboPhase,
+    /// Which members were pressed while the combo was pending
+    pending_presses: Vec<bool>,
+}
+
+/// Where a [`ComboButton`] is in its press cycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ComboPhase {
+    /// None of the members are pressed
+    Idle,
+    /// Some members were pressed this many updates ago, and the others may still follow
+    Pending(u32),
+    /// The window expired, and the members are reported individually until they are all released
+    Individual,
+    /// All members were pressed within the window, and the combo is reported
+    Combo,
+    /// The combo was released, and the members are ignored until they are all released
+    Spent,
+}
+
+impl<A: Actionlike> ComboButton<A> {
+    /// Creates a [`ComboButton`] that presses `combo` when all of the `members` are pressed within `window` updates
+    #[must_use]
+    pub fn new(members: impl IntoIterator<Item = A>, combo: A, window: u32) -> Self {
+        let members: Vec<A> = members.into_iter().collect();
+        let pending_presses = vec![false; members.len()];
+        Self {
+            members,
+            combo,
+            window,
+            phase: ComboPhase::Idle,
+            pending_presses,
+        }
+    }
+
+    /// Is the [`ComboButton::combo`] currently reported as pressed?
+    #[must_use]
+    pub fn active(&self) -> bool {
+        self.phase == ComboPhase::Combo
+    }
+
+    fn apply(&mut self, action_data: &mut [ActionData]) {
+        let held: Vec<bool> = self
+            .members
+            .iter()
+            .map(|member| action_data[member.index()].state.pressed())
+            .collect();
+        let any_held = held.iter().any(|&held| held);
+        let all_held = !held.is_empty() && held.iter().all(|&held| held);
+
+        self.phase = match self.phase {
+            ComboPhase::Idle | ComboPhase::Pending(_) if all_held => ComboPhase::Combo,
+            ComboPhase::Idle if !any_held => ComboPhase::Idle,
+            ComboPhase::Idle => {
+                self.pending_presses.clone_from(&held);
+                self.pending_after(0, any_held)
+            }
+            ComboPhase::Pending(elapsed) => {
+                for (pressed, &held) in self.pending_presses.iter_mut().zip(&held) {
+                    *pressed |= held;
+                }
+                self.pending_after(elapsed + 1, any_held)
+            }
+            ComboPhase::Combo if all_held => ComboPhase::Combo,
+            ComboPhase::Individual | ComboPhase::Combo | ComboPhase::Spent if !any_held => {
+                ComboPhase::Idle
+            }
+            ComboPhase::Combo => ComboPhase::Spent,
+            phase => phase,
+        };
+
+        match self.phase {
+            ComboPhase::Idle => (),
+            ComboPhase::Individual => {
+                // Members that were tapped and released while the combo was pending are reported for one update
+                if !any_held {
+                    for (member, &pressed) in self.members.iter().zip(&self.pending_presses) {
+                        if pressed {
+                            let data = &mut action_data[member.index()];
+                            data.state = ButtonState::JustPressed;
+                            data.value = 1.0;
+                        }
+                    }
+                }
+            }
+            ComboPhase::Pending(_) | ComboPhase::Spent => self.release_members(action_data),
+            ComboPhase::Combo => {
+                self.release_members(action_data);
+                let data = &mut action_data[self.combo.index()];
+                data.state = ButtonState::JustPressed;
+                data.value = 1.0;
+            }
+        }
+    }
+
+    /// The phase `elapsed` updates after the first member was pressed, when the combo did not complete
+    fn pending_after(&self, elapsed: u32, any_held: bool) -> ComboPhase {
+        if elapsed >= self.window || !any_held {
+            ComboPhase::Individual
+        } else {
+            ComboPhase::Pending(elapsed)
+        }
+    }
+
+    fn release_members(&self, action_data: &mut [ActionData]) {
+        for member in &self.members {
+            action_data[member.index()] = ActionData::default();
+        }
+    }
+}
+
+/// Presses distinct actions when groups of actions are pressed within a short window of each other
+///
+/// As a resource, this affects the [`ActionState`](crate::action_state::ActionState) resource,
+/// and as a component, the [`ActionState`](crate::action_state::ActionState) of the same entity.
+///
+/// Each [`ComboButton`] is applied by [`update_action_state`](crate::systems::update_action_state),
+/// before the pressed actions reach the [`ActionState`](crate::action_state::ActionState).
+/// While the first members of a group are pressed, they are held back for up to [`ComboButton::window`] updates.
+/// If the rest of the group follows in time, only the [`ComboButton::combo`] is pressed,
+/// until any of the members is released.
+/// Otherwise the members are pressed individually once the window expires,
+/// and completing the group late does not press the combo.
+/// Either way, the group has to be fully released before it can trigger again.
+///
+/// Members that are tapped and released before the window expires are still pressed for a single update.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::combo_buttons::{ComboButton, ComboButtons};
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     LightPunch,
+///     HeavyPunch,
+///     Throw,
+/// }
+///
+/// let mut world = World::new();
+/// world
+///     .spawn(InputManagerBundle {
+///         input_map: InputMap::new([
+///             (KeyCode::U, Action::LightPunch),
+///             (KeyCode::I, Action::HeavyPunch),
+///         ]),
+///         ..Default::default()
+///     })
+///     // Pressing both punches within 3 frames throws instead
+///     .insert(ComboButtons::new([ComboButton::new(
+///         [Action::LightPunch, Action::HeavyPunch],
+///         Action::Throw,
+///         3,
+///     )]));
+/// ```
+#[derive(Resource, Component, Debug, Clone, PartialEq, Eq)]
+pub struct ComboButtons<A: Actionlike> {
+    /// The groups of actions, applied in order
+    pub combos: Vec<ComboButton<A>>,
+}
+
+impl<A: Actionlike> Default for ComboButtons<A> {
+    fn default() -> Self {
+        Self { combos: Vec::new() }
+    }
+}
+
+impl<A: Actionlike> ComboButtons<A> {
+    /// Creates [`ComboButtons`] from the provided `combos`
+    #[must_use]
+    pub fn new(combos: impl IntoIterator<Item = ComboButton<A>>) -> Self {
+        Self {
+            combos: combos.into_iter().collect(),
+        }
+    }
+
+    /// Adds a [`ComboButton`]
+    #[must_use]
+    pub fn with(mut self, combo: ComboButton<A>) -> Self {
+        self.combos.push(combo);
+        self
+    }
+
+    /// Holds back, combines or releases the pressed actions of each [`ComboButton`]
+    ///
+    /// The `action_data` should be collected from the inputs once per update,
+    /// as by [`InputMap::which_pressed`](crate::input_map::InputMap::which_pressed).
+    pub fn apply(&mut self, action_data: &mut [ActionData]) {
+        for combo in &mut self.combos {
+            combo.apply(action_data);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as leafwing_input_manager;
+
+    #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    enum Action {
+        Light,
+        Heavy,
+        Throw,
+    }
+
+    fn combo_buttons() -> ComboButtons<Action> {
+        ComboButtons::new([ComboButton::new(
+            [Action::Light, Action::Heavy],
+            Action::Throw,
+            3,
+        )])
+    }
+
+    /// Applies the combo buttons to the `held` actions, returning the actions that are reported as pressed
+    fn step(combo_buttons: &mut ComboButtons<Action>, held: &[Action]) -> Vec<Action> {
+        let mut action_data = vec![ActionData::default(); Action::N_VARIANTS];
+        for action in held {
+            action_data[action.index()].state = ButtonState::JustPressed;
+        }
+        combo_buttons.apply(&mut action_data);
+        Action::variants()
+            .filter(|action| action_data[action.index()].state.pressed())
+            .collect()
+    }
+
+    #[test]
+    fn simultaneous_presses_trigger_the_combo() {
+        let mut combo_buttons = combo_buttons();
+        assert_eq!(step(&mut combo_buttons, &[Action::Light]), []);
+        assert_eq!(step(&mut combo_buttons, &[Action::Light]), []);
+        assert_eq!(
+            step(&mut combo_buttons, &[Action::Light, Action::Heavy]),
+            [Action::Throw]
+        );
+        assert!(combo_buttons.combos[0].active());
+        assert_eq!(
+            step(&mut combo_buttons, &[Action::Light, Action::Heavy]),
+            [Action::Throw]
+        );
+
+        // Releasing one member ends the combo, without pressing the other
+        assert_eq!(step(&mut combo_buttons, &[Action::Heavy]), []);
+        assert_eq!(step(&mut combo_buttons, &[]), []);
+        assert_eq!(step(&mut combo_buttons, &[Action::Heavy]), []);
+    }
+
+    #[test]
+    fn expired_window_presses_the_members() {
+        let mut combo_buttons = combo_buttons();
+        for _ in 0..3 {
+            assert_eq!(step(&mut combo_buttons, &[Action::Light]), []);
+        }
+        assert_eq!(step(&mut combo_buttons, &[Action::Light]), [Action::Light]);
+
+        // Completing the group late does not press the combo
+        assert_eq!(
+            step(&mut combo_buttons, &[Action::Light, Action::Heavy]),
+            [Action::Light, Action::Heavy]
+        );
+        assert_eq!(step(&mut combo_buttons, &[]), []);
+    }
+
+    #[test]
+    fn taps_within_the_window_are_not_lost() {
+        let mut combo_buttons = combo_buttons();
+        assert_eq!(step(&mut combo_buttons, &[Action::Heavy]), []);
+        assert_eq!(step(&mut combo_buttons, &[]), [Action::Heavy]);
+        assert_eq!(step(&mut combo_buttons, &[]), []);
+    }
+
+    #[test]
+    fn zero_window_requires_the_same_update() {
+        let mut combo_buttons = ComboButtons::default().with(ComboButton::new(
+            [Action::Light, Action::Heavy],
+            Action::Throw,
+            0,
+        ));
+        assert_eq!(step(&mut combo_buttons, &[Action::Light]), [Action::Light]);
+        assert_eq!(step(&mut combo_buttons, &[]), []);
+        assert_eq!(
+            step(&mut combo_buttons, &[Action::Light, Action::Heavy]),
+            [Action::Throw]
+        );
+    }
+}
@@ -0,0 +1,304 @@
+//! This module contains [`DirectionalRepeat`] and its supporting methods and impls.
+//!
+//! The [`DirectionalRepeat`] is an optional addition to an [`InputManagerBundle`](crate::InputManagerBundle),
+//! which turns four directional actions into menu-friendly inputs:
+//! holding a direction repeatedly re-presses it after a delay, and diagonal inputs can be filtered down to a single axis.
+//! This pairs well with [`VirtualDPad::gamepad_stick`](crate::axislike::VirtualDPad::gamepad_stick),
+//! which binds an analog stick to four button-like inputs.
+
+use bevy::ecs::prelude::*;
+use bevy::utils::Duration;
+
+use crate::action_state::ActionState;
+use crate::Actionlike;
+
+/// Repeats and filters four directional actions, as is typically desired for menu navigation
+///
+/// While a direction is held, it is pressed again after [`delay`](Self::delay),
+/// and then once every [`interval`](Self::interval), so that [`ActionState::just_pressed`] fires for each repeat.
+/// Each repeat releases and re-presses the action, resetting its [`ActionState::current_duration`].
+///
+/// When [`filter_diagonals`](Self::filter_diagonals) is set and both a vertical and a horizontal direction are pressed,
+/// only the axis with the larger [`ActionState::value`] remains pressed.
+///
+/// This is applied by [`apply_directional_repeat`](crate::systems::apply_directional_repeat),
+/// as part of [`InputManagerSystem::ManualControl`](crate::plugin::InputManagerSystem::ManualControl).
+///
+/// # Example
+/// ```rust
+/// use bevy::input::gamepad::GamepadAxisType;
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::directional_repeat::DirectionalRepeat;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Menu {
+///     Up,
+///     Down,
+///     Left,
+///     Right,
+/// }
+///
+/// let stick = VirtualDPad::gamepad_stick(GamepadAxisType::LeftStickX, GamepadAxisType::LeftStickY, 0.5);
+/// let input_map = InputMap::new([
+///     (stick.up, Menu::Up),
+///     (stick.down, Menu::Down),
+///     (stick.left, Menu::Left),
+///     (stick.right, Menu::Right),
+/// ]);
+///
+/// let mut world = World::new();
+/// world.spawn(InputManagerBundle {
+///     input_map,
+///     ..Default::default()
+/// })
+/// .insert(DirectionalRepeat::new(Menu::Up, Menu::Down, Menu::Left, Menu::Right));
+/// ```
+#[derive(Resource, Component, Debug, Clone, PartialEq)]
+pub struct DirectionalRepeat<A: Actionlike> {
+    /// The action that moves up
+    pub up: A,
+    /// The action that moves down
+    pub down: A,
+    /// The action that moves left
+    pub left: A,
+    /// The action that moves right
+    pub right: A,
+    /// How long a direction must be held before it starts repeating
+    pub delay: Duration,
+    /// How long to wait between each repeat, once the [`delay`](Self::delay) has elapsed
+    pub interval: Duration,
+    /// Should diagonal inputs be reduced to their dominant axis?
+    pub filter_diagonals: bool,
+    /// How long each of the up, down, left and right actions has been held for
+    held: [Duration; 4],
+}
+
+impl<A: Actionlike> DirectionalRepeat<A> {
+    /// The default value of [`DirectionalRepeat::delay`]
+    pub const DEFAULT_DELAY: Duration = Duration::from_millis(400);
+
+    /// The default value of [`DirectionalRepeat::interval`]
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(100);
+
+    /// Creates a new [`DirectionalRepeat`] for the provided directional actions
+    ///
+    /// Uses the [`DEFAULT_DELAY`](Self::DEFAULT_DELAY) and [`DEFAULT_INTERVAL`](Self::DEFAULT_INTERVAL),
+    /// and filters diagonal inputs.
+    #[must_use]
+    pub fn new(up: A, down: A, left: A, right: A) -> Self {
+        Self {
+            up,
+            down,
+            left,
+            right,
+            delay: Self::DEFAULT_DELAY,
+            interval: Self::DEFAULT_INTERVAL,
+            filter_diagonals: true,
+            held: [Duration::ZERO; 4],
+        }
+    }
+
+    /// Sets the [`delay`](Self::delay) and [`interval`](Self::interval) of this [`DirectionalRepeat`]
+    #[must_use]
+    pub fn with_timing(mut self, delay: Duration, interval: Duration) -> Self {
+        self.delay = delay;
+        self.interval = interval;
+        self
+    }
+
+    /// Sets whether or not this [`DirectionalRepeat`] should [filter diagonals](Self::filter_diagonals)
+    #[must_use]
+    pub fn with_diagonal_filtering(mut self, filter_diagonals: bool) -> Self {
+        self.filter_diagonals = filter_diagonals;
+        self
+    }
+
+    /// Filters and repeats the directional actions of the `action_state`, advancing the repeat timers by `delta`
+    ///
+    /// This should be called once per frame, after the `action_state` has been updated from inputs.
+    pub fn apply(&mut self, action_state: &mut ActionState<A>, delta: Duration) {
+        if self.filter_diagonals {
+            self.filter_diagonals(action_state);
+        }
+
+        let directions = [
+            self.up.clone(),
+            self.down.clone(),
+            self.left.clone(),
+            self.right.clone(),
+        ];
+
+        for (held, action) in self.held.iter_mut().zip(directions) {
+            if !action_state.pressed(action.clone()) {
+                *held = Duration::ZERO;
+                continue;
+            }
+
+            if action_state.just_pressed(action.clone()) {
+                *held = Duration::ZERO;
+                continue;
+            }
+
+            let previous = *held;
+            *held += delta;
+
+            if repeats_elapsed(*held, self.delay, self.interval)
+                > repeats_elapsed(previous, self.delay, self.interval)
+            {
+                action_state.release(action.clone());
+                action_state.press(action);
+            }
+        }
+    }
+
+    /// Releases the directions of the weaker axis when both a vertical and a horizontal direction are pressed
+    fn filter_diagonals(&self, action_state: &mut ActionState<A>) {
+        let strength = |action: &A| {
+            if action_state.pressed(action.clone()) {
+                action_state.value(action.clone()).abs()
+            } else {
+                0.0
+            }
+        };
+
+        let vertical = strength(&self.up).max(strength(&self.down));
+        let horizontal = strength(&self.left).max(strength(&self.right));
+        if vertical == 0.0 || horizontal == 0.0 {
+            return;
+        }
+
+        // Ties favor the vertical axis, as most menus are laid out in columns
+        let weaker = if vertical >= horizontal {
+            [self.left.clone(), self.right.clone()]
+        } else {
+            [self.up.clone(), self.down.clone()]
+        };
+
+        for action in weaker {
+            action_state.release(action);
+        }
+    }
+}
+
+/// The number of repeats that have fired after holding a direction for `held`
+fn repeats_elapsed(held: Duration, delay: Duration, interval: Duration) -> u128 {
+    if held < delay {
+        0
+    } else if interval.is_zero() {
+        1
+    } else {
+        1 + (held - delay).as_nanos() / interval.as_nanos()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DirectionalRepeat;
+    use crate as leafwing_input_manager;
+    use crate::action_state::{ActionData, ActionState};
+    use crate::buttonlike::ButtonState;
+    use crate::Actionlike;
+    use bevy::utils::{Duration, Instant};
+
+    #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    enum Menu {
+        Up,
+        Down,
+        Left,
+        Right,
+    }
+
+    const FRAME: Duration = Duration::from_millis(100);
+
+    fn directional_repeat() -> DirectionalRepeat<Menu> {
+        DirectionalRepeat::new(Menu::Up, Menu::Down, Menu::Left, Menu::Right)
+            .with_timing(Duration::from_millis(300), Duration::from_millis(200))
+    }
+
+    /// Advances the `action_state` by one frame, with `pressed` held at their `value`
+    fn step(
+        action_state: &mut ActionState<Menu>,
+        directional_repeat: &mut DirectionalRepeat<Menu>,
+        pressed: &[(Menu, f32)],
+    ) {
+        let now = Instant::now();
+        action_state.tick(now, now - FRAME);
+
+        let action_data = Menu::variants()
+            .map(
+                |action| match pressed.iter().find(|(held, _)| *held == action) {
+                    Some((_, value)) => ActionData {
+                        state: ButtonState::JustPressed,
+                        value: *value,
+                        ..Default::default()
+                    },
+                    None => ActionData::default(),
+                },
+            )
+            .collect();
+        action_state.update(action_data);
+        directional_repeat.apply(action_state, FRAME);
+    }
+
+    #[test]
+    fn held_directions_repeat() {
+        let mut action_state = ActionState::<Menu>::default();
+        let mut directional_repeat = directional_repeat();
+
+        let just_pressed: Vec<bool> = (0..8)
+            .map(|_| {
+                step(
+                    &mut action_state,
+                    &mut directional_repeat,
+                    &[(Menu::Down, 1.0)],
+                );
+                action_state.just_pressed(Menu::Down)
+            })
+            .collect();
+
+        // Pressed initially, then after the delay of 300 ms, then every 200 ms
+        assert_eq!(
+            just_pressed,
+            [true, false, false, true, false, true, false, true]
+        );
+
+        // Releasing resets the delay
+        step(&mut action_state, &mut directional_repeat, &[]);
+        step(
+            &mut action_state,
+            &mut directional_repeat,
+            &[(Menu::Down, 1.0)],
+        );
+        assert!(action_state.just_pressed(Menu::Down));
+        step(
+            &mut action_state,
+            &mut directional_repeat,
+            &[(Menu::Down, 1.0)],
+        );
+        assert!(!action_state.just_pressed(Menu::Down));
+    }
+
+    #[test]
+    fn diagonals_are_filtered() {
+        let mut action_state = ActionState::<Menu>::default();
+        let mut directional_repeat = directional_repeat();
+
+        step(
+            &mut action_state,
+            &mut directional_repeat,
+            &[(Menu::Up, 0.6), (Menu::Right, 0.8)],
+        );
+        assert!(action_state.released(Menu::Up));
+        assert!(action_state.pressed(Menu::Right));
+
+        directional_repeat.filter_diagonals = false;
+        step(
+            &mut action_state,
+            &mut directional_repeat,
+            &[(Menu::Up, 0.6), (Menu::Right, 0.8)],
+        );
+        assert!(action_state.pressed(Menu::Up));
+        assert!(action_state.pressed(Menu::Right));
+    }
+}
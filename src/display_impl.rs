@@ -1,7 +1,9 @@
 //! Containment module for boring implmentations of the [`Display`] trait
 
 use crate::axislike::{VirtualAxis, VirtualDPad};
+use crate::input_map::BindingIssue;
 use crate::user_input::{InputKind, UserInput};
+use crate::Actionlike;
 use std::fmt::Display;
 
 impl Display for UserInput {
@@ -52,6 +54,32 @@ impl Display for InputKind {
             InputKind::MouseMotion(button) => write!(f, "{button:?}"),
             InputKind::Keyboard(button) => write!(f, "{button:?}"),
             InputKind::Modifier(button) => write!(f, "{button:?}"),
+            InputKind::Wildcard(wildcard) => write!(f, "{wildcard:?}"),
+        }
+    }
+}
+
+impl<A: Actionlike> Display for BindingIssue<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BindingIssue::Unbound { action } => {
+                write!(f, "{} has no bindings", action.metadata().name)
+            }
+            BindingIssue::ImpossibleChord { action, input } => write!(
+                f,
+                "{input} is bound to {}, but cannot be pressed all at once",
+                action.metadata().name
+            ),
+            BindingIssue::UnreachableAxis { action, input } => write!(
+                f,
+                "{input} is bound to {}, but its thresholds are beyond the range of the gamepad",
+                action.metadata().name
+            ),
+            BindingIssue::GhostingRisk { action, input } => write!(
+                f,
+                "{input} is bound to {}, but may not be pressable on keyboards without n-key rollover",
+                action.metadata().name
+            ),
         }
     }
 }
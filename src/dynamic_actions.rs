@@ -0,0 +1,297 @@
+//! This module contains [`DynamicActions`] and its supporting methods and impls.
+//!
+//! The [`DynamicActions`] holds bindable actions that are only known at runtime, keyed by name,
+//! alongside the [`Actionlike`](crate::Actionlike) enum of a game.
+//! Mods and data-driven games can use it to add actions without recompiling the action enum.
+
+use bevy::ecs::prelude::*;
+use bevy::input::gamepad::Gamepad;
+use serde::{Deserialize, Serialize};
+
+use crate::buttonlike::ButtonState;
+use crate::input_streams::InputStreams;
+use crate::user_input::UserInput;
+
+/// Identifies an action registered with [`DynamicActions::register`]
+///
+/// Identifiers are only meaningful for the [`DynamicActions`] that created them,
+/// and depend on the order in which actions were registered.
+/// Use the [name](DynamicActions::name) of the action to refer to it in saved data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct DynamicActionId(usize);
+
+impl DynamicActionId {
+    /// The position of the action in the order in which actions were registered, starting from `0`
+    #[must_use]
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// A single action registered at runtime
+#[derive(Debug, Clone, PartialEq)]
+struct DynamicAction {
+    name: String,
+    bindings: Vec<UserInput>,
+    state: ButtonState,
+    value: f32,
+}
+
+/// Bindable actions that are registered at runtime rather than defined by an [`Actionlike`](crate::Actionlike) enum
+///
+/// This can be used as a resource, or as a component for per-player actions.
+/// Either way, gamepad inputs are read from the [associated gamepad](Self::set_gamepad), or any gamepad if there isn't one.
+/// This is updated by [`update_dynamic_actions`](crate::systems::update_dynamic_actions),
+/// before [`InputManagerSystem::Update`](crate::plugin::InputManagerSystem::Update).
+///
+/// Names share a single namespace, so mods should prefix the names of their actions, as in `"my_mod:teleport"`.
+/// Registering a name twice returns the existing action.
+/// Dynamic actions are not affected by the [`ClashStrategy`](crate::clashing_inputs::ClashStrategy),
+/// and do not consume the inputs bound to them.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::dynamic_actions::DynamicActions;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Jump,
+/// }
+///
+/// fn load_mod(mut dynamic_actions: ResMut<DynamicActions>) {
+///     let teleport = dynamic_actions.register("my_mod:teleport");
+///     dynamic_actions.insert(KeyCode::T, teleport);
+/// }
+///
+/// fn teleport(dynamic_actions: Res<DynamicActions>) {
+///     if dynamic_actions.just_pressed_named("my_mod:teleport") {
+///         println!("Whoosh!");
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugins(MinimalPlugins)
+///     .add_plugin(InputManagerPlugin::<Action>::default())
+///     .init_resource::<DynamicActions>()
+///     .add_startup_system(load_mod)
+///     .add_system(teleport);
+/// ```
+#[derive(Resource, Component, Debug, Clone, PartialEq, Default)]
+pub struct DynamicActions {
+    actions: Vec<DynamicAction>,
+    gamepad: Option<Gamepad>,
+}
+
+impl DynamicActions {
+    /// Registers an action called `name`, returning its [`DynamicActionId`]
+    ///
+    /// If an action with this name was already registered, it is returned unchanged.
+    pub fn register(&mut self, name: impl Into<String>) -> DynamicActionId {
+        let name = name.into();
+        if let Some(id) = self.id(&name) {
+            return id;
+        }
+
+        self.actions.push(DynamicAction {
+            name,
+            bindings: Vec::new(),
+            state: ButtonState::Released,
+            value: 0.0,
+        });
+        DynamicActionId(self.actions.len() - 1)
+    }
+
+    /// The [`DynamicActionId`] of the action called `name`, if it was registered
+    #[must_use]
+    pub fn id(&self, name: &str) -> Option<DynamicActionId> {
+        self.actions
+            .iter()
+            .position(|action| action.name == name)
+            .map(DynamicActionId)
+    }
+
+    /// The name of the action `id`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` was not created by these [`DynamicActions`].
+    #[must_use]
+    pub fn name(&self, id: DynamicActionId) -> &str {
+        &self.actions[id.0].name
+    }
+
+    /// Iterates over the registered actions, in the order they were registered
+    pub fn iter(&self) -> impl Iterator<Item = (DynamicActionId, &str)> {
+        self.actions
+            .iter()
+            .enumerate()
+            .map(|(index, action)| (DynamicActionId(index), action.name.as_str()))
+    }
+
+    /// The number of registered actions
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.actions.len()
+    }
+
+    /// Are there no registered actions?
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    /// Binds the `input` to the action `id`
+    ///
+    /// Inputs that are already bound to the action are not bound twice.
+    pub fn insert(&mut self, input: impl Into<UserInput>, id: DynamicActionId) -> &mut Self {
+        let input = input.into();
+        let bindings = &mut self.actions[id.0].bindings;
+        if !bindings.contains(&input) {
+            bindings.push(input);
+        }
+        self
+    }
+
+    /// The inputs bound to the action `id`
+    #[must_use]
+    pub fn bindings(&self, id: DynamicActionId) -> &[UserInput] {
+        &self.actions[id.0].bindings
+    }
+
+    /// Removes every binding of the action `id`
+    pub fn clear_bindings(&mut self, id: DynamicActionId) {
+        self.actions[id.0].bindings.clear();
+    }
+
+    /// The gamepad whose inputs press these actions, or [`None`] if any gamepad can press them
+    #[must_use]
+    pub fn gamepad(&self) -> Option<Gamepad> {
+        self.gamepad
+    }
+
+    /// Sets the gamepad whose inputs press these actions, or [`None`] if any gamepad can press them
+    pub fn set_gamepad(&mut self, gamepad: Option<Gamepad>) {
+        self.gamepad = gamepad;
+    }
+
+    /// Is the action `id` currently pressed?
+    #[must_use]
+    pub fn pressed(&self, id: DynamicActionId) -> bool {
+        self.actions[id.0].state.pressed()
+    }
+
+    /// Was the action `id` pressed since the last update?
+    #[must_use]
+    pub fn just_pressed(&self, id: DynamicActionId) -> bool {
+        self.actions[id.0].state.just_pressed()
+    }
+
+    /// Was the action `id` released since the last update?
+    #[must_use]
+    pub fn just_released(&self, id: DynamicActionId) -> bool {
+        self.actions[id.0].state.just_released()
+    }
+
+    /// The combined value of the inputs bound to the action `id`
+    ///
+    /// As for [`ActionState::value`](crate::action_state::ActionState::value), this is `1.0` for pressed buttons,
+    /// and the sum of the values of each pressed binding for analog inputs.
+    #[must_use]
+    pub fn value(&self, id: DynamicActionId) -> f32 {
+        self.actions[id.0].value
+    }
+
+    /// Is the action called `name` currently pressed?
+    ///
+    /// Returns `false` if no such action was registered.
+    #[must_use]
+    pub fn pressed_named(&self, name: &str) -> bool {
+        self.id(name).is_some_and(|id| self.pressed(id))
+    }
+
+    /// Was the action called `name` pressed since the last update?
+    ///
+    /// Returns `false` if no such action was registered.
+    #[must_use]
+    pub fn just_pressed_named(&self, name: &str) -> bool {
+        self.id(name).is_some_and(|id| self.just_pressed(id))
+    }
+
+    /// Updates the state of every action from the `input_streams`
+    pub fn update(&mut self, input_streams: &InputStreams) {
+        for action in &mut self.actions {
+            let pressed: Vec<&UserInput> = action
+                .bindings
+                .iter()
+                .filter(|input| input_streams.input_pressed(input))
+                .collect();
+
+            action.state.tick();
+            if pressed.is_empty() {
+                action.state.release();
+                action.value = 0.0;
+            } else {
+                action.state.press();
+                action.value = pressed
+                    .into_iter()
+                    .map(|input| input_streams.input_value(input))
+                    .sum();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DynamicActions;
+    use crate::input_streams::InputStreams;
+    use bevy::input::keyboard::KeyCode;
+    use bevy::input::mouse::MouseButton;
+    use bevy::prelude::*;
+
+    #[test]
+    fn actions_are_registered_once() {
+        let mut dynamic_actions = DynamicActions::default();
+        let teleport = dynamic_actions.register("my_mod:teleport");
+        let fly = dynamic_actions.register("my_mod:fly");
+
+        assert_ne!(teleport, fly);
+        assert_eq!(dynamic_actions.register("my_mod:teleport"), teleport);
+        assert_eq!(dynamic_actions.id("my_mod:fly"), Some(fly));
+        assert_eq!(dynamic_actions.name(teleport), "my_mod:teleport");
+        assert_eq!(dynamic_actions.len(), 2);
+        assert_eq!(dynamic_actions.id("my_mod:swim"), None);
+    }
+
+    #[test]
+    fn actions_are_pressed_by_their_bindings() {
+        let mut dynamic_actions = DynamicActions::default();
+        let teleport = dynamic_actions.register("my_mod:teleport");
+        dynamic_actions
+            .insert(KeyCode::T, teleport)
+            .insert(MouseButton::Middle, teleport);
+
+        let mut app = App::new();
+        app.add_plugin(bevy::input::InputPlugin);
+        app.world
+            .resource_mut::<Input<MouseButton>>()
+            .press(MouseButton::Middle);
+
+        dynamic_actions.update(&InputStreams::from_world(&app.world, None));
+        assert!(dynamic_actions.just_pressed(teleport));
+        assert!(dynamic_actions.pressed_named("my_mod:teleport"));
+        assert_eq!(dynamic_actions.value(teleport), 1.0);
+
+        dynamic_actions.update(&InputStreams::from_world(&app.world, None));
+        assert!(dynamic_actions.pressed(teleport));
+        assert!(!dynamic_actions.just_pressed(teleport));
+
+        app.world
+            .resource_mut::<Input<MouseButton>>()
+            .release(MouseButton::Middle);
+        dynamic_actions.update(&InputStreams::from_world(&app.world, None));
+        assert!(dynamic_actions.just_released(teleport));
+    }
+}
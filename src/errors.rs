@@ -1,5 +1,6 @@
-//! Errors that may occur when working with 2D coordinates
+//! Errors that may occur when working with 2D coordinates, input maps or the configuration of the input manager
 
+use bevy::input::keyboard::KeyCode;
 use derive_more::{Display, Error};
 
 /// The supplied vector-like struct was too close to zero to be converted into a rotation-like type
@@ -11,3 +12,150 @@ use derive_more::{Display, Error};
 /// In almost all cases, the correct way to handle this error is to simply not change the rotation.
 #[derive(Debug, Clone, Copy, Error, Display, PartialEq, Eq)]
 pub struct NearlySingularConversion;
+
+/// A binding could not be inserted into an [`InputMap`](crate::input_map::InputMap)
+///
+/// These errors are produced when the relevant field of the map's
+/// [`BindingPolicy`](crate::input_map::BindingPolicy) is set to `Error`,
+/// or when attempting to rebind a [fixed](crate::ActionMetadata::fixed) action.
+#[derive(Debug, Clone, Copy, Error, Display, PartialEq, Eq)]
+pub enum BindingError {
+    /// The action already holds the maximum number of bindings allowed by the policy
+    #[display(
+        fmt = "the action already has {} bindings, which is the maximum allowed",
+        capacity
+    )]
+    CapacityExceeded {
+        /// The capacity of the action
+        capacity: usize,
+    },
+    /// The input is already bound to a different action
+    #[display(fmt = "the input is already bound to a different action")]
+    AlreadyBound,
+    /// The action is [fixed](crate::ActionMetadata::fixed), and so cannot be rebound
+    #[display(fmt = "the action is fixed, and so cannot be rebound")]
+    FixedAction,
+}
+
+/// [`SavedBindings`](crate::saved_bindings::SavedBindings) could not be migrated or loaded
+#[derive(Debug, Clone, Error, Display, PartialEq, Eq)]
+pub enum SavedBindingsError {
+    /// The bindings were saved with a newer format version than the one being loaded
+    #[display(
+        fmt = "the bindings were saved with format version {}, but only versions up to {} are supported",
+        version,
+        latest
+    )]
+    UnsupportedVersion {
+        /// The format version of the saved bindings
+        version: u32,
+        /// The newest format version that is supported
+        latest: u32,
+    },
+    /// No migration was provided to upgrade the bindings from a format version
+    #[display(fmt = "no migration was provided from format version {}", version)]
+    MissingMigration {
+        /// The format version that could not be migrated
+        version: u32,
+    },
+    /// The bindings refer to an action that does not exist
+    #[display(fmt = "no action has the identifier or alias `{}`", identifier)]
+    UnknownAction {
+        /// The identifier of the missing action
+        #[error(not(source))]
+        identifier: String,
+    },
+    /// A binding could not be inserted into the [`InputMap`](crate::input_map::InputMap)
+    #[display(fmt = "the binding of `{}` was rejected: {}", identifier, error)]
+    RejectedBinding {
+        /// The identifier of the action
+        identifier: String,
+        /// Why the binding was refused
+        #[error(source)]
+        error: BindingError,
+    },
+}
+
+/// The [`InputMap`](crate::input_map::InputMap)s of players sharing a keyboard claim some of the same keys
+///
+/// Produced by [`validate_split_keyboard`](crate::split_keyboard::validate_split_keyboard).
+/// Maps are identified by their position in the list that was validated.
+#[derive(Debug, Clone, Copy, Error, Display, PartialEq, Eq)]
+pub enum SplitKeyboardError {
+    /// The key is bound in more than one input map
+    #[display(
+        fmt = "{:?} is bound in both input map {} and input map {}",
+        key,
+        first,
+        second
+    )]
+    SharedKey {
+        /// The key that is bound in both maps
+        key: KeyCode,
+        /// The first map that binds the key
+        first: usize,
+        /// The second map that binds the key
+        second: usize,
+    },
+    /// The input map binds a keyboard wildcard, which claims every key
+    #[display(
+        fmt = "input map {} binds a wildcard, which claims the entire keyboard",
+        map
+    )]
+    WholeKeyboard {
+        /// The map that binds the wildcard
+        map: usize,
+    },
+}
+
+/// The input manager was configured in a way that cannot be represented
+///
+/// Returned by the fallible counterparts of methods that would otherwise panic,
+/// such as [`UserInput::try_chord`](crate::user_input::UserInput::try_chord)
+/// and [`ActionState::try_update`](crate::action_state::ActionState::try_update),
+/// so that configuration errors can be reported to players rather than crashing the game.
+#[derive(Debug, Clone, Copy, Error, Display, PartialEq, Eq)]
+pub enum InputManagerError {
+    /// A chord was created from no inputs at all, and so would always be pressed
+    #[display(fmt = "a chord must contain at least one input")]
+    EmptyChord,
+    /// A chord was created from more distinct inputs than it can hold
+    #[display(fmt = "a chord can contain at most {} distinct inputs", max)]
+    ChordTooLong {
+        /// The maximum number of inputs in a chord
+        max: usize,
+    },
+    /// The data provided did not contain exactly one entry for each action
+    #[display(
+        fmt = "expected data for {} actions, but data for {} actions was provided",
+        expected,
+        found
+    )]
+    ActionCountMismatch {
+        /// The number of variants of the action type
+        expected: usize,
+        /// The number of entries that were provided
+        found: usize,
+    },
+    /// A binding could not be inserted into an [`InputMap`](crate::input_map::InputMap)
+    #[display(fmt = "could not insert binding: {}", _0)]
+    Binding(BindingError),
+    /// An [`InputManagerPlugin`](crate::plugin::InputManagerPlugin) was added more than once for the same action type
+    ///
+    /// The extra copies of the plugin are ignored, and this error is logged as a warning.
+    #[display(
+        fmt = "`InputManagerPlugin<{}>` was added more than once; the extra copies are ignored",
+        action_type
+    )]
+    DuplicatePlugin {
+        /// The name of the action type
+        #[error(not(source))]
+        action_type: &'static str,
+    },
+}
+
+impl From<BindingError> for InputManagerError {
+    fn from(error: BindingError) -> Self {
+        InputManagerError::Binding(error)
+    }
+}
@@ -0,0 +1,310 @@
+//! This module contains [`ExclusiveActions`] and its supporting methods and impls.
+//!
+//! The [`ExclusiveActions`] is an optional addition to an [`InputManagerBundle`](crate::InputManagerBundle),
+//! which arbitrates between groups of mutually exclusive actions, such as weapon stances or tool selection,
+//! so that at most one action of each group is pressed at a time.
+
+use bevy::ecs::prelude::*;
+use std::cmp::Reverse;
+
+use crate::action_state::ActionState;
+use crate::input_map::InputMap;
+use crate::Actionlike;
+
+/// How the winner of an [`ExclusiveActions`] group is chosen when several of its actions are pressed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ExclusivePolicy {
+    /// The most recently pressed action wins
+    ///
+    /// Actions that were pressed during the same update are ordered by [`ActionState::press_order`],
+    /// so the last of them wins.
+    #[default]
+    MostRecent,
+    /// The pressed action that is listed first in the group wins
+    Priority,
+    /// The pressed action with the highest [priority](InputMap::priority) in the [`InputMap`] wins,
+    /// with ties going to the action that is listed first in the group
+    ///
+    /// Without an [`InputMap`], this behaves like [`ExclusivePolicy::Priority`].
+    MapPriority,
+}
+
+/// A group of actions where at most one may be pressed at a time
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExclusiveGroup<A: Actionlike> {
+    /// The actions in this group
+    ///
+    /// Under [`ExclusivePolicy::Priority`], earlier actions take precedence over later ones.
+    pub actions: Vec<A>,
+    /// How the winner is chosen when several of the [`actions`](Self::actions) are pressed
+    pub policy: ExclusivePolicy,
+}
+
+impl<A: Actionlike> ExclusiveGroup<A> {
+    /// The action of this group that should remain pressed, if any are pressed
+    ///
+    /// The `input_map` supplies the action priorities used by [`ExclusivePolicy::MapPriority`].
+    /// This is the winner chosen by [`ExclusiveActions::apply`], and is useful for debugging arbitration.
+    #[must_use]
+    pub fn winner(
+        &self,
+        action_state: &ActionState<A>,
+        input_map: Option<&InputMap<A>>,
+    ) -> Option<A> {
+        let mut pressed = self
+            .actions
+            .iter()
+            .filter(|action| action_state.pressed((*action).clone()));
+
+        match self.policy {
+            ExclusivePolicy::Priority => pressed.next().cloned(),
+            ExclusivePolicy::MapPriority => match input_map {
+                Some(input_map) => pressed
+                    .min_by_key(|action| Reverse(input_map.priority((*action).clone())))
+                    .cloned(),
+                None => pressed.next().cloned(),
+            },
+            ExclusivePolicy::MostRecent => pressed
+                .min_by_key(|action| {
+                    // Just pressed actions are more recent than any held action
+                    match action_state.press_order((*action).clone()) {
+                        Some(press_order) => (0, usize::MAX - press_order, Default::default()),
+                        None => (1, 0, action_state.current_duration((*action).clone())),
+                    }
+                })
+                .cloned(),
+        }
+    }
+}
+
+/// Groups of mutually exclusive actions, as is typically desired for weapon stances or tool selection
+///
+/// After each update, every group with more than one pressed action keeps only its winner pressed,
+/// as chosen by the group's [`ExclusivePolicy`].
+/// The other actions are [consumed](ActionState::consume), so that they are not pressed again until their inputs are released.
+///
+/// This is applied by [`apply_exclusive_actions`](crate::systems::apply_exclusive_actions),
+/// as part of [`InputManagerSystem::ManualControl`](crate::plugin::InputManagerSystem::ManualControl).
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::exclusive_actions::{ExclusiveActions, ExclusivePolicy};
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Stance {
+///     Sword,
+///     Bow,
+///     Shield,
+/// }
+///
+/// let mut world = World::new();
+/// world.spawn(InputManagerBundle {
+///     input_map: InputMap::new([
+///         (KeyCode::Key1, Stance::Sword),
+///         (KeyCode::Key2, Stance::Bow),
+///         (KeyCode::Key3, Stance::Shield),
+///     ]),
+///     ..Default::default()
+/// })
+/// .insert(ExclusiveActions::default().with_group(
+///     [Stance::Sword, Stance::Bow, Stance::Shield],
+///     ExclusivePolicy::MostRecent,
+/// ));
+/// ```
+#[derive(Resource, Component, Debug, Clone, PartialEq)]
+pub struct ExclusiveActions<A: Actionlike> {
+    groups: Vec<ExclusiveGroup<A>>,
+}
+
+impl<A: Actionlike> Default for ExclusiveActions<A> {
+    fn default() -> Self {
+        Self { groups: Vec::new() }
+    }
+}
+
+impl<A: Actionlike> ExclusiveActions<A> {
+    /// Adds a group of mutually exclusive `actions`, arbitrated according to the `policy`
+    #[must_use]
+    pub fn with_group(
+        mut self,
+        actions: impl IntoIterator<Item = A>,
+        policy: ExclusivePolicy,
+    ) -> Self {
+        self.add_group(actions, policy);
+        self
+    }
+
+    /// Adds a group of mutually exclusive `actions`, arbitrated according to the `policy`
+    pub fn add_group(&mut self, actions: impl IntoIterator<Item = A>, policy: ExclusivePolicy) {
+        self.groups.push(ExclusiveGroup {
+            actions: actions.into_iter().collect(),
+            policy,
+        });
+    }
+
+    /// The groups of mutually exclusive actions
+    #[must_use]
+    pub fn groups(&self) -> &[ExclusiveGroup<A>] {
+        &self.groups
+    }
+
+    /// Consumes every pressed action of the `action_state` that has lost the arbitration of its group
+    ///
+    /// This should be called once per frame, after the `action_state` has been updated from inputs.
+    /// Use [`apply_with_input_map`](Self::apply_with_input_map) for groups using [`ExclusivePolicy::MapPriority`].
+    pub fn apply(&self, action_state: &mut ActionState<A>) {
+        self.apply_prioritized(action_state, None);
+    }
+
+    /// Consumes every pressed action of the `action_state` that has lost the arbitration of its group,
+    /// using the action priorities of the `input_map`
+    pub fn apply_with_input_map(&self, action_state: &mut ActionState<A>, input_map: &InputMap<A>) {
+        self.apply_prioritized(action_state, Some(input_map));
+    }
+
+    /// Consumes every pressed action of the `action_state` that has lost the arbitration of its group,
+    /// using the action priorities of the `input_map`, if any
+    pub(crate) fn apply_prioritized(
+        &self,
+        action_state: &mut ActionState<A>,
+        input_map: Option<&InputMap<A>>,
+    ) {
+        for group in &self.groups {
+            let Some(winner) = group.winner(action_state, input_map) else {
+                continue;
+            };
+
+            for action in &group.actions {
+                if action.index() != winner.index() && action_state.pressed(action.clone()) {
+                    action_state.consume(action.clone());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExclusiveActions, ExclusivePolicy};
+    use crate as leafwing_input_manager;
+    use crate::action_state::{ActionData, ActionState};
+    use crate::buttonlike::ButtonState;
+    use crate::input_map::InputMap;
+    use crate::Actionlike;
+    use bevy::utils::{Duration, Instant};
+
+    #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    enum Tool {
+        Hammer,
+        Saw,
+        Drill,
+    }
+
+    /// Advances the `action_state` by one frame, with the `pressed` actions held
+    fn step(
+        action_state: &mut ActionState<Tool>,
+        exclusive_actions: &ExclusiveActions<Tool>,
+        pressed: &[Tool],
+    ) {
+        let now = Instant::now();
+        action_state.tick(now, now - Duration::from_millis(16));
+
+        let action_data = Tool::variants()
+            .map(|action| ActionData {
+                state: if pressed.contains(&action) {
+                    ButtonState::JustPressed
+                } else {
+                    ButtonState::JustReleased
+                },
+                ..Default::default()
+            })
+            .collect();
+        action_state.update(action_data);
+        exclusive_actions.apply(action_state);
+    }
+
+    #[test]
+    fn most_recent_press_wins() {
+        let mut action_state = ActionState::<Tool>::default();
+        let exclusive_actions = ExclusiveActions::default().with_group(
+            [Tool::Hammer, Tool::Saw, Tool::Drill],
+            ExclusivePolicy::MostRecent,
+        );
+
+        step(&mut action_state, &exclusive_actions, &[Tool::Hammer]);
+        assert!(action_state.pressed(Tool::Hammer));
+
+        step(
+            &mut action_state,
+            &exclusive_actions,
+            &[Tool::Hammer, Tool::Saw],
+        );
+        assert!(action_state.released(Tool::Hammer));
+        assert!(action_state.just_pressed(Tool::Saw));
+
+        // The losing action stays released while its input is held
+        step(
+            &mut action_state,
+            &exclusive_actions,
+            &[Tool::Hammer, Tool::Saw],
+        );
+        assert!(action_state.released(Tool::Hammer));
+        assert!(action_state.pressed(Tool::Saw));
+
+        step(&mut action_state, &exclusive_actions, &[Tool::Hammer]);
+        assert!(action_state.released(Tool::Hammer));
+
+        // Until it is released and pressed again
+        step(&mut action_state, &exclusive_actions, &[]);
+        step(&mut action_state, &exclusive_actions, &[Tool::Hammer]);
+        assert!(action_state.just_pressed(Tool::Hammer));
+    }
+
+    #[test]
+    fn priority_order_wins() {
+        let mut action_state = ActionState::<Tool>::default();
+        let exclusive_actions = ExclusiveActions::default()
+            .with_group([Tool::Drill, Tool::Hammer], ExclusivePolicy::Priority);
+
+        step(&mut action_state, &exclusive_actions, &[Tool::Hammer]);
+        assert!(action_state.pressed(Tool::Hammer));
+
+        step(
+            &mut action_state,
+            &exclusive_actions,
+            &[Tool::Hammer, Tool::Drill, Tool::Saw],
+        );
+        assert!(action_state.released(Tool::Hammer));
+        assert!(action_state.pressed(Tool::Drill));
+        // Actions outside of any group are unaffected
+        assert!(action_state.pressed(Tool::Saw));
+    }
+
+    #[test]
+    fn map_priority_wins() {
+        let mut action_state = ActionState::<Tool>::default();
+        let exclusive_actions = ExclusiveActions::default()
+            .with_group([Tool::Drill, Tool::Hammer], ExclusivePolicy::MapPriority);
+        let mut input_map = InputMap::<Tool>::default();
+        input_map.set_priority(Tool::Hammer, 2);
+
+        step(
+            &mut action_state,
+            &ExclusiveActions::default(),
+            &[Tool::Hammer, Tool::Drill],
+        );
+        let group = &exclusive_actions.groups()[0];
+        // Without the input map, the group order is used
+        assert_eq!(group.winner(&action_state, None), Some(Tool::Drill));
+        assert_eq!(
+            group.winner(&action_state, Some(&input_map)),
+            Some(Tool::Hammer)
+        );
+
+        exclusive_actions.apply_with_input_map(&mut action_state, &input_map);
+        assert!(action_state.pressed(Tool::Hammer));
+        assert!(action_state.released(Tool::Drill));
+    }
+}
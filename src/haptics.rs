@@ -0,0 +1,359 @@
+//! This module contains [`HapticRequest`] and its supporting methods and impls.
+//!
+//! A [`HapticEffect`] describes a single haptic intent: plain rumble, high-definition rumble at chosen frequencies,
+//! and adaptive trigger resistance, all shaped by an [`Envelope`].
+//! Games send it once as a [`HapticRequest`] event, and the backend that drives the gamepad
+//! plays as much of it as the hardware supports, as reported by its [`HapticCapabilities`].
+//! Bevy does not drive haptics itself, so the backend is provided separately, such as an SDL or HID integration.
+
+use bevy::ecs::prelude::*;
+use bevy::input::gamepad::Gamepad;
+use bevy::utils::{Duration, HashMap};
+use serde::{Deserialize, Serialize};
+
+/// How the strength of a [`HapticEffect`] changes over its duration
+///
+/// The effect ramps up from nothing over the [`attack`](Self::attack), holds its full strength,
+/// and then fades out over the [`release`](Self::release), which ends with the effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Envelope {
+    /// How long the effect takes to reach its full strength
+    pub attack: Duration,
+    /// How long the effect takes to fade out at its end
+    pub release: Duration,
+}
+
+impl Envelope {
+    /// The fraction of the full strength of an effect lasting `duration`, at `elapsed` since it started
+    ///
+    /// This is between `0.0` and `1.0`, and `0.0` once the effect is over.
+    #[must_use]
+    pub fn strength(&self, elapsed: Duration, duration: Duration) -> f32 {
+        if elapsed >= duration {
+            return 0.0;
+        }
+
+        let attack = if elapsed < self.attack {
+            elapsed.as_secs_f32() / self.attack.as_secs_f32()
+        } else {
+            1.0
+        };
+        let remaining = duration - elapsed;
+        let release = if remaining < self.release {
+            remaining.as_secs_f32() / self.release.as_secs_f32()
+        } else {
+            1.0
+        };
+        attack.min(release)
+    }
+}
+
+/// Plain rumble, as supported by almost every gamepad
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Rumble {
+    /// The strength of the heavy, low-frequency motor, between `0.0` and `1.0`
+    pub strong: f32,
+    /// The strength of the light, high-frequency motor, between `0.0` and `1.0`
+    pub weak: f32,
+}
+
+/// Rumble at chosen frequencies, as supported by linear actuators such as those of Switch and PS5 controllers
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct HdRumble {
+    /// The frequency of the low band, in hertz
+    pub low_frequency: f32,
+    /// The amplitude of the low band, between `0.0` and `1.0`
+    pub low_amplitude: f32,
+    /// The frequency of the high band, in hertz
+    pub high_frequency: f32,
+    /// The amplitude of the high band, between `0.0` and `1.0`
+    pub high_amplitude: f32,
+}
+
+impl HdRumble {
+    /// The closest plain [`Rumble`], for gamepads without HD rumble
+    ///
+    /// The low band drives the strong motor, and the high band the weak one.
+    #[must_use]
+    pub fn to_rumble(&self) -> Rumble {
+        Rumble {
+            strong: self.low_amplitude,
+            weak: self.high_amplitude,
+        }
+    }
+}
+
+/// One of the two analog triggers of a gamepad
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Trigger {
+    /// The left analog trigger
+    Left,
+    /// The right analog trigger
+    Right,
+}
+
+/// Resistance applied to an adaptive trigger, as supported by PS5 controllers
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TriggerResistance {
+    /// The trigger that resists
+    pub trigger: Trigger,
+    /// How far the trigger must be pulled before it resists, between `0.0` and `1.0`
+    pub start: f32,
+    /// How strongly the trigger resists, between `0.0` and `1.0`
+    pub strength: f32,
+}
+
+/// What the haptics of a gamepad can do, as reported by the backend that drives them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct HapticCapabilities {
+    /// Can the gamepad play [`Rumble`]?
+    pub rumble: bool,
+    /// Can the gamepad play [`HdRumble`]?
+    pub hd_rumble: bool,
+    /// Can the gamepad apply [`TriggerResistance`]?
+    pub adaptive_triggers: bool,
+}
+
+impl HapticCapabilities {
+    /// A gamepad that can only play plain [`Rumble`]
+    pub const RUMBLE: HapticCapabilities = HapticCapabilities {
+        rumble: true,
+        hd_rumble: false,
+        adaptive_triggers: false,
+    };
+}
+
+/// A single haptic intent, which backends play as far as the gamepad supports it
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy::utils::Duration;
+/// use leafwing_input_manager::haptics::{
+///     Envelope, HapticCapabilities, HapticEffect, HdRumble, Rumble, Trigger, TriggerResistance,
+/// };
+///
+/// let bowstring = HapticEffect::new(Duration::from_millis(400))
+///     .with_hd_rumble(HdRumble {
+///         low_frequency: 160.0,
+///         low_amplitude: 0.3,
+///         high_frequency: 320.0,
+///         high_amplitude: 0.6,
+///     })
+///     .with_trigger_resistance(TriggerResistance {
+///         trigger: Trigger::Right,
+///         start: 0.2,
+///         strength: 0.8,
+///     })
+///     .with_envelope(Envelope {
+///         attack: Duration::from_millis(100),
+///         release: Duration::from_millis(50),
+///     });
+///
+/// // An ordinary gamepad feels the closest plain rumble instead
+/// let degraded = bowstring.degraded(HapticCapabilities::RUMBLE);
+/// assert_eq!(degraded.rumble, Some(Rumble { strong: 0.3, weak: 0.6 }));
+/// assert_eq!(degraded.hd_rumble, None);
+/// assert_eq!(degraded.trigger_resistance, None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HapticEffect {
+    /// How long the effect lasts
+    pub duration: Duration,
+    /// How the strength of the effect changes over its duration
+    pub envelope: Envelope,
+    /// The plain rumble of the effect
+    ///
+    /// If this is [`None`] but the [`hd_rumble`](Self::hd_rumble) is not, the HD rumble is converted for gamepads without HD rumble.
+    pub rumble: Option<Rumble>,
+    /// The HD rumble of the effect
+    pub hd_rumble: Option<HdRumble>,
+    /// The resistance applied to the adaptive triggers over the duration of the effect
+    pub trigger_resistance: Option<TriggerResistance>,
+}
+
+impl HapticEffect {
+    /// Creates an empty [`HapticEffect`] lasting `duration`
+    #[must_use]
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            envelope: Envelope::default(),
+            rumble: None,
+            hd_rumble: None,
+            trigger_resistance: None,
+        }
+    }
+
+    /// Creates a [`HapticEffect`] lasting `duration` that plays plain rumble with motors of the provided strengths
+    #[must_use]
+    pub fn rumble(duration: Duration, strong: f32, weak: f32) -> Self {
+        Self::new(duration).with_rumble(Rumble { strong, weak })
+    }
+
+    /// Sets how the strength of the effect changes over its duration
+    #[must_use]
+    pub fn with_envelope(mut self, envelope: Envelope) -> Self {
+        self.envelope = envelope;
+        self
+    }
+
+    /// Sets the plain rumble of the effect
+    #[must_use]
+    pub fn with_rumble(mut self, rumble: Rumble) -> Self {
+        self.rumble = Some(rumble);
+        self
+    }
+
+    /// Sets the HD rumble of the effect
+    #[must_use]
+    pub fn with_hd_rumble(mut self, hd_rumble: HdRumble) -> Self {
+        self.hd_rumble = Some(hd_rumble);
+        self
+    }
+
+    /// Sets the resistance applied to an adaptive trigger during the effect
+    #[must_use]
+    pub fn with_trigger_resistance(mut self, trigger_resistance: TriggerResistance) -> Self {
+        self.trigger_resistance = Some(trigger_resistance);
+        self
+    }
+
+    /// The parts of this effect that a gamepad with the `capabilities` can play
+    ///
+    /// HD rumble falls back to plain rumble if no plain rumble was provided, and unsupported parts are removed.
+    /// Backends should play the degraded effect, so that games can author a single effect for every gamepad.
+    #[must_use]
+    pub fn degraded(&self, capabilities: HapticCapabilities) -> HapticEffect {
+        let mut effect = self.clone();
+
+        if !capabilities.hd_rumble {
+            if let Some(hd_rumble) = effect.hd_rumble.take() {
+                effect.rumble = effect.rumble.or(Some(hd_rumble.to_rumble()));
+            }
+        }
+        if !capabilities.rumble {
+            effect.rumble = None;
+        }
+        if !capabilities.adaptive_triggers {
+            effect.trigger_resistance = None;
+        }
+        effect
+    }
+
+    /// Does this effect do nothing at all?
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rumble.is_none() && self.hd_rumble.is_none() && self.trigger_resistance.is_none()
+    }
+}
+
+/// An event asking the backend to play a [`HapticEffect`] on a gamepad
+///
+/// Backends should read these events, [degrade](HapticEffect::degraded) each effect to the [`HapticCapabilities`]
+/// of its gamepad, and play the result.
+/// A new request for a gamepad replaces the effect that is currently playing on it.
+/// These events are added by the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin).
+#[derive(Debug, Clone, PartialEq)]
+pub struct HapticRequest {
+    /// The gamepad to play the effect on
+    pub gamepad: Gamepad,
+    /// The effect to play
+    pub effect: HapticEffect,
+}
+
+impl HapticRequest {
+    /// Creates a [`HapticRequest`] to play the `effect` on the `gamepad`
+    #[must_use]
+    pub fn new(gamepad: Gamepad, effect: HapticEffect) -> Self {
+        Self { gamepad, effect }
+    }
+
+    /// Creates a [`HapticRequest`] that stops any effect playing on the `gamepad`
+    #[must_use]
+    pub fn stop(gamepad: Gamepad) -> Self {
+        Self::new(gamepad, HapticEffect::new(Duration::ZERO))
+    }
+}
+
+/// The [`HapticCapabilities`] of each gamepad, as reported by the backend that drives their haptics
+///
+/// Gamepads that the backend has not reported are treated as having no haptics at all,
+/// so that games can skip authoring work for effects that would not be felt.
+/// This resource is added by the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin).
+#[derive(Resource, Debug, Clone, PartialEq, Default)]
+pub struct GamepadHaptics {
+    capabilities: HashMap<Gamepad, HapticCapabilities>,
+}
+
+impl GamepadHaptics {
+    /// Records the `capabilities` of the `gamepad`
+    pub fn set_capabilities(&mut self, gamepad: Gamepad, capabilities: HapticCapabilities) {
+        self.capabilities.insert(gamepad, capabilities);
+    }
+
+    /// Forgets the capabilities of the `gamepad`, such as when it is disconnected
+    pub fn remove(&mut self, gamepad: Gamepad) {
+        self.capabilities.remove(&gamepad);
+    }
+
+    /// The capabilities of the `gamepad`, or no capabilities if the backend has not reported it
+    #[must_use]
+    pub fn capabilities(&self, gamepad: Gamepad) -> HapticCapabilities {
+        self.capabilities.get(&gamepad).copied().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn envelopes_ramp_the_strength() {
+        let envelope = Envelope {
+            attack: Duration::from_millis(100),
+            release: Duration::from_millis(200),
+        };
+        let duration = Duration::from_millis(1000);
+
+        assert_eq!(envelope.strength(Duration::ZERO, duration), 0.0);
+        let half = |strength: f32| (strength - 0.5).abs() < 1e-6;
+        assert!(half(envelope.strength(Duration::from_millis(50), duration)));
+        assert_eq!(envelope.strength(Duration::from_millis(500), duration), 1.0);
+        assert!(half(
+            envelope.strength(Duration::from_millis(900), duration)
+        ));
+        assert_eq!(envelope.strength(duration, duration), 0.0);
+        assert_eq!(Envelope::default().strength(Duration::ZERO, duration), 1.0);
+    }
+
+    #[test]
+    fn effects_degrade_to_the_capabilities() {
+        let effect = HapticEffect::rumble(Duration::from_millis(300), 1.0, 0.5)
+            .with_trigger_resistance(TriggerResistance {
+                trigger: Trigger::Left,
+                start: 0.5,
+                strength: 1.0,
+            });
+
+        let full = HapticCapabilities {
+            rumble: true,
+            hd_rumble: true,
+            adaptive_triggers: true,
+        };
+        assert_eq!(effect.degraded(full), effect);
+        assert_eq!(
+            effect
+                .degraded(HapticCapabilities::RUMBLE)
+                .trigger_resistance,
+            None
+        );
+        assert!(effect.degraded(HapticCapabilities::default()).is_empty());
+
+        let mut haptics = GamepadHaptics::default();
+        let gamepad = Gamepad { id: 0 };
+        assert_eq!(haptics.capabilities(gamepad), HapticCapabilities::default());
+        haptics.set_capabilities(gamepad, full);
+        assert_eq!(haptics.capabilities(gamepad), full);
+    }
+}
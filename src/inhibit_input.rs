@@ -0,0 +1,168 @@
+//! This module contains [`InhibitInput`] and its supporting methods and impls.
+//!
+//! The [`InhibitInput`] is an optional addition to an [`InputManagerBundle`](crate::InputManagerBundle),
+//! which stops the inputs of some devices from affecting its [`ActionState`](crate::action_state::ActionState).
+//! This is useful for modal UI: opening an inventory can block the keyboard bindings of gameplay actions,
+//! while the gamepad remains usable.
+
+use bevy::ecs::prelude::*;
+use bevy::input::gamepad::Gamepads;
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+use crate::Actionlike;
+
+/// A kind of device that inputs can be read from
+///
+/// This is used to ignore the inputs of some devices, to pick which bindings to show in prompts,
+/// and to choose the default bindings of [`PlatformBindings`](crate::platform_bindings::PlatformBindings).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InputDevice {
+    /// [`KeyCode`](bevy::input::keyboard::KeyCode) inputs
+    Keyboard,
+    /// [`MouseButton`](bevy::input::mouse::MouseButton), mouse wheel, mouse motion and cursor inputs
+    Mouse,
+    /// Gamepad button and axis inputs
+    Gamepad,
+    /// Touchscreens
+    ///
+    /// No [`InputKind`](crate::user_input::InputKind) is read from this device: touch controls are typically on-screen buttons,
+    /// which press actions through an [`ActionStateDriver`](crate::action_state::ActionStateDriver).
+    Touch,
+}
+
+impl InputDevice {
+    /// Every kind of device
+    pub const ALL: [InputDevice; 4] = [
+        InputDevice::Keyboard,
+        InputDevice::Mouse,
+        InputDevice::Gamepad,
+        InputDevice::Touch,
+    ];
+
+    /// The devices that are used together with this one, including itself
+    ///
+    /// The keyboard and mouse are used together, so their bindings are shown and switched between as one control scheme.
+    #[must_use]
+    pub fn used_with(self) -> &'static [InputDevice] {
+        match self {
+            InputDevice::Keyboard | InputDevice::Mouse => {
+                &[InputDevice::Keyboard, InputDevice::Mouse]
+            }
+            InputDevice::Gamepad => &[InputDevice::Gamepad],
+            InputDevice::Touch => &[InputDevice::Touch],
+        }
+    }
+
+    /// The devices that the player is likely to be using, based on the devices that are available
+    ///
+    /// Mobile platforms are assumed to have a touchscreen, while every other platform is assumed to have a keyboard and mouse.
+    /// [`InputDevice::Gamepad`] is added whenever a gamepad is connected.
+    #[must_use]
+    pub fn detect(gamepads: &Gamepads) -> Vec<InputDevice> {
+        let mut devices = if cfg!(any(target_os = "android", target_os = "ios")) {
+            vec![InputDevice::Touch]
+        } else {
+            vec![InputDevice::Keyboard, InputDevice::Mouse]
+        };
+
+        if gamepads.iter().next().is_some() {
+            devices.push(InputDevice::Gamepad);
+        }
+
+        devices
+    }
+}
+
+/// Ignores the inputs of some [`InputDevice`]s when updating the [`ActionState`](crate::action_state::ActionState)
+///
+/// As a resource, this affects the [`ActionState`](crate::action_state::ActionState) resource,
+/// and as a component, the [`ActionState`](crate::action_state::ActionState) of the same entity.
+///
+/// Inhibited inputs are treated as if they were released by [`update_action_state`](crate::systems::update_action_state),
+/// so actions that were only held by those inputs are released automatically,
+/// and actions bound to other devices keep working.
+/// Remove the [`InhibitInput`] to read every device again.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::inhibit_input::{InhibitInput, InputDevice};
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Jump,
+///     CloseMenu,
+/// }
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// // While the inventory is open, typing in its search box should not make the player jump
+/// fn open_inventory(mut commands: Commands, player: Query<Entity, With<Player>>) {
+///     commands
+///         .entity(player.single())
+///         .insert(InhibitInput::<Action>::new([InputDevice::Keyboard]));
+/// }
+///
+/// let mut world = World::new();
+/// world
+///     .spawn(InputManagerBundle {
+///         input_map: InputMap::new([
+///             (UserInput::from(KeyCode::Space), Action::Jump),
+///             (UserInput::from(GamepadButtonType::East), Action::CloseMenu),
+///         ]),
+///         ..Default::default()
+///     })
+///     .insert(Player);
+/// ```
+#[derive(Resource, Component, Debug, Clone, PartialEq, Eq)]
+pub struct InhibitInput<A: Actionlike> {
+    /// Whether the inputs of each [`InputDevice`] are ignored, in the order of [`InputDevice::ALL`]
+    inhibited: [bool; InputDevice::ALL.len()],
+    _phantom: PhantomData<A>,
+}
+
+impl<A: Actionlike> Default for InhibitInput<A> {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl<A: Actionlike> InhibitInput<A> {
+    /// Ignores the inputs of the provided `devices`
+    #[must_use]
+    pub fn new(devices: impl IntoIterator<Item = InputDevice>) -> Self {
+        let mut inhibit_input = Self {
+            inhibited: [false; InputDevice::ALL.len()],
+            _phantom: PhantomData,
+        };
+        for device in devices {
+            inhibit_input.insert(device);
+        }
+        inhibit_input
+    }
+
+    /// Ignores the inputs of every device
+    #[must_use]
+    pub fn all() -> Self {
+        Self::new(InputDevice::ALL)
+    }
+
+    /// Starts ignoring the inputs of the `device`
+    pub fn insert(&mut self, device: InputDevice) {
+        self.inhibited[device as usize] = true;
+    }
+
+    /// Stops ignoring the inputs of the `device`
+    pub fn remove(&mut self, device: InputDevice) {
+        self.inhibited[device as usize] = false;
+    }
+
+    /// Are the inputs of the `device` ignored?
+    #[must_use]
+    pub fn inhibits(&self, device: InputDevice) -> bool {
+        self.inhibited[device as usize]
+    }
+}
@@ -0,0 +1,252 @@
+//! This module contains [`InputDisplayPlugin`] and its supporting methods and impls.
+//!
+//! The [`InputDisplayPlugin`] renders the pressed actions of an [`ActionState`] as a text overlay,
+//! along with the bindings that pressed them and the positions of any sticks.
+//! If an [`InputLog`] is recorded for the same [`ActionState`], its most recent entries are shown below.
+//! This is useful for trailers, tutorials and bug reports, and requires the `input_display` feature.
+
+use bevy::app::{App, Plugin};
+use bevy::asset::Handle;
+use bevy::ecs::prelude::*;
+use bevy::prelude::{Color, TextBundle};
+use bevy::text::{Font, Text, TextSection, TextStyle};
+use bevy::ui::{PositionType, Style, UiRect, Val};
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::action_state::ActionState;
+use crate::input_log::InputLog;
+use crate::Actionlike;
+
+/// A [`Plugin`] that displays the pressed actions of `A` on screen
+///
+/// Actions are displayed by their [`Debug`] representation.
+/// The [`InputDisplay`] resource added by this plugin controls what is displayed and how it looks.
+/// Bevy does not render text without a font, so one must be provided with [`InputDisplay::font`].
+///
+/// # Example
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::input_display::{InputDisplay, InputDisplayPlugin};
+/// use leafwing_input_manager::input_log::InputLog;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Jump,
+///     Move,
+/// }
+///
+/// fn setup(asset_server: Res<AssetServer>, mut input_display: ResMut<InputDisplay<Action>>) {
+///     input_display.font = asset_server.load("fonts/FiraMono-Medium.ttf");
+/// }
+///
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugin(InputManagerPlugin::<Action>::default())
+///     .add_plugin(InputDisplayPlugin::<Action>::default())
+///     .init_resource::<ActionState<Action>>()
+///     .insert_resource(InputLog::<Action>::default())
+///     .add_startup_system(setup)
+///     .run();
+/// ```
+pub struct InputDisplayPlugin<A: Actionlike> {
+    _phantom: PhantomData<A>,
+}
+
+impl<A: Actionlike> Default for InputDisplayPlugin<A> {
+    fn default() -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A: Actionlike + Debug> Plugin for InputDisplayPlugin<A> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InputDisplay<A>>()
+            .add_startup_system(spawn_input_display::<A>)
+            // Runs in `CoreStage::Update`, after every `ActionState` and `InputLog` was updated in `CoreStage::PreUpdate`
+            .add_system(update_input_display::<A>);
+    }
+}
+
+/// Controls what the [`InputDisplayPlugin`] displays, and how
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct InputDisplay<A: Actionlike> {
+    /// Is the overlay visible?
+    pub enabled: bool,
+    /// The entity whose [`ActionState`] and [`InputLog`] are displayed
+    ///
+    /// If this is [`None`], the [`ActionState`] and [`InputLog`] resources are displayed.
+    pub entity: Option<Entity>,
+    /// The font of the overlay
+    pub font: Handle<Font>,
+    /// The size of the font of the overlay
+    pub font_size: f32,
+    /// The color of the text of the overlay
+    pub color: Color,
+    /// How many of the most recent [`InputLog`] entries are displayed
+    pub history: usize,
+    _phantom: PhantomData<A>,
+}
+
+impl<A: Actionlike> Default for InputDisplay<A> {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            entity: None,
+            font: Handle::default(),
+            font_size: 20.0,
+            color: Color::WHITE,
+            history: 8,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A: Actionlike + Debug> InputDisplay<A> {
+    /// The lines of text describing the `action_state` and the most recent entries of the `input_log`
+    ///
+    /// Each pressed action is listed with the binding that pressed it, and the position of its axis pair if it has one.
+    #[must_use]
+    pub fn lines(
+        &self,
+        action_state: &ActionState<A>,
+        input_log: Option<&InputLog<A>>,
+    ) -> Vec<String> {
+        let mut lines = Vec::new();
+
+        for action in action_state.get_pressed() {
+            let mut line = format!("{action:?}");
+            if let Some(input) = action_state.triggering_input(action.clone()) {
+                line.push_str(&format!(" [{input}]"));
+            }
+            if let Some(axis_pair) = action_state.axis_pair(action) {
+                line.push_str(&format!(" ({:.2}, {:.2})", axis_pair.x(), axis_pair.y()));
+            }
+            lines.push(line);
+        }
+
+        if let Some(input_log) = input_log.filter(|_| self.history > 0) {
+            if !input_log.is_empty() {
+                lines.push(String::from("--"));
+            }
+            // Newest first, so that the history reads downwards from the current state
+            for entry in input_log.latest(self.history).rev() {
+                let sign = if entry.pressed { '+' } else { '-' };
+                let mut line = format!("{sign}{:?}", entry.action);
+                if let Some(input) = &entry.input {
+                    line.push_str(&format!(" [{input}]"));
+                }
+                lines.push(line);
+            }
+        }
+
+        lines
+    }
+}
+
+/// Marks the text entity spawned by the [`InputDisplayPlugin`] for the actions of type `A`
+#[derive(Component, Debug)]
+pub struct InputDisplayText<A: Actionlike> {
+    _phantom: PhantomData<A>,
+}
+
+/// Spawns the text of the overlay in the bottom left corner of the screen
+pub fn spawn_input_display<A: Actionlike>(mut commands: Commands) {
+    commands.spawn((
+        TextBundle::default().with_style(Style {
+            position_type: PositionType::Absolute,
+            position: UiRect {
+                left: Val::Px(8.0),
+                bottom: Val::Px(8.0),
+                ..Default::default()
+            },
+            ..Default::default()
+        }),
+        InputDisplayText::<A> {
+            _phantom: PhantomData,
+        },
+    ));
+}
+
+/// Rewrites the text of the overlay from the displayed [`ActionState`] and [`InputLog`]
+pub fn update_input_display<A: Actionlike + Debug>(
+    input_display: Res<InputDisplay<A>>,
+    action_state: Option<Res<ActionState<A>>>,
+    input_log: Option<Res<InputLog<A>>>,
+    action_states: Query<(&ActionState<A>, Option<&InputLog<A>>)>,
+    mut texts: Query<&mut Text, With<InputDisplayText<A>>>,
+) {
+    let lines = match input_display.entity {
+        _ if !input_display.enabled => Vec::new(),
+        Some(entity) => action_states
+            .get(entity)
+            .map(|(action_state, input_log)| input_display.lines(action_state, input_log))
+            .unwrap_or_default(),
+        None => action_state
+            .map(|action_state| input_display.lines(&action_state, input_log.as_deref()))
+            .unwrap_or_default(),
+    };
+
+    let style = TextStyle {
+        font: input_display.font.clone(),
+        font_size: input_display.font_size,
+        color: input_display.color,
+    };
+    for mut text in texts.iter_mut() {
+        text.sections = lines
+            .iter()
+            .map(|line| TextSection::new(format!("{line}\n"), style.clone()))
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InputDisplay;
+    use crate as leafwing_input_manager;
+    use crate::action_state::ActionState;
+    use crate::axislike::DualAxisData;
+    use crate::input_log::InputLog;
+    use crate::user_input::UserInput;
+    use crate::Actionlike;
+    use bevy::input::keyboard::KeyCode;
+    use bevy::utils::Duration;
+
+    #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    enum Action {
+        Jump,
+        Move,
+    }
+
+    #[test]
+    fn pressed_actions_and_history_are_described() {
+        let mut action_state = ActionState::<Action>::default();
+        action_state.press(Action::Jump);
+        action_state.action_data_mut(Action::Jump).triggering_input =
+            Some(UserInput::from(KeyCode::Space));
+        action_state.press(Action::Move);
+        action_state.action_data_mut(Action::Move).axis_pair = Some(DualAxisData::new(0.5, -1.0));
+
+        let mut input_log = InputLog::default();
+        input_log.record(&action_state, Duration::ZERO);
+
+        let input_display = InputDisplay::<Action>::default();
+        assert_eq!(
+            input_display.lines(&action_state, None),
+            ["Jump [Space]", "Move (0.50, -1.00)"]
+        );
+        assert_eq!(
+            input_display.lines(&action_state, Some(&input_log)),
+            [
+                "Jump [Space]",
+                "Move (0.50, -1.00)",
+                "--",
+                "+Move",
+                "+Jump [Space]"
+            ]
+        );
+    }
+}
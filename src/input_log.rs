@@ -0,0 +1,239 @@
+//! This module contains [`InputLog`] and its supporting methods and impls.
+//!
+//! The [`InputLog`] is an optional addition to an [`InputManagerBundle`](crate::InputManagerBundle),
+//! which keeps a bounded history of the inputs that pressed and released each action, with timestamps.
+//! This is useful for debugging overlays, replays and "show inputs" widgets for streamers.
+
+use bevy::ecs::prelude::*;
+use bevy::input::gamepad::Gamepad;
+use bevy::utils::Duration;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+use crate::action_state::ActionState;
+use crate::inhibit_input::InputDevice;
+use crate::user_input::UserInput;
+use crate::Actionlike;
+
+/// A change in the state of an action, as recorded by an [`InputLog`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InputLogEntry<A: Actionlike> {
+    /// The action that changed
+    pub action: A,
+    /// Was the action pressed, or released?
+    pub pressed: bool,
+    /// The binding that pressed the action, with any [`Wildcard`](crate::user_input::Wildcard)s resolved
+    ///
+    /// For releases, this is the binding that pressed the action in the first place.
+    /// This is [`None`] for actions that were pressed by other means, such as [`ActionState::press`].
+    pub input: Option<UserInput>,
+    /// The devices that held the action pressed
+    ///
+    /// For releases, these are the devices that held the action pressed during its final update.
+    /// See [`ActionState::sources`].
+    pub sources: Vec<(InputDevice, Option<Gamepad>)>,
+    /// The [`ActionState::tick_count`] at which the change occurred
+    pub tick: u64,
+    /// When the change was recorded, as the time elapsed since the app started
+    pub time: Duration,
+}
+
+/// A bounded history of the presses and releases of each action
+///
+/// Once the [`InputLog::capacity`] is reached, the oldest entries are discarded to make room for new ones.
+///
+/// As a resource, this records the [`ActionState`] resource,
+/// and as a component, the [`ActionState`] of the same entity.
+/// This is updated by [`update_input_log`](crate::systems::update_input_log),
+/// as part of [`InputManagerSystem::Activity`](crate::plugin::InputManagerSystem::Activity).
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::input_log::InputLog;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Jump,
+/// }
+///
+/// fn show_recent_inputs(input_log: Res<InputLog<Action>>) {
+///     for entry in input_log.latest(5) {
+///         let verb = if entry.pressed { "pressed" } else { "released" };
+///         println!("{:?} {verb} by {:?} at {:?}", entry.action, entry.input, entry.time);
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugin(InputManagerPlugin::<Action>::default())
+///     .init_resource::<ActionState<Action>>()
+///     .insert_resource(InputLog::<Action>::new(64))
+///     .add_system(show_recent_inputs);
+/// ```
+#[derive(Resource, Component, Debug, Clone, PartialEq)]
+pub struct InputLog<A: Actionlike> {
+    entries: VecDeque<InputLogEntry<A>>,
+    capacity: usize,
+    /// The binding and sources that last held each action pressed, used to describe releases
+    held_by: Vec<(Option<UserInput>, Vec<(InputDevice, Option<Gamepad>)>)>,
+}
+
+impl<A: Actionlike> Default for InputLog<A> {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl<A: Actionlike> InputLog<A> {
+    /// The number of entries kept by [`InputLog::default`]
+    pub const DEFAULT_CAPACITY: usize = 256;
+
+    /// Creates an empty [`InputLog`] that holds up to `capacity` entries
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+            held_by: vec![(None, Vec::new()); A::N_VARIANTS],
+        }
+    }
+
+    /// Records the presses and releases of the `action_state` since the previous call, at the `time` since the app started
+    ///
+    /// This should be called once per frame, after the `action_state` has been updated.
+    pub fn record(&mut self, action_state: &ActionState<A>, time: Duration) {
+        for action in A::variants() {
+            let index = action.index();
+            let pressed = action_state.just_pressed(action.clone());
+
+            if action_state.pressed(action.clone()) {
+                let input = action_state.triggering_input(action.clone()).cloned();
+                self.held_by[index] = (input, action_state.sources(action.clone()).to_vec());
+            }
+
+            if pressed || action_state.just_released(action.clone()) {
+                let (input, sources) = self.held_by[index].clone();
+                self.push(InputLogEntry {
+                    action,
+                    pressed,
+                    input,
+                    sources,
+                    tick: action_state.tick_count(),
+                    time,
+                });
+            }
+        }
+    }
+
+    /// Adds an `entry`, discarding the oldest entry if the log is full
+    pub fn push(&mut self, entry: InputLogEntry<A>) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+
+    /// Every entry in the log, from oldest to newest
+    pub fn entries(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = &InputLogEntry<A>> + ExactSizeIterator {
+        self.entries.iter()
+    }
+
+    /// The `n` most recent entries, from oldest to newest
+    pub fn latest(&self, n: usize) -> impl DoubleEndedIterator<Item = &InputLogEntry<A>> {
+        self.entries
+            .iter()
+            .skip(self.entries.len().saturating_sub(n))
+    }
+
+    /// The maximum number of entries that are kept
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The number of entries in the log
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Is the log empty?
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Discards every entry
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InputLog;
+    use crate as leafwing_input_manager;
+    use crate::action_state::ActionState;
+    use crate::Actionlike;
+    use bevy::utils::{Duration, Instant};
+
+    #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    enum Action {
+        Jump,
+        Crouch,
+    }
+
+    #[test]
+    fn presses_and_releases_are_logged() {
+        let mut action_state = ActionState::<Action>::default();
+        let mut input_log = InputLog::<Action>::new(3);
+        let frame = Duration::from_millis(100);
+        let start = Instant::now();
+
+        let steps: [&[Action]; 5] = [
+            &[Action::Jump],
+            &[Action::Jump],
+            &[Action::Crouch],
+            &[],
+            &[Action::Jump],
+        ];
+        for (n_frames, pressed) in steps.into_iter().enumerate() {
+            let previous = start + frame * n_frames as u32;
+            action_state.tick(previous + frame, previous);
+            for action in Action::variants() {
+                if pressed.contains(&action) {
+                    action_state.press(action);
+                } else {
+                    action_state.release(action);
+                }
+            }
+            input_log.record(&action_state, frame * n_frames as u32);
+        }
+
+        // Jump pressed, Jump released, Crouch pressed, Crouch released, Jump pressed
+        assert_eq!(input_log.len(), 3);
+        let entries: Vec<_> = input_log
+            .entries()
+            .map(|entry| (entry.action, entry.pressed, entry.tick))
+            .collect();
+        assert_eq!(
+            entries,
+            [
+                (Action::Crouch, true, 3),
+                (Action::Crouch, false, 4),
+                (Action::Jump, true, 5)
+            ]
+        );
+        assert_eq!(input_log.latest(1).next().unwrap().time, frame * 4);
+
+        input_log.clear();
+        assert!(input_log.is_empty());
+    }
+}
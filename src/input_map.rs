@@ -1,16 +1,24 @@
 //! This module contains [`InputMap`] and its supporting methods and impls.
 
-use crate::action_state::ActionData;
+use crate::action_set::ActionSet;
+use crate::action_state::{ActionData, ActionState};
+use crate::axislike::{AxisRange, AxisSemantics, AxisType, OutputRange};
 use crate::buttonlike::ButtonState;
-use crate::clashing_inputs::ClashStrategy;
-use crate::input_streams::InputStreams;
+use crate::calibration::{map_single_axes, AxisCalibration};
+use crate::clashing_inputs::{ChordExclusivity, ClashStrategy};
+use crate::errors::{BindingError, InputManagerError};
+use crate::inhibit_input::InputDevice;
+use crate::input_streams::{InputStreams, ReleasedStreams};
 use crate::user_input::{InputKind, Modifier, UserInput};
 use crate::Actionlike;
 
 use bevy::ecs::component::Component;
 use bevy::ecs::system::Resource;
-use bevy::input::gamepad::Gamepad;
+use bevy::input::gamepad::{Gamepad, GamepadAxisType};
+use bevy::input::keyboard::KeyCode;
 use bevy::reflect::TypeUuid;
+use bevy::utils::{Duration, FloatOrd, Uuid};
+use bevy::window::WindowId;
 
 use core::fmt::Debug;
 use petitset::PetitSet;
@@ -26,8 +34,9 @@ use std::marker::PhantomData;
 ///
 /// The provided input types must be able to be converted into a [`UserInput`].
 ///
-/// The maximum number of bindings (total) that can be stored for each action is 16.
-/// Insertions will silently fail if you have reached this cap.
+/// The maximum number of bindings (total) that can be stored for each action is [`MAX_BINDINGS_PER_ACTION`].
+/// A lower limit, along with what happens when inserting past it or inserting an input that is already bound
+/// to another action, can be configured with a [`BindingPolicy`].
 ///
 /// By default, if two actions would be triggered by a combination of buttons,
 /// and one combination is a strict subset of the other, only the larger input is registered.
@@ -73,21 +82,210 @@ use std::marker::PhantomData;
 /// // Removal
 /// input_map.clear_action(Action::Hide);
 ///```
-#[derive(Resource, Component, Debug, Clone, PartialEq, Eq, TypeUuid)]
-#[uuid = "D7DECC78-8573-42FF-851A-F0344C7D05C9"]
+#[derive(Resource, Component, Debug, Clone, PartialEq, Eq)]
 pub struct InputMap<A: Actionlike> {
     /// The raw vector of [PetitSet]s used to store the input mapping,
     /// indexed by the `Actionlike::id` of `A`
     map: Vec<PetitSet<UserInput, 16>>,
     associated_gamepad: Option<Gamepad>,
+    gamepad_association: GamepadAssociation,
+    associated_window: Option<WindowId>,
+    policy: BindingPolicy,
+    /// The priority of each action, indexed by the `Actionlike::id` of `A`
+    priorities: Vec<i32>,
+    /// The range that the value of each action is remapped onto, indexed by the `Actionlike::id` of `A`
+    output_ranges: Vec<Option<OutputRange>>,
+    /// The value that each action must reach to be pressed, indexed by the `Actionlike::id` of `A`
+    press_thresholds: Vec<Option<FloatOrd>>,
+    /// The bindings of each action that do not use [`BindingTrigger::Press`], indexed by the `Actionlike::id` of `A`
+    triggers: Vec<Vec<(UserInput, BindingTrigger)>>,
+    /// The bindings of each action that require another action to be active, indexed by the `Actionlike::id` of `A`
+    modifier_actions: Vec<Vec<(UserInput, A)>>,
+    /// The chord bindings of each action that do not use [`ChordExclusivity::FollowStrategy`], indexed by the `Actionlike::id` of `A`
+    chord_exclusivity: Vec<Vec<(UserInput, ChordExclusivity)>>,
+    /// Whether the inputs of each [`InputDevice`] are read, in the order of [`InputDevice::ALL`]
+    allowed_devices: [bool; InputDevice::ALL.len()],
     marker: PhantomData<A>,
 }
 
+// Each action type needs its own uuid, so that the `InputMap`s of different action types are distinct asset types
+impl<A: Actionlike + TypeUuid> TypeUuid for InputMap<A> {
+    const TYPE_UUID: Uuid =
+        Uuid::from_u128(0xD7DECC78_8573_42FF_851A_F0344C7D05C9 ^ A::TYPE_UUID.as_u128());
+}
+
+/// The maximum number of bindings that can be stored for a single action in an [`InputMap`]
+pub const MAX_BINDINGS_PER_ACTION: usize = 16;
+
+/// Controls how insertions into an [`InputMap`] handle full and duplicate bindings
+///
+/// This is particularly useful for rebinding UIs, which typically want a small, fixed number of slots per action.
+/// Set this using [`InputMap::set_binding_policy`].
+///
+/// The default policy allows up to [`MAX_BINDINGS_PER_ACTION`] bindings per action,
+/// permits the same input to be bound to multiple actions,
+/// and panics on overflow (or returns an error when using [`InputMap::try_insert`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BindingPolicy {
+    /// The maximum number of bindings that each action may hold
+    ///
+    /// Values larger than [`MAX_BINDINGS_PER_ACTION`] are treated as [`MAX_BINDINGS_PER_ACTION`].
+    pub capacity: usize,
+    /// What happens when inserting a new binding into an action that is already at capacity
+    pub on_overflow: OverflowPolicy,
+    /// What happens when inserting an input that is already bound to a different action
+    pub on_duplicate: DuplicatePolicy,
+}
+
+impl Default for BindingPolicy {
+    fn default() -> Self {
+        BindingPolicy {
+            capacity: MAX_BINDINGS_PER_ACTION,
+            on_overflow: OverflowPolicy::Error,
+            on_duplicate: DuplicatePolicy::Allow,
+        }
+    }
+}
+
+/// Which gamepads an [`InputMap`] reads from when it has no [associated gamepad](InputMap::gamepad)
+///
+/// Set this using [`InputMap::set_gamepad_association`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum GamepadAssociation {
+    /// Inputs are read from every connected gamepad, as is convenient for single-player games
+    ///
+    /// Each input is read from the first gamepad, in registration order, on which it is active.
+    /// This is the default.
+    #[default]
+    AnyGamepad,
+    /// Gamepad inputs are ignored until a gamepad is associated, as is safest for local multiplayer
+    ///
+    /// This ensures that a player who has not been assigned a gamepad yet cannot be controlled by the gamepads of other players.
+    Strict,
+}
+
+/// What an [`InputMap`] should do when a binding is inserted into a full action
+///
+/// Used in [`BindingPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OverflowPolicy {
+    /// The new binding is silently discarded
+    Reject,
+    /// The oldest binding of the action is removed to make room for the new binding
+    ///
+    /// Bindings are considered in iteration order,
+    /// which matches insertion order unless bindings have been moved with [`InputMap::insert_at`].
+    ReplaceOldest,
+    /// [`InputMap::try_insert`] returns [`BindingError::CapacityExceeded`], while other insertion methods panic
+    Error,
+}
+
+/// What an [`InputMap`] should do when an input that is already bound to a different action is inserted
+///
+/// Inserting an input that is already bound to the *same* action never has any effect.
+/// Used in [`BindingPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DuplicatePolicy {
+    /// The input is bound to both actions
+    Allow,
+    /// The new binding is silently discarded
+    Reject,
+    /// The input is removed from every other action before being bound to the new action
+    ///
+    /// If the input is bound to a [fixed](crate::ActionMetadata::fixed) action,
+    /// the new binding is silently discarded instead.
+    Move,
+    /// [`InputMap::try_insert`] returns [`BindingError::AlreadyBound`], while other insertion methods panic
+    Error,
+}
+
+/// When a binding of an [`InputMap`] presses its action
+///
+/// Set this using [`InputMap::insert_with_trigger`] or [`InputMap::set_trigger`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum BindingTrigger {
+    /// The action is pressed while the input is held
+    ///
+    /// This is the default.
+    #[default]
+    Press,
+    /// The action is pressed for a single update when the input is released
+    ///
+    /// This is known as "negative edge" in fighting games, and suits "release to fire" mechanics.
+    /// Only buttons can be released, as described in [`InputStreams::input_just_released`].
+    Release,
+    /// The action is pressed once the input has been held for the duration
+    ///
+    /// This suits hold-to-confirm prompts and skipping cutscenes.
+    /// The progress towards the duration is reported by [`ActionState::hold_progress`].
+    Hold(Duration),
+    /// While the input is held, the action is repeatedly pressed for a single update, once per period
+    ///
+    /// The first press happens as soon as the input is pressed, and the action is released between presses,
+    /// so that each press is reported by [`ActionState::just_pressed`].
+    /// This suits autofire, and lets players who cannot mash buttons repeat an action by holding it instead.
+    /// The rate is independent of the key repeat settings of the operating system.
+    Turbo(Duration),
+}
+
+/// Provides canonical default bindings for an [`Actionlike`] type
+///
+/// Implement this trait next to your action enum to define its default bindings in one place.
+/// These are used by [`InputManagerBundle::with_defaults`](crate::InputManagerBundle::with_defaults)
+/// and [`InputMap::reset_to_defaults`].
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+/// enum Action {
+///     Jump,
+/// }
+///
+/// impl DefaultBindings for Action {
+///     fn default_input_map() -> InputMap<Action> {
+///         InputMap::new([(KeyCode::Space, Action::Jump)])
+///     }
+/// }
+///
+/// let mut input_map = Action::default_input_map();
+/// input_map.insert(KeyCode::W, Action::Jump);
+/// assert_eq!(input_map.len(), 2);
+///
+/// input_map.reset_to_defaults();
+/// assert_eq!(input_map, Action::default_input_map());
+/// ```
+pub trait DefaultBindings: Actionlike {
+    /// The default [`InputMap`] for this action type
+    fn default_input_map() -> InputMap<Self>;
+
+    /// The default [`InputMap`] for this action type, when played with the given [`InputDevice`]
+    ///
+    /// By default, this is the subset of [`DefaultBindings::default_input_map`] that is [read from](InputMap::device_bindings) the `device`.
+    /// Override this to provide entirely different defaults per platform, for example for touch controls.
+    /// These are used by [`PlatformBindings::from_defaults`](crate::platform_bindings::PlatformBindings::from_defaults).
+    fn device_input_map(device: InputDevice) -> InputMap<Self> {
+        Self::default_input_map().device_bindings(device)
+    }
+}
+
 impl<A: Actionlike> Default for InputMap<A> {
     fn default() -> Self {
         InputMap {
             map: A::variants().map(|_| PetitSet::default()).collect(),
             associated_gamepad: None,
+            gamepad_association: GamepadAssociation::default(),
+            associated_window: None,
+            policy: BindingPolicy::default(),
+            priorities: vec![0; A::N_VARIANTS],
+            output_ranges: vec![None; A::N_VARIANTS],
+            press_thresholds: vec![None; A::N_VARIANTS],
+            triggers: vec![Vec::new(); A::N_VARIANTS],
+            modifier_actions: vec![Vec::new(); A::N_VARIANTS],
+            chord_exclusivity: vec![Vec::new(); A::N_VARIANTS],
+            allowed_devices: [true; InputDevice::ALL.len()],
             marker: PhantomData,
         }
     }
@@ -118,10 +316,15 @@ impl<A: Actionlike> InputMap<A> {
     ///
     /// assert_eq!(input_map.len(), 2);
     /// ```
+    ///
+    /// Unlike [`insert`](Self::insert), this also binds [fixed](crate::ActionMetadata::fixed) actions,
+    /// as it is typically used to define default bindings.
     #[must_use]
     pub fn new(bindings: impl IntoIterator<Item = (impl Into<UserInput>, A)>) -> Self {
         let mut input_map = InputMap::default();
-        input_map.insert_multiple(bindings);
+        for (input, action) in bindings {
+            input_map.insert_fixed(input, action);
+        }
 
         input_map
     }
@@ -158,32 +361,229 @@ impl<A: Actionlike> InputMap<A> {
     }
 }
 
+impl<A: DefaultBindings> InputMap<A> {
+    /// Replaces all bindings with those provided by [`DefaultBindings::default_input_map`]
+    ///
+    /// The associated gamepad, associated window, [`BindingPolicy`], action priorities, output ranges and press thresholds are preserved.
+    pub fn reset_to_defaults(&mut self) -> &mut Self {
+        let defaults = A::default_input_map();
+        self.map = defaults.map;
+        self.triggers = defaults.triggers;
+        self.modifier_actions = defaults.modifier_actions;
+        self.chord_exclusivity = defaults.chord_exclusivity;
+        self
+    }
+}
+
 // Insertion
 impl<A: Actionlike> InputMap<A> {
     /// Insert a mapping between `input` and `action`
     ///
+    /// Full actions and inputs that are already bound to other actions are handled according to the [`BindingPolicy`].
+    /// Bindings of [fixed](crate::ActionMetadata::fixed) actions must be defined with
+    /// [`InputMap::new`] or [`InputMap::insert_fixed`] instead.
+    ///
     /// # Panics
     ///
-    /// Panics if the map is full and `input` is not a duplicate.
+    /// Panics if the insertion fails and the corresponding [`BindingPolicy`] field is set to `Error`.
+    /// With the default policy, this occurs when the action is full and `input` is not a duplicate.
+    /// Also panics if the `action` is fixed; use [`InputMap::try_insert`] to handle these cases instead.
     pub fn insert(&mut self, input: impl Into<UserInput>, action: A) -> &mut Self {
-        let input = input.into();
+        if let Err(error) = self.try_insert(input, action) {
+            panic!("Could not insert binding into `InputMap`: {error}");
+        }
+
+        self
+    }
+
+    /// Attempts to insert a mapping between `input` and `action`
+    ///
+    /// Full actions and inputs that are already bound to other actions are handled according to the [`BindingPolicy`].
+    /// Returns an error if the insertion fails and the corresponding policy is set to `Error`,
+    /// or [`BindingError::FixedAction`] if the action is [fixed](crate::ActionMetadata::fixed).
+    /// Insertions that are silently rejected by the policy still return [`Ok`].
+    pub fn try_insert(
+        &mut self,
+        input: impl Into<UserInput>,
+        action: A,
+    ) -> Result<&mut Self, BindingError> {
+        if action.metadata().fixed {
+            return Err(BindingError::FixedAction);
+        }
 
-        self.map[action.index()].insert(input);
+        self.insert_binding(input.into(), action)?;
+        Ok(self)
+    }
+
+    /// Insert a mapping between `input` and `action`, even if the action is [fixed](crate::ActionMetadata::fixed)
+    ///
+    /// This is intended for defining default bindings, such as in [`DefaultBindings::default_input_map`].
+    /// Rebinding code should use [`insert`](Self::insert) or [`try_insert`](Self::try_insert), which refuse fixed actions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the insertion fails and the corresponding [`BindingPolicy`] field is set to `Error`.
+    pub fn insert_fixed(&mut self, input: impl Into<UserInput>, action: A) -> &mut Self {
+        if let Err(error) = self.insert_binding(input.into(), action) {
+            panic!("Could not insert binding into `InputMap`: {error}");
+        }
 
         self
     }
 
+    /// Inserts a mapping between `input` and `action` according to the [`BindingPolicy`], without checking whether the action is fixed
+    fn insert_binding(&mut self, input: UserInput, action: A) -> Result<(), BindingError> {
+        let action_index = action.index();
+
+        if self.map[action_index].contains(&input) {
+            return Ok(());
+        }
+
+        let other_actions: Vec<A> = A::variants()
+            .filter(|other| {
+                other.index() != action_index && self.map[other.index()].contains(&input)
+            })
+            .collect();
+
+        if !other_actions.is_empty() {
+            match self.policy.on_duplicate {
+                DuplicatePolicy::Allow => (),
+                DuplicatePolicy::Reject => return Ok(()),
+                DuplicatePolicy::Move => {
+                    if other_actions.iter().any(|other| other.metadata().fixed) {
+                        return Ok(());
+                    }
+
+                    for other in other_actions {
+                        self.map[other.index()].remove(&input);
+                    }
+                }
+                DuplicatePolicy::Error => return Err(BindingError::AlreadyBound),
+            }
+        }
+
+        let capacity = self.policy.capacity.min(MAX_BINDINGS_PER_ACTION);
+        let set = &mut self.map[action_index];
+        if set.len() >= capacity {
+            match self.policy.on_overflow {
+                OverflowPolicy::Reject => return Ok(()),
+                // The bindings of fixed actions are never evicted
+                OverflowPolicy::ReplaceOldest if action.metadata().fixed => {
+                    return Err(BindingError::CapacityExceeded { capacity })
+                }
+                OverflowPolicy::ReplaceOldest => {
+                    // Rebuild the set so that the remaining bindings fill the lowest indexes,
+                    // ensuring that the new binding is stored last
+                    let remaining: Vec<UserInput> =
+                        set.iter().skip(set.len() + 1 - capacity).cloned().collect();
+                    *set = PetitSet::from_iter(remaining);
+                }
+                OverflowPolicy::Error => return Err(BindingError::CapacityExceeded { capacity }),
+            }
+        }
+
+        if capacity > 0 {
+            set.insert(input);
+        }
+
+        // Moved and replaced bindings lose their triggers, modifier actions and chord exclusivity
+        self.forget_unbound_settings();
+        Ok(())
+    }
+
+    /// Insert a mapping between `input` and `action`, which presses the action according to the `trigger`
+    ///
+    /// See [`InputMap::insert`] for how the insertion is handled,
+    /// and [`InputMap::set_trigger`] for the effect of the `trigger`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::input_map::BindingTrigger;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     DrawBow,
+    ///     FireArrow,
+    /// }
+    ///
+    /// let mut input_map = InputMap::new([(MouseButton::Left, Action::DrawBow)]);
+    /// // Letting go of the mouse button looses the arrow
+    /// input_map.insert_with_trigger(MouseButton::Left, Action::FireArrow, BindingTrigger::Release);
+    ///
+    /// assert_eq!(
+    ///     input_map.trigger(Action::FireArrow, &MouseButton::Left.into()),
+    ///     BindingTrigger::Release
+    /// );
+    /// ```
+    pub fn insert_with_trigger(
+        &mut self,
+        input: impl Into<UserInput>,
+        action: A,
+        trigger: BindingTrigger,
+    ) -> &mut Self {
+        let input = input.into();
+        self.insert(input.clone(), action.clone());
+        self.set_trigger(action, input, trigger)
+    }
+
+    /// Insert a mapping between `input` and `action`, which only presses the action while the `modifier` action is active
+    ///
+    /// See [`InputMap::insert`] for how the insertion is handled,
+    /// and [`InputMap::set_modifier_action`] for the effect of the `modifier`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Aim,
+    ///     Fire,
+    ///     AltFire,
+    /// }
+    ///
+    /// let mut input_map = InputMap::new([
+    ///     (UserInput::from(MouseButton::Right), Action::Aim),
+    ///     (UserInput::from(GamepadButtonType::LeftTrigger), Action::Aim),
+    ///     (UserInput::from(MouseButton::Left), Action::Fire),
+    /// ]);
+    /// // Firing while aiming uses the alternate fire, however aiming is bound
+    /// input_map.insert_with_modifier_action(MouseButton::Left, Action::AltFire, Action::Aim);
+    ///
+    /// assert_eq!(
+    ///     input_map.modifier_action(Action::AltFire, &MouseButton::Left.into()),
+    ///     Some(Action::Aim)
+    /// );
+    /// ```
+    pub fn insert_with_modifier_action(
+        &mut self,
+        input: impl Into<UserInput>,
+        action: A,
+        modifier: A,
+    ) -> &mut Self {
+        let input = input.into();
+        self.insert(input.clone(), action.clone());
+        self.set_modifier_action(action, input, Some(modifier))
+    }
+
     /// Insert a mapping between `input` and `action` at the provided index
     ///
     /// If a matching input already existed in the set, it will be moved to the supplied index. Any input that was previously there will be moved to the matching input’s original index.
+    /// New inputs are first inserted according to the [`BindingPolicy`], as in [`insert`](Self::insert).
     ///
     /// # Panics
     ///
-    /// Panics if the map is full and `input` is not a duplicate.
+    /// Panics if the insertion fails and the corresponding [`BindingPolicy`] field is set to `Error`.
     pub fn insert_at(&mut self, input: impl Into<UserInput>, action: A, index: usize) -> &mut Self {
         let input = input.into();
 
-        self.map[action.index()].insert_at(input, index);
+        self.insert(input.clone(), action.clone());
+        if self.map[action.index()].contains(&input) {
+            self.map[action.index()].insert_at(input, index);
+        }
 
         self
     }
@@ -227,6 +627,19 @@ impl<A: Actionlike> InputMap<A> {
         self
     }
 
+    /// Attempts to insert a mapping between the simultaneous combination of `buttons` and the `action` provided
+    ///
+    /// Returns an error if `buttons` cannot form a chord (see [`UserInput::try_chord`]),
+    /// or if the insertion fails as described in [`InputMap::try_insert`].
+    pub fn try_insert_chord(
+        &mut self,
+        buttons: impl IntoIterator<Item = impl Into<InputKind>>,
+        action: A,
+    ) -> Result<&mut Self, InputManagerError> {
+        let chord = UserInput::try_chord(buttons)?;
+        Ok(self.try_insert(chord, action)?)
+    }
+
     /// Inserts a mapping between the simultaneous combination of the [`Modifier`] plus the `input` and the `action` provided.
     ///
     /// When working with keyboard modifiers, should be preferred over `insert_chord`.
@@ -242,42 +655,250 @@ impl<A: Actionlike> InputMap<A> {
 
     /// Merges the provided [`InputMap`] into the [`InputMap`] this method was called on
     ///
-    /// This adds both of their bindings to the resulting [`InputMap`].
+    /// This adds both of their bindings to the resulting [`InputMap`], according to the [`BindingPolicy`] of this map.
     /// Like usual, any duplicate bindings are ignored.
+    /// Bindings of [fixed](crate::ActionMetadata::fixed) actions are merged too.
+    /// Bindings that the policy refuses with an error are skipped:
+    /// use [`try_merge`](Self::try_merge) to detect them.
     ///
     /// If the associated gamepads do not match, the resulting associated gamepad will be set to `None`.
+    /// The same is true of the associated windows.
     pub fn merge(&mut self, other: &InputMap<A>) -> &mut Self {
+        let (merged, _) = self.merged(other);
+        *self = merged;
+        self
+    }
+
+    /// Attempts to merge the provided [`InputMap`] into the [`InputMap`] this method was called on
+    ///
+    /// Behaves like [`merge`](Self::merge), but if any binding is refused by the [`BindingPolicy`] with an error,
+    /// this map is left unchanged and the refused bindings are returned along with their errors.
+    pub fn try_merge(
+        &mut self,
+        other: &InputMap<A>,
+    ) -> Result<&mut Self, Vec<(UserInput, A, BindingError)>> {
+        let (merged, errors) = self.merged(other);
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        *self = merged;
+        Ok(self)
+    }
+
+    /// The result of merging `other` into this map, along with the bindings that were refused
+    fn merged(&self, other: &InputMap<A>) -> (InputMap<A>, Vec<(UserInput, A, BindingError)>) {
         let associated_gamepad = if self.associated_gamepad == other.associated_gamepad {
             self.associated_gamepad
         } else {
             None
         };
+        let associated_window = if self.associated_window == other.associated_window {
+            self.associated_window
+        } else {
+            None
+        };
 
         let mut new_map = InputMap {
             associated_gamepad,
+            gamepad_association: self.gamepad_association,
+            associated_window,
+            policy: self.policy,
+            priorities: self.priorities.clone(),
+            output_ranges: self.output_ranges.clone(),
+            press_thresholds: self.press_thresholds.clone(),
+            allowed_devices: self.allowed_devices,
             ..Default::default()
         };
 
+        let mut errors = Vec::new();
         for action in A::variants() {
-            for input in self.get(action.clone()).iter() {
-                new_map.insert(input.clone(), action.clone());
+            let inputs = self.get(action.clone()).iter();
+            for input in inputs.chain(other.get(action.clone()).iter()) {
+                if let Err(error) = new_map.insert_binding(input.clone(), action.clone()) {
+                    errors.push((input.clone(), action.clone(), error));
+                }
             }
 
-            for input in other.get(action.clone()).iter() {
-                new_map.insert(input.clone(), action.clone());
+            let triggers = self.triggers[action.index()].iter();
+            for (input, trigger) in triggers.chain(other.triggers[action.index()].iter()) {
+                new_map.set_trigger(action.clone(), input.clone(), *trigger);
             }
+
+            let modifiers = self.modifier_actions[action.index()].iter();
+            for (input, modifier) in modifiers.chain(other.modifier_actions[action.index()].iter())
+            {
+                new_map.set_modifier_action(action.clone(), input.clone(), Some(modifier.clone()));
+            }
+
+            let exclusivity = self.chord_exclusivity[action.index()].iter();
+            for (chord, exclusivity) in
+                exclusivity.chain(other.chord_exclusivity[action.index()].iter())
+            {
+                new_map.set_chord_exclusivity(action.clone(), chord.clone(), *exclusivity);
+            }
+        }
+
+        (new_map, errors)
+    }
+}
+
+/// How an overlay [`InputMap`] is combined with a base [`InputMap`] by [`InputMap::layered`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LayerMode {
+    /// The bindings of both maps are used
+    #[default]
+    Extend,
+    /// For each action with at least one binding in the overlay, only the overlay's bindings are used
+    ///
+    /// Actions that are unbound in the overlay fall back to the bindings of the base map.
+    Shadow,
+}
+
+/// An [`InputMap`] layered on top of the [`InputMap`] component of the same entity
+///
+/// While this component is present, the entity's [`ActionState`](crate::action_state::ActionState) is updated
+/// according to [`InputMapOverlay::apply`], rather than just the base [`InputMap`].
+/// Insert and remove this component to temporarily change an entity's bindings,
+/// such as while a player is mounted in a vehicle.
+///
+/// The layered map is rebuilt whenever the base map or the overlay changes, so both can be freely modified.
+///
+/// By default, the inputs of the overlay pass through to the base map: when both bind the same input to different actions,
+/// both actions are pressed. Actions in [`blocking`](Self::blocking) instead consume their inputs,
+/// so that the base map's bindings to those inputs are ignored.
+/// This allows HUD widgets to claim the keys they use, while leaving every other key to gameplay.
+///
+/// # Example
+/// ```rust
+/// use bevy::input::keyboard::KeyCode;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     SwitchWeapon,
+///     UseQuickSlot,
+/// }
+///
+/// let base = InputMap::new([(KeyCode::Key1, Action::SwitchWeapon)]);
+/// let hud = InputMapOverlay::new(
+///     InputMap::new([(KeyCode::Key1, Action::UseQuickSlot)]),
+///     LayerMode::Extend,
+/// )
+/// .with_blocking(Action::UseQuickSlot);
+///
+/// let layered = hud.apply(&base);
+/// assert!(layered.get(Action::SwitchWeapon).is_empty());
+/// assert_eq!(layered.get(Action::UseQuickSlot).len(), 1);
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct InputMapOverlay<A: Actionlike> {
+    /// The bindings layered on top of the base map
+    pub map: InputMap<A>,
+    /// How the bindings are combined with the base map
+    pub mode: LayerMode,
+    /// The actions whose overlay bindings block the base map from using the same inputs
+    ///
+    /// The inputs of all other actions pass through to the base map.
+    pub blocking: ActionSet<A>,
+}
+
+impl<A: Actionlike> InputMapOverlay<A> {
+    /// Creates a new overlay, which combines `map` with the base map according to `mode`
+    ///
+    /// The inputs of every action pass through to the base map.
+    #[must_use]
+    pub fn new(map: InputMap<A>, mode: LayerMode) -> Self {
+        InputMapOverlay {
+            map,
+            mode,
+            blocking: ActionSet::default(),
         }
+    }
 
-        *self = new_map;
+    /// Makes the overlay bindings of the `action` block the base map from using the same inputs
+    #[must_use]
+    pub fn with_blocking(mut self, action: A) -> Self {
+        self.blocking.insert(action);
         self
     }
+
+    /// Combines the `base` map with this overlay
+    ///
+    /// The maps are first combined with [`InputMap::layered`], according to the [`mode`](Self::mode).
+    /// Then, every binding of another action that is identical to an overlay binding of a [`blocking`](Self::blocking) action is removed.
+    /// Chords and virtual gamepads that merely contain a blocked input are kept.
+    #[must_use]
+    pub fn apply(&self, base: &InputMap<A>) -> InputMap<A> {
+        let mut layered = base.layered(&self.map, self.mode);
+
+        for blocking_action in self.blocking.iter() {
+            let blocked_inputs = self.map.get(blocking_action.clone());
+
+            for (index, set) in layered.map.iter_mut().enumerate() {
+                if index != blocking_action.index() {
+                    for input in blocked_inputs.iter() {
+                        set.remove(input);
+                    }
+                }
+            }
+        }
+        layered.forget_unbound_settings();
+
+        layered
+    }
+}
+
+// Layering
+impl<A: Actionlike> InputMap<A> {
+    /// Creates a new [`InputMap`] by layering the `overlay` on top of this map, according to the [`LayerMode`]
+    ///
+    /// Unlike [`merge`](Self::merge), this does not apply the [`BindingPolicy`];
+    /// bindings beyond [`MAX_BINDINGS_PER_ACTION`] are discarded.
+    /// The associated gamepad and window of the overlay are used if it has them,
+    /// falling back to those of this map.
+    #[must_use]
+    pub fn layered(&self, overlay: &InputMap<A>, mode: LayerMode) -> InputMap<A> {
+        let mut layered = self.clone();
+        layered.associated_gamepad = overlay.associated_gamepad.or(self.associated_gamepad);
+        layered.associated_window = overlay.associated_window.or(self.associated_window);
+
+        for (set, overlay_set) in layered.map.iter_mut().zip(overlay.map.iter()) {
+            match mode {
+                LayerMode::Shadow if !overlay_set.is_empty() => *set = overlay_set.clone(),
+                LayerMode::Shadow => (),
+                LayerMode::Extend => {
+                    for input in overlay_set.iter() {
+                        if !set.contains(input) && !set.is_full() {
+                            set.insert(input.clone());
+                        }
+                    }
+                }
+            }
+        }
+
+        // The overlay decides the triggers, modifier actions and chord exclusivity of its own bindings
+        for action in A::variants() {
+            for input in overlay.get(action.clone()).iter() {
+                let trigger = overlay.trigger(action.clone(), input);
+                layered.set_trigger(action.clone(), input.clone(), trigger);
+                let modifier = overlay.modifier_action(action.clone(), input);
+                layered.set_modifier_action(action.clone(), input.clone(), modifier);
+                let exclusivity = overlay.chord_exclusivity(action.clone(), input);
+                layered.set_chord_exclusivity(action.clone(), input.clone(), exclusivity);
+            }
+        }
+        layered.forget_unbound_settings();
+
+        layered
+    }
 }
 
 // Configuration
 impl<A: Actionlike> InputMap<A> {
     /// Fetches the [Gamepad] associated with the entity controlled by this entity map
     ///
-    /// If this is [`None`], input from any connected gamepad will be used.
+    /// If this is [`None`], the [`GamepadAssociation`] decides which gamepads are used.
     #[must_use]
     pub fn gamepad(&self) -> Option<Gamepad> {
         self.associated_gamepad
@@ -288,6 +909,7 @@ impl<A: Actionlike> InputMap<A> {
     /// If this is not called, input from any connected gamepad will be used.
     /// The first matching non-zero input will be accepted,
     /// as determined by gamepad registration order.
+    /// Use [`GamepadAssociation::Strict`] to ignore every gamepad instead.
     ///
     /// Because of this robust fallback behavior,
     /// this method can typically be ignored when writing single-player games.
@@ -301,71 +923,800 @@ impl<A: Actionlike> InputMap<A> {
         self.associated_gamepad = None;
         self
     }
-}
 
-// Check whether buttons are pressed
-impl<A: Actionlike> InputMap<A> {
-    /// Is at least one of the corresponding inputs for `action` found in the provided `input` streams?
-    ///
-    /// Accounts for clashing inputs according to the [`ClashStrategy`].
-    /// If you need to inspect many inputs at once, prefer [`InputMap::which_pressed`] instead.
+    /// Fetches which gamepads are read from while no [Gamepad] is associated with this input map
     #[must_use]
-    pub fn pressed(
-        &self,
-        action: A,
-        input_streams: &InputStreams,
-        clash_strategy: ClashStrategy,
-    ) -> bool {
-        let action_data = self.which_pressed(input_streams, clash_strategy);
-        action_data[action.index()].state.pressed()
+    pub fn gamepad_association(&self) -> GamepadAssociation {
+        self.gamepad_association
     }
 
-    /// Returns the actions that are currently pressed, and the responsible [`UserInput`] for each action
+    /// Sets which gamepads are read from while no [Gamepad] is associated with this input map
     ///
-    /// Accounts for clashing inputs according to the [`ClashStrategy`].
-    /// The position in each vector corresponds to `Actionlike::index()`.
+    /// This can be changed at any time, such as when a second player joins a local multiplayer game.
+    pub fn set_gamepad_association(
+        &mut self,
+        gamepad_association: GamepadAssociation,
+    ) -> &mut Self {
+        self.gamepad_association = gamepad_association;
+        self
+    }
+
+    /// Fetches the [`WindowId`] of the window that this input map is scoped to
+    ///
+    /// If this is [`None`], keyboard and mouse input from any window will be used,
+    /// and the cursor position is read from the primary window.
     #[must_use]
-    pub fn which_pressed(
-        &self,
-        input_streams: &InputStreams,
-        clash_strategy: ClashStrategy,
-    ) -> Vec<ActionData> {
-        let mut action_data = vec![ActionData::default(); A::n_variants()];
+    pub fn window(&self) -> Option<WindowId> {
+        self.associated_window
+    }
 
-        // Generate the raw action presses
-        for action in A::variants() {
-            let mut inputs = Vec::new();
+    /// Scopes this input map to a particular window, as is typically needed for multi-window editor tools
+    ///
+    /// Keyboard input will only be read while that window is focused,
+    /// and mouse input while the cursor is inside of it.
+    /// The cursor position is read from that window, rather than the primary window.
+    /// Gamepad input is unaffected.
+    pub fn set_window(&mut self, window: WindowId) -> &mut Self {
+        self.associated_window = Some(window);
+        self
+    }
 
-            for input in self.get(action.clone()).iter() {
-                let action = &mut action_data[action.index()];
+    /// Clears any window that this input map is scoped to
+    pub fn clear_window(&mut self) -> &mut Self {
+        self.associated_window = None;
+        self
+    }
 
-                // Merge axis pair into action data
-                let axis_pair = input_streams.input_axis_pair(input);
-                if let Some(axis_pair) = axis_pair {
-                    if let Some(current_axis_pair) = &mut action.axis_pair {
-                        *current_axis_pair = current_axis_pair.merged_with(axis_pair);
+    /// Only reads the inputs of the provided `devices`
+    ///
+    /// The inputs of every other device are treated as released, as with [`InhibitInput`](crate::inhibit_input::InhibitInput).
+    /// This is applied whenever this map is evaluated, so a keyboard player never picks up gamepad input,
+    /// even through a stray gamepad binding or a [`Wildcard::AnyInput`](crate::user_input::Wildcard::AnyInput).
+    /// Every device is read by default.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::inhibit_input::InputDevice;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Jump,
+    /// }
+    ///
+    /// let mut input_map = InputMap::new([
+    ///     (UserInput::from(KeyCode::Space), Action::Jump),
+    ///     (UserInput::from(GamepadButtonType::South), Action::Jump),
+    /// ]);
+    /// input_map.set_allowed_devices([InputDevice::Keyboard, InputDevice::Mouse]);
+    ///
+    /// assert!(!input_map.allows_device(InputDevice::Gamepad));
+    /// ```
+    pub fn set_allowed_devices(
+        &mut self,
+        devices: impl IntoIterator<Item = InputDevice>,
+    ) -> &mut Self {
+        self.allowed_devices = [false; InputDevice::ALL.len()];
+        for device in devices {
+            self.allowed_devices[device as usize] = true;
+        }
+        self
+    }
+
+    /// Are the inputs of the `device` read by this map?
+    ///
+    /// See [`InputMap::set_allowed_devices`].
+    #[must_use]
+    pub fn allows_device(&self, device: InputDevice) -> bool {
+        self.allowed_devices[device as usize]
+    }
+
+    /// The devices whose inputs are read by this map, in the order of [`InputDevice::ALL`]
+    #[must_use]
+    pub fn allowed_devices(&self) -> Vec<InputDevice> {
+        InputDevice::ALL
+            .into_iter()
+            .filter(|&device| self.allows_device(device))
+            .collect()
+    }
+
+    /// Fetches the [`BindingPolicy`] used when inserting new bindings
+    #[must_use]
+    pub fn binding_policy(&self) -> BindingPolicy {
+        self.policy
+    }
+
+    /// Sets the [`BindingPolicy`] used when inserting new bindings
+    ///
+    /// Existing bindings are not affected, even if they exceed the new capacity.
+    pub fn set_binding_policy(&mut self, policy: BindingPolicy) -> &mut Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Fetches the priority of the `action`, which decides which of several competing actions wins
+    ///
+    /// Every action has a priority of 0 by default.
+    #[must_use]
+    pub fn priority(&self, action: A) -> i32 {
+        self.priorities[action.index()]
+    }
+
+    /// Sets the priority of the `action`
+    ///
+    /// Actions with higher priorities win over those with lower priorities
+    /// when clashes are resolved with [`ClashStrategy::UseActionOrder`],
+    /// and in [`ExclusivePolicy::MapPriority`](crate::exclusive_actions::ExclusivePolicy::MapPriority) groups.
+    /// Actions with equal priorities fall back to the order in which they are defined.
+    pub fn set_priority(&mut self, action: A, priority: i32) -> &mut Self {
+        self.priorities[action.index()] = priority;
+        self
+    }
+
+    /// Every action, ordered from the highest to the lowest priority
+    ///
+    /// Actions with equal priorities are ordered by [`Actionlike::index`].
+    #[must_use]
+    pub fn priority_order(&self) -> Vec<A> {
+        let mut actions: Vec<A> = A::variants().collect();
+        // Sorting is stable, so ties keep their definition order
+        actions.sort_by_key(|action| std::cmp::Reverse(self.priority(action.clone())));
+        actions
+    }
+
+    /// Fetches the range that the value of the `action` is remapped onto, if any
+    #[must_use]
+    pub fn output_range(&self, action: A) -> Option<OutputRange> {
+        self.output_ranges[action.index()]
+    }
+
+    /// Remaps the value of the `action` onto the `range`
+    ///
+    /// This lets a throttle report values in `0..1` and a steering action in `-1..1`,
+    /// regardless of how their bindings are configured. See [`OutputRange`] for how values are remapped.
+    /// Only the [`ActionState::value`] is affected: axis pairs are reported unchanged.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::input::InputPlugin;
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::axislike::OutputRange;
+    /// use leafwing_input_manager::input_streams::InputStreams;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Accelerate,
+    /// }
+    ///
+    /// let mut input_map = InputMap::new([(KeyCode::W, Action::Accelerate)]);
+    /// input_map.set_output_range(Action::Accelerate, OutputRange::new(0.0, 60.0));
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugin(InputPlugin);
+    /// app.send_input(KeyCode::W);
+    /// app.update();
+    ///
+    /// let input_streams = InputStreams::from_world(&app.world, None);
+    /// let action_data = input_map.process(Action::Accelerate, &input_streams, ClashStrategy::PressAll);
+    /// assert_eq!(action_data.value, 60.0);
+    /// ```
+    pub fn set_output_range(&mut self, action: A, range: OutputRange) -> &mut Self {
+        self.output_ranges[action.index()] = Some(range);
+        self
+    }
+
+    /// Stops remapping the value of the `action`, so that it is reported as the sum of the values of its inputs
+    pub fn clear_output_range(&mut self, action: A) -> &mut Self {
+        self.output_ranges[action.index()] = None;
+        self
+    }
+
+    /// Fetches the value that the `action` must reach to be pressed, if any
+    #[must_use]
+    pub fn press_threshold(&self, action: A) -> Option<f32> {
+        self.press_thresholds[action.index()].map(|threshold| threshold.0)
+    }
+
+    /// Only presses the `action` while the magnitude of its value is at least `threshold`
+    ///
+    /// This adapts analog bindings to digital actions: a trigger or stick bound to a "Fire" action
+    /// only fires once it is pushed past the `threshold`, while buttons (which report a value of 1.0) are unaffected.
+    /// The threshold is compared against the summed value of the held bindings, before any [`OutputRange`] is applied.
+    /// Actions that fall short of the threshold are released, and do not take part in clashes.
+    ///
+    /// For the opposite direction, turning button presses into a gradual analog value,
+    /// see [`AxisRamp`](crate::axis_ramp::AxisRamp).
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::input::InputPlugin;
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::axislike::SingleAxis;
+    /// use leafwing_input_manager::input_streams::InputStreams;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Fire,
+    /// }
+    ///
+    /// let mut input_map = InputMap::new([
+    ///     (UserInput::from(SingleAxis::symmetric(GamepadAxisType::RightZ, 0.0)), Action::Fire),
+    ///     (UserInput::from(MouseButton::Left), Action::Fire),
+    /// ]);
+    /// input_map.set_press_threshold(Action::Fire, 0.5);
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugin(InputPlugin);
+    /// app.send_input(MouseButton::Left);
+    /// app.update();
+    ///
+    /// let input_streams = InputStreams::from_world(&app.world, None);
+    /// let action_data = input_map.process(Action::Fire, &input_streams, ClashStrategy::PressAll);
+    /// assert!(action_data.state.pressed());
+    /// ```
+    pub fn set_press_threshold(&mut self, action: A, threshold: f32) -> &mut Self {
+        self.press_thresholds[action.index()] = Some(FloatOrd(threshold));
+        self
+    }
+
+    /// Stops requiring the value of the `action` to reach a threshold, so that it is pressed whenever one of its bindings is
+    pub fn clear_press_threshold(&mut self, action: A) -> &mut Self {
+        self.press_thresholds[action.index()] = None;
+        self
+    }
+
+    /// Fetches the [`BindingTrigger`] of the binding between `input` and `action`
+    ///
+    /// Inputs that are not bound to the action report the default [`BindingTrigger::Press`].
+    #[must_use]
+    pub fn trigger(&self, action: A, input: &UserInput) -> BindingTrigger {
+        self.triggers[action.index()]
+            .iter()
+            .find(|(bound, _)| bound == input)
+            .map_or(BindingTrigger::Press, |(_, trigger)| *trigger)
+    }
+
+    /// Sets the [`BindingTrigger`] of the existing binding between `input` and `action`
+    ///
+    /// Bindings with [`BindingTrigger::Release`] press their action for a single update when the input is released,
+    /// instead of while it is held, and contribute a value of `1.0`.
+    /// Other bindings of the same action are unaffected, so an action can be bound to both edges of different inputs.
+    /// Release bindings never clash with other bindings, as they are not held when they trigger.
+    ///
+    /// Bindings with [`BindingTrigger::Hold`] only press their action once they have been held for the required duration,
+    /// as reported by [`ActionState::hold_progress`].
+    /// Bindings with [`BindingTrigger::Turbo`] press their action in repeated pulses while held.
+    /// Held [`BindingTrigger::Press`] and [`BindingTrigger::Release`] bindings take precedence over both,
+    /// pressing the action as usual, and turbo bindings take precedence over hold bindings.
+    ///
+    /// Does nothing if the `input` is not bound to the `action`.
+    /// Removing the binding also forgets its trigger.
+    pub fn set_trigger(
+        &mut self,
+        action: A,
+        input: impl Into<UserInput>,
+        trigger: BindingTrigger,
+    ) -> &mut Self {
+        let input = input.into();
+        let triggers = &mut self.triggers[action.index()];
+        triggers.retain(|(bound, _)| *bound != input);
+        if trigger != BindingTrigger::Press && self.map[action.index()].contains(&input) {
+            triggers.push((input, trigger));
+        }
+        self
+    }
+
+    /// Fetches the action that must be active for the binding between `input` and `action` to press it
+    ///
+    /// Returns [`None`] if the binding does not require a modifier action, or if the `input` is not bound to the `action`.
+    #[must_use]
+    pub fn modifier_action(&self, action: A, input: &UserInput) -> Option<A> {
+        self.modifier_actions[action.index()]
+            .iter()
+            .find(|(bound, _)| bound == input)
+            .map(|(_, modifier)| modifier.clone())
+    }
+
+    /// Sets the action that must be active for the existing binding between `input` and `action` to press it
+    ///
+    /// This is like a chord with [`InputMap::insert_modified`], except that the modifier is an action rather than a key:
+    /// rebinding the `modifier` changes every binding that refers to it.
+    /// The `modifier` is active while any of its own bindings are held, ignoring their [`BindingTrigger`]s.
+    /// Bindings of the `modifier` that themselves require a modifier action do not count,
+    /// so modifier actions cannot be chained.
+    ///
+    /// Unless the [`ClashStrategy`] is [`ClashStrategy::PressAll`],
+    /// an input that presses an action through a binding with an active modifier action
+    /// no longer presses other actions through bindings without one.
+    /// This way, binding `Fire` and `AltFire` to the same button, with `AltFire` requiring `Aim`,
+    /// fires one or the other, but never both.
+    ///
+    /// Passing [`None`] removes the requirement.
+    /// Does nothing if the `input` is not bound to the `action`.
+    /// Removing the binding also forgets its modifier action.
+    pub fn set_modifier_action(
+        &mut self,
+        action: A,
+        input: impl Into<UserInput>,
+        modifier: Option<A>,
+    ) -> &mut Self {
+        let input = input.into();
+        let modifiers = &mut self.modifier_actions[action.index()];
+        modifiers.retain(|(bound, _)| *bound != input);
+        if let Some(modifier) = modifier {
+            if self.map[action.index()].contains(&input) {
+                modifiers.push((input, modifier));
+            }
+        }
+        self
+    }
+
+    /// Fetches the [`ChordExclusivity`] of the binding between the `chord` and `action`
+    ///
+    /// Inputs that are not chords bound to the action report the default [`ChordExclusivity::FollowStrategy`].
+    #[must_use]
+    pub fn chord_exclusivity(&self, action: A, chord: &UserInput) -> ChordExclusivity {
+        self.chord_exclusivity[action.index()]
+            .iter()
+            .find(|(bound, _)| bound == chord)
+            .map_or(ChordExclusivity::FollowStrategy, |(_, exclusivity)| {
+                *exclusivity
+            })
+    }
+
+    /// Sets whether the members of the existing `chord` binding of the `action` may still press their own actions while the chord is held
+    ///
+    /// By default, this is decided by the [`ClashStrategy`] for every chord at once.
+    /// With [`ChordExclusivity::Passthrough`], this chord never suppresses the actions of its members:
+    /// binding `Select` to `Shift + Click` and `Sprint` to `Shift` lets the player sprint while selecting.
+    /// With [`ChordExclusivity::Exclusive`], this chord always suppresses the actions of its members while it is held,
+    /// even under [`ClashStrategy::PressAll`].
+    ///
+    /// Does nothing if the `chord` is not a [`UserInput::Chord`] bound to the `action`.
+    /// Removing the binding also forgets its exclusivity.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::input::InputPlugin;
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::clashing_inputs::ChordExclusivity;
+    /// use leafwing_input_manager::input_streams::InputStreams;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Sprint,
+    ///     Select,
+    /// }
+    ///
+    /// let select = UserInput::modified(Modifier::Shift, MouseButton::Left);
+    /// let mut input_map = InputMap::new([(Modifier::Shift, Action::Sprint)]);
+    /// input_map.insert(select.clone(), Action::Select);
+    /// input_map.set_chord_exclusivity(Action::Select, select.clone(), ChordExclusivity::Passthrough);
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugin(InputPlugin);
+    /// app.send_input(select);
+    /// app.update();
+    ///
+    /// let input_streams = InputStreams::from_world(&app.world, None);
+    /// let pressed = input_map.evaluate(&input_streams, ClashStrategy::PrioritizeLongest);
+    /// assert_eq!(pressed.iter().collect::<Vec<_>>(), [Action::Sprint, Action::Select]);
+    /// ```
+    pub fn set_chord_exclusivity(
+        &mut self,
+        action: A,
+        chord: impl Into<UserInput>,
+        exclusivity: ChordExclusivity,
+    ) -> &mut Self {
+        let chord = chord.into();
+        let chords = &mut self.chord_exclusivity[action.index()];
+        chords.retain(|(bound, _)| *bound != chord);
+        if exclusivity != ChordExclusivity::FollowStrategy
+            && matches!(chord, UserInput::Chord(_))
+            && self.map[action.index()].contains(&chord)
+        {
+            chords.push((chord, exclusivity));
+        }
+        self
+    }
+
+    /// Drops the triggers, modifier actions and chord exclusivity of inputs that are no longer bound to their action
+    fn forget_unbound_settings(&mut self) {
+        for (bindings, triggers) in self.map.iter().zip(self.triggers.iter_mut()) {
+            triggers.retain(|(input, _)| bindings.contains(input));
+        }
+        for (bindings, modifiers) in self.map.iter().zip(self.modifier_actions.iter_mut()) {
+            modifiers.retain(|(input, _)| bindings.contains(input));
+        }
+        for (bindings, chords) in self.map.iter().zip(self.chord_exclusivity.iter_mut()) {
+            chords.retain(|(input, _)| bindings.contains(input));
+        }
+    }
+
+    /// Applies the `calibration` to every binding that reads the gamepad `axis_type`
+    ///
+    /// The calibration is stored in the offset and thresholds of the affected [`SingleAxis`](crate::axislike::SingleAxis)s,
+    /// and so is saved and loaded along with the rest of this map.
+    /// See [`AxisCalibration::apply`] for how each axis is changed.
+    /// See [`AxisCalibrator`](crate::calibration::AxisCalibrator) for how to measure a calibration.
+    ///
+    /// Unlike the methods that remove bindings, this also affects [fixed](crate::ActionMetadata::fixed) actions,
+    /// as calibration compensates for hardware rather than changing which inputs are bound.
+    pub fn calibrate(
+        &mut self,
+        axis_type: GamepadAxisType,
+        calibration: AxisCalibration,
+    ) -> &mut Self {
+        for inputs in self.map.iter_mut() {
+            *inputs = inputs
+                .iter()
+                .map(|input| calibration.apply_to_input(axis_type, input))
+                .collect();
+        }
+        self
+    }
+
+    /// Remaps the values of every binding that reads the `axis_type` from the `range` onto the full range of the axis
+    ///
+    /// The range is stored in the affected [`SingleAxis`](crate::axislike::SingleAxis)s,
+    /// and so is saved and loaded along with the rest of this map.
+    /// See [`AxisRangeLearner`](crate::calibration::AxisRangeLearner) for how to measure the range of a controller.
+    ///
+    /// Like [`calibrate`](Self::calibrate), this also affects [fixed](crate::ActionMetadata::fixed) actions.
+    pub fn set_axis_range(
+        &mut self,
+        axis_type: impl Into<AxisType>,
+        range: AxisRange,
+    ) -> &mut Self {
+        let axis_type = axis_type.into();
+        for inputs in self.map.iter_mut() {
+            *inputs = inputs
+                .iter()
+                .map(|input| {
+                    map_single_axes(input, |axis| {
+                        if axis.axis_type == axis_type {
+                            axis.with_range(range)
+                        } else {
+                            axis
+                        }
+                    })
+                })
+                .collect();
+        }
+        self
+    }
+}
+
+// Check whether buttons are pressed
+impl<A: Actionlike> InputMap<A> {
+    /// Is at least one of the corresponding inputs for `action` found in the provided `input` streams?
+    ///
+    /// Accounts for clashing inputs according to the [`ClashStrategy`].
+    /// If you need to inspect many inputs at once, prefer [`InputMap::which_pressed`] instead.
+    #[must_use]
+    pub fn pressed(
+        &self,
+        action: A,
+        input_streams: &InputStreams,
+        clash_strategy: ClashStrategy,
+    ) -> bool {
+        let action_data = self.which_pressed(input_streams, clash_strategy);
+        action_data[action.index()].state.pressed()
+    }
+
+    /// The set of actions whose bindings are pressed in the `raw_state`, after clashes are resolved with the `clash_strategy`
+    ///
+    /// This is a pure function of the bindings and the raw input state: no [`ActionState`] is involved,
+    /// so there is no timing, consumption or history to account for.
+    /// The associated gamepad and window of this map are respected.
+    /// This makes it well suited to property-based tests of your bindings, such as checking that a set of keys
+    /// never triggers two conflicting actions at once.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::input::InputPlugin;
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::input_streams::InputStreams;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Copy,
+    ///     Cut,
+    ///     Cancel,
+    /// }
+    ///
+    /// let input_map = InputMap::new([
+    ///     (UserInput::chord([KeyCode::LControl, KeyCode::C]), Action::Copy),
+    ///     (UserInput::chord([KeyCode::LControl, KeyCode::X]), Action::Cut),
+    ///     (UserInput::from(KeyCode::C), Action::Cancel),
+    /// ]);
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugin(InputPlugin);
+    /// app.send_input(UserInput::chord([KeyCode::LControl, KeyCode::C]));
+    /// app.update();
+    ///
+    /// let raw_state = InputStreams::from_world(&app.world, None);
+    /// let pressed = input_map.evaluate(&raw_state, ClashStrategy::PrioritizeLongest);
+    /// assert_eq!(pressed.iter().collect::<Vec<_>>(), [Action::Copy]);
+    /// ```
+    #[must_use]
+    pub fn evaluate(
+        &self,
+        raw_state: &InputStreams,
+        clash_strategy: ClashStrategy,
+    ) -> ActionSet<A> {
+        self.process_data(raw_state, clash_strategy)
+            .iter()
+            .zip(A::variants())
+            .filter(|(action_data, _)| action_data.state.pressed())
+            .map(|(_, action)| action)
+            .collect()
+    }
+
+    /// Returns the actions that are currently pressed, and the responsible [`UserInput`] for each action
+    ///
+    /// Accounts for clashing inputs according to the [`ClashStrategy`],
+    /// and ignores the inputs of devices that are not [allowed](InputMap::set_allowed_devices).
+    /// The position in each vector corresponds to `Actionlike::index()`.
+    #[must_use]
+    pub fn which_pressed(
+        &self,
+        input_streams: &InputStreams,
+        clash_strategy: ClashStrategy,
+    ) -> Vec<ActionData> {
+        let released = ReleasedStreams::default();
+        let filtered;
+        let input_streams = if self.allowed_devices == [true; InputDevice::ALL.len()] {
+            input_streams
+        } else {
+            filtered = input_streams.filter_devices(|device| self.allows_device(device), &released);
+            &filtered
+        };
+
+        let mut action_data = vec![ActionData::default(); A::N_VARIANTS];
+        let active_modifiers = self.active_modifier_actions(input_streams);
+        let shadowed_inputs = match clash_strategy {
+            ClashStrategy::PressAll => Vec::new(),
+            _ => self.modified_inputs_pressed(&active_modifiers, input_streams),
+        };
+
+        // Generate the raw action presses
+        for action in A::variants() {
+            let mut inputs = Vec::new();
+            let mut all_axes_relative = None;
+            // The shortest hold and turbo period of the held bindings, unless one of them presses the action immediately
+            let mut hold_requirement = None;
+            let mut turbo_period = None;
+            let mut immediate = false;
+
+            for input in self.get(action.clone()).iter() {
+                match self.modifier_action(action.clone(), input) {
+                    Some(modifier) if !active_modifiers[modifier.index()] => continue,
+                    None if shadowed_inputs.contains(&input) => continue,
+                    _ => (),
+                }
+
+                let trigger = self.trigger(action.clone(), input);
+                let action = &mut action_data[action.index()];
+
+                // Merge axis pair into action data
+                let axis_pair = input_streams.input_axis_pair(input);
+                if let Some(axis_pair) = axis_pair {
+                    if let Some(current_axis_pair) = &mut action.axis_pair {
+                        *current_axis_pair = current_axis_pair.merged_with(axis_pair);
                     } else {
                         action.axis_pair = Some(axis_pair);
                     }
+
+                    let relative = input.axis_semantics() == AxisSemantics::Relative;
+                    all_axes_relative = Some(all_axes_relative.unwrap_or(true) && relative);
                 }
 
-                if input_streams.input_pressed(input) {
+                let on_release = trigger == BindingTrigger::Release;
+                let triggered = if on_release {
+                    input_streams.input_just_released(input)
+                } else {
+                    input_streams.input_pressed(input)
+                };
+
+                if triggered {
                     inputs.push(input.clone());
 
-                    action.value += input_streams.input_value(input);
+                    let shortest = |current: Option<Duration>, duration: Duration| {
+                        Some(current.map_or(duration, |current| current.min(duration)))
+                    };
+                    match trigger {
+                        BindingTrigger::Hold(duration) => {
+                            hold_requirement = shortest(hold_requirement, duration);
+                        }
+                        BindingTrigger::Turbo(period) => {
+                            turbo_period = shortest(turbo_period, period);
+                        }
+                        BindingTrigger::Press | BindingTrigger::Release => immediate = true,
+                    }
+
+                    if action.triggering_input.is_none() {
+                        action.triggering_input = Some(input_streams.resolve_wildcards(input));
+                    }
+
+                    for source in input_streams.input_sources(input) {
+                        if !action.sources.contains(&source) {
+                            action.sources.push(source);
+                        }
+                    }
+
+                    // Released inputs no longer report a value of their own
+                    action.value += if on_release {
+                        1.0
+                    } else {
+                        input_streams.input_value(input)
+                    };
                 }
             }
 
             if !inputs.is_empty() {
                 action_data[action.index()].state = ButtonState::JustPressed;
             }
+
+            if !immediate {
+                action_data[action.index()].turbo_period = turbo_period;
+                action_data[action.index()].hold_requirement =
+                    hold_requirement.filter(|_| turbo_period.is_none());
+            }
+
+            if all_axes_relative == Some(true) {
+                action_data[action.index()].axis_semantics = AxisSemantics::Relative;
+            }
+        }
+
+        // Release actions whose analog value falls short of their press threshold
+        for (action_data, threshold) in action_data.iter_mut().zip(&self.press_thresholds) {
+            if let Some(FloatOrd(threshold)) = threshold {
+                if action_data.state.pressed() && action_data.value.abs() < *threshold {
+                    *action_data = ActionData {
+                        axis_pair: action_data.axis_pair,
+                        axis_semantics: action_data.axis_semantics,
+                        ..Default::default()
+                    };
+                }
+            }
         }
 
         // Handle clashing inputs, possibly removing some pressed actions from the list
         self.handle_clashes(&mut action_data, input_streams, clash_strategy);
 
+        for (action_data, range) in action_data.iter_mut().zip(&self.output_ranges) {
+            if let Some(range) = range {
+                action_data.value = range.remap(action_data.value);
+            }
+        }
+
         action_data
     }
+
+    /// Which actions are active as modifier actions, indexed by the `Actionlike::id` of `A`
+    ///
+    /// See [`InputMap::set_modifier_action`] for when an action is active.
+    fn active_modifier_actions(&self, input_streams: &InputStreams) -> Vec<bool> {
+        let mut active = vec![false; A::N_VARIANTS];
+        for modifier in self
+            .modifier_actions
+            .iter()
+            .flatten()
+            .map(|(_, modifier)| modifier)
+        {
+            if !active[modifier.index()] {
+                active[modifier.index()] = self.get(modifier.clone()).iter().any(|input| {
+                    self.modifier_action(modifier.clone(), input).is_none()
+                        && input_streams.input_pressed(input)
+                });
+            }
+        }
+        active
+    }
+
+    /// The pressed inputs of bindings whose modifier action is active
+    fn modified_inputs_pressed<'a>(
+        &'a self,
+        active_modifiers: &[bool],
+        input_streams: &InputStreams,
+    ) -> Vec<&'a UserInput> {
+        self.modifier_actions
+            .iter()
+            .flatten()
+            .filter(|(input, modifier)| {
+                active_modifiers[modifier.index()] && input_streams.input_pressed(input)
+            })
+            .map(|(input, _)| input)
+            .collect()
+    }
+
+    /// Evaluates the bindings of `action` against the provided `input_streams` on demand
+    ///
+    /// Unlike the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin), this does not require any entities or systems,
+    /// making it suitable for tools, editors and other non-ECS code.
+    /// If this map has an [associated gamepad](InputMap::gamepad) or [window](InputMap::window),
+    /// they take precedence over those of the `input_streams`.
+    ///
+    /// Accounts for clashing inputs according to the [`ClashStrategy`].
+    /// If you need to evaluate every action at once, prefer [`InputMap::process_all`] instead.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::input::InputPlugin;
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::input_streams::InputStreams;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Jump,
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugin(InputPlugin);
+    /// app.send_input(KeyCode::Space);
+    /// app.update();
+    ///
+    /// let input_map = InputMap::new([(KeyCode::Space, Action::Jump)]);
+    /// let input_streams = InputStreams::from_world(&app.world, None);
+    /// let action_data = input_map.process(Action::Jump, &input_streams, ClashStrategy::PressAll);
+    ///
+    /// assert!(action_data.state.pressed());
+    /// assert_eq!(action_data.value, 1.0);
+    /// ```
+    #[must_use]
+    pub fn process(
+        &self,
+        action: A,
+        input_streams: &InputStreams,
+        clash_strategy: ClashStrategy,
+    ) -> ActionData {
+        let mut action_data = self.process_data(input_streams, clash_strategy);
+        action_data.swap_remove(action.index())
+    }
+
+    /// Evaluates the bindings of every action against the provided `input_streams` on demand, producing a new [`ActionState`]
+    ///
+    /// The returned [`ActionState`] has never been ticked,
+    /// so every pressed action is just pressed and no timing information is available.
+    /// To track presses over time, call [`ActionState::update`] with [`InputMap::which_pressed`] instead.
+    ///
+    /// See [`InputMap::process`] for more details.
+    #[must_use]
+    pub fn process_all(
+        &self,
+        input_streams: &InputStreams,
+        clash_strategy: ClashStrategy,
+    ) -> ActionState<A> {
+        let mut action_state = ActionState::default();
+        action_state.update(self.process_data(input_streams, clash_strategy));
+        action_state
+    }
+
+    /// Calls [`InputMap::which_pressed`], preferring the associated gamepad and window of this map over those of the `input_streams`
+    pub(crate) fn process_data(
+        &self,
+        input_streams: &InputStreams,
+        clash_strategy: ClashStrategy,
+    ) -> Vec<ActionData> {
+        let mut input_streams = input_streams.clone();
+        if let Some(gamepad) = self.gamepad() {
+            input_streams.associated_gamepad = Some(gamepad);
+        }
+        input_streams.gamepad_association = self.gamepad_association;
+        if let Some(window) = self.window() {
+            input_streams.associated_window = Some(window);
+        }
+
+        self.which_pressed(&input_streams, clash_strategy)
+    }
 }
 
 // Utilities
@@ -405,28 +1756,478 @@ impl<A: Actionlike> InputMap<A> {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// The actions that have no input bindings, and so cannot be triggered by the player
+    #[must_use]
+    pub fn unbound_actions(&self) -> Vec<A> {
+        A::variants()
+            .filter(|action| self.get(action.clone()).is_empty())
+            .collect()
+    }
+
+    /// The bindings of this map that are read from the given [`InputDevice`], as reported by [`UserInput::device`]
+    ///
+    /// Inputs that match every device, such as [`Wildcard::AnyInput`](crate::user_input::Wildcard::AnyInput),
+    /// belong to every device.
+    /// The associated gamepad, associated window, [`BindingPolicy`], action priorities, output ranges and press thresholds are preserved.
+    #[must_use]
+    pub fn device_bindings(&self, device: InputDevice) -> InputMap<A> {
+        let mut bindings = InputMap {
+            map: self
+                .map
+                .iter()
+                .map(|inputs| {
+                    inputs
+                        .iter()
+                        .filter(|input| input.device().unwrap_or(device) == device)
+                        .cloned()
+                        .collect()
+                })
+                .collect(),
+            associated_gamepad: self.associated_gamepad,
+            gamepad_association: self.gamepad_association,
+            associated_window: self.associated_window,
+            policy: self.policy,
+            priorities: self.priorities.clone(),
+            output_ranges: self.output_ranges.clone(),
+            press_thresholds: self.press_thresholds.clone(),
+            triggers: self.triggers.clone(),
+            modifier_actions: self.modifier_actions.clone(),
+            chord_exclusivity: self.chord_exclusivity.clone(),
+            allowed_devices: self.allowed_devices,
+            marker: PhantomData,
+        };
+        bindings.forget_unbound_settings();
+        bindings
+    }
+
+    /// Replaces all bindings with those of `bindings`
+    ///
+    /// The associated gamepad, associated window, [`BindingPolicy`], action priorities, output ranges and press thresholds of this map are preserved.
+    pub fn replace_bindings(&mut self, bindings: InputMap<A>) -> &mut Self {
+        self.map = bindings.map;
+        self.triggers = bindings.triggers;
+        self.modifier_actions = bindings.modifier_actions;
+        self.chord_exclusivity = bindings.chord_exclusivity;
+        self
+    }
+}
+
+// Removing
+//
+// Actions marked as fixed via `#[actionlike(fixed)]` are never modified by these methods.
+// To change their bindings, construct a new `InputMap` instead.
+impl<A: Actionlike> InputMap<A> {
+    /// Clears all inputs registered for the `action`
+    ///
+    /// Does nothing if the action is [fixed](crate::ActionMetadata::fixed).
+    pub fn clear_action(&mut self, action: A) {
+        if action.metadata().fixed {
+            return;
+        }
+
+        self.map[action.index()].clear();
+        self.triggers[action.index()].clear();
+        self.modifier_actions[action.index()].clear();
+        self.chord_exclusivity[action.index()].clear();
+    }
+
+    /// Removes the input for the `action` at the provided index
+    ///
+    /// Returns `true` if an element was found.
+    /// Fixed actions are left unchanged, and `false` is returned.
+    pub fn remove_at(&mut self, action: A, index: usize) -> bool {
+        if action.metadata().fixed {
+            return false;
+        }
+
+        let removed = self.map[action.index()].remove_at(index);
+        self.forget_unbound_settings();
+        removed
+    }
+
+    /// Removes the input for the `action`, if it exists
+    ///
+    /// Returns [`Some`] with index if the input was found, or [`None`] if no matching input was found.
+    /// Fixed actions are left unchanged, and [`None`] is returned.
+    pub fn remove(&mut self, action: A, input: impl Into<UserInput>) -> Option<usize> {
+        if action.metadata().fixed {
+            return None;
+        }
+
+        let input = input.into();
+        self.triggers[action.index()].retain(|(bound, _)| *bound != input);
+        self.modifier_actions[action.index()].retain(|(bound, _)| *bound != input);
+        self.chord_exclusivity[action.index()].retain(|(bound, _)| *bound != input);
+        self.map[action.index()].remove(&input)
+    }
+}
+
+/// The bindings of a single action that differ between two [`InputMap`]s
+///
+/// Returned as part of an [`InputMapDiff`] by [`InputMap::diff`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BindingDiff<A: Actionlike> {
+    /// The action whose bindings differ
+    pub action: A,
+    /// The inputs that are bound to the action, but were not bound in the base map
+    pub added: Vec<UserInput>,
+    /// The inputs that were bound to the action in the base map, but are no longer bound
+    pub removed: Vec<UserInput>,
+}
+
+/// The differences between the bindings of an [`InputMap`] and those of a base map, such as the defaults
+///
+/// Settings screens can use this to mark modified actions, and persistence layers can store only the deviations from the defaults,
+/// restoring the full map later with [`InputMap::apply_diff`].
+/// Only bindings are compared: the associated gamepad, window and [`BindingPolicy`] are ignored.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputMapDiff<A: Actionlike> {
+    /// The actions whose bindings differ, ordered by [`Actionlike::index`]
+    pub actions: Vec<BindingDiff<A>>,
+}
+
+impl<A: Actionlike> Default for InputMapDiff<A> {
+    fn default() -> Self {
+        Self {
+            actions: Vec::new(),
+        }
+    }
+}
+
+impl<A: Actionlike> InputMapDiff<A> {
+    /// Are the bindings of both maps identical?
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    /// The differences in the bindings of the `action`, if there are any
+    #[must_use]
+    pub fn get(&self, action: A) -> Option<&BindingDiff<A>> {
+        self.actions
+            .iter()
+            .find(|binding_diff| binding_diff.action.index() == action.index())
+    }
+
+    /// Do the bindings of the `action` differ?
+    #[must_use]
+    pub fn is_modified(&self, action: A) -> bool {
+        self.get(action).is_some()
+    }
+}
+
+// Diffing
+impl<A: Actionlike> InputMap<A> {
+    /// Compares the bindings of this map to those of the `base` map, such as the defaults
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::input::keyboard::KeyCode;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Jump,
+    ///     Run,
+    /// }
+    ///
+    /// let defaults = InputMap::new([(KeyCode::Space, Action::Jump), (KeyCode::LShift, Action::Run)]);
+    /// let mut input_map = defaults.clone();
+    /// input_map.clear_action(Action::Jump);
+    /// input_map.insert(KeyCode::W, Action::Jump);
+    ///
+    /// let diff = input_map.diff(&defaults);
+    /// assert!(diff.is_modified(Action::Jump));
+    /// assert!(!diff.is_modified(Action::Run));
+    ///
+    /// let mut restored = defaults.clone();
+    /// assert!(restored.apply_diff(&diff).is_empty());
+    /// assert_eq!(restored, input_map);
+    /// ```
+    #[must_use]
+    pub fn diff(&self, base: &InputMap<A>) -> InputMapDiff<A> {
+        let actions = A::variants()
+            .filter_map(|action| {
+                let inputs = self.get(action.clone());
+                let base_inputs = base.get(action.clone());
+
+                let added: Vec<UserInput> = inputs
+                    .iter()
+                    .filter(|input| !base_inputs.contains(input))
+                    .cloned()
+                    .collect();
+                let removed: Vec<UserInput> = base_inputs
+                    .iter()
+                    .filter(|input| !inputs.contains(input))
+                    .cloned()
+                    .collect();
+
+                (!added.is_empty() || !removed.is_empty()).then_some(BindingDiff {
+                    action,
+                    added,
+                    removed,
+                })
+            })
+            .collect();
+
+        InputMapDiff { actions }
+    }
+
+    /// Removes and adds the bindings listed in the `diff`, as produced by [`InputMap::diff`]
+    ///
+    /// Applying the diff of a map to its base map restores the bindings of the original map.
+    /// Added bindings are inserted according to the [`BindingPolicy`] of this map.
+    ///
+    /// Bindings of [fixed](crate::ActionMetadata::fixed) actions are left unchanged.
+    /// Every change that could not be applied is returned along with the reason,
+    /// so an empty list means the diff was applied in full.
+    pub fn apply_diff(&mut self, diff: &InputMapDiff<A>) -> Vec<(UserInput, A, BindingError)> {
+        let mut failures = Vec::new();
+        for binding_diff in &diff.actions {
+            let action = binding_diff.action.clone();
+            if action.metadata().fixed {
+                let changes = binding_diff.removed.iter().chain(&binding_diff.added);
+                failures.extend(
+                    changes.map(|input| (input.clone(), action.clone(), BindingError::FixedAction)),
+                );
+                continue;
+            }
+
+            for input in &binding_diff.removed {
+                self.remove(action.clone(), input.clone());
+            }
+            for input in &binding_diff.added {
+                if let Err(error) = self.try_insert(input.clone(), action.clone()) {
+                    failures.push((input.clone(), action.clone(), error));
+                }
+            }
+        }
+
+        failures
+    }
+}
+
+/// A problem with the bindings of an [`InputMap`], found by [`InputMap::validate`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BindingIssue<A: Actionlike> {
+    /// The action has no bindings, and so can never be triggered by the player
+    Unbound {
+        /// The unbound action
+        action: A,
+    },
+    /// A chord of the action requires inputs that can never be pressed at the same time,
+    /// such as scrolling the mouse wheel both up and down
+    ImpossibleChord {
+        /// The action that the chord is bound to
+        action: A,
+        /// The impossible chord
+        input: UserInput,
+    },
+    /// A binding of the action requires a gamepad axis or analog button to move past the range of values it reports,
+    /// and so can never be triggered
+    ///
+    /// Gamepad axes report values from -1.0 to 1.0, while analog buttons report values from 0.0 to 1.0.
+    UnreachableAxis {
+        /// The action that the input is bound to
+        action: A,
+        /// The input whose thresholds can never be crossed
+        input: UserInput,
+    },
+    /// A chord of the action requires three or more keys other than modifiers to be held at once,
+    /// which many keyboards cannot report reliably
+    ///
+    /// Keyboards without "n-key rollover" share wiring between keys,
+    /// and drop or invent key presses when too many keys on a shared line are held: this is known as ghosting.
+    /// Modifier keys are wired separately on almost every keyboard, and so are not counted.
+    /// Only reported by [`InputMap::ghosting_risks`], as the chord works on many keyboards.
+    GhostingRisk {
+        /// The action that the chord is bound to
+        action: A,
+        /// The chord that may not be pressable
+        input: UserInput,
+    },
+}
+
+// Validation
+impl<A: Actionlike> InputMap<A> {
+    /// Checks the bindings of this map for problems that would prevent the player from triggering actions
+    ///
+    /// Issues are ordered by action. An empty list means that no problems were found.
+    /// This is cheap enough to run in a `debug_assert!` on startup, or after every change in a settings menu.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::input_map::BindingIssue;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Jump,
+    ///     Zoom,
+    /// }
+    ///
+    /// let mut input_map = InputMap::new([(KeyCode::Space, Action::Jump)]);
+    /// assert_eq!(
+    ///     input_map.validate(),
+    ///     [BindingIssue::Unbound { action: Action::Zoom }]
+    /// );
+    ///
+    /// input_map.insert_chord([MouseWheelDirection::Up, MouseWheelDirection::Down], Action::Zoom);
+    /// assert!(matches!(
+    ///     input_map.validate()[..],
+    ///     [BindingIssue::ImpossibleChord { action: Action::Zoom, .. }]
+    /// ));
+    /// ```
+    #[must_use]
+    pub fn validate(&self) -> Vec<BindingIssue<A>> {
+        let mut issues = Vec::new();
+
+        for (inputs, action) in self.iter() {
+            if inputs.is_empty() {
+                issues.push(BindingIssue::Unbound { action });
+                continue;
+            }
+
+            for input in inputs.iter() {
+                if is_impossible_chord(input) {
+                    issues.push(BindingIssue::ImpossibleChord {
+                        action: action.clone(),
+                        input: input.clone(),
+                    });
+                }
+
+                if input_kinds(input).into_iter().any(is_unreachable_axis) {
+                    issues.push(BindingIssue::UnreachableAxis {
+                        action: action.clone(),
+                        input: input.clone(),
+                    });
+                }
+            }
+        }
+
+        issues
+    }
+
+    /// Checks the chords of this map for combinations of keys that many keyboards cannot report, due to ghosting
+    ///
+    /// Returns a [`BindingIssue::GhostingRisk`] for each chord that requires three or more keys other than modifiers,
+    /// ordered by action.
+    /// Use this to pick safer defaults, or to warn players who rebind their controls.
+    /// Unlike the issues found by [`InputMap::validate`], these chords work on keyboards with n-key rollover.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::input_map::BindingIssue;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Sprint,
+    ///     QuickSave,
+    /// }
+    ///
+    /// let mut input_map = InputMap::default();
+    /// input_map.insert_chord([KeyCode::W, KeyCode::A, KeyCode::Space], Action::Sprint);
+    /// // Modifiers are wired separately, so this chord is safe
+    /// input_map.insert_chord([KeyCode::LControl, KeyCode::LShift, KeyCode::S], Action::QuickSave);
+    ///
+    /// assert!(matches!(
+    ///     input_map.ghosting_risks()[..],
+    ///     [BindingIssue::GhostingRisk { action: Action::Sprint, .. }]
+    /// ));
+    /// ```
+    #[must_use]
+    pub fn ghosting_risks(&self) -> Vec<BindingIssue<A>> {
+        self.iter()
+            .flat_map(|(inputs, action)| {
+                inputs
+                    .iter()
+                    .filter(|input| is_ghosting_risk(input))
+                    .map(move |input| BindingIssue::GhostingRisk {
+                        action: action.clone(),
+                        input: input.clone(),
+                    })
+            })
+            .collect()
+    }
 }
 
-// Removing
-impl<A: Actionlike> InputMap<A> {
-    /// Clears all inputs registered for the `action`
-    pub fn clear_action(&mut self, action: A) {
-        self.map[action.index()].clear();
-    }
+/// The number of keys other than modifiers that a chord may require before it risks ghosting
+const GHOSTING_FREE_KEYS: usize = 2;
+
+/// Does the chord require more keys other than modifiers than typical keyboards can report at once?
+fn is_ghosting_risk(input: &UserInput) -> bool {
+    let UserInput::Chord(input_kinds) = input else {
+        return false;
+    };
+
+    let modifier_keys: Vec<KeyCode> = [
+        Modifier::Alt,
+        Modifier::Control,
+        Modifier::Shift,
+        Modifier::Win,
+    ]
+    .into_iter()
+    .flat_map(Modifier::key_codes)
+    .collect();
+
+    let keys = input_kinds
+        .iter()
+        .filter(|input_kind| match input_kind {
+            InputKind::Keyboard(key) => !modifier_keys.contains(key),
+            _ => false,
+        })
+        .count();
+    keys > GHOSTING_FREE_KEYS
+}
 
-    /// Removes the input for the `action` at the provided index
-    ///
-    /// Returns `true` if an element was found.
-    pub fn remove_at(&mut self, action: A, index: usize) -> bool {
-        self.map[action.index()].remove_at(index)
+/// Every [`InputKind`] that makes up the `input`
+pub(crate) fn input_kinds(input: &UserInput) -> Vec<&InputKind> {
+    match input {
+        UserInput::Single(input_kind) => vec![input_kind],
+        UserInput::Chord(input_kinds) => input_kinds.iter().collect(),
+        UserInput::VirtualDPad(dpad) => vec![&dpad.up, &dpad.down, &dpad.left, &dpad.right],
+        UserInput::VirtualAxis(axis) => vec![&axis.negative, &axis.positive],
     }
+}
 
-    /// Removes the input for the `action`, if it exists
-    ///
-    /// Returns [`Some`] with index if the input was found, or [`None`] if no matching input was found.
-    pub fn remove(&mut self, action: A, input: impl Into<UserInput>) -> Option<usize> {
-        self.map[action.index()].remove(&input.into())
-    }
+/// Does the chord require two opposite directions of the same axis to be pressed at once?
+fn is_impossible_chord(input: &UserInput) -> bool {
+    let UserInput::Chord(input_kinds) = input else {
+        return false;
+    };
+
+    input_kinds.iter().any(|a| {
+        input_kinds.iter().any(|b| match (a, b) {
+            (InputKind::MouseWheel(a), InputKind::MouseWheel(b)) => {
+                use crate::buttonlike::MouseWheelDirection::*;
+                matches!((a, b), (Up, Down) | (Right, Left))
+            }
+            (InputKind::MouseMotion(a), InputKind::MouseMotion(b)) => {
+                use crate::buttonlike::MouseMotionDirection::*;
+                matches!((a, b), (Up, Down) | (Right, Left))
+            }
+            _ => false,
+        })
+    })
+}
+
+/// Do the thresholds of the single axis lie beyond the range of values that its gamepad reports?
+fn is_unreachable_axis(input_kind: &InputKind) -> bool {
+    let InputKind::SingleAxis(axis) = input_kind else {
+        return false;
+    };
+
+    let min_value = match axis.axis_type {
+        AxisType::Gamepad(_) => -1.0,
+        AxisType::GamepadButton(_) => 0.0,
+        _ => return false,
+    };
+
+    axis.positive_low >= 1.0 && axis.negative_low <= min_value
 }
 
 impl<A: Actionlike> From<HashMap<A, Vec<UserInput>>> for InputMap<A> {
@@ -474,6 +2275,17 @@ impl<A: Actionlike> FromIterator<(A, UserInput)> for InputMap<A> {
     }
 }
 
+/// Keys the entries of a table indexed by [`Actionlike::index`] by their action, skipping those for which `f` returns [`None`]
+fn by_action<'a, A: Actionlike + Ord, T, U>(
+    table: &'a [T],
+    f: impl Fn(&'a T) -> Option<U>,
+) -> std::collections::BTreeMap<A, U> {
+    A::variants()
+        .zip(table)
+        .filter_map(|(action, entry)| Some((action, f(entry)?)))
+        .collect()
+}
+
 impl<A> Serialize for InputMap<A>
 where
     A: Actionlike + Serialize + Eq + Hash + Ord,
@@ -483,20 +2295,92 @@ where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        use std::collections::BTreeMap;
 
-        let mut input_map = serializer.serialize_struct("InputMap", 1)?;
+        // The associated gamepad and window are chosen at runtime, and are not saved
+        let mut input_map = serializer.serialize_struct("InputMap", 10)?;
         input_map.serialize_field(
             "map",
-            &self
-                .iter()
-                .map(|(set, action)| (action, set.iter().collect()))
-                .collect::<BTreeMap<A, Vec<&UserInput>>>(),
+            &by_action::<A, _, _>(&self.map, |inputs| Some(inputs.iter().collect::<Vec<_>>())),
+        )?;
+        input_map.serialize_field(
+            "triggers",
+            &by_action::<A, _, _>(&self.triggers, |triggers| {
+                (!triggers.is_empty()).then_some(triggers)
+            }),
+        )?;
+        input_map.serialize_field(
+            "modifier_actions",
+            &by_action::<A, _, _>(&self.modifier_actions, |modifier_actions| {
+                (!modifier_actions.is_empty()).then_some(modifier_actions)
+            }),
+        )?;
+        input_map.serialize_field(
+            "chord_exclusivity",
+            &by_action::<A, _, _>(&self.chord_exclusivity, |chord_exclusivity| {
+                (!chord_exclusivity.is_empty()).then_some(chord_exclusivity)
+            }),
         )?;
+        input_map.serialize_field(
+            "priorities",
+            &by_action::<A, _, _>(&self.priorities, |&priority| {
+                (priority != 0).then_some(priority)
+            }),
+        )?;
+        input_map.serialize_field(
+            "output_ranges",
+            &by_action::<A, _, _>(&self.output_ranges, Option::as_ref),
+        )?;
+        input_map.serialize_field(
+            "press_thresholds",
+            &by_action::<A, _, _>(&self.press_thresholds, |threshold| {
+                threshold.map(|threshold| threshold.0)
+            }),
+        )?;
+        input_map.serialize_field("gamepad_association", &self.gamepad_association)?;
+        input_map.serialize_field(
+            "allowed_devices",
+            &InputDevice::ALL
+                .into_iter()
+                .filter(|&device| self.allows_device(device))
+                .collect::<Vec<_>>(),
+        )?;
+        input_map.serialize_field("policy", &self.policy)?;
         input_map.end()
     }
 }
 
+/// The serialized fields of an [`InputMap`], from which it is rebuilt
+///
+/// Every field but the bindings themselves is optional, so that files written before these settings existed still load.
+#[derive(Deserialize)]
+#[serde(rename = "InputMap")]
+struct SerializedInputMap<A: Eq + Hash> {
+    map: HashMap<A, Vec<UserInput>>,
+    #[serde(default)]
+    triggers: HashMap<A, Vec<(UserInput, BindingTrigger)>>,
+    #[serde(default)]
+    modifier_actions: HashMap<A, Vec<(UserInput, A)>>,
+    #[serde(default)]
+    chord_exclusivity: HashMap<A, Vec<(UserInput, ChordExclusivity)>>,
+    #[serde(default)]
+    priorities: HashMap<A, i32>,
+    #[serde(default)]
+    output_ranges: HashMap<A, OutputRange>,
+    #[serde(default)]
+    press_thresholds: HashMap<A, f32>,
+    #[serde(default)]
+    gamepad_association: GamepadAssociation,
+    #[serde(default = "all_devices")]
+    allowed_devices: Vec<InputDevice>,
+    #[serde(default)]
+    policy: BindingPolicy,
+}
+
+/// Every device is read by default
+fn all_devices() -> Vec<InputDevice> {
+    InputDevice::ALL.to_vec()
+}
+
 impl<'de, A> Deserialize<'de> for InputMap<A>
 where
     A: Actionlike + Deserialize<'de> + Eq + Hash,
@@ -505,56 +2389,33 @@ where
     where
         D: Deserializer<'de>,
     {
-        use serde::de::Visitor;
+        let serialized = SerializedInputMap::<A>::deserialize(deserializer)?;
 
-        #[derive(Deserialize, PartialEq)]
-        #[serde(field_identifier, rename_all = "lowercase")]
-        enum Field {
-            Map,
+        // The policy is only applied once the saved bindings are restored, so that it cannot reject any of them
+        let mut input_map = InputMap::from(serialized.map);
+        for (action, triggers) in serialized.triggers {
+            input_map.triggers[action.index()] = triggers;
         }
-
-        struct InputMapVisitor<'de, A: Actionlike + Deserialize<'de>> {
-            marker: PhantomData<&'de A>,
+        for (action, modifier_actions) in serialized.modifier_actions {
+            input_map.modifier_actions[action.index()] = modifier_actions;
         }
-
-        impl<'de, A> Visitor<'de> for InputMapVisitor<'de, A>
-        where
-            A: Actionlike + Eq + Hash + Deserialize<'de>,
-        {
-            type Value = InputMap<A>;
-
-            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-                write!(formatter, "a struct with field 'map' of type map where key is `Actionlike` and value is sequents of `UserInput`")
-            }
-
-            fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
-            where
-                S: serde::de::SeqAccess<'de>,
-            {
-                let map = seq.next_element::<HashMap<A, Vec<UserInput>>>()?;
-                map.ok_or_else(|| {
-                    serde::de::Error::invalid_length(0, &"one argument with type `map`")
-                })
-                .map(InputMap::from)
-            }
-
-            fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
-            where
-                M: serde::de::MapAccess<'de>,
-            {
-                map.next_key::<Field>()?
-                    .filter(|key| *key == Field::Map)
-                    .ok_or_else(|| serde::de::Error::missing_field("map"))?;
-                let value = map.next_value::<HashMap<A, Vec<UserInput>>>()?;
-                Ok(value.into())
-            }
+        for (action, chord_exclusivity) in serialized.chord_exclusivity {
+            input_map.chord_exclusivity[action.index()] = chord_exclusivity;
+        }
+        for (action, priority) in serialized.priorities {
+            input_map.priorities[action.index()] = priority;
+        }
+        for (action, output_range) in serialized.output_ranges {
+            input_map.output_ranges[action.index()] = Some(output_range);
+        }
+        for (action, threshold) in serialized.press_thresholds {
+            input_map.press_thresholds[action.index()] = Some(FloatOrd(threshold));
         }
+        input_map.gamepad_association = serialized.gamepad_association;
+        input_map.set_allowed_devices(serialized.allowed_devices);
+        input_map.policy = serialized.policy;
 
-        let visitor = InputMapVisitor {
-            marker: PhantomData,
-        };
-        const FIELDS: &[&str] = &["map"];
-        deserializer.deserialize_struct("InputMap", FIELDS, visitor)
+        Ok(input_map)
     }
 }
 
@@ -617,6 +2478,280 @@ mod tests {
         assert_eq!(input_map_1, input_map_2);
     }
 
+    impl DefaultBindings for Action {
+        fn default_input_map() -> InputMap<Action> {
+            use bevy::input::keyboard::KeyCode;
+
+            InputMap::new([
+                (KeyCode::LShift, Action::Run),
+                (KeyCode::Space, Action::Jump),
+            ])
+        }
+    }
+
+    #[test]
+    fn reset_to_defaults() {
+        use bevy::input::gamepad::Gamepad;
+        use bevy::input::keyboard::KeyCode;
+
+        let mut input_map = Action::default_input_map();
+        input_map.clear_action(Action::Run);
+        input_map.insert(KeyCode::H, Action::Hide);
+        input_map.set_gamepad(Gamepad { id: 42 });
+
+        input_map.reset_to_defaults();
+        assert_eq!(input_map.len(), 2);
+        assert!(input_map.get(Action::Hide).is_empty());
+        assert_eq!(input_map.gamepad(), Some(Gamepad { id: 42 }));
+
+        let bundle = InputManagerBundle::<Action>::with_defaults();
+        assert_eq!(bundle.input_map, Action::default_input_map());
+    }
+
+    #[test]
+    fn fixed_actions_cannot_be_removed() {
+        use bevy::input::keyboard::KeyCode;
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+        enum ReservedAction {
+            Jump,
+            #[actionlike(fixed)]
+            Screenshot,
+        }
+
+        let mut input_map = InputMap::new([
+            (KeyCode::Space, ReservedAction::Jump),
+            (KeyCode::F12, ReservedAction::Screenshot),
+        ]);
+
+        assert_eq!(
+            input_map.remove(ReservedAction::Screenshot, KeyCode::F12),
+            None
+        );
+        assert!(!input_map.remove_at(ReservedAction::Screenshot, 0));
+        input_map.clear_action(ReservedAction::Screenshot);
+        assert_eq!(input_map.get(ReservedAction::Screenshot).len(), 1);
+
+        input_map.clear_action(ReservedAction::Jump);
+        assert!(input_map.get(ReservedAction::Jump).is_empty());
+    }
+
+    #[test]
+    fn fixed_actions_cannot_be_rebound() {
+        use crate::errors::BindingError;
+        use crate::input_map::{BindingPolicy, OverflowPolicy};
+        use bevy::input::keyboard::KeyCode;
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+        enum ReservedAction {
+            Jump,
+            #[actionlike(fixed)]
+            Screenshot,
+        }
+
+        let mut input_map = InputMap::new([(KeyCode::F12, ReservedAction::Screenshot)]);
+        assert_eq!(
+            input_map
+                .try_insert(KeyCode::P, ReservedAction::Screenshot)
+                .err(),
+            Some(BindingError::FixedAction)
+        );
+        assert_eq!(input_map.get(ReservedAction::Screenshot).len(), 1);
+
+        // Default bindings can still be defined explicitly, but are never evicted
+        input_map.set_binding_policy(BindingPolicy {
+            capacity: 2,
+            on_overflow: OverflowPolicy::ReplaceOldest,
+            ..Default::default()
+        });
+        input_map.insert_fixed(KeyCode::Print, ReservedAction::Screenshot);
+        assert_eq!(input_map.get(ReservedAction::Screenshot).len(), 2);
+        assert_eq!(
+            input_map.insert_binding(KeyCode::P.into(), ReservedAction::Screenshot),
+            Err(BindingError::CapacityExceeded { capacity: 2 })
+        );
+        assert!(input_map
+            .get(ReservedAction::Screenshot)
+            .contains(&KeyCode::F12.into()));
+
+        input_map.insert(KeyCode::Space, ReservedAction::Jump);
+        assert_eq!(input_map.get(ReservedAction::Jump).len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "the action is fixed")]
+    fn inserting_into_fixed_actions_panics() {
+        use crate::input_map::BindingTrigger;
+        use bevy::input::keyboard::KeyCode;
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+        enum ReservedAction {
+            #[actionlike(fixed)]
+            Screenshot,
+        }
+
+        // The default binding must not gain a trigger either
+        let mut input_map = InputMap::new([(KeyCode::F12, ReservedAction::Screenshot)]);
+        input_map.insert_with_trigger(
+            KeyCode::F12,
+            ReservedAction::Screenshot,
+            BindingTrigger::Release,
+        );
+    }
+
+    #[test]
+    fn binding_capacity() {
+        use crate::errors::BindingError;
+        use crate::input_map::{BindingPolicy, OverflowPolicy};
+        use bevy::input::keyboard::KeyCode;
+
+        let mut input_map = InputMap::<Action>::default();
+        input_map.set_binding_policy(BindingPolicy {
+            capacity: 2,
+            on_overflow: OverflowPolicy::Reject,
+            ..Default::default()
+        });
+        input_map.insert_multiple([
+            (KeyCode::A, Action::Run),
+            (KeyCode::B, Action::Run),
+            (KeyCode::C, Action::Run),
+        ]);
+        assert_eq!(
+            input_map
+                .get(Action::Run)
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec![KeyCode::A.into(), KeyCode::B.into()]
+        );
+
+        input_map.set_binding_policy(BindingPolicy {
+            capacity: 2,
+            on_overflow: OverflowPolicy::ReplaceOldest,
+            ..Default::default()
+        });
+        input_map.insert(KeyCode::C, Action::Run);
+        assert_eq!(
+            input_map
+                .get(Action::Run)
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec![KeyCode::B.into(), KeyCode::C.into()]
+        );
+
+        input_map.set_binding_policy(BindingPolicy {
+            capacity: 2,
+            on_overflow: OverflowPolicy::Error,
+            ..Default::default()
+        });
+        assert_eq!(
+            input_map.try_insert(KeyCode::D, Action::Run).err(),
+            Some(BindingError::CapacityExceeded { capacity: 2 })
+        );
+        // Re-inserting an existing binding never overflows
+        assert!(input_map.try_insert(KeyCode::C, Action::Run).is_ok());
+    }
+
+    #[test]
+    fn duplicate_bindings() {
+        use crate::errors::{BindingError, InputManagerError};
+        use crate::input_map::{BindingPolicy, DuplicatePolicy};
+        use bevy::input::keyboard::KeyCode;
+
+        let mut input_map = InputMap::<Action>::default();
+        input_map.insert(KeyCode::Space, Action::Run);
+        input_map.insert(KeyCode::Space, Action::Jump);
+        assert_eq!(input_map.len(), 2);
+
+        input_map.set_binding_policy(BindingPolicy {
+            on_duplicate: DuplicatePolicy::Reject,
+            ..Default::default()
+        });
+        input_map.insert(KeyCode::Space, Action::Hide);
+        assert!(input_map.get(Action::Hide).is_empty());
+
+        input_map.set_binding_policy(BindingPolicy {
+            on_duplicate: DuplicatePolicy::Error,
+            ..Default::default()
+        });
+        assert_eq!(
+            input_map.try_insert(KeyCode::Space, Action::Hide).err(),
+            Some(BindingError::AlreadyBound)
+        );
+        assert_eq!(
+            input_map
+                .try_insert_chord(Vec::<KeyCode>::new(), Action::Hide)
+                .err(),
+            Some(InputManagerError::EmptyChord)
+        );
+        assert_eq!(
+            input_map
+                .try_insert_chord([KeyCode::Space], Action::Hide)
+                .err(),
+            Some(InputManagerError::Binding(BindingError::AlreadyBound))
+        );
+
+        input_map.set_binding_policy(BindingPolicy {
+            on_duplicate: DuplicatePolicy::Move,
+            ..Default::default()
+        });
+        input_map.insert(KeyCode::Space, Action::Hide);
+        assert!(input_map.get(Action::Run).is_empty());
+        assert!(input_map.get(Action::Jump).is_empty());
+        assert_eq!(input_map.get(Action::Hide).len(), 1);
+    }
+
+    #[test]
+    fn layering() {
+        use crate::input_map::LayerMode;
+        use bevy::input::keyboard::KeyCode;
+
+        let base = InputMap::new([(KeyCode::W, Action::Run), (KeyCode::Space, Action::Jump)]);
+        let overlay = InputMap::new([(KeyCode::LShift, Action::Run)]);
+
+        let extended = base.layered(&overlay, LayerMode::Extend);
+        assert_eq!(extended.get(Action::Run).len(), 2);
+        assert_eq!(extended.get(Action::Jump).len(), 1);
+
+        let shadowed = base.layered(&overlay, LayerMode::Shadow);
+        assert_eq!(*shadowed.get(Action::Run), *overlay.get(Action::Run));
+        assert_eq!(*shadowed.get(Action::Jump), *base.get(Action::Jump));
+    }
+
+    #[test]
+    fn blocking_overlays() {
+        use crate::input_map::{InputMapOverlay, LayerMode};
+        use crate::user_input::UserInput;
+        use bevy::input::keyboard::KeyCode;
+
+        let base = InputMap::new([
+            (UserInput::from(KeyCode::Space), Action::Run),
+            (UserInput::from(KeyCode::E), Action::Jump),
+            (
+                UserInput::chord([KeyCode::LControl, KeyCode::E]),
+                Action::Run,
+            ),
+        ]);
+        let hud_map = InputMap::new([(KeyCode::E, Action::Hide), (KeyCode::Space, Action::Hide)]);
+
+        // Inputs pass through by default
+        let passing = InputMapOverlay::new(hud_map.clone(), LayerMode::Extend).apply(&base);
+        assert_eq!(passing, base.layered(&hud_map, LayerMode::Extend));
+        assert_eq!(passing.get(Action::Jump).len(), 1);
+
+        let blocking = InputMapOverlay::new(hud_map, LayerMode::Extend)
+            .with_blocking(Action::Hide)
+            .apply(&base);
+        assert!(blocking.get(Action::Jump).is_empty());
+        // Chords that contain a blocked input are kept
+        assert_eq!(
+            blocking.get(Action::Run).iter().collect::<Vec<_>>(),
+            [&UserInput::chord([KeyCode::LControl, KeyCode::E])]
+        );
+        assert_eq!(blocking.get(Action::Hide).len(), 2);
+    }
+
     #[test]
     fn chord_singleton_coercion() {
         use crate::input_map::UserInput;
@@ -660,6 +2795,8 @@ mod tests {
 
     #[test]
     fn merging() {
+        use crate::errors::BindingError;
+        use crate::input_map::{BindingPolicy, DuplicatePolicy};
         use bevy::input::{gamepad::GamepadButtonType, keyboard::KeyCode};
 
         let mut input_map = InputMap::default();
@@ -677,6 +2814,29 @@ mod tests {
         // Merging is idempotent
         input_map.merge(&default_keyboard_map);
         assert_eq!(input_map, default_keyboard_map);
+
+        // Bindings refused by the policy are reported rather than panicking
+        let mut conflicting_map = InputMap::default();
+        conflicting_map.insert(KeyCode::LShift, Action::Hide);
+        input_map.set_binding_policy(BindingPolicy {
+            on_duplicate: DuplicatePolicy::Error,
+            ..Default::default()
+        });
+        let unmerged_map = input_map.clone();
+        assert_eq!(
+            input_map.try_merge(&conflicting_map).err(),
+            Some(vec![(
+                KeyCode::LShift.into(),
+                Action::Hide,
+                BindingError::AlreadyBound
+            )])
+        );
+        assert_eq!(input_map, unmerged_map);
+
+        input_map.merge(&conflicting_map);
+        assert_eq!(input_map.get(Action::Hide).len(), 1);
+        input_map.merge(&default_gamepad_map);
+        assert_eq!(input_map.get(Action::Run).len(), 2);
     }
 
     #[test]
@@ -718,6 +2878,205 @@ mod tests {
         assert_eq!(input_map, map.into());
     }
 
+    #[test]
+    fn immediate_mode_processing() {
+        use crate::input_streams::InputStreams;
+        use bevy::input::gamepad::{
+            Gamepad, GamepadButtonType, GamepadEventRaw, GamepadEventType, GamepadInfo,
+        };
+        use bevy::input::InputPlugin;
+        use bevy::prelude::*;
+
+        let mut app = App::new();
+        app.add_plugin(InputPlugin);
+        app.world
+            .resource_mut::<Events<GamepadEventRaw>>()
+            .extend([0, 1].map(|id| GamepadEventRaw {
+                gamepad: Gamepad { id },
+                event_type: GamepadEventType::Connected(GamepadInfo {
+                    name: "TestController".into(),
+                }),
+            }));
+        app.update();
+        app.update();
+
+        app.send_input_as_gamepad(GamepadButtonType::South, Some(Gamepad { id: 1 }));
+        app.update();
+
+        let mut input_map = InputMap::new([(GamepadButtonType::South, Action::Jump)]);
+        let input_streams = InputStreams::from_world(&app.world, Some(Gamepad { id: 0 }));
+
+        // Only the gamepad of the input streams is read
+        let action_data = input_map.process(Action::Jump, &input_streams, ClashStrategy::PressAll);
+        assert!(!action_data.state.pressed());
+
+        // The associated gamepad of the map takes precedence
+        input_map.set_gamepad(Gamepad { id: 1 });
+        let action_data = input_map.process(Action::Jump, &input_streams, ClashStrategy::PressAll);
+        assert!(action_data.state.pressed());
+
+        let action_state = input_map.process_all(&input_streams, ClashStrategy::PressAll);
+        assert!(action_state.just_pressed(Action::Jump));
+        assert!(action_state.released(Action::Run));
+    }
+
+    #[test]
+    fn diffing() {
+        use crate::errors::BindingError;
+        use crate::input_map::{BindingPolicy, DuplicatePolicy, InputMapDiff};
+        use bevy::input::keyboard::KeyCode;
+
+        let defaults = InputMap::new([
+            (KeyCode::Space, Action::Jump),
+            (KeyCode::LShift, Action::Run),
+            (KeyCode::LControl, Action::Hide),
+        ]);
+        assert_eq!(defaults.diff(&defaults), InputMapDiff::default());
+
+        let mut input_map = defaults.clone();
+        input_map.remove(Action::Jump, KeyCode::Space);
+        input_map.insert(KeyCode::W, Action::Jump);
+        input_map.insert(KeyCode::R, Action::Run);
+        input_map.clear_action(Action::Hide);
+
+        let diff = input_map.diff(&defaults);
+        let jump = diff.get(Action::Jump).unwrap();
+        assert_eq!(jump.added, [KeyCode::W.into()]);
+        assert_eq!(jump.removed, [KeyCode::Space.into()]);
+        let run = diff.get(Action::Run).unwrap();
+        assert_eq!(run.added, [KeyCode::R.into()]);
+        assert!(run.removed.is_empty());
+        let hide = diff.get(Action::Hide).unwrap();
+        assert!(hide.added.is_empty());
+        assert_eq!(hide.removed, [KeyCode::LControl.into()]);
+
+        let mut restored = defaults.clone();
+        assert!(restored.apply_diff(&diff).is_empty());
+        assert_eq!(restored, input_map);
+
+        // Changes that cannot be applied are reported rather than panicking
+        let mut strict_map = defaults.clone();
+        strict_map.set_binding_policy(BindingPolicy {
+            on_duplicate: DuplicatePolicy::Error,
+            ..Default::default()
+        });
+        strict_map.insert(KeyCode::R, Action::Hide);
+        assert_eq!(
+            strict_map.apply_diff(&diff),
+            [(KeyCode::R.into(), Action::Run, BindingError::AlreadyBound)]
+        );
+        assert!(strict_map.get(Action::Jump).contains(&KeyCode::W.into()));
+    }
+
+    #[test]
+    fn diffs_leave_fixed_actions_unchanged() {
+        use crate::errors::BindingError;
+        use bevy::input::keyboard::KeyCode;
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+        enum ReservedAction {
+            Jump,
+            #[actionlike(fixed)]
+            Screenshot,
+        }
+
+        let defaults = InputMap::new([(KeyCode::F12, ReservedAction::Screenshot)]);
+        let mut input_map = defaults.clone();
+        input_map.insert(KeyCode::Space, ReservedAction::Jump);
+        input_map.insert_fixed(KeyCode::Print, ReservedAction::Screenshot);
+        let diff = input_map.diff(&defaults);
+
+        let mut restored = defaults.clone();
+        assert_eq!(
+            restored.apply_diff(&diff),
+            [(
+                KeyCode::Print.into(),
+                ReservedAction::Screenshot,
+                BindingError::FixedAction
+            )]
+        );
+        assert_eq!(restored.get(ReservedAction::Jump).len(), 1);
+        assert_eq!(restored.get(ReservedAction::Screenshot).len(), 1);
+    }
+
+    #[test]
+    fn validation() {
+        use crate::axislike::SingleAxis;
+        use crate::buttonlike::MouseMotionDirection;
+        use crate::input_map::BindingIssue;
+        use bevy::input::gamepad::{GamepadAxisType, GamepadButtonType};
+        use bevy::input::keyboard::KeyCode;
+
+        let mut input_map = InputMap::new([(KeyCode::Space, Action::Jump)]);
+        input_map.insert_chord(
+            [MouseMotionDirection::Left, MouseMotionDirection::Right],
+            Action::Run,
+        );
+        input_map.insert(
+            SingleAxis::symmetric(GamepadAxisType::LeftStickX, 1.0),
+            Action::Run,
+        );
+        // Analog buttons never report negative values, so only the positive threshold matters
+        let trigger = SingleAxis {
+            positive_low: 0.5,
+            negative_low: -1.0,
+            ..SingleAxis::from_value(GamepadButtonType::RightTrigger2, 0.0)
+        };
+        input_map.insert(trigger, Action::Run);
+
+        let issues = input_map.validate();
+        assert_eq!(
+            issues,
+            [
+                BindingIssue::ImpossibleChord {
+                    action: Action::Run,
+                    input: UserInput::chord([
+                        MouseMotionDirection::Left,
+                        MouseMotionDirection::Right
+                    ]),
+                },
+                BindingIssue::UnreachableAxis {
+                    action: Action::Run,
+                    input: SingleAxis::symmetric(GamepadAxisType::LeftStickX, 1.0).into(),
+                },
+                BindingIssue::Unbound {
+                    action: Action::Hide
+                },
+            ]
+        );
+        assert_eq!(issues[2].to_string(), "Hide has no bindings");
+    }
+
+    #[test]
+    fn ghosting_risks() {
+        use crate::input_map::BindingIssue;
+        use crate::user_input::{InputKind, Modifier};
+        use bevy::input::keyboard::KeyCode;
+
+        let mut input_map = InputMap::default();
+        input_map.insert_chord([KeyCode::W, KeyCode::D], Action::Run);
+        input_map.insert_chord([KeyCode::W, KeyCode::D, KeyCode::Space], Action::Jump);
+        input_map.insert_chord(
+            [
+                InputKind::Modifier(Modifier::Control),
+                InputKind::Keyboard(KeyCode::RShift),
+                InputKind::Keyboard(KeyCode::H),
+                InputKind::Keyboard(KeyCode::J),
+            ],
+            Action::Hide,
+        );
+
+        let issues = input_map.ghosting_risks();
+        assert_eq!(
+            issues,
+            [BindingIssue::GhostingRisk {
+                action: Action::Jump,
+                input: UserInput::chord([KeyCode::W, KeyCode::D, KeyCode::Space]),
+            }]
+        );
+        assert!(input_map.validate().is_empty());
+    }
+
     #[test]
     fn serde() {
         use bevy::prelude::KeyCode;
@@ -735,7 +3094,7 @@ mod tests {
             &[
                 Token::Struct {
                     name: "InputMap",
-                    len: 1,
+                    len: 10,
                 },
                 Token::Str("map"),
                 Token::Map { len: Some(3) },
@@ -824,8 +3183,118 @@ mod tests {
                 Token::SeqEnd,
                 Token::SeqEnd,
                 Token::MapEnd,
+                Token::Str("triggers"),
+                Token::Map { len: Some(0) },
+                Token::MapEnd,
+                Token::Str("modifier_actions"),
+                Token::Map { len: Some(0) },
+                Token::MapEnd,
+                Token::Str("chord_exclusivity"),
+                Token::Map { len: Some(0) },
+                Token::MapEnd,
+                Token::Str("priorities"),
+                Token::Map { len: Some(0) },
+                Token::MapEnd,
+                Token::Str("output_ranges"),
+                Token::Map { len: Some(0) },
+                Token::MapEnd,
+                Token::Str("press_thresholds"),
+                Token::Map { len: Some(0) },
+                Token::MapEnd,
+                Token::Str("gamepad_association"),
+                Token::UnitVariant {
+                    name: "GamepadAssociation",
+                    variant: "AnyGamepad",
+                },
+                Token::Str("allowed_devices"),
+                Token::Seq { len: Some(4) },
+                Token::UnitVariant {
+                    name: "InputDevice",
+                    variant: "Keyboard",
+                },
+                Token::UnitVariant {
+                    name: "InputDevice",
+                    variant: "Mouse",
+                },
+                Token::UnitVariant {
+                    name: "InputDevice",
+                    variant: "Gamepad",
+                },
+                Token::UnitVariant {
+                    name: "InputDevice",
+                    variant: "Touch",
+                },
+                Token::SeqEnd,
+                Token::Str("policy"),
+                Token::Struct {
+                    name: "BindingPolicy",
+                    len: 3,
+                },
+                Token::Str("capacity"),
+                Token::U64(16),
+                Token::Str("on_overflow"),
+                Token::UnitVariant {
+                    name: "OverflowPolicy",
+                    variant: "Error",
+                },
+                Token::Str("on_duplicate"),
+                Token::UnitVariant {
+                    name: "DuplicatePolicy",
+                    variant: "Allow",
+                },
+                Token::StructEnd,
                 Token::StructEnd,
             ],
         )
     }
+
+    #[test]
+    fn serde_round_trip_keeps_every_setting() {
+        use crate::axislike::OutputRange;
+        use crate::clashing_inputs::ChordExclusivity;
+        use crate::inhibit_input::InputDevice;
+        use crate::input_map::{
+            BindingPolicy, BindingTrigger, DuplicatePolicy, GamepadAssociation, OverflowPolicy,
+        };
+        use bevy::input::{keyboard::KeyCode, mouse::MouseButton};
+        use bevy::utils::Duration;
+
+        let mut input_map = InputMap::default();
+        input_map
+            .insert_with_trigger(
+                KeyCode::Space,
+                Action::Jump,
+                BindingTrigger::Hold(Duration::from_millis(500)),
+            )
+            .insert(MouseButton::Right, Action::Hide)
+            .insert_with_modifier_action(MouseButton::Left, Action::Run, Action::Hide)
+            .insert_chord([KeyCode::LShift, KeyCode::W], Action::Run)
+            .set_chord_exclusivity(
+                Action::Run,
+                UserInput::chord([KeyCode::LShift, KeyCode::W]),
+                ChordExclusivity::Passthrough,
+            )
+            .set_priority(Action::Jump, 2)
+            .set_output_range(Action::Run, OutputRange::new(0.0, 2.0))
+            .set_press_threshold(Action::Hide, 0.25)
+            .set_gamepad_association(GamepadAssociation::Strict)
+            .set_allowed_devices([InputDevice::Keyboard, InputDevice::Mouse])
+            .set_binding_policy(BindingPolicy {
+                capacity: 4,
+                on_overflow: OverflowPolicy::ReplaceOldest,
+                on_duplicate: DuplicatePolicy::Move,
+            });
+
+        let contents = ron::ser::to_string(&input_map).unwrap();
+        let deserialized: InputMap<Action> = ron::de::from_str(&contents).unwrap();
+        assert_eq!(deserialized, input_map);
+
+        // Files that only contain bindings leave every other setting at its default
+        let bindings_only: InputMap<Action> =
+            ron::de::from_str("(map: {Jump: [Single(Keyboard(Space))]})").unwrap();
+        assert_eq!(
+            bindings_only,
+            InputMap::new([(KeyCode::Space, Action::Jump)])
+        );
+    }
 }
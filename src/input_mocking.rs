@@ -7,10 +7,13 @@
 //! These are then parsed down to their [`UserInput::raw_inputs()`],
 //! which are then sent as [`bevy::input`] events of the appropriate types.
 
+use crate::action_state::ActionState;
 use crate::axislike::{AxisType, MouseMotionAxisType, MouseWheelAxisType};
 use crate::buttonlike::{MouseMotionDirection, MouseWheelDirection};
 use crate::input_streams::{InputStreams, MutableInputStreams};
+use crate::plugin::InputManagerPlugin;
 use crate::user_input::UserInput;
+use crate::Actionlike;
 
 use bevy::app::App;
 use bevy::ecs::event::Events;
@@ -18,9 +21,8 @@ use bevy::ecs::system::{ResMut, SystemState};
 use bevy::ecs::world::World;
 #[cfg(feature = "ui")]
 use bevy::ecs::{component::Component, query::With, system::Query};
-use bevy::input::gamepad::GamepadEventRaw;
+use bevy::input::gamepad::{GamepadEventRaw, GamepadInfo};
 use bevy::input::mouse::MouseScrollUnit;
-use bevy::input::ButtonState;
 use bevy::input::{
     gamepad::{Gamepad, GamepadButton, GamepadEvent, GamepadEventType},
     keyboard::{KeyCode, KeyboardInput},
@@ -28,10 +30,12 @@ use bevy::input::{
     touch::{TouchInput, Touches},
     Input,
 };
+use bevy::input::{ButtonState, InputPlugin};
 use bevy::math::Vec2;
 #[cfg(feature = "ui")]
 use bevy::ui::Interaction;
 use bevy::window::CursorMoved;
+use bevy::MinimalPlugins;
 
 /// Send fake input events for testing purposes
 ///
@@ -173,22 +177,22 @@ impl MockInput for MutableInputStreams<'_> {
         for mouse_wheel_direction in raw_inputs.mouse_wheel {
             match mouse_wheel_direction {
                 MouseWheelDirection::Left => self.mouse_wheel.send(MouseWheel {
-                    unit: MouseScrollUnit::Pixel,
+                    unit: MouseScrollUnit::Line,
                     x: -1.0,
                     y: 0.0,
                 }),
                 MouseWheelDirection::Right => self.mouse_wheel.send(MouseWheel {
-                    unit: MouseScrollUnit::Pixel,
+                    unit: MouseScrollUnit::Line,
                     x: 1.0,
                     y: 0.0,
                 }),
                 MouseWheelDirection::Up => self.mouse_wheel.send(MouseWheel {
-                    unit: MouseScrollUnit::Pixel,
+                    unit: MouseScrollUnit::Line,
                     x: 0.0,
                     y: 1.0,
                 }),
                 MouseWheelDirection::Down => self.mouse_wheel.send(MouseWheel {
-                    unit: MouseScrollUnit::Pixel,
+                    unit: MouseScrollUnit::Line,
                     x: 0.0,
                     y: -1.0,
                 }),
@@ -235,21 +239,29 @@ impl MockInput for MutableInputStreams<'_> {
                             });
                         }
                     }
-                    AxisType::MouseWheel(axis_type) => {
-                        match axis_type {
-                            // FIXME: MouseScrollUnit is not recorded and is always assumed to be Pixel
-                            MouseWheelAxisType::X => self.mouse_wheel.send(MouseWheel {
-                                unit: MouseScrollUnit::Pixel,
-                                x: position_data,
-                                y: 0.0,
-                            }),
-                            MouseWheelAxisType::Y => self.mouse_wheel.send(MouseWheel {
-                                unit: MouseScrollUnit::Pixel,
-                                x: 0.0,
-                                y: position_data,
-                            }),
+                    AxisType::GamepadButton(button_type) => {
+                        if let Some(gamepad) = gamepad {
+                            self.gamepad_events.send(GamepadEventRaw {
+                                gamepad,
+                                event_type: GamepadEventType::ButtonChanged(
+                                    button_type,
+                                    position_data,
+                                ),
+                            });
                         }
                     }
+                    AxisType::MouseWheel(axis_type) => match axis_type {
+                        MouseWheelAxisType::X => self.mouse_wheel.send(MouseWheel {
+                            unit: MouseScrollUnit::Line,
+                            x: position_data,
+                            y: 0.0,
+                        }),
+                        MouseWheelAxisType::Y => self.mouse_wheel.send(MouseWheel {
+                            unit: MouseScrollUnit::Line,
+                            x: 0.0,
+                            y: position_data,
+                        }),
+                    },
                     AxisType::MouseMotion(axis_type) => match axis_type {
                         MouseMotionAxisType::X => self.mouse_motion.send(MouseMotion {
                             delta: Vec2 {
@@ -264,6 +276,9 @@ impl MockInput for MutableInputStreams<'_> {
                             },
                         }),
                     },
+                    AxisType::CursorPosition(axis_type) => {
+                        self.set_cursor_position_axis(axis_type, position_data);
+                    }
                 }
             }
         }
@@ -477,6 +492,66 @@ impl MockInput for App {
     }
 }
 
+/// The gamepad registered by [`test_app`]
+///
+/// Gamepad inputs sent by [`MockInput::send_input`] are attributed to this gamepad.
+pub const TEST_GAMEPAD: Gamepad = Gamepad { id: 0 };
+
+/// Creates a headless [`App`] that processes the inputs of the action type `A`, for integration tests
+///
+/// The app contains `MinimalPlugins`, the [`InputPlugin`] and an [`InputManagerPlugin<A>`],
+/// with an [`ActionState<A>`] resource initialized.
+/// The [`TEST_GAMEPAD`] is registered, so [`MockInput`] can send gamepad inputs straight away.
+///
+/// Insert an [`InputMap<A>`](crate::input_map::InputMap) resource, or spawn an [`InputManagerBundle`](crate::InputManagerBundle),
+/// then send inputs with [`MockInput`] and call `app.update()` to process them.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::input_mocking::test_app;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Jump,
+/// }
+///
+/// let mut app = test_app::<Action>();
+/// app.insert_resource(InputMap::new([
+///     (UserInput::from(KeyCode::Space), Action::Jump),
+///     (UserInput::from(GamepadButtonType::South), Action::Jump),
+/// ]));
+///
+/// app.send_input(GamepadButtonType::South);
+/// app.update();
+///
+/// let action_state = app.world.resource::<ActionState<Action>>();
+/// assert!(action_state.pressed(Action::Jump));
+/// ```
+#[must_use]
+pub fn test_app<A: Actionlike>() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<A>::default())
+        .init_resource::<ActionState<A>>();
+
+    app.world
+        .resource_mut::<Events<GamepadEventRaw>>()
+        .send(GamepadEventRaw {
+            gamepad: TEST_GAMEPAD,
+            event_type: GamepadEventType::Connected(GamepadInfo {
+                name: "TestController".into(),
+            }),
+        });
+    // Register the gamepad, then flush the connection event
+    app.update();
+    app.update();
+
+    app
+}
+
 #[cfg(test)]
 mod test {
     use crate::input_mocking::MockInput;
@@ -644,4 +719,38 @@ mod test {
             assert_eq!(*interaction, Interaction::None)
         }
     }
+
+    #[test]
+    fn test_app_drives_action_state() {
+        use crate as leafwing_input_manager;
+        use crate::action_state::ActionState;
+        use crate::input_map::InputMap;
+        use crate::input_mocking::test_app;
+        use crate::{Actionlike, InputManagerBundle};
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+        enum Action {
+            Jump,
+            Dash,
+        }
+
+        let mut app = test_app::<Action>();
+        app.insert_resource(InputMap::new([(KeyCode::Space, Action::Jump)]));
+        let player = app
+            .world
+            .spawn(InputManagerBundle {
+                input_map: InputMap::new([(GamepadButtonType::East, Action::Dash)]),
+                ..Default::default()
+            })
+            .id();
+
+        app.send_input(KeyCode::Space);
+        app.send_input(GamepadButtonType::East);
+        app.update();
+
+        let action_state = app.world.resource::<ActionState<Action>>();
+        assert!(action_state.pressed(Action::Jump));
+        let action_state = app.world.get::<ActionState<Action>>(player).unwrap();
+        assert!(action_state.pressed(Action::Dash));
+    }
 }
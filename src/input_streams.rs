@@ -6,17 +6,25 @@ use bevy::input::{
     mouse::{MouseButton, MouseButtonInput, MouseMotion, MouseWheel},
     Axis, Input,
 };
+use bevy::math::Vec2;
+use core::hash::Hash;
 use petitset::PetitSet;
 
 use bevy::ecs::prelude::{Events, ResMut, World};
 use bevy::ecs::system::SystemState;
+#[cfg(feature = "web")]
+use bevy::window::CursorGrabMode;
+use bevy::window::{Window, WindowId, Windows};
 
 use crate::axislike::{
-    AxisType, DualAxisData, MouseMotionAxisType, MouseWheelAxisType, SingleAxis, VirtualAxis,
+    AxisType, CursorPositionAxisType, CursorSpace, DeadZoneShape, DualAxisData,
+    MouseMotionAxisType, MouseWheelAxisType, MouseWheelSettings, SingleAxis, VirtualAxis,
     VirtualDPad,
 };
-use crate::buttonlike::{MouseMotionDirection, MouseWheelDirection};
-use crate::user_input::{InputKind, UserInput};
+use crate::buttonlike::{GamepadButtonSettings, MouseMotionDirection, MouseWheelDirection};
+use crate::inhibit_input::InputDevice;
+use crate::input_map::GamepadAssociation;
+use crate::user_input::{InputKind, UserInput, Wildcard};
 
 /// A collection of [`Input`] structs, which can be used to update an [`InputMap`](crate::input_map::InputMap).
 ///
@@ -27,6 +35,8 @@ pub struct InputStreams<'a> {
     pub gamepad_buttons: &'a Input<GamepadButton>,
     /// A [`GamepadButton`] [`Axis`] stream
     pub gamepad_button_axes: &'a Axis<GamepadButton>,
+    /// When analog [`GamepadButton`]s are considered pressed
+    pub gamepad_button_settings: GamepadButtonSettings,
     /// A [`GamepadAxis`] [`Axis`] stream
     pub gamepad_axes: &'a Axis<GamepadAxis>,
     /// A list of registered gamepads
@@ -37,10 +47,20 @@ pub struct InputStreams<'a> {
     pub mouse_buttons: Option<&'a Input<MouseButton>>,
     /// A [`MouseWheel`] event stream
     pub mouse_wheel: Option<&'a Events<MouseWheel>>,
+    /// How [`MouseWheel`] events are scaled
+    pub mouse_wheel_settings: MouseWheelSettings,
     /// A [`MouseMotion`] event stream
     pub mouse_motion: &'a Events<MouseMotion>,
+    /// The [`Windows`], used to read the cursor position
+    pub windows: Option<&'a Windows>,
     /// The [`Gamepad`] that this struct will detect inputs from
     pub associated_gamepad: Option<Gamepad>,
+    /// Which gamepads inputs are detected from while there is no [`associated_gamepad`](Self::associated_gamepad)
+    pub gamepad_association: GamepadAssociation,
+    /// The window that keyboard and mouse inputs are read from
+    ///
+    /// If this is [`None`], inputs from every window are used, and the cursor position is read from the primary window.
+    pub associated_window: Option<WindowId>,
 }
 
 // Constructors
@@ -49,42 +69,214 @@ impl<'a> InputStreams<'a> {
     pub fn from_world(world: &'a World, gamepad: Option<Gamepad>) -> Self {
         let gamepad_buttons = world.resource::<Input<GamepadButton>>();
         let gamepad_button_axes = world.resource::<Axis<GamepadButton>>();
+        let gamepad_button_settings = world
+            .get_resource::<GamepadButtonSettings>()
+            .copied()
+            .unwrap_or_default();
         let gamepad_axes = world.resource::<Axis<GamepadAxis>>();
         let gamepads = world.resource::<Gamepads>();
         let keycodes = world.get_resource::<Input<KeyCode>>();
         let mouse_buttons = world.get_resource::<Input<MouseButton>>();
         let mouse_wheel = world.get_resource::<Events<MouseWheel>>();
+        let mouse_wheel_settings = world
+            .get_resource::<MouseWheelSettings>()
+            .copied()
+            .unwrap_or_default();
         let mouse_motion = world.resource::<Events<MouseMotion>>();
+        let windows = world.get_resource::<Windows>();
 
         InputStreams {
             gamepad_buttons,
             gamepad_button_axes,
+            gamepad_button_settings,
             gamepad_axes,
             gamepads,
             keycodes,
             mouse_buttons,
             mouse_wheel,
+            mouse_wheel_settings,
             mouse_motion,
+            windows,
             associated_gamepad: gamepad,
+            gamepad_association: GamepadAssociation::default(),
+            associated_window: None,
+        }
+    }
+
+    /// Treats every input of the devices that are not `allowed` as released
+    ///
+    /// The streams of those devices are replaced with the empty streams of `released`.
+    #[must_use]
+    pub(crate) fn filter_devices<'b>(
+        &self,
+        allowed: impl Fn(InputDevice) -> bool,
+        released: &'b ReleasedStreams,
+    ) -> InputStreams<'b>
+    where
+        'a: 'b,
+    {
+        let gamepad = allowed(InputDevice::Gamepad);
+        let mouse = allowed(InputDevice::Mouse);
+        InputStreams {
+            gamepad_buttons: if gamepad {
+                self.gamepad_buttons
+            } else {
+                &released.gamepad_buttons
+            },
+            gamepad_button_axes: if gamepad {
+                self.gamepad_button_axes
+            } else {
+                &released.gamepad_button_axes
+            },
+            gamepad_axes: if gamepad {
+                self.gamepad_axes
+            } else {
+                &released.gamepad_axes
+            },
+            keycodes: self.keycodes.filter(|_| allowed(InputDevice::Keyboard)),
+            mouse_buttons: self.mouse_buttons.filter(|_| mouse),
+            mouse_wheel: self.mouse_wheel.filter(|_| mouse),
+            mouse_motion: if mouse {
+                self.mouse_motion
+            } else {
+                &released.mouse_motion
+            },
+            ..self.clone()
         }
     }
 }
 
+/// Empty stand-ins for the streams that [`InputStreams`] always requires, used by [`InputStreams::filter_devices`]
+#[derive(Default)]
+pub(crate) struct ReleasedStreams {
+    gamepad_buttons: Input<GamepadButton>,
+    gamepad_button_axes: Axis<GamepadButton>,
+    gamepad_axes: Axis<GamepadAxis>,
+    mouse_motion: Events<MouseMotion>,
+}
+
 // Input checking
 impl<'a> InputStreams<'a> {
     /// Guess which registered [`Gamepad`] should be used.
     ///
     /// If an associated gamepad is set, use that.
-    /// Otherwise use the first registered gamepad, if any.
+    /// Otherwise use the first registered gamepad, if any, unless the [`GamepadAssociation`] is strict.
     pub fn guess_gamepad(&self) -> Option<Gamepad> {
-        match self.associated_gamepad {
-            Some(gamepad) => Some(gamepad),
-            None => self.gamepads.iter().next(),
+        match (self.associated_gamepad, self.gamepad_association) {
+            (Some(gamepad), _) => Some(gamepad),
+            (None, GamepadAssociation::AnyGamepad) => self.gamepads.iter().next(),
+            (None, GamepadAssociation::Strict) => None,
+        }
+    }
+
+    /// The gamepad that the `input` is active on, if it is read from a gamepad
+    ///
+    /// This is the [associated gamepad](Self::associated_gamepad) if set,
+    /// and otherwise the first registered gamepad on which the `input` is pressed, unless the [`GamepadAssociation`] is strict.
+    #[must_use]
+    pub fn active_gamepad(&self, input: &UserInput) -> Option<Gamepad> {
+        match (self.associated_gamepad, self.gamepad_association) {
+            (Some(gamepad), _) => Some(gamepad),
+            (None, GamepadAssociation::AnyGamepad) => self
+                .gamepads
+                .iter()
+                .find(|&gamepad| self.with_gamepad(gamepad).input_pressed(input)),
+            (None, GamepadAssociation::Strict) => None,
+        }
+    }
+
+    /// A copy of these streams that only reads from the `gamepad`
+    fn with_gamepad(&self, gamepad: Gamepad) -> Self {
+        InputStreams {
+            associated_gamepad: Some(gamepad),
+            ..self.clone()
+        }
+    }
+
+    /// Reads an input from each registered gamepad in turn, if there is no associated gamepad and any gamepad may be used
+    ///
+    /// Returns the first reading accepted by `is_active`, or else the reading of the first gamepad.
+    /// Returns [`None`] if the input should be read as usual instead, as only a single gamepad could be used.
+    fn read_each_gamepad<T>(
+        &self,
+        read: impl Fn(&InputStreams) -> T,
+        is_active: impl Fn(&T) -> bool,
+    ) -> Option<T> {
+        if self.associated_gamepad.is_some()
+            || self.gamepad_association == GamepadAssociation::Strict
+            || self.gamepads.iter().nth(1).is_none()
+        {
+            return None;
+        }
+
+        let mut first_reading = None;
+        for gamepad in self.gamepads.iter() {
+            let reading = read(&self.with_gamepad(gamepad));
+            if is_active(&reading) {
+                return Some(reading);
+            }
+            first_reading.get_or_insert(reading);
+        }
+        first_reading
+    }
+
+    /// The window that the cursor position is read from
+    ///
+    /// This is the [`associated_window`](Self::associated_window) if set, or the primary window otherwise.
+    #[must_use]
+    pub fn window(&self) -> Option<&Window> {
+        let windows = self.windows?;
+        match self.associated_window {
+            Some(window_id) => windows.get(window_id),
+            None => windows.get_primary(),
         }
     }
 
+    /// Should keyboard inputs be read?
+    ///
+    /// This is only `false` if there is an [`associated_window`](Self::associated_window) that is not focused.
+    #[must_use]
+    pub fn keyboard_routed(&self) -> bool {
+        self.associated_window.is_none() || self.window().is_some_and(Window::is_focused)
+    }
+
+    /// Should mouse inputs be read?
+    ///
+    /// This is only `false` if there is an [`associated_window`](Self::associated_window) that does not contain the cursor.
+    #[must_use]
+    pub fn pointer_routed(&self) -> bool {
+        self.associated_window.is_none()
+            || self
+                .window()
+                .is_some_and(|window| window.cursor_position().is_some())
+    }
+
+    /// Should mouse motion be read?
+    ///
+    /// In addition to requiring that mouse input is [routed](Self::pointer_routed) to this [`InputStreams`],
+    /// the `web` feature requires the cursor of the [`window`](Self::window) to be locked,
+    /// as browsers only report meaningful mouse motion under pointer lock.
+    /// Note that browsers can release the pointer lock (such as when `Esc` is pressed) without notifying Bevy.
+    #[must_use]
+    pub fn mouse_motion_routed(&self) -> bool {
+        #[cfg(feature = "web")]
+        let pointer_locked = self
+            .window()
+            .is_some_and(|window| window.cursor_grab_mode() == CursorGrabMode::Locked);
+        #[cfg(not(feature = "web"))]
+        let pointer_locked = true;
+
+        pointer_locked && self.pointer_routed()
+    }
+
     /// Is the `input` matched by the [`InputStreams`]?
     pub fn input_pressed(&self, input: &UserInput) -> bool {
+        if let Some(pressed) =
+            self.read_each_gamepad(|streams| streams.input_pressed(input), |pressed| *pressed)
+        {
+            return pressed;
+        }
+
         match input {
             UserInput::Single(button) => self.button_pressed(*button),
             UserInput::Chord(buttons) => self.all_buttons_pressed(buttons),
@@ -119,6 +311,80 @@ impl<'a> InputStreams<'a> {
         false
     }
 
+    /// Was the `input` released during the last update?
+    ///
+    /// Chords are released when they stop being fully held, as one of their buttons is released while the others are still held or also released.
+    /// Virtual gamepads are released whenever one of their buttons is released.
+    /// Only buttons can be released: axes, mouse wheel and mouse motion inputs, as well as [`Wildcard`](crate::user_input::Wildcard)s, never are.
+    #[must_use]
+    pub fn input_just_released(&self, input: &UserInput) -> bool {
+        if let Some(released) = self.read_each_gamepad(
+            |streams| streams.input_just_released(input),
+            |released| *released,
+        ) {
+            return released;
+        }
+
+        match input {
+            UserInput::Single(button) => self.button_just_released(*button),
+            UserInput::Chord(buttons) => {
+                buttons
+                    .iter()
+                    .any(|&button| self.button_just_released(button))
+                    && buttons.iter().all(|&button| {
+                        self.button_pressed(button) || self.button_just_released(button)
+                    })
+            }
+            UserInput::VirtualDPad(VirtualDPad {
+                up,
+                down,
+                left,
+                right,
+            }) => [up, down, left, right]
+                .into_iter()
+                .any(|button| self.button_just_released(*button)),
+            UserInput::VirtualAxis(VirtualAxis { negative, positive }) => {
+                self.button_just_released(*negative) || self.button_just_released(*positive)
+            }
+        }
+    }
+
+    /// Was the `button` released during the last update?
+    ///
+    /// See [`InputStreams::input_just_released`] for the inputs that can be released.
+    #[must_use]
+    pub fn button_just_released(&self, button: InputKind) -> bool {
+        match button {
+            InputKind::GamepadButton(gamepad_button) => {
+                self.guess_gamepad().is_some_and(|gamepad| {
+                    self.gamepad_buttons.just_released(GamepadButton {
+                        gamepad,
+                        button_type: gamepad_button,
+                    })
+                })
+            }
+            InputKind::Keyboard(keycode) => {
+                self.keyboard_routed()
+                    && matches!(self.keycodes, Some(keycodes) if keycodes.just_released(keycode))
+            }
+            InputKind::Modifier(modifier) => {
+                let key_codes = modifier.key_codes();
+                // The modifier is only released once neither of its keys is held
+                self.keyboard_routed()
+                    && matches!(self.keycodes, Some(keycodes) if keycodes.any_just_released(key_codes) && !keycodes.any_pressed(key_codes))
+            }
+            InputKind::Mouse(mouse_button) => {
+                self.pointer_routed()
+                    && matches!(self.mouse_buttons, Some(mouse_buttons) if mouse_buttons.just_released(mouse_button))
+            }
+            InputKind::DualAxis(_)
+            | InputKind::SingleAxis(_)
+            | InputKind::MouseWheel(_)
+            | InputKind::MouseMotion(_)
+            | InputKind::Wildcard(_) => false,
+        }
+    }
+
     /// Is the `button` pressed?
     #[must_use]
     pub fn button_pressed(&self, button: InputKind) -> bool {
@@ -135,47 +401,43 @@ impl<'a> InputStreams<'a> {
             }
             InputKind::GamepadButton(gamepad_button) => {
                 if let Some(gamepad) = self.guess_gamepad() {
-                    self.gamepad_buttons.pressed(GamepadButton {
+                    let button = GamepadButton {
                         gamepad,
                         button_type: gamepad_button,
-                    })
+                    };
+
+                    match (
+                        self.gamepad_button_settings.press_threshold,
+                        self.gamepad_button_axes.get(button),
+                    ) {
+                        (Some(threshold), Some(value)) => value > threshold,
+                        _ => self.gamepad_buttons.pressed(button),
+                    }
                 } else {
                     false
                 }
             }
             InputKind::Keyboard(keycode) => {
-                matches!(self.keycodes, Some(keycodes) if keycodes.pressed(keycode))
+                self.keyboard_routed()
+                    && matches!(self.keycodes, Some(keycodes) if keycodes.pressed(keycode))
             }
             InputKind::Modifier(modifier) => {
                 let key_codes = modifier.key_codes();
                 // Short circuiting is probably not worth the branch here
-                matches!(self.keycodes, Some(keycodes) if keycodes.pressed(key_codes[0]) | keycodes.pressed(key_codes[1]))
+                self.keyboard_routed()
+                    && matches!(self.keycodes, Some(keycodes) if keycodes.pressed(key_codes[0]) | keycodes.pressed(key_codes[1]))
             }
             InputKind::Mouse(mouse_button) => {
-                matches!(self.mouse_buttons, Some(mouse_buttons) if mouse_buttons.pressed(mouse_button))
+                self.pointer_routed()
+                    && matches!(self.mouse_buttons, Some(mouse_buttons) if mouse_buttons.pressed(mouse_button))
             }
             InputKind::MouseWheel(mouse_wheel_direction) => {
-                let Some(mouse_wheel) = self.mouse_wheel else {
-                    return false;
+                let delta = self.mouse_wheel_delta();
+                let total_mouse_wheel_movement = match mouse_wheel_direction {
+                    MouseWheelDirection::Up | MouseWheelDirection::Down => delta.y,
+                    MouseWheelDirection::Left | MouseWheelDirection::Right => delta.x,
                 };
 
-                let mut total_mouse_wheel_movement = 0.0;
-
-                // FIXME: verify that this works and doesn't double count events
-                let mut event_reader = mouse_wheel.get_reader();
-
-                // PERF: this summing is computed for every individual input
-                // This should probably be computed once, and then cached / read
-                // Fix upstream!
-                for mouse_wheel_event in event_reader.iter(mouse_wheel) {
-                    total_mouse_wheel_movement += match mouse_wheel_direction {
-                        MouseWheelDirection::Up | MouseWheelDirection::Down => mouse_wheel_event.y,
-                        MouseWheelDirection::Left | MouseWheelDirection::Right => {
-                            mouse_wheel_event.x
-                        }
-                    }
-                }
-
                 match mouse_wheel_direction {
                     MouseWheelDirection::Up | MouseWheelDirection::Right => {
                         total_mouse_wheel_movement > 0.0
@@ -185,8 +447,13 @@ impl<'a> InputStreams<'a> {
                     }
                 }
             }
+            InputKind::Wildcard(wildcard) => self.wildcard_match(wildcard).is_some(),
             // CLEANUP: refactor to share code with MouseWheel
             InputKind::MouseMotion(mouse_motion_direction) => {
+                if !self.mouse_motion_routed() {
+                    return false;
+                }
+
                 let mut total_mouse_movement = 0.0;
 
                 // FIXME: verify that this works and doesn't double count events
@@ -215,6 +482,125 @@ impl<'a> InputStreams<'a> {
         }
     }
 
+    /// The concrete input that matches the `wildcard`, if any
+    ///
+    /// Inputs that were just pressed are preferred over those that were already held,
+    /// so that a new press is reported even while another input is held down.
+    #[must_use]
+    pub fn wildcard_match(&self, wildcard: Wildcard) -> Option<InputKind> {
+        self.find_wildcard_match(wildcard, true)
+            .or_else(|| self.find_wildcard_match(wildcard, false))
+    }
+
+    /// The concrete input that matches the `wildcard`, only considering inputs that were just pressed if `just_pressed` is set
+    fn find_wildcard_match(&self, wildcard: Wildcard, just_pressed: bool) -> Option<InputKind> {
+        match wildcard {
+            Wildcard::AnyKey => {
+                let keycodes = self.keycodes.filter(|_| self.keyboard_routed())?;
+                first_pressed(keycodes, just_pressed, |_| true).map(InputKind::Keyboard)
+            }
+            Wildcard::AnyGamepadButton => {
+                let reads_gamepad = |gamepad: Gamepad| match self.associated_gamepad {
+                    Some(associated_gamepad) => gamepad == associated_gamepad,
+                    None => self.gamepad_association == GamepadAssociation::AnyGamepad,
+                };
+                first_pressed(self.gamepad_buttons, just_pressed, |button| {
+                    reads_gamepad(button.gamepad)
+                })
+                .map(|button| InputKind::GamepadButton(button.button_type))
+            }
+            Wildcard::AnyMouseButton => {
+                let mouse_buttons = self.mouse_buttons.filter(|_| self.pointer_routed())?;
+                first_pressed(mouse_buttons, just_pressed, |_| true).map(InputKind::Mouse)
+            }
+            Wildcard::AnyInput => [
+                Wildcard::AnyKey,
+                Wildcard::AnyGamepadButton,
+                Wildcard::AnyMouseButton,
+            ]
+            .into_iter()
+            .find_map(|wildcard| self.find_wildcard_match(wildcard, just_pressed)),
+        }
+    }
+
+    /// The devices that are currently pressing the `input`
+    ///
+    /// Each [`InputKind`] of the `input` that is pressed on its own contributes its device,
+    /// with any [`Wildcard`]s resolved, and gamepad inputs attributed to the [gamepad they are active on](Self::active_gamepad).
+    /// Keyboard and mouse inputs are not attributed to any [`Gamepad`].
+    #[must_use]
+    pub fn input_sources(&self, input: &UserInput) -> Vec<(InputDevice, Option<Gamepad>)> {
+        let input_kinds: Vec<InputKind> = match self.resolve_wildcards(input) {
+            UserInput::Single(input_kind) => vec![input_kind],
+            UserInput::Chord(input_kinds) => input_kinds.iter().copied().collect(),
+            UserInput::VirtualDPad(VirtualDPad {
+                up,
+                down,
+                left,
+                right,
+            }) => vec![up, down, left, right],
+            UserInput::VirtualAxis(VirtualAxis { negative, positive }) => vec![negative, positive],
+        };
+
+        let mut sources = Vec::new();
+        for input_kind in input_kinds {
+            if !self.input_pressed(&UserInput::Single(input_kind)) {
+                continue;
+            }
+
+            let source = match input_kind.device() {
+                Some(InputDevice::Gamepad) => {
+                    match self.active_gamepad(&UserInput::Single(input_kind)) {
+                        Some(gamepad) => (InputDevice::Gamepad, Some(gamepad)),
+                        None => continue,
+                    }
+                }
+                Some(device) => (device, None),
+                None => continue,
+            };
+
+            if !sources.contains(&source) {
+                sources.push(source);
+            }
+        }
+        sources
+    }
+
+    /// Replaces each [`Wildcard`] in the `input` with the concrete input that [matches](Self::wildcard_match) it
+    ///
+    /// Wildcards that do not match any input are left unchanged.
+    #[must_use]
+    pub fn resolve_wildcards(&self, input: &UserInput) -> UserInput {
+        let resolve = |input_kind: InputKind| match input_kind {
+            InputKind::Wildcard(wildcard) => self.wildcard_match(wildcard).unwrap_or(input_kind),
+            _ => input_kind,
+        };
+
+        match input {
+            UserInput::Single(button) => UserInput::Single(resolve(*button)),
+            UserInput::Chord(buttons) => {
+                UserInput::Chord(buttons.iter().map(|&button| resolve(button)).collect())
+            }
+            UserInput::VirtualDPad(VirtualDPad {
+                up,
+                down,
+                left,
+                right,
+            }) => UserInput::VirtualDPad(VirtualDPad {
+                up: resolve(*up),
+                down: resolve(*down),
+                left: resolve(*left),
+                right: resolve(*right),
+            }),
+            UserInput::VirtualAxis(VirtualAxis { negative, positive }) => {
+                UserInput::VirtualAxis(VirtualAxis {
+                    negative: resolve(*negative),
+                    positive: resolve(*positive),
+                })
+            }
+        }
+    }
+
     /// Are all of the `buttons` pressed?
     #[must_use]
     pub fn all_buttons_pressed(&self, buttons: &PetitSet<InputKind, 8>) -> bool {
@@ -241,6 +627,12 @@ impl<'a> InputStreams<'a> {
     /// If you need to ensure that this value is always in the range `[-1., 1.]`,
     /// be sure to clamp the returned data.
     pub fn input_value(&self, input: &UserInput) -> f32 {
+        if let Some(value) =
+            self.read_each_gamepad(|streams| streams.input_value(input), |value| *value != 0.0)
+        {
+            return value;
+        }
+
         let use_button_value = || -> f32 {
             if self.input_pressed(input) {
                 1.0
@@ -251,7 +643,9 @@ impl<'a> InputStreams<'a> {
 
         // Helper that takes the value returned by an axis and returns 0.0 if it is not within the
         // triggering range.
+        // The value is first remapped according to the range of the axis.
         let value_in_axis_range = |axis: &SingleAxis, value: f32| -> f32 {
+            let value = axis.remap(value);
             if value >= axis.negative_low && value <= axis.positive_low {
                 0.0
             } else {
@@ -274,25 +668,32 @@ impl<'a> InputStreams<'a> {
                             0.0
                         }
                     }
-                    AxisType::MouseWheel(axis_type) => {
-                        let Some(mouse_wheel) = self.mouse_wheel else {
-                            return 0.0;
-                        };
-
-                        let mut total_mouse_wheel_movement = 0.0;
-                        // FIXME: verify that this works and doesn't double count events
-                        let mut event_reader = mouse_wheel.get_reader();
+                    AxisType::GamepadButton(button_type) => {
+                        if let Some(gamepad) = self.guess_gamepad() {
+                            let value = self.gamepad_button_value(GamepadButton {
+                                gamepad,
+                                button_type,
+                            });
 
-                        for mouse_wheel_event in event_reader.iter(mouse_wheel) {
-                            total_mouse_wheel_movement += match axis_type {
-                                MouseWheelAxisType::X => mouse_wheel_event.x,
-                                MouseWheelAxisType::Y => mouse_wheel_event.y,
-                            }
+                            value_in_axis_range(single_axis, value)
+                        } else {
+                            0.0
                         }
+                    }
+                    AxisType::MouseWheel(axis_type) => {
+                        let delta = self.mouse_wheel_delta();
+                        let total_mouse_wheel_movement = match axis_type {
+                            MouseWheelAxisType::X => delta.x,
+                            MouseWheelAxisType::Y => delta.y,
+                        };
                         value_in_axis_range(single_axis, total_mouse_wheel_movement)
                     }
                     // CLEANUP: deduplicate code with MouseWheel
                     AxisType::MouseMotion(axis_type) => {
+                        if !self.mouse_motion_routed() {
+                            return 0.0;
+                        }
+
                         let mut total_mouse_motion_movement = 0.0;
                         // FIXME: verify that this works and doesn't double count events
                         let mut event_reader = self.mouse_motion.get_reader();
@@ -305,6 +706,18 @@ impl<'a> InputStreams<'a> {
                         }
                         value_in_axis_range(single_axis, total_mouse_motion_movement)
                     }
+                    AxisType::CursorPosition(axis_type) => {
+                        let (space, component): (_, fn(Vec2) -> f32) = match axis_type {
+                            CursorPositionAxisType::X(space) => (space, |position| position.x),
+                            CursorPositionAxisType::Y(space) => (space, |position| position.y),
+                        };
+
+                        let value = self
+                            .cursor_position(space)
+                            .map(component)
+                            .unwrap_or_default();
+                        value_in_axis_range(single_axis, value)
+                    }
                 }
             }
             UserInput::VirtualAxis(VirtualAxis { negative, positive }) => {
@@ -320,13 +733,16 @@ impl<'a> InputStreams<'a> {
             // This is required because upstream bevy::input still waffles about whether triggers are buttons or axes
             UserInput::Single(InputKind::GamepadButton(button_type)) => {
                 if let Some(gamepad) = self.guess_gamepad() {
-                    // Get the value from the registered gamepad
-                    self.gamepad_button_axes
-                        .get(GamepadButton {
-                            gamepad,
-                            button_type: *button_type,
-                        })
-                        .unwrap_or_else(use_button_value)
+                    // Pressure-sensitive buttons report how far they are pressed, rather than just 0.0 or 1.0
+                    let button = GamepadButton {
+                        gamepad,
+                        button_type: *button_type,
+                    };
+                    // Digital-only buttons may report no value, or a value that is never updated
+                    match self.gamepad_button_axes.get(button) {
+                        Some(value) if value != 0.0 => value,
+                        _ => use_button_value(),
+                    }
                 } else {
                     0.0
                 }
@@ -335,6 +751,63 @@ impl<'a> InputStreams<'a> {
         }
     }
 
+    /// The analog value of the gamepad `button`, in the range `0.0..=1.0`
+    ///
+    /// Pressure-sensitive buttons, such as triggers and the face buttons of some controllers, report how far they are pressed.
+    /// Digital-only buttons fall back to `1.0` while pressed and `0.0` otherwise,
+    /// including when the backend registers an analog value for them that is never updated.
+    #[must_use]
+    pub fn gamepad_button_value(&self, button: GamepadButton) -> f32 {
+        let pressed = || {
+            if self.gamepad_buttons.pressed(button) {
+                1.0
+            } else {
+                0.0
+            }
+        };
+
+        match self.gamepad_button_axes.get(button) {
+            Some(value) if value != 0.0 => value,
+            _ => pressed(),
+        }
+    }
+
+    /// The total [`MouseWheel`] movement in the current frame, scaled according to the [`MouseWheelSettings`]
+    ///
+    /// Returns [`Vec2::ZERO`] if there is no [`MouseWheel`] event stream,
+    /// or if mouse input is not [routed](Self::pointer_routed) to this [`InputStreams`].
+    #[must_use]
+    pub fn mouse_wheel_delta(&self) -> Vec2 {
+        let Some(mouse_wheel) = self.mouse_wheel.filter(|_| self.pointer_routed()) else {
+            return Vec2::ZERO;
+        };
+
+        // FIXME: verify that this works and doesn't double count events
+        let mut event_reader = mouse_wheel.get_reader();
+
+        // PERF: this summing is computed for every individual input
+        // This should probably be computed once, and then cached / read
+        // Fix upstream!
+        event_reader
+            .iter(mouse_wheel)
+            .map(|event| self.mouse_wheel_settings.scaled_delta(event))
+            .sum()
+    }
+
+    /// The position of the cursor in the [`window`](Self::window), in the given [`CursorSpace`]
+    ///
+    /// Returns [`None`] if there is no such window, or if the cursor is not inside of it.
+    #[must_use]
+    pub fn cursor_position(&self, space: CursorSpace) -> Option<Vec2> {
+        let window = self.window()?;
+        let position = window.cursor_position()?;
+
+        Some(match space {
+            CursorSpace::Screen => position,
+            CursorSpace::Ndc => screen_to_ndc(window, position),
+        })
+    }
+
     /// Get the axis pair associated to the user input.
     ///
     /// If `input` is not a [`DualAxis`](crate::axislike::DualAxis) or [`VirtualDPad`], returns [`None`].
@@ -346,20 +819,50 @@ impl<'a> InputStreams<'a> {
     /// If you need to ensure that this value is always in the range `[-1., 1.]`,
     /// be sure to clamp the returned data.
     pub fn input_axis_pair(&self, input: &UserInput) -> Option<DualAxisData> {
+        if let Some(axis_pair) = self.read_each_gamepad(
+            |streams| streams.input_axis_pair(input),
+            |axis_pair| axis_pair.is_some_and(|axis_pair| axis_pair.xy() != Vec2::ZERO),
+        ) {
+            return axis_pair;
+        }
+
         match input {
             UserInput::Single(InputKind::DualAxis(dual_axis)) => {
-                let x = self.input_value(&UserInput::Single(InputKind::SingleAxis(dual_axis.x)));
-                let y = self.input_value(&UserInput::Single(InputKind::SingleAxis(dual_axis.y)));
-
-                if x > dual_axis.x.positive_low
-                    || x < dual_axis.x.negative_low
-                    || y > dual_axis.y.positive_low
-                    || y < dual_axis.y.negative_low
-                {
-                    Some(DualAxisData::new(x, y))
-                } else {
-                    Some(DualAxisData::new(0.0, 0.0))
-                }
+                let xy = match dual_axis.deadzone_shape {
+                    DeadZoneShape::Cross => {
+                        let x = self
+                            .input_value(&UserInput::Single(InputKind::SingleAxis(dual_axis.x)));
+                        let y = self
+                            .input_value(&UserInput::Single(InputKind::SingleAxis(dual_axis.y)));
+
+                        if x > dual_axis.x.positive_low
+                            || x < dual_axis.x.negative_low
+                            || y > dual_axis.y.positive_low
+                            || y < dual_axis.y.negative_low
+                        {
+                            Vec2::new(dual_axis.x.rescale(x), dual_axis.y.rescale(y))
+                        } else {
+                            Vec2::ZERO
+                        }
+                    }
+                    deadzone_shape => {
+                        // The deadzone is applied to the pair as a whole, so the raw values of each axis are needed
+                        let raw_value = |axis: SingleAxis| {
+                            self.input_value(&UserInput::Single(InputKind::SingleAxis(
+                                SingleAxis {
+                                    range: axis.range,
+                                    offset: axis.offset,
+                                    ..SingleAxis::from_value(axis.axis_type, 0.0)
+                                },
+                            )))
+                        };
+
+                        deadzone_shape
+                            .apply(Vec2::new(raw_value(dual_axis.x), raw_value(dual_axis.y)))
+                    }
+                };
+
+                Some(DualAxisData::from_xy(dual_axis.magnitude.apply(xy)))
             }
             UserInput::VirtualDPad(VirtualDPad {
                 up,
@@ -378,6 +881,31 @@ impl<'a> InputStreams<'a> {
     }
 }
 
+/// The first input of the `input` stream accepted by the `filter` that is pressed (or just pressed, if `just_pressed` is set)
+fn first_pressed<T: Copy + Eq + Hash + Send + Sync + 'static>(
+    input: &Input<T>,
+    just_pressed: bool,
+    filter: impl Fn(&T) -> bool,
+) -> Option<T> {
+    if just_pressed {
+        input.get_just_pressed().copied().find(filter)
+    } else {
+        input.get_pressed().copied().find(filter)
+    }
+}
+
+/// Converts a position in logical pixels into normalized device coordinates for the provided `window`
+pub(crate) fn screen_to_ndc(window: &Window, position: Vec2) -> Vec2 {
+    let size = Vec2::new(window.width(), window.height());
+    position / size * 2.0 - Vec2::ONE
+}
+
+/// Converts normalized device coordinates into a position in logical pixels for the provided `window`
+pub(crate) fn ndc_to_screen(window: &Window, ndc: Vec2) -> Vec2 {
+    let size = Vec2::new(window.width(), window.height());
+    (ndc + Vec2::ONE) / 2.0 * size
+}
+
 /// A mutable collection of [`Input`] structs, which can be used for mocking user inputs.
 ///
 /// These are typically collected via a system from the [`World`](bevy::prelude::World) as resources.
@@ -408,6 +936,8 @@ pub struct MutableInputStreams<'a> {
     pub mouse_wheel: &'a mut Events<MouseWheel>,
     /// A [`MouseMotion`] event stream
     pub mouse_motion: &'a mut Events<MouseMotion>,
+    /// The [`Windows`], used for mocking the cursor position
+    pub windows: Option<&'a mut Windows>,
 
     /// The [`Gamepad`] that this struct will detect inputs from
     pub associated_gamepad: Option<Gamepad>,
@@ -428,6 +958,7 @@ impl<'a> MutableInputStreams<'a> {
             ResMut<Events<MouseButtonInput>>,
             ResMut<Events<MouseWheel>>,
             ResMut<Events<MouseMotion>>,
+            Option<ResMut<Windows>>,
         )> = SystemState::new(world);
 
         let (
@@ -442,6 +973,7 @@ impl<'a> MutableInputStreams<'a> {
             mouse_button_events,
             mouse_wheel,
             mouse_motion,
+            windows,
         ) = input_system_state.get_mut(world);
 
         MutableInputStreams {
@@ -456,6 +988,7 @@ impl<'a> MutableInputStreams<'a> {
             mouse_button_events: mouse_button_events.into_inner(),
             mouse_wheel: mouse_wheel.into_inner(),
             mouse_motion: mouse_motion.into_inner(),
+            windows: windows.map(|windows| windows.into_inner()),
             associated_gamepad: gamepad,
         }
     }
@@ -470,6 +1003,45 @@ impl<'a> MutableInputStreams<'a> {
             None => self.gamepads.iter().next(),
         }
     }
+
+    /// Moves the cursor in the primary window along a single axis, leaving the other axis unchanged
+    ///
+    /// If the cursor is not currently in the window, the other axis is placed at the origin of the chosen space.
+    /// Does nothing if there is no primary window.
+    pub(crate) fn set_cursor_position_axis(
+        &mut self,
+        axis_type: CursorPositionAxisType,
+        value: f32,
+    ) {
+        let Some(window) = self
+            .windows
+            .as_deref_mut()
+            .and_then(Windows::get_primary_mut)
+        else {
+            return;
+        };
+
+        let (space, set_component): (_, fn(&mut Vec2, f32)) = match axis_type {
+            CursorPositionAxisType::X(space) => (space, |position, value| position.x = value),
+            CursorPositionAxisType::Y(space) => (space, |position, value| position.y = value),
+        };
+
+        let mut position = match space {
+            CursorSpace::Screen => window.cursor_position().unwrap_or_default(),
+            CursorSpace::Ndc => window
+                .cursor_position()
+                .map(|position| screen_to_ndc(window, position))
+                .unwrap_or_default(),
+        };
+        set_component(&mut position, value);
+
+        let position = match space {
+            CursorSpace::Screen => position,
+            CursorSpace::Ndc => ndc_to_screen(window, position),
+        };
+        let physical_position = position.as_dvec2() * window.scale_factor();
+        window.update_cursor_physical_position_from_backend(Some(physical_position));
+    }
 }
 
 impl<'a> From<MutableInputStreams<'a>> for InputStreams<'a> {
@@ -477,13 +1049,18 @@ impl<'a> From<MutableInputStreams<'a>> for InputStreams<'a> {
         InputStreams {
             gamepad_buttons: mutable_streams.gamepad_buttons,
             gamepad_button_axes: mutable_streams.gamepad_button_axes,
+            gamepad_button_settings: GamepadButtonSettings::default(),
             gamepad_axes: mutable_streams.gamepad_axes,
             gamepads: mutable_streams.gamepads,
             keycodes: Some(mutable_streams.keycodes),
             mouse_buttons: Some(mutable_streams.mouse_buttons),
             mouse_wheel: Some(mutable_streams.mouse_wheel),
+            mouse_wheel_settings: MouseWheelSettings::default(),
             mouse_motion: mutable_streams.mouse_motion,
+            windows: mutable_streams.windows.map(|windows| &*windows),
             associated_gamepad: mutable_streams.associated_gamepad,
+            gamepad_association: GamepadAssociation::default(),
+            associated_window: None,
         }
     }
 }
@@ -493,13 +1070,18 @@ impl<'a> From<&'a MutableInputStreams<'a>> for InputStreams<'a> {
         InputStreams {
             gamepad_buttons: mutable_streams.gamepad_buttons,
             gamepad_button_axes: mutable_streams.gamepad_button_axes,
+            gamepad_button_settings: GamepadButtonSettings::default(),
             gamepad_axes: mutable_streams.gamepad_axes,
             gamepads: mutable_streams.gamepads,
             keycodes: Some(mutable_streams.keycodes),
             mouse_buttons: Some(mutable_streams.mouse_buttons),
             mouse_wheel: Some(mutable_streams.mouse_wheel),
+            mouse_wheel_settings: MouseWheelSettings::default(),
             mouse_motion: mutable_streams.mouse_motion,
+            windows: mutable_streams.windows.as_deref(),
             associated_gamepad: mutable_streams.associated_gamepad,
+            gamepad_association: GamepadAssociation::default(),
+            associated_window: None,
         }
     }
 }
@@ -0,0 +1,223 @@
+//! This module contains [`InputTimeline`] and its supporting methods and impls.
+//!
+//! An [`InputTimeline`] is a keyframed script of inputs, such as "press Jump at 2 seconds, then hold the stick up from 3 to 5 seconds".
+//! Timelines are plain data that can be saved and loaded with [`serde`], for example from a RON file,
+//! and are played onto an [`ActionState`](crate::action_state::ActionState) by a [`VirtualController`].
+//! This is useful for cutscenes and tutorials that "play" the character using the same gameplay systems as the player.
+
+use bevy::utils::Duration;
+use serde::{Deserialize, Serialize};
+
+use crate::axislike::DualAxisData;
+use crate::virtual_controller::{ScheduledInput, VirtualController};
+use crate::Actionlike;
+
+/// A single input of an [`InputTimeline`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Keyframe<A: Actionlike> {
+    /// The action to press
+    pub action: A,
+    /// When the action is pressed, relative to the start of the timeline
+    pub start: Duration,
+    /// How long the action stays pressed for
+    ///
+    /// Actions are always pressed for at least one frame, even if this is [`Duration::ZERO`].
+    #[serde(default)]
+    pub duration: Duration,
+    /// The value reported while the action is pressed
+    ///
+    /// If this is [`None`], the value is 1.0, or the length of the [`axis_pair`](Self::axis_pair) if there is one.
+    #[serde(default)]
+    pub value: Option<f32>,
+    /// The axis pair reported while the action is pressed
+    #[serde(default)]
+    pub axis_pair: Option<DualAxisData>,
+}
+
+impl<A: Actionlike> Keyframe<A> {
+    /// The [`ScheduledInput`] that plays this keyframe
+    #[must_use]
+    pub fn scheduled_input(&self) -> ScheduledInput<A> {
+        let mut input =
+            ScheduledInput::new(self.action.clone(), self.duration).with_delay(self.start);
+        if let Some(axis_pair) = self.axis_pair {
+            input = input
+                .with_value(axis_pair.length())
+                .with_axis_pair(axis_pair);
+        }
+        if let Some(value) = self.value {
+            input = input.with_value(value);
+        }
+        input
+    }
+}
+
+/// A keyframed script of inputs, played onto an [`ActionState`](crate::action_state::ActionState) by a [`VirtualController`]
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy::utils::Duration;
+/// use leafwing_input_manager::axislike::DualAxisData;
+/// use leafwing_input_manager::input_timeline::InputTimeline;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::virtual_controller::VirtualController;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Jump,
+///     Move,
+/// }
+///
+/// let timeline = InputTimeline::default()
+///     .with_press(Action::Jump, Duration::from_secs(2), Duration::ZERO)
+///     .with_axis_pair(
+///         Action::Move,
+///         Duration::from_secs(3),
+///         Duration::from_secs(2),
+///         DualAxisData::new(0.0, 1.0),
+///     );
+/// assert_eq!(timeline.duration(), Duration::from_secs(5));
+///
+/// let mut controller = VirtualController::default();
+/// controller.play(&timeline);
+///
+/// let mut world = World::new();
+/// world.spawn((ActionState::<Action>::default(), controller));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InputTimeline<A: Actionlike> {
+    /// The inputs of this timeline
+    ///
+    /// These do not need to be sorted: keyframes that overlap on the same action are resolved
+    /// in favor of the one listed last.
+    pub keyframes: Vec<Keyframe<A>>,
+}
+
+impl<A: Actionlike> Default for InputTimeline<A> {
+    fn default() -> Self {
+        Self {
+            keyframes: Vec::new(),
+        }
+    }
+}
+
+impl<A: Actionlike> InputTimeline<A> {
+    /// Adds the `keyframe` to this timeline
+    #[must_use]
+    pub fn with_keyframe(mut self, keyframe: Keyframe<A>) -> Self {
+        self.keyframes.push(keyframe);
+        self
+    }
+
+    /// Presses the `action` at `start`, for the given `duration`
+    #[must_use]
+    pub fn with_press(self, action: A, start: Duration, duration: Duration) -> Self {
+        self.with_keyframe(Keyframe {
+            action,
+            start,
+            duration,
+            value: None,
+            axis_pair: None,
+        })
+    }
+
+    /// Presses the `action` with the given `value` at `start`, for the given `duration`
+    #[must_use]
+    pub fn with_value(self, action: A, start: Duration, duration: Duration, value: f32) -> Self {
+        self.with_keyframe(Keyframe {
+            action,
+            start,
+            duration,
+            value: Some(value),
+            axis_pair: None,
+        })
+    }
+
+    /// Presses the `action` with the given `axis_pair` at `start`, for the given `duration`
+    #[must_use]
+    pub fn with_axis_pair(
+        self,
+        action: A,
+        start: Duration,
+        duration: Duration,
+        axis_pair: DualAxisData,
+    ) -> Self {
+        self.with_keyframe(Keyframe {
+            action,
+            start,
+            duration,
+            value: None,
+            axis_pair: Some(axis_pair),
+        })
+    }
+
+    /// The time at which the last keyframe ends
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.keyframes
+            .iter()
+            .map(|keyframe| keyframe.start + keyframe.duration)
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+impl<A: Actionlike> VirtualController<A> {
+    /// Schedules every keyframe of the `timeline`, relative to the current time
+    ///
+    /// Call [`VirtualController::clear`] first to interrupt any inputs that are already playing.
+    pub fn play(&mut self, timeline: &InputTimeline<A>) {
+        for keyframe in &timeline.keyframes {
+            self.schedule(keyframe.scheduled_input());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InputTimeline;
+    use crate as leafwing_input_manager;
+    use crate::action_state::ActionState;
+    use crate::axislike::DualAxisData;
+    use crate::virtual_controller::VirtualController;
+    use crate::Actionlike;
+    use bevy::utils::{Duration, Instant};
+
+    #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    enum Action {
+        Jump,
+        Move,
+    }
+
+    #[test]
+    fn timeline_playback() {
+        let frame = Duration::from_millis(100);
+        let timeline = InputTimeline::default()
+            .with_press(Action::Jump, frame * 2, Duration::ZERO)
+            .with_axis_pair(Action::Move, frame, frame * 2, DualAxisData::new(0.0, 1.0));
+        assert_eq!(timeline.duration(), frame * 3);
+
+        let mut action_state = ActionState::<Action>::default();
+        let mut controller = VirtualController::default();
+        controller.play(&timeline);
+
+        let mut pressed = Vec::new();
+        for _ in 0..4 {
+            let now = Instant::now();
+            action_state.tick(now, now - frame);
+            controller.apply(&mut action_state, frame);
+            pressed.push((
+                action_state.pressed(Action::Jump),
+                action_state.axis_pair(Action::Move),
+            ));
+        }
+
+        let up = Some(DualAxisData::new(0.0, 1.0));
+        assert_eq!(
+            pressed,
+            [(false, None), (false, up), (true, up), (false, None)]
+        );
+        assert!(controller.is_idle());
+    }
+}
@@ -0,0 +1,270 @@
+//! This module contains [`KeySequences`] and its supporting methods and impls.
+//!
+//! The [`KeySequences`] is an optional addition to an [`InputManagerBundle`](crate::InputManagerBundle),
+//! which presses an action once an ordered sequence of keys has been typed, such as a cheat code
+//! or a vim-like command in an editor tool.
+//! Unlike [`ComboButtons`](crate::combo_buttons::ComboButtons), the keys are pressed one after another rather than together.
+
+use bevy::ecs::prelude::*;
+use bevy::input::keyboard::KeyCode;
+use bevy::utils::Duration;
+
+use crate::action_state::ActionState;
+use crate::Actionlike;
+
+/// An ordered sequence of keys that presses an action once it has been typed in full
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeySequence<A: Actionlike> {
+    /// The keys that must be pressed, in order
+    pub keys: Vec<KeyCode>,
+    /// The action pressed for a single update once every one of the [`KeySequence::keys`] has been pressed
+    pub action: A,
+    /// How long may pass between pressing one key of the sequence and the next before the sequence starts over
+    pub step_timeout: Duration,
+    /// How many of the keys have been pressed so far
+    progress: usize,
+    /// How long ago the last key of the sequence was pressed
+    since_step: Duration,
+}
+
+impl<A: Actionlike> KeySequence<A> {
+    /// Creates a [`KeySequence`] that presses `action` once the `keys` have been pressed in order,
+    /// with no more than `step_timeout` between each
+    #[must_use]
+    pub fn new(keys: impl IntoIterator<Item = KeyCode>, action: A, step_timeout: Duration) -> Self {
+        Self {
+            keys: keys.into_iter().collect(),
+            action,
+            step_timeout,
+            progress: 0,
+            since_step: Duration::ZERO,
+        }
+    }
+
+    /// How many of the [`KeySequence::keys`] have been pressed so far
+    ///
+    /// This is `0` when the sequence has not been started, or has just been completed.
+    #[must_use]
+    pub fn progress(&self) -> usize {
+        self.progress
+    }
+
+    /// The fraction of the [`KeySequence::keys`] that have been pressed so far, between `0.0` and `1.0`
+    #[must_use]
+    pub fn progress_fraction(&self) -> f32 {
+        if self.keys.is_empty() {
+            return 0.0;
+        }
+
+        self.progress as f32 / self.keys.len() as f32
+    }
+
+    /// Forgets the keys that have been pressed so far
+    pub fn reset(&mut self) {
+        self.progress = 0;
+        self.since_step = Duration::ZERO;
+    }
+
+    /// Advances the sequence by the `pressed` keys, after `delta` has passed
+    ///
+    /// Returns `true` if the sequence was completed.
+    fn advance(&mut self, pressed: &[KeyCode], delta: Duration) -> bool {
+        self.since_step += delta;
+        if self.progress > 0 && self.since_step > self.step_timeout {
+            self.reset();
+        }
+
+        let mut completed = false;
+        for &key in pressed {
+            self.progress = self.matched_after(key);
+            self.since_step = Duration::ZERO;
+
+            if self.progress > 0 && self.progress == self.keys.len() {
+                self.reset();
+                completed = true;
+            }
+        }
+        completed
+    }
+
+    /// The progress once `key` has been pressed
+    ///
+    /// A wrong key does not always start the sequence over: in `Up, Up, Down`,
+    /// pressing `Up` a third time still leaves the first two keys pressed.
+    fn matched_after(&self, key: KeyCode) -> usize {
+        if self.keys.get(self.progress) == Some(&key) {
+            return self.progress + 1;
+        }
+
+        // The longest prefix of the keys that ends the keys pressed so far
+        let typed: Vec<KeyCode> = self.keys[..self.progress]
+            .iter()
+            .copied()
+            .chain([key])
+            .collect();
+        (1..=self.progress)
+            .rev()
+            .find(|&len| self.keys[..len] == typed[typed.len() - len..])
+            .unwrap_or(0)
+    }
+}
+
+/// Presses actions once ordered sequences of keys have been typed
+///
+/// As a resource, this affects the [`ActionState`] resource,
+/// and as a component, the [`ActionState`] of the same entity.
+/// This is applied by [`apply_key_sequences`](crate::systems::apply_key_sequences),
+/// as part of [`InputManagerSystem::ManualControl`](crate::plugin::InputManagerSystem::ManualControl).
+///
+/// Each [`KeySequence`] advances when its next key is pressed,
+/// and starts over when any other key is pressed or its [`KeySequence::step_timeout`] expires.
+/// Once the final key is pressed, its action is pressed for a single update, and the sequence starts over.
+/// The actions of key sequences should not be bound in the [`InputMap`](crate::input_map::InputMap),
+/// so that they are released again by the following update.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy::utils::Duration;
+/// use leafwing_input_manager::key_sequences::{KeySequence, KeySequences};
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Jump,
+///     ExtraLives,
+/// }
+///
+/// use KeyCode::*;
+/// let konami_code = KeySequence::new(
+///     [Up, Up, Down, Down, Left, Right, Left, Right, B, A],
+///     Action::ExtraLives,
+///     Duration::from_millis(500),
+/// );
+///
+/// App::new()
+///     .add_plugin(InputManagerPlugin::<Action>::default())
+///     .init_resource::<ActionState<Action>>()
+///     .insert_resource(InputMap::new([(Space, Action::Jump)]))
+///     .insert_resource(KeySequences::new([konami_code]));
+/// ```
+#[derive(Resource, Component, Debug, Clone, PartialEq, Eq)]
+pub struct KeySequences<A: Actionlike> {
+    /// The sequences, which are matched independently of each other
+    pub sequences: Vec<KeySequence<A>>,
+}
+
+impl<A: Actionlike> Default for KeySequences<A> {
+    fn default() -> Self {
+        Self {
+            sequences: Vec::new(),
+        }
+    }
+}
+
+impl<A: Actionlike> KeySequences<A> {
+    /// Creates [`KeySequences`] from the provided `sequences`
+    #[must_use]
+    pub fn new(sequences: impl IntoIterator<Item = KeySequence<A>>) -> Self {
+        Self {
+            sequences: sequences.into_iter().collect(),
+        }
+    }
+
+    /// Adds a [`KeySequence`]
+    #[must_use]
+    pub fn with(mut self, sequence: KeySequence<A>) -> Self {
+        self.sequences.push(sequence);
+        self
+    }
+
+    /// The furthest [`KeySequence::progress_fraction`] of the sequences that press `action`
+    ///
+    /// This is useful to hint that a cheat code is being entered.
+    #[must_use]
+    pub fn progress(&self, action: A) -> f32 {
+        self.sequences
+            .iter()
+            .filter(|sequence| sequence.action.index() == action.index())
+            .map(KeySequence::progress_fraction)
+            .fold(0.0, f32::max)
+    }
+
+    /// Forgets the keys that have been pressed so far in every sequence
+    pub fn reset(&mut self) {
+        for sequence in &mut self.sequences {
+            sequence.reset();
+        }
+    }
+
+    /// Advances each sequence by the keys `pressed` this update, after `delta` has passed,
+    /// pressing the actions of completed sequences in the `action_state`
+    pub fn apply(
+        &mut self,
+        action_state: &mut ActionState<A>,
+        pressed: &[KeyCode],
+        delta: Duration,
+    ) {
+        for sequence in &mut self.sequences {
+            if sequence.advance(pressed, delta) {
+                action_state.press(sequence.action.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as leafwing_input_manager;
+    use bevy::input::keyboard::KeyCode::*;
+
+    #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    enum Action {
+        Cheat,
+    }
+
+    const STEP: Duration = Duration::from_millis(100);
+
+    fn sequences() -> KeySequences<Action> {
+        KeySequences::new([KeySequence::new([Up, Up, Down], Action::Cheat, STEP * 3)])
+    }
+
+    #[test]
+    fn sequences_press_their_action_once_typed() {
+        let mut sequences = sequences();
+        let mut action_state = ActionState::<Action>::default();
+
+        for key in [Up, Up] {
+            sequences.apply(&mut action_state, &[key], STEP);
+            assert!(action_state.released(Action::Cheat));
+        }
+        assert_eq!(sequences.sequences[0].progress(), 2);
+        assert!((sequences.progress(Action::Cheat) - 2.0 / 3.0).abs() < f32::EPSILON);
+
+        sequences.apply(&mut action_state, &[Down], STEP);
+        assert!(action_state.pressed(Action::Cheat));
+        assert_eq!(sequences.progress(Action::Cheat), 0.0);
+    }
+
+    #[test]
+    fn wrong_keys_and_timeouts_start_over() {
+        let mut sequences = sequences();
+        let mut action_state = ActionState::<Action>::default();
+
+        sequences.apply(&mut action_state, &[Up], STEP);
+        sequences.apply(&mut action_state, &[A], STEP);
+        assert_eq!(sequences.sequences[0].progress(), 0);
+
+        // A third `Up` still leaves the last two pressed
+        for key in [Up, Up, Up] {
+            sequences.apply(&mut action_state, &[key], STEP);
+        }
+        assert_eq!(sequences.sequences[0].progress(), 2);
+
+        sequences.apply(&mut action_state, &[], STEP * 4);
+        assert_eq!(sequences.sequences[0].progress(), 0);
+        sequences.apply(&mut action_state, &[Down], STEP);
+        assert!(action_state.released(Action::Cheat));
+    }
+}
@@ -0,0 +1,298 @@
+//! This module contains [`KeyboardLayout`] and its supporting methods and impls.
+//!
+//! Bindings to a [`KeyCode`] are already translated by the operating system according to the active layout:
+//! the key labelled "Z" on an AZERTY keyboard is reported as [`KeyCode::Z`], and so needs no translation.
+//! Physical keys, identified by their [`ScanCode`], are the same on every layout,
+//! and the [`KeyboardLayout`] translates them into the labels printed on the keyboard of the player,
+//! so that a prompt to press the key in the position of W on a QWERTY keyboard can show "Z" on an AZERTY keyboard.
+//! Bevy does not expose the active layout of the operating system, so the labels are provided by a [`KeyLabels`] implementation:
+//! either one of the presets, or a query layer that asks the operating system.
+//! This requires the `keyboard_layout` feature.
+
+use bevy::ecs::prelude::*;
+use bevy::input::keyboard::{KeyCode, ScanCode};
+use std::fmt::Debug;
+
+use crate::axislike::{VirtualAxis, VirtualDPad};
+use crate::user_input::{InputKind, UserInput};
+
+/// Translates physical keys into the labels printed on the keyboard of the player
+///
+/// Implemented for every `Fn(ScanCode) -> Option<String>`, so a closure calling into the operating system can be used directly.
+pub trait KeyLabels: Send + Sync + 'static {
+    /// The label of the key with the `scan_code` on the active layout, or [`None`] if it is not known
+    fn label(&self, scan_code: ScanCode) -> Option<String>;
+}
+
+impl<F: Fn(ScanCode) -> Option<String> + Send + Sync + 'static> KeyLabels for F {
+    fn label(&self, scan_code: ScanCode) -> Option<String> {
+        self(scan_code)
+    }
+}
+
+/// A fixed table of labels, used by the presets of [`KeyboardLayout`]
+struct LabelTable(&'static [(u32, &'static str)]);
+
+impl KeyLabels for LabelTable {
+    fn label(&self, scan_code: ScanCode) -> Option<String> {
+        self.0
+            .iter()
+            .find(|(table_code, _)| *table_code == scan_code.0)
+            .map(|(_, label)| label.to_string())
+    }
+}
+
+/// The labels of the letter and punctuation keys of a QWERTY keyboard, by scan code
+const QWERTY: &[(u32, &str)] = &[
+    (0x0C, "-"),
+    (0x10, "Q"),
+    (0x11, "W"),
+    (0x12, "E"),
+    (0x13, "R"),
+    (0x14, "T"),
+    (0x15, "Y"),
+    (0x16, "U"),
+    (0x17, "I"),
+    (0x18, "O"),
+    (0x19, "P"),
+    (0x1A, "["),
+    (0x1E, "A"),
+    (0x1F, "S"),
+    (0x20, "D"),
+    (0x21, "F"),
+    (0x22, "G"),
+    (0x23, "H"),
+    (0x24, "J"),
+    (0x25, "K"),
+    (0x26, "L"),
+    (0x27, ";"),
+    (0x28, "'"),
+    (0x2C, "Z"),
+    (0x2D, "X"),
+    (0x2E, "C"),
+    (0x2F, "V"),
+    (0x30, "B"),
+    (0x31, "N"),
+    (0x32, "M"),
+    (0x33, ","),
+    (0x34, "."),
+    (0x35, "/"),
+];
+
+/// The keys whose labels differ between QWERTY and AZERTY keyboards, by scan code
+const AZERTY: &[(u32, &str)] = &[
+    (0x10, "A"),
+    (0x11, "Z"),
+    (0x1E, "Q"),
+    (0x2C, "W"),
+    (0x27, "M"),
+    (0x32, ","),
+    (0x33, ";"),
+    (0x34, ":"),
+    (0x35, "!"),
+];
+
+/// The keys whose labels differ between QWERTY and QWERTZ keyboards, by scan code
+const QWERTZ: &[(u32, &str)] = &[
+    (0x15, "Z"),
+    (0x2C, "Y"),
+    (0x0C, "ß"),
+    (0x27, "Ö"),
+    (0x28, "Ä"),
+    (0x1A, "Ü"),
+];
+
+/// A preset layout, which overrides the labels of some of the keys of a QWERTY keyboard
+struct PresetLayout(&'static [(u32, &'static str)]);
+
+impl KeyLabels for PresetLayout {
+    fn label(&self, scan_code: ScanCode) -> Option<String> {
+        LabelTable(self.0)
+            .label(scan_code)
+            .or_else(|| LabelTable(QWERTY).label(scan_code))
+    }
+}
+
+/// Translates keys into the labels of the keyboard layout of the player, for display in binding prompts
+///
+/// Only the names shown to players are affected: bindings are matched against inputs exactly as before.
+/// Keys bound by [`KeyCode`] are shown by the name of their [`KeyCode`],
+/// which the operating system has already translated according to the active layout.
+/// Physical keys are shown by the label returned by the [`KeyLabels`] of this layout, as seen by [`KeyboardLayout::scan_code_label`].
+///
+/// The presets use the scan codes reported on Windows and Linux, which follow the PC keyboard (set 1) scan codes.
+/// Other platforms number their keys differently, and should provide their own [`KeyLabels`] through [`KeyboardLayout::new`].
+/// By default, no physical keys are labelled.
+///
+/// # Example
+/// ```rust
+/// use bevy::input::keyboard::ScanCode;
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::keyboard_layout::KeyboardLayout;
+/// use leafwing_input_manager::prelude::*;
+///
+/// // The key in the position of W on a QWERTY keyboard
+/// let layout = KeyboardLayout::azerty();
+/// assert_eq!(layout.scan_code_label(ScanCode(0x11)), "Z");
+/// // Keys are already translated by the operating system
+/// assert_eq!(layout.key_label(KeyCode::Z), "Z");
+/// assert_eq!(layout.input_label(&UserInput::chord([KeyCode::LControl, KeyCode::A])), "LControl+A");
+///
+/// // Layers that query the operating system can be plugged in as closures
+/// let layout = KeyboardLayout::new(|scan_code: ScanCode| (scan_code.0 == 0x15).then(|| "Z".to_string()));
+/// assert_eq!(layout.scan_code_label(ScanCode(0x15)), "Z");
+/// ```
+#[derive(Resource)]
+pub struct KeyboardLayout {
+    labels: Box<dyn KeyLabels>,
+}
+
+impl Default for KeyboardLayout {
+    fn default() -> Self {
+        Self::new(|_| None)
+    }
+}
+
+impl Debug for KeyboardLayout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeyboardLayout").finish_non_exhaustive()
+    }
+}
+
+impl KeyboardLayout {
+    /// Creates a [`KeyboardLayout`] that labels physical keys according to the `labels`
+    #[must_use]
+    pub fn new(labels: impl KeyLabels) -> Self {
+        Self {
+            labels: Box::new(labels),
+        }
+    }
+
+    /// The US QWERTY layout
+    #[must_use]
+    pub fn qwerty() -> Self {
+        Self::new(PresetLayout(&[]))
+    }
+
+    /// The French AZERTY layout
+    #[must_use]
+    pub fn azerty() -> Self {
+        Self::new(PresetLayout(AZERTY))
+    }
+
+    /// The German QWERTZ layout
+    #[must_use]
+    pub fn qwertz() -> Self {
+        Self::new(PresetLayout(QWERTZ))
+    }
+
+    /// The label printed on the physical key with the `scan_code` on this layout
+    ///
+    /// Unknown keys are shown by their scan code.
+    #[must_use]
+    pub fn scan_code_label(&self, scan_code: ScanCode) -> String {
+        self.labels
+            .label(scan_code)
+            .unwrap_or_else(|| format!("{scan_code:?}"))
+    }
+
+    /// The label of the `key`
+    ///
+    /// A [`KeyCode`] is already translated according to the active layout, so this is the name of the `key`.
+    #[must_use]
+    pub fn key_label(&self, key: KeyCode) -> String {
+        format!("{key:?}")
+    }
+
+    /// The name of the `button`, with any key shown by its [`KeyboardLayout::key_label`]
+    ///
+    /// Other inputs are named as by their [`Display`](std::fmt::Display) implementation.
+    #[must_use]
+    pub fn button_label(&self, button: InputKind) -> String {
+        match button {
+            InputKind::Keyboard(key) => self.key_label(key),
+            button => button.to_string(),
+        }
+    }
+
+    /// The name of the `input`, with every key shown by its [`KeyboardLayout::key_label`]
+    ///
+    /// The buttons of chords are separated by "+".
+    #[must_use]
+    pub fn input_label(&self, input: &UserInput) -> String {
+        match input {
+            UserInput::Single(button) => self.button_label(*button),
+            UserInput::Chord(buttons) => buttons
+                .iter()
+                .map(|button| self.button_label(*button))
+                .collect::<Vec<_>>()
+                .join("+"),
+            UserInput::VirtualDPad(VirtualDPad {
+                up,
+                down,
+                left,
+                right,
+            }) => format!(
+                "VirtualDPad(up: {}, down: {}, left: {}, right: {})",
+                self.button_label(*up),
+                self.button_label(*down),
+                self.button_label(*left),
+                self.button_label(*right)
+            ),
+            UserInput::VirtualAxis(VirtualAxis { negative, positive }) => format!(
+                "VirtualAxis(negative: {}, positive: {})",
+                self.button_label(*negative),
+                self.button_label(*positive)
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeyboardLayout;
+    use crate::axislike::VirtualDPad;
+    use crate::user_input::UserInput;
+    use bevy::input::gamepad::GamepadButtonType;
+    use bevy::input::keyboard::{KeyCode, ScanCode};
+
+    #[test]
+    fn physical_keys_are_labelled_by_the_layout() {
+        // The scan codes of the keys in the positions of Q, W, Y and Space on a QWERTY keyboard
+        let (q, w, y, space) = (
+            ScanCode(0x10),
+            ScanCode(0x11),
+            ScanCode(0x15),
+            ScanCode(0x39),
+        );
+
+        let azerty = KeyboardLayout::azerty();
+        assert_eq!(azerty.scan_code_label(q), "A");
+        assert_eq!(azerty.scan_code_label(w), "Z");
+        assert_eq!(azerty.scan_code_label(y), "Y");
+        assert_eq!(azerty.scan_code_label(space), "ScanCode(57)");
+
+        let qwertz = KeyboardLayout::qwertz();
+        assert_eq!(qwertz.scan_code_label(w), "W");
+        assert_eq!(qwertz.scan_code_label(y), "Z");
+
+        assert_eq!(KeyboardLayout::qwerty().scan_code_label(w), "W");
+        assert_eq!(KeyboardLayout::default().scan_code_label(w), "ScanCode(17)");
+    }
+
+    #[test]
+    fn key_codes_are_already_translated() {
+        // Pressing the key labelled Z on an AZERTY keyboard is reported as `KeyCode::Z`
+        let azerty = KeyboardLayout::azerty();
+        assert_eq!(azerty.key_label(KeyCode::Z), "Z");
+        assert_eq!(azerty.key_label(KeyCode::W), "W");
+        assert_eq!(
+            azerty.input_label(&UserInput::from(VirtualDPad::wasd())),
+            "VirtualDPad(up: W, down: S, left: A, right: D)"
+        );
+        assert_eq!(
+            azerty.input_label(&UserInput::from(GamepadButtonType::South)),
+            "South"
+        );
+    }
+}
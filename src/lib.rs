@@ -4,24 +4,63 @@
 #![doc = include_str!("../README.md")]
 
 use crate::action_state::ActionState;
-use crate::input_map::InputMap;
+use crate::input_map::{DefaultBindings, InputMap};
 use bevy::ecs::prelude::*;
+use bevy::input::gamepad::Gamepad;
 use std::marker::PhantomData;
 
+pub mod action_aliases;
+pub mod action_set;
 pub mod action_state;
+pub mod action_statistics;
+pub mod action_usage;
+pub mod activity;
+pub mod axis_ramp;
 pub mod axislike;
+#[cfg(feature = "asset")]
+pub mod binding_assets;
+pub mod binding_capture;
 pub mod buttonlike;
+pub mod calibration;
 pub mod clashing_inputs;
+pub mod combo_buttons;
+pub mod directional_repeat;
 mod display_impl;
+pub mod dynamic_actions;
 pub mod errors;
+pub mod exclusive_actions;
+pub mod haptics;
+pub mod inhibit_input;
+#[cfg(feature = "input_display")]
+pub mod input_display;
+pub mod input_log;
 pub mod input_map;
 pub mod input_mocking;
 pub mod input_streams;
+pub mod input_timeline;
+pub mod key_sequences;
+#[cfg(feature = "keyboard_layout")]
+pub mod keyboard_layout;
+pub mod localization;
+#[cfg(feature = "ui")]
+pub mod menu_navigation;
+pub mod motion_controls;
 pub mod orientation;
+pub mod platform_bindings;
 pub mod plugin;
 pub mod press_scheduler;
+pub mod saved_bindings;
+pub mod split_keyboard;
+pub mod state_scoped_actions;
+pub mod stick_flicks;
+pub mod system_params;
 pub mod systems;
+pub mod text_input;
+#[cfg(feature = "touchpad")]
+pub mod touchpad;
 pub mod user_input;
+pub mod value_thresholds;
+pub mod virtual_controller;
 
 // Importing the derive macro
 pub use leafwing_input_manager_macros::Actionlike;
@@ -29,16 +68,19 @@ pub use leafwing_input_manager_macros::Actionlike;
 /// Everything you need to get started
 pub mod prelude {
     pub use crate::action_state::{ActionState, ActionStateDriver};
+    pub use crate::activity::{InputActivity, InputIdle};
     pub use crate::axislike::{DualAxis, MouseWheelAxisType, SingleAxis, VirtualDPad};
     pub use crate::buttonlike::MouseWheelDirection;
     pub use crate::clashing_inputs::ClashStrategy;
-    pub use crate::input_map::InputMap;
+    pub use crate::input_map::{DefaultBindings, InputMap, InputMapOverlay, LayerMode};
     pub use crate::input_mocking::MockInput;
-    pub use crate::user_input::{Modifier, UserInput};
+    pub use crate::user_input::{Modifier, UserInput, Wildcard};
 
     pub use crate::plugin::InputManagerPlugin;
     pub use crate::plugin::ToggleActions;
-    pub use crate::{Actionlike, InputManagerBundle};
+    pub use crate::plugin::{PauseTiming, TimeSource};
+    pub use crate::system_params::{Actions, ActionsMut};
+    pub use crate::{ActionMetadata, Actionlike, InputManagerBundle};
 }
 
 /// Allows a type to be used as a gameplay action in an input-agnostic fashion
@@ -72,9 +114,47 @@ pub mod prelude {
 ///    Ultimate,
 /// }
 /// ```
+///
+/// # Metadata
+///
+/// Each variant can be annotated with `#[actionlike(...)]` attributes,
+/// which are exposed at runtime via [`Actionlike::metadata`].
+/// This is useful for labelling and grouping actions in settings screens.
+///
+/// ```rust
+/// use leafwing_input_manager::Actionlike;
+///
+/// #[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash)]
+/// enum PlayerAction {
+///     #[actionlike(name = "Jump", category = "Movement")]
+///     Jump,
+///     #[actionlike(description = "Restores a small amount of health", category = "Abilities")]
+///     Heal,
+/// }
+///
+/// assert_eq!(PlayerAction::Jump.metadata().category, Some("Movement"));
+/// // If no name is provided, the name of the variant is used
+/// assert_eq!(PlayerAction::Heal.metadata().name, "Heal");
+/// ```
+///
+/// Actions that players should not be able to rebind (such as taking a screenshot or opening a debug console)
+/// can be marked with `#[actionlike(fixed)]`.
+/// See [`ActionMetadata::fixed`] for how this is respected.
+///
+/// Variants that have been renamed can keep their old names with `#[actionlike(alias = "OldName")]`,
+/// so that [`SavedBindings`](crate::saved_bindings::SavedBindings) referring to them still load.
 pub trait Actionlike: Send + Sync + Clone + 'static {
     /// The number of variants of this action type
-    fn n_variants() -> usize;
+    ///
+    /// As this is known at compile time, it can be used to size arrays and bitsets keyed by [`Actionlike::index`].
+    const N_VARIANTS: usize;
+
+    /// The number of variants of this action type
+    ///
+    /// This is always equal to [`Actionlike::N_VARIANTS`].
+    fn n_variants() -> usize {
+        Self::N_VARIANTS
+    }
 
     /// Iterates over the possible actions in the order they were defined
     fn variants() -> ActionIter<Self> {
@@ -87,7 +167,72 @@ pub trait Actionlike: Send + Sync + Clone + 'static {
     fn get_at(index: usize) -> Option<Self>;
 
     /// Returns the position in the defining enum of the given action
+    ///
+    /// When derived, this is the order in which the variants are defined, starting from `0`, and is always less than [`Actionlike::N_VARIANTS`].
+    /// It only changes if variants are added, removed or reordered, and so can be used as a compact key in wire formats
+    /// that are shared between builds of the same game version.
     fn index(&self) -> usize;
+
+    /// Returns the human-readable [`ActionMetadata`] for this action
+    ///
+    /// When derived, this is populated from the `#[actionlike(...)]` attributes on each variant.
+    /// Manual implementations that do not override this method return empty metadata.
+    fn metadata(&self) -> ActionMetadata {
+        ActionMetadata::default()
+    }
+
+    /// Returns the action whose [identifier](ActionMetadata::identifier) or one of whose [aliases](ActionMetadata::aliases) is `identifier`
+    ///
+    /// Identifiers take precedence over aliases.
+    fn from_identifier(identifier: &str) -> Option<Self> {
+        Self::variants()
+            .find(|action| action.metadata().saved_identifier() == identifier)
+            .or_else(|| {
+                Self::variants().find(|action| action.metadata().aliases.contains(&identifier))
+            })
+    }
+}
+
+/// Human-readable information about an [`Actionlike`] action, returned by [`Actionlike::metadata`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ActionMetadata {
+    /// The display name of the action
+    ///
+    /// When derived, this defaults to the name of the variant.
+    pub name: &'static str,
+    /// The name of the variant that defines this action, exactly as written in the source code
+    ///
+    /// This identifies the action in [`SavedBindings`](crate::saved_bindings::SavedBindings).
+    /// Manual implementations of [`Actionlike`] that leave this empty fall back to the [`name`](Self::name).
+    pub identifier: &'static str,
+    /// The previous identifiers of this action, set with `#[actionlike(alias = "OldName")]`
+    ///
+    /// Saved bindings that refer to an alias are loaded as bindings of this action,
+    /// so that variants can be renamed without discarding the bindings of players.
+    pub aliases: &'static [&'static str],
+    /// A longer description of what the action does
+    pub description: Option<&'static str>,
+    /// The category that this action should be grouped under
+    pub category: Option<&'static str>,
+    /// Is this action reserved, and thus hidden from rebinding?
+    ///
+    /// The bindings of fixed actions are ignored by the removal methods of [`InputMap`] and refused by its insertion methods,
+    /// so they cannot be clobbered by rebinding code.
+    /// Define them in your default bindings with [`InputMap::new`] or [`InputMap::insert_fixed`] instead.
+    /// Settings screens should hide these actions.
+    pub fixed: bool,
+}
+
+impl ActionMetadata {
+    /// The [`identifier`](Self::identifier) of the action, or its [`name`](Self::name) if no identifier was provided
+    #[must_use]
+    pub fn saved_identifier(&self) -> &'static str {
+        if self.identifier.is_empty() {
+            self.name
+        } else {
+            self.identifier
+        }
+    }
 }
 
 /// An iterator of [`Actionlike`] actions
@@ -114,7 +259,7 @@ impl<A: Actionlike> Iterator for ActionIter<A> {
 
 impl<A: Actionlike> ExactSizeIterator for ActionIter<A> {
     fn len(&self) -> usize {
-        A::n_variants()
+        A::N_VARIANTS
     }
 }
 
@@ -131,6 +276,22 @@ impl<A: Actionlike> Default for ActionIter<A> {
 /// This [`Bundle`] allows entities to collect and interpret inputs from across input sources
 ///
 /// Use with [`InputManagerPlugin`](crate::plugin::InputManagerPlugin), providing the same enum type to both.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+/// enum Action {
+///     Jump,
+/// }
+///
+/// let bundle = InputManagerBundle::with_map(InputMap::new([(GamepadButtonType::South, Action::Jump)]))
+///     .with_gamepad(Gamepad { id: 0 });
+///
+/// assert_eq!(bundle.input_map.gamepad(), Some(Gamepad { id: 0 }));
+/// ```
 #[derive(Bundle)]
 pub struct InputManagerBundle<A: Actionlike> {
     /// An [`ActionState`] component
@@ -148,3 +309,41 @@ impl<A: Actionlike> Default for InputManagerBundle<A> {
         }
     }
 }
+
+impl<A: Actionlike> InputManagerBundle<A> {
+    /// Creates a bundle with the provided [`InputMap`] and a default [`ActionState`]
+    #[must_use]
+    pub fn with_map(input_map: InputMap<A>) -> Self {
+        Self {
+            action_state: ActionState::default(),
+            input_map,
+        }
+    }
+
+    /// Associates the [`InputMap`] of this bundle with the provided [`Gamepad`]
+    ///
+    /// See [`InputMap::set_gamepad`] for more details.
+    #[must_use]
+    pub fn with_gamepad(mut self, gamepad: Gamepad) -> Self {
+        self.input_map.set_gamepad(gamepad);
+        self
+    }
+
+    /// Replaces the [`ActionState`] that this bundle starts with
+    #[must_use]
+    pub fn with_initial_state(mut self, action_state: ActionState<A>) -> Self {
+        self.action_state = action_state;
+        self
+    }
+}
+
+impl<A: DefaultBindings> InputManagerBundle<A> {
+    /// Creates a bundle using the [`DefaultBindings::default_input_map`] of `A`
+    #[must_use]
+    pub fn with_defaults() -> Self {
+        Self {
+            action_state: ActionState::default(),
+            input_map: A::default_input_map(),
+        }
+    }
+}
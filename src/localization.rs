@@ -0,0 +1,248 @@
+//! This module contains [`Localization`] and its supporting methods and impls.
+//!
+//! The [`Display`](std::fmt::Display) implementations of inputs and the [`ActionMetadata`] of actions are written in English.
+//! Prompts and settings screens that are shown to players should instead name actions and bindings through a [`Localization`],
+//! which asks a [`Localizer`] for the translated names and falls back to the English ones for anything it does not translate.
+//! Games using fluent or gettext can plug in their message bundles with [`Localization::from_lookup`].
+
+use bevy::ecs::prelude::*;
+use std::fmt::Debug;
+
+use crate::axislike::{VirtualAxis, VirtualDPad};
+use crate::user_input::{InputKind, UserInput};
+use crate::{ActionMetadata, Actionlike};
+
+/// Translates the names of actions and inputs into the language of the player
+///
+/// Every method returns [`None`] by default, leaving the English name in place.
+pub trait Localizer: Send + Sync + 'static {
+    /// The translated name of the action described by the `metadata`
+    fn action_name(&self, _metadata: &ActionMetadata) -> Option<String> {
+        None
+    }
+
+    /// The translated description of the action described by the `metadata`
+    fn action_description(&self, _metadata: &ActionMetadata) -> Option<String> {
+        None
+    }
+
+    /// The translated name of a single `button`
+    fn button_name(&self, _button: InputKind) -> Option<String> {
+        None
+    }
+
+    /// The translated name of an entire `input`
+    ///
+    /// When [`None`], the name is composed from the [`button_name`](Self::button_name) of each button in the input.
+    fn input_name(&self, _input: &UserInput) -> Option<String> {
+        None
+    }
+}
+
+/// A [`Localizer`] that looks up the names of actions and inputs by message id
+///
+/// Created by [`Localization::from_lookup`].
+struct MessageLookup<F>(F);
+
+impl<F: Fn(&str) -> Option<String> + Send + Sync + 'static> Localizer for MessageLookup<F> {
+    fn action_name(&self, metadata: &ActionMetadata) -> Option<String> {
+        (self.0)(&format!("action-{}", metadata.saved_identifier()))
+    }
+
+    fn action_description(&self, metadata: &ActionMetadata) -> Option<String> {
+        (self.0)(&format!(
+            "action-{}-description",
+            metadata.saved_identifier()
+        ))
+    }
+
+    fn button_name(&self, button: InputKind) -> Option<String> {
+        (self.0)(&format!("input-{button}"))
+    }
+}
+
+/// Names actions and bindings in the language of the player, for display in prompts and settings screens
+///
+/// Insert this as a resource, and read it wherever actions or bindings are shown to players.
+/// By default, nothing is translated:
+/// actions are named by their [`ActionMetadata`], and inputs by their [`Display`](std::fmt::Display) implementation.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::localization::Localization;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Jump,
+///     Crouch,
+/// }
+///
+/// // Stands in for a fluent or gettext message bundle
+/// let localization = Localization::from_lookup(|id: &str| match id {
+///     "action-Jump" => Some("Sauter".to_string()),
+///     "input-Space" => Some("Espace".to_string()),
+///     _ => None,
+/// });
+///
+/// assert_eq!(localization.action_name(&Action::Jump), "Sauter");
+/// assert_eq!(localization.action_name(&Action::Crouch), "Crouch");
+/// assert_eq!(localization.input_name(&UserInput::chord([KeyCode::LShift, KeyCode::Space])), "LShift+Espace");
+/// ```
+#[derive(Resource)]
+pub struct Localization {
+    localizer: Box<dyn Localizer>,
+}
+
+/// Leaves every name untranslated
+struct English;
+
+impl Localizer for English {}
+
+impl Default for Localization {
+    fn default() -> Self {
+        Self::new(English)
+    }
+}
+
+impl Debug for Localization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Localization").finish_non_exhaustive()
+    }
+}
+
+impl Localization {
+    /// Creates a [`Localization`] that translates names with the `localizer`
+    #[must_use]
+    pub fn new(localizer: impl Localizer) -> Self {
+        Self {
+            localizer: Box::new(localizer),
+        }
+    }
+
+    /// Creates a [`Localization`] that looks up each name by its message id
+    ///
+    /// The message ids are:
+    /// - `action-{identifier}` for the name of an action, using its [`ActionMetadata::saved_identifier`]
+    /// - `action-{identifier}-description` for the description of an action
+    /// - `input-{button}` for the name of a button, using its [`Display`](std::fmt::Display) implementation, such as `input-Space` or `input-South`
+    #[must_use]
+    pub fn from_lookup(lookup: impl Fn(&str) -> Option<String> + Send + Sync + 'static) -> Self {
+        Self::new(MessageLookup(lookup))
+    }
+
+    /// The name of the `action`, as shown to players
+    #[must_use]
+    pub fn action_name<A: Actionlike>(&self, action: &A) -> String {
+        let metadata = action.metadata();
+        self.localizer
+            .action_name(&metadata)
+            .unwrap_or_else(|| metadata.name.to_string())
+    }
+
+    /// The description of the `action`, as shown to players, if it has one
+    #[must_use]
+    pub fn action_description<A: Actionlike>(&self, action: &A) -> Option<String> {
+        let metadata = action.metadata();
+        self.localizer
+            .action_description(&metadata)
+            .or_else(|| metadata.description.map(ToString::to_string))
+    }
+
+    /// The name of the `button`, as shown to players
+    #[must_use]
+    pub fn button_name(&self, button: InputKind) -> String {
+        self.localizer
+            .button_name(button)
+            .unwrap_or_else(|| button.to_string())
+    }
+
+    /// The name of the `input`, as shown to players
+    ///
+    /// Unless the [`Localizer`] names the whole input, the buttons of chords are separated by "+",
+    /// and virtual axes and dpads list their buttons in parentheses.
+    #[must_use]
+    pub fn input_name(&self, input: &UserInput) -> String {
+        if let Some(name) = self.localizer.input_name(input) {
+            return name;
+        }
+
+        match input {
+            UserInput::Single(button) => self.button_name(*button),
+            UserInput::Chord(buttons) => buttons
+                .iter()
+                .map(|button| self.button_name(*button))
+                .collect::<Vec<_>>()
+                .join("+"),
+            UserInput::VirtualDPad(VirtualDPad {
+                up,
+                down,
+                left,
+                right,
+            }) => format!(
+                "({}, {}, {}, {})",
+                self.button_name(*up),
+                self.button_name(*left),
+                self.button_name(*down),
+                self.button_name(*right)
+            ),
+            UserInput::VirtualAxis(VirtualAxis { negative, positive }) => format!(
+                "({}, {})",
+                self.button_name(*negative),
+                self.button_name(*positive)
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Localization, Localizer};
+    use crate as leafwing_input_manager;
+    use crate::axislike::VirtualDPad;
+    use crate::user_input::{InputKind, UserInput};
+    use crate::{ActionMetadata, Actionlike};
+    use bevy::input::keyboard::KeyCode;
+
+    #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    enum Action {
+        #[actionlike(description = "Leave the ground")]
+        Jump,
+        Run,
+    }
+
+    struct Shouting;
+
+    impl Localizer for Shouting {
+        fn action_name(&self, metadata: &ActionMetadata) -> Option<String> {
+            (metadata.name == "Jump").then(|| "JUMP".to_string())
+        }
+
+        fn button_name(&self, button: InputKind) -> Option<String> {
+            Some(button.to_string().to_uppercase())
+        }
+    }
+
+    #[test]
+    fn untranslated_names_fall_back_to_english() {
+        let localization = Localization::new(Shouting);
+        assert_eq!(localization.action_name(&Action::Jump), "JUMP");
+        assert_eq!(localization.action_name(&Action::Run), "Run");
+        assert_eq!(
+            localization.action_description(&Action::Jump).as_deref(),
+            Some("Leave the ground")
+        );
+        assert_eq!(localization.action_description(&Action::Run), None);
+        assert_eq!(
+            localization.input_name(&UserInput::from(VirtualDPad::wasd())),
+            "(W, A, S, D)"
+        );
+
+        let english = Localization::default();
+        assert_eq!(
+            english.input_name(&UserInput::from(KeyCode::Space)),
+            "Space"
+        );
+    }
+}
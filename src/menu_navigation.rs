@@ -0,0 +1,338 @@
+//! Gamepad and keyboard navigation of `bevy_ui` menus, powered by the [`MenuNavigation`] actions.
+//!
+//! Add the [`MenuNavigationPlugin`] to your app, and mark each navigable UI node with the [`Focusable`] component.
+//! Directional actions move the [`Focused`] marker to the nearest [`Focusable`] node in that direction,
+//! while [`MenuNavigation::Select`] activates the focused node by setting its [`Interaction`] to [`Interaction::Clicked`].
+//!
+//! To click the focused node with an action of your own instead, add a [`ClickFocusedPlugin`].
+
+use crate as leafwing_input_manager;
+use crate::action_state::ActionState;
+use crate::axislike::VirtualDPad;
+use crate::directional_repeat::DirectionalRepeat;
+use crate::input_map::{DefaultBindings, InputMap};
+use crate::plugin::{InputManagerPlugin, InputManagerSystem, InputManagerSystemFor};
+use crate::system_params::Actions;
+use crate::Actionlike;
+
+use bevy::app::{App, CoreStage, Plugin};
+use bevy::ecs::prelude::*;
+use bevy::input::gamepad::GamepadButtonType;
+use bevy::input::keyboard::KeyCode;
+use bevy::math::Vec2;
+use bevy::transform::components::GlobalTransform;
+use bevy::ui::{Interaction, UiSystem};
+use bevy::utils::FloatOrd;
+
+/// Actions used to navigate between the [`Focusable`] nodes of a `bevy_ui` menu
+#[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum MenuNavigation {
+    /// Moves focus to the nearest node above the focused node
+    Up,
+    /// Moves focus to the nearest node below the focused node
+    Down,
+    /// Moves focus to the nearest node to the left of the focused node
+    Left,
+    /// Moves focus to the nearest node to the right of the focused node
+    Right,
+    /// Activates the focused node
+    Select,
+}
+
+impl MenuNavigation {
+    /// The threshold past which the left stick will trigger directional actions
+    pub const STICK_THRESHOLD: f32 = 0.5;
+
+    /// The direction in UI space that this action moves focus in, if any
+    ///
+    /// Like the [`GlobalTransform`] of UI nodes, UI space has its origin at the bottom-left corner of the window,
+    /// with `y` increasing upwards.
+    #[must_use]
+    pub fn direction(self) -> Option<Vec2> {
+        match self {
+            MenuNavigation::Up => Some(Vec2::new(0.0, 1.0)),
+            MenuNavigation::Down => Some(Vec2::new(0.0, -1.0)),
+            MenuNavigation::Left => Some(Vec2::new(-1.0, 0.0)),
+            MenuNavigation::Right => Some(Vec2::new(1.0, 0.0)),
+            MenuNavigation::Select => None,
+        }
+    }
+}
+
+impl DefaultBindings for MenuNavigation {
+    /// The bindings used by the [`MenuNavigationPlugin`] if no [`InputMap<MenuNavigation>`] resource exists
+    ///
+    /// Directions are bound to the arrow keys, the gamepad d-pad and the left stick,
+    /// while selection is bound to `Return`, `Space` and the south face button.
+    fn default_input_map() -> InputMap<MenuNavigation> {
+        use MenuNavigation::*;
+
+        let mut input_map = InputMap::default();
+
+        input_map.insert(KeyCode::Up, Up);
+        input_map.insert(KeyCode::Down, Down);
+        input_map.insert(KeyCode::Left, Left);
+        input_map.insert(KeyCode::Right, Right);
+        input_map.insert(KeyCode::Return, Select);
+        input_map.insert(KeyCode::Space, Select);
+
+        input_map.insert(GamepadButtonType::DPadUp, Up);
+        input_map.insert(GamepadButtonType::DPadDown, Down);
+        input_map.insert(GamepadButtonType::DPadLeft, Left);
+        input_map.insert(GamepadButtonType::DPadRight, Right);
+        input_map.insert(GamepadButtonType::South, Select);
+
+        let stick = VirtualDPad::left_stick(MenuNavigation::STICK_THRESHOLD);
+        input_map.insert(stick.up, Up);
+        input_map.insert(stick.down, Down);
+        input_map.insert(stick.left, Left);
+        input_map.insert(stick.right, Right);
+
+        input_map
+    }
+}
+
+/// Marks a UI node as a target for [`MenuNavigation`]
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Focusable;
+
+/// Marks the [`Focusable`] node that currently has focus
+///
+/// At most one entity should have this component at once.
+/// If no entity is focused, the first directional action will focus the top-left-most [`Focusable`] node.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Focused;
+
+/// A [`Plugin`] that moves focus between [`Focusable`] UI nodes using [`MenuNavigation`] actions
+///
+/// This adds an [`InputManagerPlugin<MenuNavigation>`], along with an [`ActionState<MenuNavigation>`] resource,
+/// an [`InputMap<MenuNavigation>`] resource containing [`DefaultBindings::default_input_map`]
+/// and a [`DirectionalRepeat<MenuNavigation>`] resource, so that held directions repeat,
+/// if you have not inserted them yourself.
+///
+/// Both [`navigate_focus`] and [`activate_focused`] run in [`CoreStage::PreUpdate`],
+/// after the [`MenuNavigation`] actions are updated.
+#[derive(Default)]
+pub struct MenuNavigationPlugin;
+
+impl Plugin for MenuNavigationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(InputManagerPlugin::<MenuNavigation>::default())
+            .init_resource::<ActionState<MenuNavigation>>();
+
+        if !app.world.contains_resource::<InputMap<MenuNavigation>>() {
+            app.insert_resource(MenuNavigation::default_input_map());
+        }
+
+        if !app
+            .world
+            .contains_resource::<DirectionalRepeat<MenuNavigation>>()
+        {
+            app.insert_resource(DirectionalRepeat::new(
+                MenuNavigation::Up,
+                MenuNavigation::Down,
+                MenuNavigation::Left,
+                MenuNavigation::Right,
+            ));
+        }
+
+        app.add_system_to_stage(
+            CoreStage::PreUpdate,
+            navigate_focus.after(InputManagerSystemFor::<MenuNavigation>::new(
+                InputManagerSystem::ManualControl,
+            )),
+        )
+        .add_system_to_stage(
+            CoreStage::PreUpdate,
+            activate_focused
+                .after(InputManagerSystemFor::<MenuNavigation>::new(
+                    InputManagerSystem::ManualControl,
+                ))
+                .after(UiSystem::Focus),
+        );
+    }
+}
+
+/// Moves the [`Focused`] marker between [`Focusable`] nodes when a directional [`MenuNavigation`] action is just pressed
+///
+/// The new target is the [`Focusable`] node in the requested direction with the lowest score,
+/// where nodes that are offset perpendicularly to the direction of travel are penalized.
+pub fn navigate_focus(
+    mut commands: Commands,
+    action_state: Res<ActionState<MenuNavigation>>,
+    focused_query: Query<(Entity, &GlobalTransform), With<Focused>>,
+    focusable_query: Query<(Entity, &GlobalTransform), (With<Focusable>, Without<Focused>)>,
+) {
+    let Some(direction) = action_state
+        .get_just_pressed()
+        .into_iter()
+        .find_map(MenuNavigation::direction)
+    else {
+        return;
+    };
+
+    let Some((focused_entity, focused_transform)) = focused_query.iter().next() else {
+        // Nothing is focused yet, so start at the top-left
+        let top_left = focusable_query
+            .iter()
+            .map(|(entity, transform)| (entity, transform.translation().truncate()))
+            .filter(|(_, position)| position.is_finite())
+            .min_by_key(|(_, position)| (FloatOrd(-position.y), FloatOrd(position.x)));
+
+        if let Some((entity, _)) = top_left {
+            commands.entity(entity).insert(Focused);
+        }
+        return;
+    };
+
+    let origin = focused_transform.translation().truncate();
+    let best_target = focusable_query
+        .iter()
+        .filter_map(|(entity, transform)| {
+            let offset = transform.translation().truncate() - origin;
+            let along = offset.dot(direction);
+            if along <= 0.0 {
+                return None;
+            }
+
+            let across = offset.perp_dot(direction).abs();
+            let score = along + 2.0 * across;
+            // Transforms containing NaN or infinities cannot be compared, and are never focused
+            score.is_finite().then_some((entity, FloatOrd(score)))
+        })
+        .min_by_key(|&(_, score)| score);
+
+    if let Some((target, _)) = best_target {
+        commands.entity(focused_entity).remove::<Focused>();
+        commands.entity(target).insert(Focused);
+    }
+}
+
+/// Sets the [`Interaction`] of the [`Focused`] node to [`Interaction::Clicked`] when [`MenuNavigation::Select`] is just pressed
+///
+/// The node is returned to [`Interaction::None`] on the following frame,
+/// so that each press of [`MenuNavigation::Select`] results in a single click.
+pub fn activate_focused(
+    action_state: Res<ActionState<MenuNavigation>>,
+    mut interaction_query: Query<&mut Interaction>,
+    focused_query: Query<Entity, (With<Focused>, With<Interaction>)>,
+    mut activated: Local<Option<Entity>>,
+) {
+    click_focused_node(
+        action_state.just_pressed(MenuNavigation::Select),
+        &mut interaction_query,
+        &focused_query,
+        &mut activated,
+    );
+}
+
+/// The action of type `A` that clicks the [`Focused`] node, as read by [`click_focused`]
+///
+/// This is inserted by the [`ClickFocusedPlugin<A>`].
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct ClickFocused<A: Actionlike> {
+    /// The action that clicks the [`Focused`] node when it is just pressed
+    pub action: A,
+}
+
+/// A [`Plugin`] that bridges an action of your own, such as a "Submit" action bound to a gamepad's confirm button,
+/// into mouse-centric UI logic, by clicking the [`Focused`] UI node when that action is just pressed
+///
+/// This inserts a [`ClickFocused<A>`] resource, and adds the [`click_focused`] system in [`CoreStage::PreUpdate`],
+/// after the `A` actions are updated and after `bevy_ui` has updated the [`Interaction`] of each node.
+/// The [`InputManagerPlugin<A>`] must be added separately.
+///
+/// The [`MenuNavigationPlugin`] already does this for [`MenuNavigation::Select`].
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::menu_navigation::ClickFocusedPlugin;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Submit,
+///     Cancel,
+/// }
+///
+/// App::new()
+///     .add_plugins(MinimalPlugins)
+///     .add_plugin(InputManagerPlugin::<Action>::default())
+///     .add_plugin(ClickFocusedPlugin::new(Action::Submit));
+/// ```
+pub struct ClickFocusedPlugin<A: Actionlike> {
+    action: A,
+}
+
+impl<A: Actionlike> ClickFocusedPlugin<A> {
+    /// Creates a plugin where the `action` clicks the [`Focused`] node
+    #[must_use]
+    pub fn new(action: A) -> Self {
+        Self { action }
+    }
+}
+
+impl<A: Actionlike> Plugin for ClickFocusedPlugin<A> {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(ClickFocused {
+            action: self.action.clone(),
+        })
+        .add_system_to_stage(
+            CoreStage::PreUpdate,
+            click_focused::<A>
+                .after(InputManagerSystemFor::<A>::new(
+                    InputManagerSystem::ManualControl,
+                ))
+                .after(UiSystem::Focus),
+        );
+    }
+}
+
+/// Sets the [`Interaction`] of the [`Focused`] node to [`Interaction::Clicked`] when the action of the [`ClickFocused<A>`] resource is just pressed
+///
+/// The action is read from the [`ActionState<A>`] of the single player, as described by [`Actions`].
+/// Like [`activate_focused`], the node is returned to [`Interaction::None`] on the following frame.
+pub fn click_focused<A: Actionlike>(
+    click_focused: Res<ClickFocused<A>>,
+    actions: Actions<A, ()>,
+    mut interaction_query: Query<&mut Interaction>,
+    focused_query: Query<Entity, (With<Focused>, With<Interaction>)>,
+    mut activated: Local<Option<Entity>>,
+) {
+    let clicked = actions.get_single().map_or(false, |action_state| {
+        action_state.just_pressed(click_focused.action.clone())
+    });
+
+    click_focused_node(
+        clicked,
+        &mut interaction_query,
+        &focused_query,
+        &mut activated,
+    );
+}
+
+/// Returns the node clicked on the previous frame to [`Interaction::None`], then clicks the [`Focused`] node if `clicked`
+fn click_focused_node(
+    clicked: bool,
+    interaction_query: &mut Query<&mut Interaction>,
+    focused_query: &Query<Entity, (With<Focused>, With<Interaction>)>,
+    activated: &mut Option<Entity>,
+) {
+    if let Some(entity) = activated.take() {
+        if let Ok(mut interaction) = interaction_query.get_mut(entity) {
+            if *interaction == Interaction::Clicked {
+                *interaction = Interaction::None;
+            }
+        }
+    }
+
+    if clicked {
+        for entity in focused_query.iter() {
+            if let Ok(mut interaction) = interaction_query.get_mut(entity) {
+                *interaction = Interaction::Clicked;
+                *activated = Some(entity);
+            }
+        }
+    }
+}
@@ -0,0 +1,321 @@
+//! This module contains [`MotionControls`] and its supporting methods and impls.
+//!
+//! The [`MotionControls`] is an optional addition to an [`InputManagerBundle`](crate::InputManagerBundle),
+//! which feeds the gyroscope and accelerometer of a gamepad into the axis pairs of actions,
+//! as is increasingly expected for aiming in shooters.
+//!
+//! Bevy does not read motion sensors itself, so the [`GamepadMotion`] resource must be filled in by the backend that does,
+//! such as an SDL or HID integration for Nintendo and Sony controllers.
+
+use bevy::ecs::prelude::*;
+use bevy::input::gamepad::Gamepad;
+use bevy::math::{Vec2, Vec3};
+use bevy::utils::{Duration, HashMap};
+
+use crate::action_state::ActionState;
+use crate::axislike::DualAxisData;
+use crate::Actionlike;
+
+/// A single reading of the motion sensors of a gamepad
+///
+/// Both vectors are in the space of the gamepad, held flat in front of the player:
+/// `x` points to the right, `y` points up and `z` points towards the player.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MotionSample {
+    /// The angular velocity reported by the gyroscope, in radians per second, following the right-hand rule around each axis
+    ///
+    /// Tilting the gamepad up is a positive `x`, and turning it to the left is a positive `y`.
+    pub gyro: Vec3,
+    /// The acceleration reported by the accelerometer, in multiples of the standard gravity
+    ///
+    /// At rest, this is dominated by gravity, and so measures how the gamepad is tilted.
+    pub accelerometer: Vec3,
+}
+
+/// The latest [`MotionSample`] of each gamepad with motion sensors
+///
+/// This should be updated once per frame by the backend that reads the motion sensors,
+/// before [`InputManagerSystem::ManualControl`](crate::plugin::InputManagerSystem::ManualControl).
+/// Gamepads without an entry are treated as if they had no motion sensors.
+#[derive(Resource, Debug, Clone, PartialEq, Default)]
+pub struct GamepadMotion {
+    samples: HashMap<Gamepad, MotionSample>,
+}
+
+impl GamepadMotion {
+    /// Records the latest `sample` of the `gamepad`
+    pub fn set(&mut self, gamepad: Gamepad, sample: MotionSample) {
+        self.samples.insert(gamepad, sample);
+    }
+
+    /// Forgets the motion sensors of the `gamepad`, such as when it is disconnected
+    pub fn remove(&mut self, gamepad: Gamepad) {
+        self.samples.remove(&gamepad);
+    }
+
+    /// The latest sample of the `gamepad`, if it has motion sensors
+    #[must_use]
+    pub fn get(&self, gamepad: Gamepad) -> Option<MotionSample> {
+        self.samples.get(&gamepad).copied()
+    }
+
+    /// The latest sample of the `gamepad`, or of the gamepad with the lowest id if this is [`None`]
+    #[must_use]
+    pub fn sample(&self, gamepad: Option<Gamepad>) -> Option<MotionSample> {
+        match gamepad {
+            Some(gamepad) => self.get(gamepad),
+            None => self
+                .samples
+                .iter()
+                .min_by_key(|(gamepad, _)| gamepad.id)
+                .map(|(_, sample)| *sample),
+        }
+    }
+}
+
+/// Which motion sensor a [`MotionBinding`] reads
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MotionSensor {
+    /// The rotation of the gamepad since the previous update, for pointing and aiming
+    ///
+    /// Turning the gamepad to the right is a positive `x`, and tilting it up is a positive `y`, in radians.
+    /// Like mouse motion, this is a change in position, and should be added to the current aim.
+    Gyroscope,
+    /// How far the gamepad is tilted, for steering and balancing
+    ///
+    /// Rolling the gamepad to the right is a positive `x`, and tilting it away from the player is a positive `y`,
+    /// both between `-1.0` and `1.0`.
+    Accelerometer,
+}
+
+/// Feeds a motion sensor into the axis pair of an action
+#[derive(Debug, Clone, PartialEq)]
+pub struct MotionBinding<A: Actionlike> {
+    /// The action whose axis pair is fed by the [`MotionBinding::sensor`]
+    pub action: A,
+    /// The motion sensor that is read
+    pub sensor: MotionSensor,
+    /// The factors applied to the `x` and `y` of the motion
+    ///
+    /// Use a negative factor to invert an axis.
+    pub sensitivity: Vec2,
+    /// The action that must be pressed for the motion to be read, if any
+    ///
+    /// This enables "gyro only while aiming", where the gamepad only moves the camera while the aim button is held.
+    pub gate: Option<A>,
+}
+
+impl<A: Actionlike> MotionBinding<A> {
+    /// Creates a [`MotionBinding`] that feeds the `sensor` into the axis pair of `action`, with a sensitivity of `1.0`
+    #[must_use]
+    pub fn new(sensor: MotionSensor, action: A) -> Self {
+        Self {
+            action,
+            sensor,
+            sensitivity: Vec2::ONE,
+            gate: None,
+        }
+    }
+
+    /// Sets the factors applied to the `x` and `y` of the motion
+    #[must_use]
+    pub fn with_sensitivity(mut self, sensitivity: Vec2) -> Self {
+        self.sensitivity = sensitivity;
+        self
+    }
+
+    /// Only reads the motion while the `gate` action is pressed
+    #[must_use]
+    pub fn only_while(mut self, gate: A) -> Self {
+        self.gate = Some(gate);
+        self
+    }
+
+    /// The motion read from the `sample`, after `delta` has passed, with the sensitivity applied
+    #[must_use]
+    pub fn motion(&self, sample: MotionSample, delta: Duration) -> Vec2 {
+        let motion = match self.sensor {
+            MotionSensor::Gyroscope => {
+                Vec2::new(-sample.gyro.y, sample.gyro.x) * delta.as_secs_f32()
+            }
+            MotionSensor::Accelerometer => {
+                let tilt = Vec2::new(sample.accelerometer.x, -sample.accelerometer.z);
+                tilt.clamp(Vec2::NEG_ONE, Vec2::ONE)
+            }
+        };
+        motion * self.sensitivity
+    }
+}
+
+/// Feeds the motion sensors of a gamepad into the axis pairs of actions
+///
+/// As a resource, this affects the [`ActionState`] resource,
+/// and as a component, the [`ActionState`] of the same entity.
+/// The sensors of the [gamepad of the matching `InputMap`](crate::input_map::InputMap::gamepad) are read,
+/// or of the gamepad with the lowest id if there isn't one.
+/// This is applied by [`apply_motion_controls`](crate::systems::apply_motion_controls),
+/// as part of [`InputManagerSystem::ManualControl`](crate::plugin::InputManagerSystem::ManualControl),
+/// from the [`GamepadMotion`] resource.
+///
+/// The motion is added to the axis pair of each action, so that the same action can be bound to a stick as well.
+/// Actions are pressed while they are moved by a motion sensor.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::motion_controls::{MotionBinding, MotionControls, MotionSensor};
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Look,
+///     Aim,
+/// }
+///
+/// let mut world = World::new();
+/// world
+///     .spawn(InputManagerBundle {
+///         input_map: InputMap::new([
+///             (UserInput::from(DualAxis::right_stick()), Action::Look),
+///             (UserInput::from(GamepadButtonType::LeftTrigger2), Action::Aim),
+///         ]),
+///         ..Default::default()
+///     })
+///     .insert(MotionControls::new([MotionBinding::new(
+///         MotionSensor::Gyroscope,
+///         Action::Look,
+///     )
+///     .with_sensitivity(Vec2::splat(2.0))
+///     .only_while(Action::Aim)]));
+/// ```
+#[derive(Resource, Component, Debug, Clone, PartialEq)]
+pub struct MotionControls<A: Actionlike> {
+    /// The bindings, which are applied independently of each other
+    pub bindings: Vec<MotionBinding<A>>,
+}
+
+impl<A: Actionlike> Default for MotionControls<A> {
+    fn default() -> Self {
+        Self {
+            bindings: Vec::new(),
+        }
+    }
+}
+
+impl<A: Actionlike> MotionControls<A> {
+    /// Creates [`MotionControls`] from the provided `bindings`
+    #[must_use]
+    pub fn new(bindings: impl IntoIterator<Item = MotionBinding<A>>) -> Self {
+        Self {
+            bindings: bindings.into_iter().collect(),
+        }
+    }
+
+    /// Adds a [`MotionBinding`]
+    #[must_use]
+    pub fn with(mut self, binding: MotionBinding<A>) -> Self {
+        self.bindings.push(binding);
+        self
+    }
+
+    /// Adds the motion of the `sample` to the axis pairs of the `action_state`, after `delta` has passed
+    pub fn apply(&self, action_state: &mut ActionState<A>, sample: MotionSample, delta: Duration) {
+        for binding in &self.bindings {
+            if let Some(gate) = &binding.gate {
+                if !action_state.pressed(gate.clone()) {
+                    continue;
+                }
+            }
+
+            let motion = binding.motion(sample, delta);
+            if motion == Vec2::ZERO {
+                continue;
+            }
+
+            let action_data = action_state.action_data_mut(binding.action.clone());
+            let motion = DualAxisData::from_xy(motion);
+            action_data.axis_pair = Some(match action_data.axis_pair {
+                Some(axis_pair) => axis_pair.merged_with(motion),
+                None => motion,
+            });
+            action_state.press(binding.action.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as leafwing_input_manager;
+
+    #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    enum Action {
+        Look,
+        Steer,
+        Aim,
+    }
+
+    const FRAME: Duration = Duration::from_millis(500);
+
+    #[test]
+    fn gyro_moves_the_axis_pair_while_aiming() {
+        let controls =
+            MotionControls::new([MotionBinding::new(MotionSensor::Gyroscope, Action::Look)
+                .with_sensitivity(Vec2::new(2.0, 1.0))
+                .only_while(Action::Aim)]);
+        // Turning to the right, and tilting up
+        let sample = MotionSample {
+            gyro: Vec3::new(1.0, -1.0, 0.0),
+            ..Default::default()
+        };
+
+        let mut action_state = ActionState::<Action>::default();
+        controls.apply(&mut action_state, sample, FRAME);
+        assert!(action_state.released(Action::Look));
+        assert_eq!(action_state.axis_pair(Action::Look), None);
+
+        action_state.press(Action::Aim);
+        controls.apply(&mut action_state, sample, FRAME);
+        assert!(action_state.pressed(Action::Look));
+        assert_eq!(
+            action_state.axis_pair(Action::Look),
+            Some(DualAxisData::new(1.0, 0.5))
+        );
+    }
+
+    #[test]
+    fn accelerometer_reports_tilt() {
+        let controls = MotionControls::default().with(MotionBinding::new(
+            MotionSensor::Accelerometer,
+            Action::Steer,
+        ));
+        // Rolled to the right, with gravity pulling along `+x`
+        let sample = MotionSample {
+            accelerometer: Vec3::new(2.0, -0.5, 0.0),
+            ..Default::default()
+        };
+
+        let mut action_state = ActionState::<Action>::default();
+        controls.apply(&mut action_state, sample, FRAME);
+        assert_eq!(
+            action_state.axis_pair(Action::Steer),
+            Some(DualAxisData::new(1.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn samples_are_read_from_the_associated_gamepad() {
+        let mut gamepad_motion = GamepadMotion::default();
+        let sample = MotionSample {
+            gyro: Vec3::X,
+            ..Default::default()
+        };
+        gamepad_motion.set(Gamepad { id: 3 }, sample);
+        gamepad_motion.set(Gamepad { id: 1 }, MotionSample::default());
+
+        assert_eq!(gamepad_motion.sample(Some(Gamepad { id: 3 })), Some(sample));
+        assert_eq!(gamepad_motion.sample(None), Some(MotionSample::default()));
+        gamepad_motion.remove(Gamepad { id: 3 });
+        assert_eq!(gamepad_motion.sample(Some(Gamepad { id: 3 })), None);
+    }
+}
@@ -0,0 +1,390 @@
+//! This module contains [`PlatformBindings`] and its supporting methods and impls.
+//!
+//! The [`PlatformBindings`] is an optional addition to an [`InputManagerBundle`](crate::InputManagerBundle),
+//! which stores a separate default [`InputMap`] for each [`InputDevice`] (keyboard, mouse, gamepad and touch).
+//! The maps of the active devices are combined into the [`InputMap`] of the entity,
+//! so that players only see the bindings of the devices they actually have.
+//! The active control scheme can also follow the device that was used last,
+//! sending a [`SchemeChanged`] event so that button prompts can be updated.
+
+use bevy::ecs::prelude::*;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+use crate::inhibit_input::InputDevice;
+use crate::input_map::{DefaultBindings, InputMap};
+use crate::Actionlike;
+
+/// Default [`InputMap`]s for each [`InputDevice`], combined according to the active devices
+///
+/// How the active devices are chosen is controlled by the [`SchemeSelection`].
+/// By default, they are selected automatically using [`InputDevice::detect`],
+/// and are updated whenever a gamepad is connected or disconnected.
+/// Use [`PlatformBindings::set_active_devices`] to choose them manually instead, for example from a settings menu.
+///
+/// Whenever these bindings or the active devices change,
+/// the [`InputMap`] of the same entity is replaced by [`PlatformBindings::input_map`].
+/// The associated gamepad, associated window and [`BindingPolicy`](crate::input_map::BindingPolicy) of that map are preserved.
+///
+/// This is applied by [`apply_platform_bindings`](crate::systems::apply_platform_bindings),
+/// before [`InputManagerSystem::Update`](crate::plugin::InputManagerSystem::Update).
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::inhibit_input::InputDevice;
+/// use leafwing_input_manager::platform_bindings::PlatformBindings;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Jump,
+/// }
+///
+/// let platform_bindings = PlatformBindings::default()
+///     .with_device(
+///         InputDevice::Keyboard,
+///         InputMap::new([(KeyCode::Space, Action::Jump)]),
+///     )
+///     .with_device(
+///         InputDevice::Gamepad,
+///         InputMap::new([(GamepadButtonType::South, Action::Jump)]),
+///     );
+///
+/// let input_map = platform_bindings.input_map(&[InputDevice::Keyboard, InputDevice::Mouse]);
+/// assert_eq!(input_map, InputMap::new([(KeyCode::Space, Action::Jump)]));
+///
+/// let mut world = World::new();
+/// world
+///     .spawn(InputManagerBundle::<Action>::default())
+///     .insert(platform_bindings);
+/// ```
+#[derive(Resource, Component, Debug, Clone, PartialEq)]
+pub struct PlatformBindings<A: Actionlike> {
+    /// The default bindings of each device
+    maps: HashMap<InputDevice, InputMap<A>>,
+    /// How the active devices are chosen
+    selection: SchemeSelection,
+    /// The device that was used last, if the devices used with it have any bindings
+    last_used: Option<InputDevice>,
+}
+
+impl<A: Actionlike> Default for PlatformBindings<A> {
+    fn default() -> Self {
+        Self {
+            maps: HashMap::default(),
+            selection: SchemeSelection::default(),
+            last_used: None,
+        }
+    }
+}
+
+/// How the active devices of [`PlatformBindings`] are chosen
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum SchemeSelection {
+    /// Combines the bindings of every device returned by [`InputDevice::detect`]
+    #[default]
+    Detected,
+    /// Only uses the bindings of the devices [used with](InputDevice::used_with) the device that was used last,
+    /// switching whenever the player picks up another device
+    ///
+    /// Until a device is used, the devices used with the first detected device with bindings are active.
+    /// A [`SchemeChanged`] event is sent whenever the active devices switch.
+    LastUsed,
+    /// Only uses the bindings of the chosen devices
+    Manual(Vec<InputDevice>),
+}
+
+/// Sent when the player switches to a device that is not [used with](InputDevice::used_with) the previous one,
+/// under [`SchemeSelection::LastUsed`]
+///
+/// Use this to update button prompts and other input-specific UI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemeChanged<A: Actionlike> {
+    /// The entity whose [`PlatformBindings`] switched devices, or [`None`] for the [`PlatformBindings`] resource
+    pub entity: Option<Entity>,
+    /// The previously used device, or [`None`] if no device had been used before
+    pub previous: Option<InputDevice>,
+    /// The newly used device
+    pub current: InputDevice,
+    /// Marker that stores the type of action whose bindings switched
+    pub phantom: PhantomData<A>,
+}
+
+impl<A: DefaultBindings> PlatformBindings<A> {
+    /// Creates platform bindings from the [`DefaultBindings::device_input_map`] of each device
+    ///
+    /// Devices without any default bindings are skipped.
+    #[must_use]
+    pub fn from_defaults() -> Self {
+        let mut platform_bindings = Self::default();
+        for device in InputDevice::ALL {
+            let input_map = A::device_input_map(device);
+            if !input_map.is_empty() {
+                platform_bindings.insert(device, input_map);
+            }
+        }
+        platform_bindings
+    }
+}
+
+impl<A: Actionlike> PlatformBindings<A> {
+    /// Uses the `input_map` as the default bindings of the `device`
+    #[must_use]
+    pub fn with_device(mut self, device: InputDevice, input_map: InputMap<A>) -> Self {
+        self.insert(device, input_map);
+        self
+    }
+
+    /// Uses the `input_map` as the default bindings of the `device`, returning the previous bindings if any
+    pub fn insert(&mut self, device: InputDevice, input_map: InputMap<A>) -> Option<InputMap<A>> {
+        self.maps.insert(device, input_map)
+    }
+
+    /// The default bindings of the `device`, if any
+    #[must_use]
+    pub fn get(&self, device: InputDevice) -> Option<&InputMap<A>> {
+        self.maps.get(&device)
+    }
+
+    /// A mutable reference to the default bindings of the `device`, if any
+    #[must_use]
+    pub fn get_mut(&mut self, device: InputDevice) -> Option<&mut InputMap<A>> {
+        self.maps.get_mut(&device)
+    }
+
+    /// Chooses the active devices using the `selection`
+    #[must_use]
+    pub fn with_selection(mut self, selection: SchemeSelection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// How the active devices are chosen
+    #[must_use]
+    pub fn selection(&self) -> &SchemeSelection {
+        &self.selection
+    }
+
+    /// Chooses the active devices using the `selection`
+    pub fn set_selection(&mut self, selection: SchemeSelection) {
+        self.selection = selection;
+    }
+
+    /// Manually selects the devices whose bindings are combined
+    pub fn set_active_devices(&mut self, devices: impl IntoIterator<Item = InputDevice>) {
+        self.selection = SchemeSelection::Manual(devices.into_iter().collect());
+    }
+
+    /// Goes back to selecting the active devices automatically, based on the detected devices
+    pub fn select_automatically(&mut self) {
+        self.selection = SchemeSelection::Detected;
+    }
+
+    /// Are the active devices selected automatically?
+    ///
+    /// This is true for every [`SchemeSelection`] except [`SchemeSelection::Manual`].
+    #[must_use]
+    pub fn is_automatic(&self) -> bool {
+        !matches!(self.selection, SchemeSelection::Manual(_))
+    }
+
+    /// Do any of the devices [used with](InputDevice::used_with) the `device` have bindings?
+    fn has_bindings_for(&self, device: InputDevice) -> bool {
+        device
+            .used_with()
+            .iter()
+            .any(|device| self.maps.contains_key(device))
+    }
+
+    /// The active devices, given the devices that were `detected`
+    #[must_use]
+    pub fn active_devices<'a>(&'a self, detected: &'a [InputDevice]) -> &'a [InputDevice] {
+        match &self.selection {
+            SchemeSelection::Detected => detected,
+            SchemeSelection::LastUsed => self
+                .last_used
+                .or_else(|| {
+                    detected
+                        .iter()
+                        .copied()
+                        .find(|&device| self.has_bindings_for(device))
+                })
+                .map_or(&[][..], InputDevice::used_with),
+            SchemeSelection::Manual(devices) => devices,
+        }
+    }
+
+    /// The device that was used last, as recorded by [`PlatformBindings::use_device`]
+    #[must_use]
+    pub fn last_used(&self) -> Option<InputDevice> {
+        self.last_used
+    }
+
+    /// Would [`PlatformBindings::use_device`] switch the active devices?
+    ///
+    /// This is only the case for [`SchemeSelection::LastUsed`], when the devices used with the `device` have bindings
+    /// and the `device` is not [used with](InputDevice::used_with) the device that was used last.
+    #[must_use]
+    pub fn switches_to(&self, device: InputDevice) -> bool {
+        self.selection == SchemeSelection::LastUsed
+            && !self
+                .last_used
+                .map_or(false, |last_used| last_used.used_with().contains(&device))
+            && self.has_bindings_for(device)
+    }
+
+    /// Records that the player used the `device`
+    ///
+    /// Returns the [`SchemeChanged`] event to send if this switched the active devices.
+    /// Its `entity` is always [`None`].
+    pub fn use_device(&mut self, device: InputDevice) -> Option<SchemeChanged<A>> {
+        if !self.switches_to(device) {
+            return None;
+        }
+
+        let previous = self.last_used.replace(device);
+        Some(SchemeChanged {
+            entity: None,
+            previous,
+            current: device,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Combines the bindings of every active device, given the devices that were `detected`
+    #[must_use]
+    pub fn input_map(&self, detected: &[InputDevice]) -> InputMap<A> {
+        let mut input_map = InputMap::default();
+        for device in self.active_devices(detected) {
+            if let Some(device_map) = self.maps.get(device) {
+                input_map.merge(device_map);
+            }
+        }
+        input_map
+    }
+
+    /// Replaces the bindings of the `input_map` with those of the active devices
+    ///
+    /// The associated gamepad, associated window and [`BindingPolicy`](crate::input_map::BindingPolicy) of the `input_map` are preserved.
+    pub fn apply(&self, input_map: &mut InputMap<A>, detected: &[InputDevice]) {
+        input_map.replace_bindings(self.input_map(detected));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PlatformBindings, SchemeChanged, SchemeSelection};
+    use crate as leafwing_input_manager;
+    use crate::inhibit_input::InputDevice;
+    use crate::input_map::{DefaultBindings, InputMap};
+    use crate::user_input::InputKind;
+    use crate::Actionlike;
+    use bevy::input::gamepad::{Gamepad, GamepadButtonType};
+    use bevy::input::keyboard::KeyCode;
+    use bevy::input::mouse::MouseButton;
+
+    #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    enum Action {
+        Jump,
+        Pause,
+        Shoot,
+    }
+
+    impl DefaultBindings for Action {
+        fn default_input_map() -> InputMap<Action> {
+            InputMap::new([
+                (InputKind::Keyboard(KeyCode::Space), Action::Jump),
+                (
+                    InputKind::GamepadButton(GamepadButtonType::South),
+                    Action::Jump,
+                ),
+                (InputKind::Keyboard(KeyCode::Escape), Action::Pause),
+                (InputKind::Mouse(MouseButton::Left), Action::Shoot),
+            ])
+        }
+    }
+
+    #[test]
+    fn devices_are_combined() {
+        let mut platform_bindings = PlatformBindings::<Action>::from_defaults();
+        assert_eq!(
+            platform_bindings.get(InputDevice::Gamepad),
+            Some(&InputMap::new([(GamepadButtonType::South, Action::Jump)]))
+        );
+        assert_eq!(
+            platform_bindings.get(InputDevice::Mouse),
+            Some(&InputMap::new([(MouseButton::Left, Action::Shoot)]))
+        );
+        assert_eq!(platform_bindings.get(InputDevice::Touch), None);
+
+        let detected = [
+            InputDevice::Keyboard,
+            InputDevice::Mouse,
+            InputDevice::Gamepad,
+        ];
+        assert!(platform_bindings.is_automatic());
+        assert_eq!(
+            platform_bindings.input_map(&detected),
+            Action::default_input_map()
+        );
+
+        let mut input_map = InputMap::default();
+        input_map.set_gamepad(Gamepad { id: 1 });
+        platform_bindings.set_active_devices([InputDevice::Gamepad]);
+        platform_bindings.apply(&mut input_map, &detected);
+
+        assert_eq!(
+            platform_bindings.active_devices(&detected),
+            [InputDevice::Gamepad]
+        );
+        assert_eq!(input_map.get(Action::Jump).len(), 1);
+        assert!(input_map.get(Action::Pause).is_empty());
+        assert_eq!(input_map.gamepad(), Some(Gamepad { id: 1 }));
+    }
+
+    #[test]
+    fn last_used_device_is_active() {
+        let mut platform_bindings =
+            PlatformBindings::<Action>::from_defaults().with_selection(SchemeSelection::LastUsed);
+        let detected = [
+            InputDevice::Keyboard,
+            InputDevice::Mouse,
+            InputDevice::Gamepad,
+        ];
+        assert_eq!(
+            platform_bindings.active_devices(&detected),
+            [InputDevice::Keyboard, InputDevice::Mouse]
+        );
+
+        assert_eq!(
+            platform_bindings.use_device(InputDevice::Gamepad),
+            Some(SchemeChanged {
+                entity: None,
+                previous: None,
+                current: InputDevice::Gamepad,
+                phantom: Default::default(),
+            })
+        );
+        assert_eq!(
+            platform_bindings.active_devices(&detected),
+            [InputDevice::Gamepad]
+        );
+        assert_eq!(platform_bindings.use_device(InputDevice::Gamepad), None);
+        // Devices without bindings are never switched to
+        assert_eq!(platform_bindings.use_device(InputDevice::Touch), None);
+
+        let changed = platform_bindings.use_device(InputDevice::Keyboard).unwrap();
+        assert_eq!(changed.previous, Some(InputDevice::Gamepad));
+        // The keyboard and mouse are used together, so switching between them changes nothing
+        assert_eq!(platform_bindings.use_device(InputDevice::Mouse), None);
+        assert_eq!(
+            platform_bindings.input_map(&detected),
+            InputMap::new([
+                (InputKind::Keyboard(KeyCode::Space), Action::Jump),
+                (InputKind::Keyboard(KeyCode::Escape), Action::Pause),
+                (InputKind::Mouse(MouseButton::Left), Action::Shoot),
+            ])
+        );
+    }
+}
@@ -1,17 +1,32 @@
 //! Contains main plugin exported by this crate.
 
+use crate::activity::{send_input_idle_events, update_input_activity, InputActivity, InputIdle};
+use crate::axislike::MouseWheelSettings;
+use crate::buttonlike::GamepadButtonSettings;
 use crate::clashing_inputs::ClashStrategy;
+use crate::errors::InputManagerError;
+use crate::haptics::{GamepadHaptics, HapticRequest};
+use crate::inhibit_input::{InhibitInput, InputDevice};
+use crate::platform_bindings::SchemeChanged;
 use crate::prelude::ActionState;
+use crate::value_thresholds::ValueThresholdCrossed;
 use crate::Actionlike;
+use core::any::{type_name, TypeId};
 use core::hash::Hash;
 use core::marker::PhantomData;
 use std::fmt::Debug;
 
 use bevy::app::{App, CoreStage, Plugin};
 use bevy::ecs::prelude::*;
+use bevy::ecs::schedule::{SystemDescriptor, SystemLabelId};
 use bevy::input::InputSystem;
+use bevy::log::warn;
+use bevy::time::Time;
+use bevy::transform::TransformSystem;
 #[cfg(feature = "ui")]
 use bevy::ui::UiSystem;
+use bevy::utils::{Duration, Instant};
+use serde::{Deserialize, Serialize};
 
 /// A [`Plugin`] that collects [`Input`](bevy::input::Input) from disparate sources, producing an [`ActionState`](crate::action_state::ActionState) that can be conveniently checked
 ///
@@ -35,112 +50,620 @@ use bevy::ui::UiSystem;
 /// you must define an ordering between your systems or behavior will be very erratic.
 /// The stable labels for these systems are available under [`InputManagerSystem`] enum.
 ///
+/// ## Frame consistency
+///
+/// [`ActionState`]s are only ticked and updated by this plugin during [`CoreStage::PreUpdate`],
+/// so every system in [`CoreStage::Update`] and [`CoreStage::PostUpdate`] sees the same [`ActionState`] within a frame
+/// (unless your own systems modify it).
+///
+/// Consumers that want end-of-frame input instead, such as a camera updated just before rendering,
+/// can opt in with [`InputManagerConfig::with_late_update`].
+/// The [`ActionState`]s of that action type are then updated a second time during [`CoreStage::PostUpdate`],
+/// taking into account any inputs that were sent (or presses that were scheduled) during [`CoreStage::Update`].
+/// Systems reading them should run after [`InputManagerSystem::LateUpdate`].
+///
+/// Mouse look only needs fresh mouse motion, and can opt in with [`InputManagerConfig::with_late_mouse_sampling`] instead,
+/// which patches the axis pairs of mouse motion actions without pressing or releasing anything.
+///
+/// ## Multiple action types
+///
+/// Setup that is shared between action types (such as the [`ClashStrategy`] resource) is only performed once,
+/// no matter how many copies of this plugin are added.
+///
+/// Adding this plugin more than once for the same action type (such as from two sub-plugins) has no further effect:
+/// the extra copies are ignored with a warning naming the action type, as described by [`InputManagerError::DuplicatePlugin`].
+/// Their [`InputManagerConfig`] is ignored too.
+/// To make only some actions available in each state of your app, add a [`StateScopedActionsPlugin`](crate::state_scoped_actions::StateScopedActionsPlugin) instead.
+///
+/// Systems of the same [`InputManagerSystem`] phase run in the order in which their plugins were added:
+/// the systems for each action type run after the corresponding systems of the previously added action type.
+/// To order your own systems relative to a single action type, use [`InputManagerSystemFor`].
+///
 /// Complete list:
 ///
 /// - [`tick_action_state`](crate::systems::tick_action_state), which resets the `pressed` and `just_pressed` fields of the [`ActionState`](crate::action_state::ActionState) each frame
+///     - durations are frozen while [`PauseTiming`] is paused, and otherwise follow the [`TimeSource`]
 ///     - labeled [`InputManagerSystem::Reset`]
+/// - [`switch_control_schemes`](crate::systems::switch_control_schemes), which records the last-used [`InputDevice`](crate::inhibit_input::InputDevice) in [`PlatformBindings`](crate::platform_bindings::PlatformBindings)
+///     - sends [`SchemeChanged`](crate::platform_bindings::SchemeChanged) events when the active control scheme switches
+/// - [`apply_platform_bindings`](crate::systems::apply_platform_bindings), which rebuilds the [`InputMap`](crate::input_map::InputMap) from the active control schemes of [`PlatformBindings`](crate::platform_bindings::PlatformBindings)
+///     - runs before [`InputManagerSystem::Update`]
+/// - [`capture_text_input`](crate::systems::capture_text_input), which appends typed characters to the [`TextInputBuffer`](crate::text_input::TextInputBuffer) while it is active
+///     - runs before [`InputManagerSystem::Update`]
+/// - [`capture_binding`](crate::systems::capture_binding), which records the next pressed input in the [`BindingCapture`](crate::binding_capture::BindingCapture) while it is active
+///     - runs before [`InputManagerSystem::Update`]
+/// - [`update_dynamic_actions`](crate::systems::update_dynamic_actions), which presses the runtime-defined actions of [`DynamicActions`](crate::dynamic_actions::DynamicActions)
+///     - runs before [`InputManagerSystem::Update`]
+/// - [`update_touchpad_inputs`](crate::systems::update_touchpad_inputs), which writes the [`GamepadTouchpads`](crate::touchpad::GamepadTouchpads) into the gamepad inputs
+///     - only with the `touchpad` feature
+///     - runs before [`InputManagerSystem::Update`]
 /// - [`update_action_state`](crate::systems::update_action_state), which collects [`Input`](bevy::input::Input) resources to update the [`ActionState`](crate::action_state::ActionState)
+///     - holds back and combines simultaneous presses according to [`ComboButtons`](crate::combo_buttons::ComboButtons)
+///     - forwards presses between actions according to [`ActionAliases`](crate::action_aliases::ActionAliases)
 ///     - labeled [`InputManagerSystem::Update`]
-/// - [`update_action_state_from_interaction`](crate::systems::update_action_state_from_interaction), for triggering actions from buttons
-///    - powers the [`ActionStateDriver`](crate::action_state::ActionStateDriver) component baseod on an [`Interaction`](bevy::ui::Interaction) component
-///    - labeled [`InputManagerSystem::Update`]
+/// - the [`InputManagerSystem::ManualControl`] systems, which run one after another in this order:
+///     - [`update_action_state_from_interaction`](crate::systems::update_action_state_from_interaction), for triggering actions from buttons
+///         - powers the [`ActionStateDriver`](crate::action_state::ActionStateDriver) and [`ActionStateDrivers`](crate::action_state::ActionStateDrivers) components based on an [`Interaction`](bevy::ui::Interaction) component
+///         - only with the `ui` feature
+///     - [`apply_virtual_controller`](crate::systems::apply_virtual_controller), which presses and releases actions over time according to [`VirtualController`](crate::virtual_controller::VirtualController)
+///     - [`apply_key_sequences`](crate::systems::apply_key_sequences), which presses the actions of typed [`KeySequences`](crate::key_sequences::KeySequences)
+///     - [`apply_stick_flicks`](crate::systems::apply_stick_flicks), which presses actions when sticks are flicked according to [`StickFlicks`](crate::stick_flicks::StickFlicks)
+///     - [`apply_motion_controls`](crate::systems::apply_motion_controls), which feeds gyroscopes and accelerometers into actions according to [`MotionControls`](crate::motion_controls::MotionControls)
+///     - [`apply_directional_repeat`](crate::systems::apply_directional_repeat), which repeats and filters directional actions according to [`DirectionalRepeat`](crate::directional_repeat::DirectionalRepeat)
+///     - [`apply_exclusive_actions`](crate::systems::apply_exclusive_actions), which arbitrates between mutually exclusive actions according to [`ExclusiveActions`](crate::exclusive_actions::ExclusiveActions)
+///     - [`apply_axis_ramp`](crate::systems::apply_axis_ramp), which ramps the values of actions over time according to [`AxisRamp`](crate::axis_ramp::AxisRamp)
+///
+///     Systems that press actions run first, so that directional repeat and exclusive actions see every press,
+///     and axis ramping smooths the final values.
+/// - [`update_action_state`](crate::systems::update_action_state) again during [`CoreStage::PostUpdate`], if [`InputManagerConfig::with_late_update`] was used
+///     - labeled [`InputManagerSystem::LateUpdate`]
+/// - [`resample_mouse_motion`](crate::systems::resample_mouse_motion) during [`CoreStage::PostUpdate`], if [`InputManagerConfig::with_late_mouse_sampling`] was used
+///     - labeled [`InputManagerSystem::LateMouseSampling`]
 /// - [`release_on_disable`](crate::systems::release_on_disable), which resets action states when [`ToggleActions`] is flipped, to avoid persistent presses.
+/// - [`update_input_activity`], which records whether any actions were active in the [`InputActivity`] resource
+///     - labeled [`InputManagerSystem::Activity`]
+/// - [`send_input_idle_events`], which sends [`InputIdle`] events once the [`InputActivity::idle_timeout`] has elapsed
+/// - [`send_value_threshold_events`](crate::systems::send_value_threshold_events), which sends [`ValueThresholdCrossed`] events according to [`ValueThresholds`](crate::value_thresholds::ValueThresholds)
+///     - labeled [`InputManagerSystem::Activity`]
+/// - [`update_action_usage`](crate::systems::update_action_usage), which records the triggered actions in [`ActionUsage`](crate::action_usage::ActionUsage)
+///     - labeled [`InputManagerSystem::Activity`]
+/// - [`update_action_statistics`](crate::systems::update_action_statistics), which counts presses and hold times in [`ActionStatistics`](crate::action_statistics::ActionStatistics)
+///     - labeled [`InputManagerSystem::Activity`]
+/// - [`update_input_log`](crate::systems::update_input_log), which records presses and releases in [`InputLog`](crate::input_log::InputLog)
+///     - labeled [`InputManagerSystem::Activity`]
 pub struct InputManagerPlugin<A: Actionlike> {
     _phantom: PhantomData<A>,
-    machine: Machine,
+    config: InputManagerConfig,
 }
 
 // Deriving default induces an undesired bound on the generic
 impl<A: Actionlike> Default for InputManagerPlugin<A> {
     fn default() -> Self {
+        Self::new(InputManagerConfig::default())
+    }
+}
+
+impl<A: Actionlike> InputManagerPlugin<A> {
+    /// Creates a version of the plugin configured by the `config`
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::inhibit_input::InputDevice;
+    /// use leafwing_input_manager::plugin::InputManagerConfig;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Jump,
+    /// }
+    ///
+    /// let config = InputManagerConfig::default()
+    ///     .with_clash_strategy(ClashStrategy::PressAll)
+    ///     .with_time_source(TimeSource::Virtual)
+    ///     .with_inhibited_devices([InputDevice::Mouse]);
+    ///
+    /// App::new()
+    ///     .add_plugins(MinimalPlugins)
+    ///     .add_plugin(InputManagerPlugin::<Action>::new(config));
+    /// ```
+    #[must_use]
+    pub fn new(config: InputManagerConfig) -> Self {
         Self {
             _phantom: PhantomData::default(),
-            machine: Machine::Client,
+            config,
         }
     }
-}
 
-impl<A: Actionlike> InputManagerPlugin<A> {
     /// Creates a version of the plugin intended to run on the server
     ///
     /// Inputs will not be processed; instead, [`ActionState`](crate::action_state::ActionState)
     /// should be copied directly from the state provided by the client,
     /// or constructed from [`ActionDiff`](crate::action_state::ActionDiff) event streams.
+    ///
+    /// This is shorthand for an [`InputManagerConfig`] with [`Machine::Server`].
     #[must_use]
     pub fn server() -> Self {
-        Self {
-            _phantom: PhantomData::default(),
-            machine: Machine::Server,
-        }
+        Self::new(InputManagerConfig::default().with_machine(Machine::Server))
+    }
+
+    /// Updates the [`ActionState`]s of this action type a second time at the end of each frame
+    ///
+    /// This is shorthand for [`InputManagerConfig::with_late_update`].
+    #[must_use]
+    pub fn with_late_update(mut self) -> Self {
+        self.config.late_update = true;
+        self
+    }
+
+    /// The configuration of this plugin
+    #[must_use]
+    pub fn config(&self) -> &InputManagerConfig {
+        &self.config
     }
 }
 
 /// Which machine is this plugin running on?
-enum Machine {
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Machine {
+    /// The machine doesn't process inputs, and only ticks the [`ActionState`]s it receives from clients
     Server,
+    /// The machine processes the local inputs
+    ///
+    /// This is the default.
+    #[default]
     Client,
 }
 
+/// The configuration of an [`InputManagerPlugin`]
+///
+/// New options are added here as builder methods, rather than as new plugin constructors.
+/// This can be serialized, so that tools and settings files can store how the plugin should be set up.
+///
+/// Options that control shared resources, such as [`clash_strategy`](Self::clash_strategy) and [`time_source`](Self::time_source),
+/// affect every action type: if several plugins set them, the last plugin added wins.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InputManagerConfig {
+    /// Which machine the plugin is running on
+    pub machine: Machine,
+    /// Should the [`ActionState`]s be updated a second time at the end of each frame?
+    ///
+    /// See [`InputManagerConfig::with_late_update`].
+    pub late_update: bool,
+    /// Should mouse motion be sampled again at the end of each frame?
+    ///
+    /// See [`InputManagerConfig::with_late_mouse_sampling`].
+    pub late_mouse_sampling: bool,
+    /// The [`ClashStrategy`] resource to insert, if any
+    ///
+    /// If this is [`None`], the existing resource is kept, or the default strategy is used.
+    pub clash_strategy: Option<ClashStrategy>,
+    /// The [`TimeSource`] resource to insert, if any
+    ///
+    /// If this is [`None`], the existing resource is kept, or real time is used.
+    pub time_source: Option<TimeSource>,
+    /// The devices whose inputs are ignored by the [`ActionState`] resource of this action type
+    ///
+    /// If this is not empty, an [`InhibitInput`] resource is inserted.
+    pub inhibited_devices: Vec<InputDevice>,
+}
+
+impl InputManagerConfig {
+    /// Sets which machine the plugin is running on
+    #[must_use]
+    pub fn with_machine(mut self, machine: Machine) -> Self {
+        self.machine = machine;
+        self
+    }
+
+    /// Updates the [`ActionState`]s a second time at the end of each frame
+    ///
+    /// The extra update runs during [`CoreStage::PostUpdate`], and is labeled [`InputManagerSystem::LateUpdate`].
+    /// It does not tick the [`ActionState`]s again: actions pressed or released since the first update
+    /// become [`just_pressed`](crate::action_state::ActionState::just_pressed) or
+    /// [`just_released`](crate::action_state::ActionState::just_released) for the rest of the frame.
+    /// Only input collection is repeated, so the [`InputManagerSystem::ManualControl`] systems do not run again.
+    ///
+    /// This has no effect on the server, which does not process inputs.
+    #[must_use]
+    pub fn with_late_update(mut self) -> Self {
+        self.late_update = true;
+        self
+    }
+
+    /// Samples mouse motion again at the end of each frame, just before rendering
+    ///
+    /// The extra sample runs during [`CoreStage::PostUpdate`], before transforms are propagated,
+    /// and is labeled [`InputManagerSystem::LateMouseSampling`].
+    /// It replaces the axis pairs of actions whose axis pairs only come from mouse motion [`DualAxis`](crate::axislike::DualAxis) bindings,
+    /// taking into account any motion that was reported during [`CoreStage::Update`],
+    /// such as by a plugin that polls the mouse at a high rate.
+    /// Cameras that read those axis pairs after this label then aim with the freshest motion available,
+    /// which reduces the perceived latency of mouse look on high refresh rate setups.
+    ///
+    /// Unlike [`with_late_update`](Self::with_late_update), no action is pressed or released,
+    /// so the rest of the frame sees the same actions as [`CoreStage::Update`] did.
+    /// This has no effect on the server, which does not process inputs.
+    #[must_use]
+    pub fn with_late_mouse_sampling(mut self) -> Self {
+        self.late_mouse_sampling = true;
+        self
+    }
+
+    /// Sets the [`ClashStrategy`] used by every action type
+    #[must_use]
+    pub fn with_clash_strategy(mut self, clash_strategy: ClashStrategy) -> Self {
+        self.clash_strategy = Some(clash_strategy);
+        self
+    }
+
+    /// Sets the [`TimeSource`] used by every action type
+    #[must_use]
+    pub fn with_time_source(mut self, time_source: TimeSource) -> Self {
+        self.time_source = Some(time_source);
+        self
+    }
+
+    /// Ignores inputs from the `devices` when updating the [`ActionState`] resource
+    ///
+    /// Use an [`InhibitInput`] component to filter the devices of individual entities.
+    #[must_use]
+    pub fn with_inhibited_devices(
+        mut self,
+        devices: impl IntoIterator<Item = InputDevice>,
+    ) -> Self {
+        self.inhibited_devices = devices.into_iter().collect();
+        self
+    }
+}
+
 impl<A: Actionlike> Plugin for InputManagerPlugin<A> {
+    // Duplicates are detected when building instead, so that they can be ignored rather than panicking
+    fn is_unique(&self) -> bool {
+        false
+    }
+
     fn build(&self, app: &mut App) {
         use crate::systems::*;
 
-        match self.machine {
+        // Adding the plugin again for the same action type would tick and update its action states twice each frame
+        if let Some(registered_action_types) = app.world.get_resource::<RegisteredActionTypes>() {
+            if registered_action_types
+                .action_types
+                .contains(&TypeId::of::<A>())
+            {
+                warn!(
+                    "{}",
+                    InputManagerError::DuplicatePlugin {
+                        action_type: type_name::<A>(),
+                    }
+                );
+                return;
+            }
+        }
+
+        // Setup shared between all action types only needs to happen once
+        if !app.world.contains_resource::<RegisteredActionTypes>() {
+            app.init_resource::<RegisteredActionTypes>()
+                .init_resource::<ClashStrategy>()
+                .init_resource::<MouseWheelSettings>()
+                .init_resource::<GamepadButtonSettings>()
+                .init_resource::<TimeSource>()
+                .init_resource::<InputActivity>()
+                .add_event::<InputIdle>()
+                .init_resource::<GamepadHaptics>()
+                .add_event::<HapticRequest>()
+                .add_system_to_stage(
+                    CoreStage::PreUpdate,
+                    send_input_idle_events.after(InputManagerSystem::Activity),
+                )
+                .add_system_to_stage(
+                    CoreStage::PreUpdate,
+                    capture_text_input
+                        .after(InputSystem)
+                        .before(InputManagerSystem::Update),
+                )
+                .add_system_to_stage(
+                    CoreStage::PreUpdate,
+                    update_dynamic_actions
+                        .after(InputSystem)
+                        .before(InputManagerSystem::Update),
+                )
+                .add_system_to_stage(
+                    CoreStage::PreUpdate,
+                    capture_binding
+                        .after(InputSystem)
+                        .before(InputManagerSystem::Update),
+                );
+
+            #[cfg(feature = "touchpad")]
+            app.add_system_to_stage(
+                CoreStage::PreUpdate,
+                update_touchpad_inputs
+                    .after(InputSystem)
+                    .before(InputManagerSystem::Update),
+            );
+        }
+
+        let mut registered_action_types = app.world.resource_mut::<RegisteredActionTypes>();
+        registered_action_types.action_types.push(TypeId::of::<A>());
+        let previous = registered_action_types.labels.last().copied();
+        registered_action_types
+            .labels
+            .push(|system| InputManagerSystemFor::<A>::new(system).as_label());
+
+        // Orders each system after the same phase of the previously registered action type
+        let ordered = |descriptor: SystemDescriptor, system: InputManagerSystem| {
+            let descriptor = descriptor.label(InputManagerSystemFor::<A>::new(system.clone()));
+            match previous {
+                Some(previous) => descriptor.after(previous(system)),
+                None => descriptor,
+            }
+        };
+
+        app.add_event::<ValueThresholdCrossed<A>>()
+            .add_event::<SchemeChanged<A>>();
+
+        if let Some(clash_strategy) = self.config.clash_strategy {
+            app.insert_resource(clash_strategy);
+        }
+        if let Some(time_source) = self.config.time_source {
+            app.insert_resource(time_source);
+        }
+        if !self.config.inhibited_devices.is_empty() {
+            app.insert_resource(InhibitInput::<A>::new(
+                self.config.inhibited_devices.iter().copied(),
+            ));
+        }
+
+        match self.config.machine {
             Machine::Client => {
                 app.add_system_to_stage(
                     CoreStage::PreUpdate,
-                    tick_action_state::<A>
-                        .with_run_criteria(run_if_enabled::<A>)
-                        .label(InputManagerSystem::Tick)
-                        .before(InputManagerSystem::Update),
+                    ordered(
+                        tick_action_state::<A>
+                            .with_run_criteria(run_if_enabled::<A>)
+                            .label(InputManagerSystem::Tick)
+                            .before(InputManagerSystem::Update),
+                        InputManagerSystem::Tick,
+                    ),
                 )
                 .add_system_to_stage(
                     CoreStage::PreUpdate,
-                    release_on_disable::<A>
-                        .label(InputManagerSystem::ReleaseOnDisable)
-                        .after(InputManagerSystem::Update),
+                    ordered(
+                        release_on_disable::<A>
+                            .label(InputManagerSystem::ReleaseOnDisable)
+                            .after(InputManagerSystem::Update),
+                        InputManagerSystem::ReleaseOnDisable,
+                    ),
+                )
+                .add_system_to_stage(
+                    CoreStage::PreUpdate,
+                    ordered(
+                        update_input_activity::<A>
+                            .label(InputManagerSystem::Activity)
+                            .after(InputManagerSystem::ReleaseOnDisable),
+                        InputManagerSystem::Activity,
+                    ),
+                )
+                .add_system_to_stage(
+                    CoreStage::PreUpdate,
+                    ordered(
+                        send_value_threshold_events::<A>
+                            .label(InputManagerSystem::Activity)
+                            .after(InputManagerSystem::ReleaseOnDisable),
+                        InputManagerSystem::Activity,
+                    ),
+                )
+                .add_system_to_stage(
+                    CoreStage::PreUpdate,
+                    ordered(
+                        update_action_usage::<A>
+                            .label(InputManagerSystem::Activity)
+                            .after(InputManagerSystem::ReleaseOnDisable),
+                        InputManagerSystem::Activity,
+                    ),
+                )
+                .add_system_to_stage(
+                    CoreStage::PreUpdate,
+                    ordered(
+                        update_action_statistics::<A>
+                            .label(InputManagerSystem::Activity)
+                            .after(InputManagerSystem::ReleaseOnDisable),
+                        InputManagerSystem::Activity,
+                    ),
+                )
+                .add_system_to_stage(
+                    CoreStage::PreUpdate,
+                    ordered(
+                        update_input_log::<A>
+                            .label(InputManagerSystem::Activity)
+                            .after(InputManagerSystem::ReleaseOnDisable),
+                        InputManagerSystem::Activity,
+                    ),
+                )
+                .add_system_to_stage(
+                    CoreStage::PreUpdate,
+                    switch_control_schemes::<A>
+                        .after(InputSystem)
+                        .before(apply_platform_bindings::<A>),
+                )
+                .add_system_to_stage(
+                    CoreStage::PreUpdate,
+                    apply_platform_bindings::<A>
+                        .after(InputSystem)
+                        .before(InputManagerSystem::Update),
                 )
                 .add_system_to_stage(CoreStage::PostUpdate, release_on_input_map_removed::<A>);
 
                 #[cfg(feature = "egui")]
                 app.add_system_to_stage(
                     CoreStage::PreUpdate,
-                    update_action_state::<A>
-                        .with_run_criteria(run_if_enabled::<A>)
-                        .label(InputManagerSystem::Update)
-                        .after(InputSystem)
-                        .after(bevy_egui::EguiSystem::ProcessInput),
+                    ordered(
+                        update_action_state::<A>
+                            .with_run_criteria(run_if_enabled::<A>)
+                            .label(InputManagerSystem::Update)
+                            .after(InputSystem)
+                            .after(bevy_egui::EguiSystem::ProcessInput),
+                        InputManagerSystem::Update,
+                    ),
                 );
                 #[cfg(not(feature = "egui"))]
                 app.add_system_to_stage(
                     CoreStage::PreUpdate,
-                    update_action_state::<A>
-                        .with_run_criteria(run_if_enabled::<A>)
-                        .label(InputManagerSystem::Update)
-                        .after(InputSystem),
+                    ordered(
+                        update_action_state::<A>
+                            .with_run_criteria(run_if_enabled::<A>)
+                            .label(InputManagerSystem::Update)
+                            .after(InputSystem),
+                        InputManagerSystem::Update,
+                    ),
                 );
 
+                // The manual control systems are chained, so that each sees the changes of the previous ones:
+                // systems that press actions run first, followed by those that filter and arbitrate between the pressed actions,
+                // and finally axis ramping, which smooths the resulting values
+                let manual_control = |descriptor: SystemDescriptor| {
+                    ordered(
+                        descriptor
+                            .with_run_criteria(run_if_enabled::<A>)
+                            .label(InputManagerSystem::ManualControl)
+                            .after(InputManagerSystem::Update)
+                            .before(InputManagerSystem::ReleaseOnDisable),
+                        InputManagerSystem::ManualControl,
+                    )
+                };
+
                 #[cfg(feature = "ui")]
                 app.add_system_to_stage(
                     CoreStage::PreUpdate,
-                    update_action_state_from_interaction::<A>
-                        .with_run_criteria(run_if_enabled::<A>)
-                        .label(InputManagerSystem::ManualControl)
-                        .before(InputManagerSystem::ReleaseOnDisable)
-                        .after(InputManagerSystem::Tick)
-                        // Must run after the system is updated from inputs, or it will be forcibly released due to the inputs
-                        // not being pressed
-                        .after(InputManagerSystem::Update)
-                        .after(UiSystem::Focus)
-                        .after(InputSystem),
+                    manual_control(
+                        update_action_state_from_interaction::<A>
+                            .after(InputManagerSystem::Tick)
+                            .after(UiSystem::Focus)
+                            .after(InputSystem)
+                            .into_descriptor(),
+                    ),
                 );
+                #[cfg(feature = "ui")]
+                let virtual_controller =
+                    apply_virtual_controller::<A>.after(update_action_state_from_interaction::<A>);
+                #[cfg(not(feature = "ui"))]
+                let virtual_controller = apply_virtual_controller::<A>.into_descriptor();
+
+                app.add_system_to_stage(CoreStage::PreUpdate, manual_control(virtual_controller))
+                    .add_system_to_stage(
+                        CoreStage::PreUpdate,
+                        manual_control(
+                            apply_key_sequences::<A>.after(apply_virtual_controller::<A>),
+                        ),
+                    )
+                    .add_system_to_stage(
+                        CoreStage::PreUpdate,
+                        manual_control(apply_stick_flicks::<A>.after(apply_key_sequences::<A>)),
+                    )
+                    .add_system_to_stage(
+                        CoreStage::PreUpdate,
+                        manual_control(apply_motion_controls::<A>.after(apply_stick_flicks::<A>)),
+                    )
+                    .add_system_to_stage(
+                        CoreStage::PreUpdate,
+                        manual_control(
+                            apply_directional_repeat::<A>.after(apply_motion_controls::<A>),
+                        ),
+                    )
+                    .add_system_to_stage(
+                        CoreStage::PreUpdate,
+                        manual_control(
+                            apply_exclusive_actions::<A>.after(apply_directional_repeat::<A>),
+                        ),
+                    )
+                    .add_system_to_stage(
+                        CoreStage::PreUpdate,
+                        manual_control(apply_axis_ramp::<A>.after(apply_exclusive_actions::<A>)),
+                    );
+
+                if self.config.late_update {
+                    app.add_system_to_stage(
+                        CoreStage::PostUpdate,
+                        update_action_state::<A>
+                            .with_run_criteria(run_if_enabled::<A>)
+                            .label(InputManagerSystem::LateUpdate)
+                            .label(InputManagerSystemFor::<A>::new(
+                                InputManagerSystem::LateUpdate,
+                            )),
+                    );
+                }
+
+                if self.config.late_mouse_sampling {
+                    app.add_system_to_stage(
+                        CoreStage::PostUpdate,
+                        resample_mouse_motion::<A>
+                            .with_run_criteria(run_if_enabled::<A>)
+                            .label(InputManagerSystem::LateMouseSampling)
+                            .label(InputManagerSystemFor::<A>::new(
+                                InputManagerSystem::LateMouseSampling,
+                            ))
+                            .after(InputManagerSystem::LateUpdate)
+                            .before(TransformSystem::TransformPropagate),
+                    );
+                }
             }
             Machine::Server => {
                 app.add_system_to_stage(
                     CoreStage::PreUpdate,
-                    tick_action_state::<A>
-                        .with_run_criteria(run_if_enabled::<A>)
-                        .label(InputManagerSystem::Tick),
+                    ordered(
+                        tick_action_state::<A>
+                            .with_run_criteria(run_if_enabled::<A>)
+                            .label(InputManagerSystem::Tick),
+                        InputManagerSystem::Tick,
+                    ),
+                )
+                .add_system_to_stage(
+                    CoreStage::PreUpdate,
+                    ordered(
+                        update_input_activity::<A>
+                            .label(InputManagerSystem::Activity)
+                            .after(InputManagerSystem::Tick),
+                        InputManagerSystem::Activity,
+                    ),
+                )
+                .add_system_to_stage(
+                    CoreStage::PreUpdate,
+                    ordered(
+                        send_value_threshold_events::<A>
+                            .label(InputManagerSystem::Activity)
+                            .after(InputManagerSystem::Tick),
+                        InputManagerSystem::Activity,
+                    ),
+                )
+                .add_system_to_stage(
+                    CoreStage::PreUpdate,
+                    ordered(
+                        update_action_usage::<A>
+                            .label(InputManagerSystem::Activity)
+                            .after(InputManagerSystem::Tick),
+                        InputManagerSystem::Activity,
+                    ),
+                )
+                .add_system_to_stage(
+                    CoreStage::PreUpdate,
+                    ordered(
+                        update_action_statistics::<A>
+                            .label(InputManagerSystem::Activity)
+                            .after(InputManagerSystem::Tick),
+                        InputManagerSystem::Activity,
+                    ),
+                )
+                .add_system_to_stage(
+                    CoreStage::PreUpdate,
+                    ordered(
+                        update_input_log::<A>
+                            .label(InputManagerSystem::Activity)
+                            .after(InputManagerSystem::Tick),
+                        InputManagerSystem::Activity,
+                    ),
                 );
             }
         };
@@ -148,10 +671,19 @@ impl<A: Actionlike> Plugin for InputManagerPlugin<A> {
         app.register_type::<ActionState<A>>()
             // Resources
             .init_resource::<ToggleActions<A>>()
-            .init_resource::<ClashStrategy>();
+            .init_resource::<PauseTiming<A>>();
     }
 }
 
+/// The [`TypeId`] and labels of each [`Actionlike`] type whose [`InputManagerPlugin`] has been added, in registration order
+///
+/// The presence of this resource also marks that the setup shared between action types has been performed.
+#[derive(Resource, Default)]
+struct RegisteredActionTypes {
+    action_types: Vec<TypeId>,
+    labels: Vec<fn(InputManagerSystem) -> SystemLabelId>,
+}
+
 /// Controls whether or not the [`ActionState`](crate::action_state::ActionState) / [`InputMap`](crate::input_map::InputMap) pairs of type `A` are active
 ///
 /// If this resource does not exist, actions work normally, as if `ToggleActions::enabled == true`.
@@ -188,6 +720,75 @@ impl<A: Actionlike> Default for ToggleActions<A> {
     }
 }
 
+/// Controls whether or not the durations of [`ActionState`](crate::action_state::ActionState)s corresponding to `A` advance
+///
+/// Unlike [`ToggleActions`], pausing timing does not release any actions:
+/// inputs are still read and actions can still be pressed and released,
+/// but hold durations are frozen until timing is resumed.
+/// This is useful for pause menus, which should not corrupt charge-up timers.
+///
+/// If this resource does not exist, durations advance normally, as if `PauseTiming::paused == false`.
+#[derive(Resource)]
+pub struct PauseTiming<A: Actionlike> {
+    /// When this is true, the durations of [`ActionState`](crate::action_state::ActionState)s corresponding to `A` do not advance
+    pub paused: bool,
+    /// Marker that stores the type of action whose timing is paused
+    pub phantom: PhantomData<A>,
+}
+
+impl<A: Actionlike> PauseTiming<A> {
+    /// A [`PauseTiming`] in running state.
+    pub const RUNNING: PauseTiming<A> = PauseTiming::<A> {
+        paused: false,
+        phantom: PhantomData::<A>,
+    };
+    /// A [`PauseTiming`] in paused state.
+    pub const PAUSED: PauseTiming<A> = PauseTiming::<A> {
+        paused: true,
+        phantom: PhantomData::<A>,
+    };
+}
+
+// Implement manually to not require [`Default`] for `A`
+impl<A: Actionlike> Default for PauseTiming<A> {
+    fn default() -> Self {
+        Self::RUNNING
+    }
+}
+
+/// The clock used by [`tick_action_state`](crate::systems::tick_action_state) to advance the durations of every [`ActionState`](crate::action_state::ActionState)
+///
+/// Switching clocks while actions are held will make their durations jump, as the clocks are not synchronized.
+///
+/// If this resource does not exist, [`TimeSource::Real`] is used.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum TimeSource {
+    /// Durations follow real time, ignoring the relative speed and pausing of [`Time`]
+    #[default]
+    Real,
+    /// Durations follow the virtual time of [`Time`],
+    /// slowing down, speeding up and pausing along with [`Time::set_relative_speed`] and [`Time::pause`]
+    Virtual,
+    /// Durations advance by exactly this step on every tick, no matter how much time has passed
+    ///
+    /// This is useful for deterministic simulations, where durations should only depend on the tick count.
+    FixedStep(Duration),
+}
+
+impl TimeSource {
+    /// The [`Instant`] at which the current tick starts, according to this clock
+    ///
+    /// The `previous_instant` is the value returned for the previous tick, or [`Time::startup`] on the first tick.
+    #[must_use]
+    pub fn current_instant(&self, time: &Time, previous_instant: Instant) -> Instant {
+        match self {
+            TimeSource::Real => time.last_update().unwrap_or_else(|| time.startup()),
+            TimeSource::Virtual => time.startup() + time.elapsed(),
+            TimeSource::FixedStep(step) => previous_instant + *step,
+        }
+    }
+}
+
 /// [`SystemLabel`]s for the [`crate::systems`] used by this crate
 ///
 /// `Reset` must occur before `Update`
@@ -199,10 +800,80 @@ pub enum InputManagerSystem {
     Tick,
     /// Collects input data to update the [`ActionState`](crate::action_state::ActionState)
     Update,
+    /// Collects input data again at the end of the frame, during [`CoreStage::PostUpdate`]
+    ///
+    /// Only used by action types whose plugin was created with [`InputManagerConfig::with_late_update`].
+    LateUpdate,
+    /// Samples mouse motion again at the end of the frame, during [`CoreStage::PostUpdate`]
+    ///
+    /// Only used by action types whose plugin was created with [`InputManagerConfig::with_late_mouse_sampling`].
+    LateMouseSampling,
     /// Release all actions in all [`ActionState`](crate::action_state::ActionState)s if [`ToggleActions`](crate::plugin::ToggleActions) was added
     ReleaseOnDisable,
     /// Manually control the [`ActionState`](crate::action_state::ActionState)
     ///
     /// Must run after [`InputManagerSystem::Update`] or the action state will be overriden
+    ///
+    /// The systems of this crate with this label run in a fixed order, as listed in the docs of [`InputManagerPlugin`].
     ManualControl,
+    /// Records whether any actions were active in the [`InputActivity`](crate::activity::InputActivity) resource
+    ///
+    /// [`InputIdle`](crate::activity::InputIdle) events are sent after this phase has run for every action type.
+    Activity,
+}
+
+/// A [`SystemLabel`] for the [`InputManagerSystem`] phases of a single [`Actionlike`] type
+///
+/// Systems added by [`InputManagerPlugin<A>`] are labeled with both the shared [`InputManagerSystem`] label
+/// and this label, allowing you to order your own systems relative to the input handling of a particular action type.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::plugin::{InputManagerSystem, InputManagerSystemFor};
+///
+/// #[derive(Actionlike, Clone, Copy)]
+/// enum MenuAction {
+///     Select,
+/// }
+///
+/// fn read_menu_actions() {}
+///
+/// App::new()
+///     .add_plugin(InputManagerPlugin::<MenuAction>::default())
+///     .add_system_to_stage(
+///         CoreStage::PreUpdate,
+///         read_menu_actions.after(InputManagerSystemFor::<MenuAction>::new(InputManagerSystem::Update)),
+///     );
+/// ```
+pub struct InputManagerSystemFor<A: Actionlike> {
+    /// The phase of the input handling that this label refers to
+    pub system: InputManagerSystem,
+    _phantom: PhantomData<A>,
+}
+
+impl<A: Actionlike> InputManagerSystemFor<A> {
+    /// Creates a label for the provided `system` phase of the action type `A`
+    #[must_use]
+    pub fn new(system: InputManagerSystem) -> Self {
+        Self {
+            system,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A: Actionlike> SystemLabel for InputManagerSystemFor<A> {
+    fn as_str(&self) -> &'static str {
+        match self.system {
+            InputManagerSystem::Tick => "InputManagerSystemFor::Tick",
+            InputManagerSystem::Update => "InputManagerSystemFor::Update",
+            InputManagerSystem::LateUpdate => "InputManagerSystemFor::LateUpdate",
+            InputManagerSystem::LateMouseSampling => "InputManagerSystemFor::LateMouseSampling",
+            InputManagerSystem::ReleaseOnDisable => "InputManagerSystemFor::ReleaseOnDisable",
+            InputManagerSystem::ManualControl => "InputManagerSystemFor::ManualControl",
+            InputManagerSystem::Activity => "InputManagerSystemFor::Activity",
+        }
+    }
 }
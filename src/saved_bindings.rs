@@ -0,0 +1,385 @@
+//! Versioned storage of player bindings, which survives changes to the action enum.
+//!
+//! The serialized form of an [`InputMap`] depends on the serde representation of its action type,
+//! so renaming a variant makes previously saved bindings fail to load.
+//! [`SavedBindings`] instead stores the bindings of each action under its [identifier](crate::ActionMetadata::identifier),
+//! along with a format version.
+//! Renamed variants are matched through `#[actionlike(alias = "OldName")]`,
+//! while larger changes can be handled by [`BindingMigration`]s.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::errors::{BindingError, SavedBindingsError};
+use crate::input_map::InputMap;
+use crate::user_input::UserInput;
+use crate::Actionlike;
+
+/// The bindings of an [`InputMap`], keyed by the identifiers of their actions and tagged with a format version
+///
+/// # Example
+/// ```rust
+/// use bevy::input::keyboard::KeyCode;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::saved_bindings::{BindingMigration, SavedBindings};
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     // This action was called `Leap` in older versions of the game
+///     #[actionlike(alias = "Leap")]
+///     Jump,
+///     Crouch,
+/// }
+///
+/// // Version 2 of the bindings format bound crouching to C by default
+/// const MIGRATIONS: &[BindingMigration] = &[BindingMigration {
+///     from_version: 1,
+///     migrate: |saved| saved.bind("Crouch", KeyCode::C),
+/// }];
+///
+/// let mut saved = SavedBindings::default();
+/// saved.version = 1;
+/// saved.bind("Leap", KeyCode::Space);
+///
+/// saved.migrate(MIGRATIONS, 2).unwrap();
+/// let input_map: InputMap<Action> = saved.to_input_map().unwrap();
+/// assert_eq!(
+///     input_map,
+///     InputMap::new([(KeyCode::Space, Action::Jump), (KeyCode::C, Action::Crouch)])
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct SavedBindings {
+    /// The version of the format these bindings were saved with
+    ///
+    /// This is chosen by your game, and should be incremented whenever a [`BindingMigration`] is needed.
+    pub version: u32,
+    /// The inputs bound to each action, keyed by the [identifier](crate::ActionMetadata::identifier) of the action
+    pub bindings: BTreeMap<String, Vec<UserInput>>,
+}
+
+/// Upgrades [`SavedBindings`] from one format version to the next
+#[derive(Debug, Clone, Copy)]
+pub struct BindingMigration {
+    /// The format version that this migration upgrades from, to `from_version + 1`
+    pub from_version: u32,
+    /// Modifies the saved bindings to match the next format version
+    ///
+    /// The [`version`](SavedBindings::version) is updated automatically.
+    pub migrate: fn(&mut SavedBindings),
+}
+
+impl SavedBindings {
+    /// Stores the bindings of the `input_map`, tagged with the current format `version`
+    #[must_use]
+    pub fn new<A: Actionlike>(input_map: &InputMap<A>, version: u32) -> Self {
+        let bindings = input_map
+            .iter()
+            .filter(|(inputs, _)| !inputs.is_empty())
+            .map(|(inputs, action)| {
+                (
+                    action.metadata().saved_identifier().to_string(),
+                    inputs.iter().cloned().collect(),
+                )
+            })
+            .collect();
+
+        SavedBindings { version, bindings }
+    }
+
+    /// Binds the `input` to the action with the given `identifier`
+    ///
+    /// This is intended for use in [`BindingMigration`]s.
+    pub fn bind(&mut self, identifier: &str, input: impl Into<UserInput>) {
+        let input = input.into();
+        let inputs = self.bindings.entry(identifier.to_string()).or_default();
+        if !inputs.contains(&input) {
+            inputs.push(input);
+        }
+    }
+
+    /// Moves the bindings of the action identified by `from` to the action identified by `to`
+    ///
+    /// This is intended for use in [`BindingMigration`]s, when actions are merged or split.
+    /// Simple renames are better handled with `#[actionlike(alias = "OldName")]`.
+    pub fn rename(&mut self, from: &str, to: &str) {
+        if let Some(inputs) = self.bindings.remove(from) {
+            for input in inputs {
+                self.bind(to, input);
+            }
+        }
+    }
+
+    /// Applies the `migrations` in order, until these bindings reach the `latest` format version
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SavedBindingsError::UnsupportedVersion`] if the bindings were saved with a version newer than `latest`,
+    /// or [`SavedBindingsError::MissingMigration`] if no migration upgrades from one of the intermediate versions.
+    /// The bindings are left partially migrated in the latter case.
+    pub fn migrate(
+        &mut self,
+        migrations: &[BindingMigration],
+        latest: u32,
+    ) -> Result<(), SavedBindingsError> {
+        if self.version > latest {
+            return Err(SavedBindingsError::UnsupportedVersion {
+                version: self.version,
+                latest,
+            });
+        }
+
+        while self.version < latest {
+            let migration = migrations
+                .iter()
+                .find(|migration| migration.from_version == self.version)
+                .ok_or(SavedBindingsError::MissingMigration {
+                    version: self.version,
+                })?;
+            (migration.migrate)(self);
+            self.version += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Rebuilds the [`InputMap`] from these bindings
+    ///
+    /// Actions are matched by their [identifier](crate::ActionMetadata::identifier) or any of their [aliases](crate::ActionMetadata::aliases).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SavedBindingsError::UnknownAction`] if any of the bindings refer to an action that does not exist,
+    /// or [`SavedBindingsError::RejectedBinding`] if any of the bindings could not be inserted into the [`InputMap`].
+    /// Use [`SavedBindings::to_input_map_lenient`] to load the remaining bindings instead.
+    pub fn to_input_map<A: Actionlike>(&self) -> Result<InputMap<A>, SavedBindingsError> {
+        let (input_map, orphans, rejected) = self.to_input_map_lenient();
+
+        if let Some(orphan) = orphans.into_iter().next() {
+            return Err(SavedBindingsError::UnknownAction {
+                identifier: orphan.identifier,
+            });
+        }
+
+        match rejected.into_iter().next() {
+            Some(rejected) => Err(SavedBindingsError::RejectedBinding {
+                identifier: rejected.identifier,
+                error: rejected.error,
+            }),
+            None => Ok(input_map),
+        }
+    }
+
+    /// Rebuilds the [`InputMap`] from these bindings, skipping any bindings that cannot be loaded
+    ///
+    /// Saved bindings are untrusted data, so each binding is inserted with [`InputMap::try_insert`].
+    /// The bindings of actions that do not exist are returned as [`OrphanedBindings`], so that they can be reported to the player,
+    /// or kept around with [`SavedBindings::preserve`] when binding files are shared between several mods or versions of a game.
+    /// Bindings of existing actions that were refused by the map, such as those of [fixed](crate::ActionMetadata::fixed) actions,
+    /// are returned as [`RejectedBinding`]s.
+    #[must_use]
+    pub fn to_input_map_lenient<A: Actionlike>(
+        &self,
+    ) -> (InputMap<A>, Vec<OrphanedBindings>, Vec<RejectedBinding>) {
+        let mut input_map = InputMap::default();
+        let mut orphans = Vec::new();
+        let mut rejected = Vec::new();
+
+        for (identifier, inputs) in &self.bindings {
+            match A::from_identifier(identifier) {
+                Some(action) => {
+                    for input in inputs {
+                        if let Err(error) = input_map.try_insert(input.clone(), action.clone()) {
+                            rejected.push(RejectedBinding {
+                                identifier: identifier.clone(),
+                                input: input.clone(),
+                                error,
+                            });
+                        }
+                    }
+                }
+                None => orphans.push(OrphanedBindings {
+                    identifier: identifier.clone(),
+                    inputs: inputs.clone(),
+                }),
+            }
+        }
+
+        (input_map, orphans, rejected)
+    }
+
+    /// Adds the `orphans` returned by [`SavedBindings::to_input_map_lenient`] back into these bindings
+    ///
+    /// Bindings that are already stored for the same identifier are kept.
+    pub fn preserve(&mut self, orphans: &[OrphanedBindings]) {
+        for orphan in orphans {
+            for input in &orphan.inputs {
+                self.bind(&orphan.identifier, input.clone());
+            }
+        }
+    }
+}
+
+/// Bindings of an action that no longer exists, skipped by [`SavedBindings::to_input_map_lenient`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrphanedBindings {
+    /// The identifier that the bindings were saved under
+    pub identifier: String,
+    /// The inputs that were bound to the missing action
+    pub inputs: Vec<UserInput>,
+}
+
+/// A binding of an existing action that was refused by the [`InputMap`], skipped by [`SavedBindings::to_input_map_lenient`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RejectedBinding {
+    /// The identifier that the binding was saved under
+    pub identifier: String,
+    /// The input that could not be bound
+    pub input: UserInput,
+    /// Why the binding was refused
+    pub error: BindingError,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BindingMigration, OrphanedBindings, RejectedBinding, SavedBindings};
+    use crate as leafwing_input_manager;
+    use crate::errors::{BindingError, SavedBindingsError};
+    use crate::input_map::InputMap;
+    use crate::Actionlike;
+    use bevy::input::keyboard::KeyCode;
+
+    #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    enum Action {
+        #[actionlike(alias = "Sprint")]
+        Run,
+        Jump,
+        Interact,
+    }
+
+    #[test]
+    fn round_trip() {
+        let input_map = InputMap::new([
+            (KeyCode::LShift, Action::Run),
+            (KeyCode::Space, Action::Jump),
+            (KeyCode::W, Action::Jump),
+        ]);
+        let saved = SavedBindings::new(&input_map, 3);
+        assert_eq!(saved.version, 3);
+        assert_eq!(saved.bindings.keys().collect::<Vec<_>>(), ["Jump", "Run"]);
+        assert_eq!(saved.to_input_map(), Ok(input_map));
+    }
+
+    #[test]
+    fn migrations_are_applied_in_order() {
+        const MIGRATIONS: &[BindingMigration] = &[
+            BindingMigration {
+                from_version: 2,
+                migrate: |saved| saved.rename("Use", "Interact"),
+            },
+            BindingMigration {
+                from_version: 1,
+                migrate: |saved| saved.bind("Use", KeyCode::E),
+            },
+        ];
+
+        let mut saved = SavedBindings {
+            version: 1,
+            ..Default::default()
+        };
+        saved.bind("Sprint", KeyCode::LShift);
+        // Unknown actions must be migrated away before they can be loaded
+        saved.bind("Use", KeyCode::F);
+        assert_eq!(
+            saved.to_input_map::<Action>(),
+            Err(SavedBindingsError::UnknownAction {
+                identifier: "Use".to_string()
+            })
+        );
+
+        saved.migrate(MIGRATIONS, 3).unwrap();
+        assert_eq!(saved.version, 3);
+        assert_eq!(
+            saved.to_input_map(),
+            Ok(InputMap::new([
+                (KeyCode::LShift, Action::Run),
+                (KeyCode::F, Action::Interact),
+                (KeyCode::E, Action::Interact),
+            ]))
+        );
+
+        assert_eq!(
+            saved.migrate(MIGRATIONS, 2),
+            Err(SavedBindingsError::UnsupportedVersion {
+                version: 3,
+                latest: 2
+            })
+        );
+        saved.version = 0;
+        assert_eq!(
+            saved.migrate(MIGRATIONS, 3),
+            Err(SavedBindingsError::MissingMigration { version: 0 })
+        );
+    }
+
+    #[test]
+    fn unknown_actions_are_reported() {
+        let mut saved = SavedBindings::default();
+        saved.bind("Jump", KeyCode::Space);
+        saved.bind("Fly", KeyCode::F);
+        saved.bind("Fly", KeyCode::G);
+
+        let (input_map, orphans, rejected) = saved.to_input_map_lenient::<Action>();
+        assert_eq!(input_map, InputMap::new([(KeyCode::Space, Action::Jump)]));
+        assert!(rejected.is_empty());
+        assert_eq!(
+            orphans,
+            [OrphanedBindings {
+                identifier: "Fly".to_string(),
+                inputs: vec![KeyCode::F.into(), KeyCode::G.into()],
+            }]
+        );
+
+        // Saving the bindings again keeps the orphans for other versions of the game
+        let mut resaved = SavedBindings::new(&input_map, saved.version);
+        resaved.preserve(&orphans);
+        assert_eq!(resaved, saved);
+    }
+
+    #[test]
+    fn fixed_actions_are_not_loaded() {
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+        enum ReservedAction {
+            Jump,
+            #[actionlike(fixed)]
+            Screenshot,
+        }
+
+        let mut saved = SavedBindings::default();
+        saved.bind("Jump", KeyCode::Space);
+        saved.bind("Screenshot", KeyCode::Space);
+
+        let (input_map, orphans, rejected) = saved.to_input_map_lenient::<ReservedAction>();
+        assert_eq!(
+            input_map,
+            InputMap::new([(KeyCode::Space, ReservedAction::Jump)])
+        );
+        assert!(orphans.is_empty());
+        assert_eq!(
+            rejected,
+            [RejectedBinding {
+                identifier: "Screenshot".to_string(),
+                input: KeyCode::Space.into(),
+                error: BindingError::FixedAction,
+            }]
+        );
+
+        assert_eq!(
+            saved.to_input_map::<ReservedAction>(),
+            Err(SavedBindingsError::RejectedBinding {
+                identifier: "Screenshot".to_string(),
+                error: BindingError::FixedAction,
+            })
+        );
+    }
+}
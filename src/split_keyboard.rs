@@ -0,0 +1,219 @@
+//! This module contains [`KeyboardRegion`] and its supporting methods and impls.
+//!
+//! Couch co-op games often seat two players at the same keyboard, one on each half.
+//! Each player's [`InputMap`] reads the entire keyboard, so their bindings must claim disjoint sets of keys:
+//! [`KeyboardRegion`] provides the classic "WASD player" and "arrows player" layouts,
+//! and [`validate_split_keyboard`] checks that a set of maps does not overlap.
+
+use bevy::input::keyboard::KeyCode;
+use std::collections::{BTreeSet, HashMap};
+
+use crate::axislike::VirtualDPad;
+use crate::errors::SplitKeyboardError;
+use crate::input_map::{input_kinds, InputMap};
+use crate::user_input::{InputKind, Wildcard};
+use crate::Actionlike;
+
+/// One half of a keyboard shared by two players
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::split_keyboard::{validate_split_keyboard, KeyboardRegion};
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Move,
+///     Jump,
+///     Attack,
+/// }
+///
+/// let player_one = KeyboardRegion::Wasd.input_map(Action::Move, [Action::Jump, Action::Attack]);
+/// let player_two = KeyboardRegion::Arrows.input_map(Action::Move, [Action::Jump, Action::Attack]);
+/// assert!(validate_split_keyboard([&player_one, &player_two]).is_ok());
+///
+/// let mut world = World::new();
+/// for input_map in [player_one, player_two] {
+///     world.spawn(InputManagerBundle {
+///         input_map,
+///         ..Default::default()
+///     });
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyboardRegion {
+    /// The left half of the keyboard, moving with `WASD`
+    Wasd,
+    /// The right half of the keyboard, moving with the arrow keys
+    Arrows,
+}
+
+impl KeyboardRegion {
+    /// The [`VirtualDPad`] used for movement in this region
+    #[must_use]
+    pub fn dpad(self) -> VirtualDPad {
+        match self {
+            KeyboardRegion::Wasd => VirtualDPad::wasd(),
+            KeyboardRegion::Arrows => VirtualDPad::arrow_keys(),
+        }
+    }
+
+    /// The keys used for buttonlike actions in this region, from the most to the least comfortable to reach
+    #[must_use]
+    pub fn buttons(self) -> [KeyCode; 8] {
+        use KeyCode::*;
+
+        match self {
+            KeyboardRegion::Wasd => [Space, LShift, Q, E, F, R, LControl, Tab],
+            KeyboardRegion::Arrows => [
+                RControl, RShift, Return, Slash, Period, Comma, Semicolon, Apostrophe,
+            ],
+        }
+    }
+
+    /// Every key in this region
+    ///
+    /// The keys of [`KeyboardRegion::Wasd`] and [`KeyboardRegion::Arrows`] are disjoint.
+    #[must_use]
+    pub fn keys(self) -> Vec<KeyCode> {
+        let dpad = self.dpad();
+        let mut keys: Vec<KeyCode> = [dpad.up, dpad.down, dpad.left, dpad.right]
+            .into_iter()
+            .filter_map(|input_kind| match input_kind {
+                InputKind::Keyboard(key) => Some(key),
+                _ => None,
+            })
+            .collect();
+        keys.extend(self.buttons());
+        keys
+    }
+
+    /// Creates an [`InputMap`] that only uses the keys of this region
+    ///
+    /// The `movement` action is bound to the [`dpad`](Self::dpad),
+    /// and each of the `buttons` actions is bound to the corresponding key of [`buttons`](Self::buttons).
+    /// Any actions beyond the number of available keys are left unbound.
+    #[must_use]
+    pub fn input_map<A: Actionlike>(
+        self,
+        movement: A,
+        buttons: impl IntoIterator<Item = A>,
+    ) -> InputMap<A> {
+        let mut input_map = InputMap::default();
+        input_map.insert(self.dpad(), movement);
+        for (key, action) in self.buttons().into_iter().zip(buttons) {
+            input_map.insert(key, action);
+        }
+        input_map
+    }
+}
+
+/// Checks that the `input_maps` of players sharing a keyboard do not claim any of the same keys
+///
+/// [`Modifier`](crate::user_input::Modifier)s claim both of their keys,
+/// and keyboard [`Wildcard`]s claim the entire keyboard, which is only allowed if there is a single map.
+/// Bindings to other devices are ignored: use [`InputMap::set_gamepad`] to separate players' gamepads.
+///
+/// Returns the first problem found, if any.
+pub fn validate_split_keyboard<'a, A: Actionlike + 'a>(
+    input_maps: impl IntoIterator<Item = &'a InputMap<A>>,
+) -> Result<(), SplitKeyboardError> {
+    let input_maps: Vec<&InputMap<A>> = input_maps.into_iter().collect();
+    let mut claimed_keys = HashMap::<KeyCode, usize>::new();
+
+    for (map, input_map) in input_maps.iter().enumerate() {
+        let mut keys = BTreeSet::new();
+        for (inputs, _action) in input_map.iter() {
+            for input_kind in inputs.iter().flat_map(input_kinds) {
+                match *input_kind {
+                    InputKind::Keyboard(key) => {
+                        keys.insert(key);
+                    }
+                    InputKind::Modifier(modifier) => keys.extend(modifier.key_codes()),
+                    InputKind::Wildcard(Wildcard::AnyKey | Wildcard::AnyInput)
+                        if input_maps.len() > 1 =>
+                    {
+                        return Err(SplitKeyboardError::WholeKeyboard { map });
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        for key in keys {
+            if let Some(&first) = claimed_keys.get(&key) {
+                return Err(SplitKeyboardError::SharedKey {
+                    key,
+                    first,
+                    second: map,
+                });
+            }
+            claimed_keys.insert(key, map);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{validate_split_keyboard, KeyboardRegion};
+    use crate as leafwing_input_manager;
+    use crate::errors::SplitKeyboardError;
+    use crate::input_map::InputMap;
+    use crate::user_input::{Modifier, UserInput, Wildcard};
+    use crate::Actionlike;
+    use bevy::input::keyboard::KeyCode;
+
+    #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    enum Action {
+        Move,
+        Jump,
+        Sprint,
+    }
+
+    #[test]
+    fn regions_are_disjoint() {
+        let wasd = KeyboardRegion::Wasd.keys();
+        assert!(KeyboardRegion::Arrows
+            .keys()
+            .iter()
+            .all(|key| !wasd.contains(key)));
+
+        let player_one = KeyboardRegion::Wasd.input_map(Action::Move, [Action::Jump]);
+        let player_two =
+            KeyboardRegion::Arrows.input_map(Action::Move, [Action::Jump, Action::Sprint]);
+        assert!(player_one
+            .get(Action::Jump)
+            .contains(&UserInput::from(KeyCode::Space)));
+        assert!(player_one.get(Action::Sprint).is_empty());
+        assert_eq!(validate_split_keyboard([&player_one, &player_two]), Ok(()));
+    }
+
+    #[test]
+    fn overlapping_maps_are_rejected() {
+        let player_one =
+            KeyboardRegion::Wasd.input_map(Action::Move, [Action::Jump, Action::Sprint]);
+        let mut player_two =
+            KeyboardRegion::Arrows.input_map(Action::Move, [Action::Jump, Action::Sprint]);
+
+        // Both shift keys are claimed by the modifier, including the one used by the first player
+        player_two.insert_modified(Modifier::Shift, KeyCode::Up, Action::Sprint);
+        assert_eq!(
+            validate_split_keyboard([&player_one, &player_two]),
+            Err(SplitKeyboardError::SharedKey {
+                key: KeyCode::LShift,
+                first: 0,
+                second: 1,
+            })
+        );
+
+        let any_key = InputMap::new([(Wildcard::AnyKey, Action::Jump)]);
+        assert_eq!(validate_split_keyboard([&any_key]), Ok(()));
+        assert_eq!(
+            validate_split_keyboard([&player_one, &any_key]),
+            Err(SplitKeyboardError::WholeKeyboard { map: 1 })
+        );
+    }
+}
@@ -0,0 +1,178 @@
+//! Restricts which actions are available in each of your app's [`State`]s.
+//!
+//! Rather than creating a separate [`Actionlike`] enum for each state of your game,
+//! a single action type can be shared between states, with only some of its actions available in each
+//! (such as ignoring gameplay-only actions while a menu is open).
+//!
+//! Add a [`StateScopedActionsPlugin`] for your action and state types,
+//! and describe the actions available in each state with the [`StateScopedActions`] resource.
+
+use bevy::app::{App, CoreStage, Plugin};
+use bevy::ecs::prelude::*;
+use bevy::ecs::schedule::StateData;
+use bevy::utils::HashMap;
+use core::marker::PhantomData;
+
+use crate::action_set::ActionSet;
+use crate::action_state::{ActionState, ConsumeRelease};
+use crate::plugin::{InputManagerSystem, InputManagerSystemFor};
+use crate::Actionlike;
+
+/// The actions of type `A` that are available in each state of type `S`
+///
+/// While the current [`State<S>`] has a scope, every [`ActionState<A>`] (both the resource and components)
+/// has its actions outside of that scope [consumed](ActionState::consume) as soon as they are pressed,
+/// so they are never pressed in that state.
+/// Actions that are held through a transition into such a state become [`just_released`](ActionState::just_released),
+/// and must be pressed again after returning to a state where they are available.
+///
+/// States without a scope leave every action available.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::state_scoped_actions::{StateScopedActions, StateScopedActionsPlugin};
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Jump,
+///     Attack,
+///     Pause,
+/// }
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+/// enum GameState {
+///     Playing,
+///     Paused,
+/// }
+///
+/// // Only unpausing is possible while paused, while every action is available while playing
+/// let scoped_actions =
+///     StateScopedActions::default().with_scope(GameState::Paused, [Action::Pause]);
+/// assert!(scoped_actions.is_available(&GameState::Playing, Action::Jump));
+/// assert!(!scoped_actions.is_available(&GameState::Paused, Action::Jump));
+///
+/// App::new()
+///     .add_plugins(MinimalPlugins)
+///     .add_plugin(InputManagerPlugin::<Action>::default())
+///     .add_plugin(StateScopedActionsPlugin::<Action, GameState>::default())
+///     .add_state(GameState::Playing)
+///     .insert_resource(scoped_actions);
+/// ```
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct StateScopedActions<A: Actionlike, S: StateData> {
+    scopes: HashMap<S, ActionSet<A>>,
+}
+
+// Deriving default induces an undesired bound on the generics
+impl<A: Actionlike, S: StateData> Default for StateScopedActions<A, S> {
+    fn default() -> Self {
+        Self {
+            scopes: HashMap::default(),
+        }
+    }
+}
+
+impl<A: Actionlike, S: StateData> StateScopedActions<A, S> {
+    /// Restricts the actions available in the `state` to the provided `actions`
+    #[must_use]
+    pub fn with_scope(mut self, state: S, actions: impl IntoIterator<Item = A>) -> Self {
+        self.insert_scope(state, actions);
+        self
+    }
+
+    /// Restricts the actions available in the `state` to the provided `actions`
+    ///
+    /// Any previous scope of the `state` is replaced.
+    pub fn insert_scope(&mut self, state: S, actions: impl IntoIterator<Item = A>) -> &mut Self {
+        self.scopes.insert(state, actions.into_iter().collect());
+        self
+    }
+
+    /// Makes every action available in the `state` again, returning its previous scope
+    pub fn remove_scope(&mut self, state: &S) -> Option<ActionSet<A>> {
+        self.scopes.remove(state)
+    }
+
+    /// The actions available in the `state`, or [`None`] if every action is available
+    #[must_use]
+    pub fn scope(&self, state: &S) -> Option<&ActionSet<A>> {
+        self.scopes.get(state)
+    }
+
+    /// Is the `action` available in the `state`?
+    #[must_use]
+    pub fn is_available(&self, state: &S, action: A) -> bool {
+        self.scope(state)
+            .map_or(true, |actions| actions.contains(action))
+    }
+
+    /// Consumes the pressed actions of the `action_state` that are not available in the `state`
+    pub fn apply(&self, state: &S, action_state: &mut ActionState<A>) {
+        let Some(actions) = self.scope(state) else {
+            return;
+        };
+
+        for action in A::variants() {
+            if action_state.pressed(action.clone()) && !actions.contains(action.clone()) {
+                action_state.consume_with(action, ConsumeRelease::UntilReleased);
+            }
+        }
+    }
+}
+
+/// A [`Plugin`] that restricts the actions of type `A` to those available in the current [`State<S>`]
+///
+/// The scope of each state is read from the [`StateScopedActions<A, S>`] resource, which is initialized empty if it does not exist.
+/// [`apply_state_scoped_actions`] runs in [`CoreStage::PreUpdate`], after the [`InputManagerSystem::ManualControl`] systems of `A`,
+/// so that actions pressed by [`VirtualController`](crate::virtual_controller::VirtualController)s and the like are restricted too.
+///
+/// This plugin should be added alongside [`InputManagerPlugin<A>`](crate::plugin::InputManagerPlugin), once per state type.
+pub struct StateScopedActionsPlugin<A: Actionlike, S: StateData> {
+    _phantom: PhantomData<(A, S)>,
+}
+
+// Deriving default induces an undesired bound on the generics
+impl<A: Actionlike, S: StateData> Default for StateScopedActionsPlugin<A, S> {
+    fn default() -> Self {
+        Self {
+            _phantom: PhantomData::default(),
+        }
+    }
+}
+
+impl<A: Actionlike, S: StateData> Plugin for StateScopedActionsPlugin<A, S> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StateScopedActions<A, S>>()
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                apply_state_scoped_actions::<A, S>
+                    .after(InputManagerSystemFor::<A>::new(
+                        InputManagerSystem::ManualControl,
+                    ))
+                    .before(InputManagerSystem::ReleaseOnDisable),
+            );
+    }
+}
+
+/// Consumes the actions of every [`ActionState<A>`] that are not available in the current [`State<S>`],
+/// according to the [`StateScopedActions<A, S>`] resource
+pub fn apply_state_scoped_actions<A: Actionlike, S: StateData>(
+    mut query: Query<&mut ActionState<A>>,
+    action_state: Option<ResMut<ActionState<A>>>,
+    scoped_actions: Res<StateScopedActions<A, S>>,
+    state: Option<Res<State<S>>>,
+) {
+    let Some(state) = state else {
+        return;
+    };
+
+    if let Some(mut action_state) = action_state {
+        scoped_actions.apply(state.current(), &mut action_state);
+    }
+
+    for mut action_state in query.iter_mut() {
+        scoped_actions.apply(state.current(), &mut action_state);
+    }
+}
@@ -0,0 +1,284 @@
+//! This module contains [`StickFlicks`] and its supporting methods and impls.
+//!
+//! The [`StickFlicks`] is an optional addition to an [`InputManagerBundle`](crate::InputManagerBundle),
+//! which detects quick flicks of a stick: pushing it past a threshold and letting it return to the center within a short time.
+//! Each flick presses an action for a single update, with the direction of the flick as its axis pair.
+//! This powers flick-stick aiming and quick-turn mechanics, without confusing flicks with ordinary stick movement.
+
+use bevy::ecs::prelude::*;
+use bevy::math::Vec2;
+use bevy::utils::Duration;
+
+use crate::action_state::ActionState;
+use crate::axislike::DualAxisData;
+use crate::Actionlike;
+
+/// Where the stick of a [`StickFlick`] is in its motion
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FlickPhase {
+    /// The stick is near its center
+    Rest,
+    /// The stick left its center this long ago, and may still be flicked
+    Pushed {
+        elapsed: Duration,
+        /// The furthest position reached so far
+        peak: Vec2,
+    },
+    /// The stick was held away from its center for too long to be a flick
+    Held,
+}
+
+/// Presses an action when the stick of another action is flicked
+#[derive(Debug, Clone, PartialEq)]
+pub struct StickFlick<A: Actionlike> {
+    /// The action whose axis pair is watched for flicks, which should be bound to a stick
+    pub stick: A,
+    /// The action pressed for a single update when the [`StickFlick::stick`] is flicked
+    ///
+    /// This action is pressed by the [`StickFlicks`], and should not be bound in the [`InputMap`](crate::input_map::InputMap).
+    /// Its axis pair is set to the direction of the flick, as a unit vector.
+    pub flick: A,
+    /// How far the stick must be pushed from its center, between `0.0` and `1.0`
+    pub threshold: f32,
+    /// How close to its center the stick must return for the flick to complete, between `0.0` and `1.0`
+    pub rest_radius: f32,
+    /// How long the stick may spend away from its center during a flick
+    pub max_duration: Duration,
+    phase: FlickPhase,
+}
+
+impl<A: Actionlike> StickFlick<A> {
+    /// The default [`StickFlick::threshold`]
+    pub const DEFAULT_THRESHOLD: f32 = 0.9;
+
+    /// The default [`StickFlick::rest_radius`]
+    pub const DEFAULT_REST_RADIUS: f32 = 0.3;
+
+    /// The default [`StickFlick::max_duration`]
+    pub const DEFAULT_MAX_DURATION: Duration = Duration::from_millis(200);
+
+    /// Creates a [`StickFlick`] that presses `flick` when the stick of `stick` is flicked, with the default settings
+    #[must_use]
+    pub fn new(stick: A, flick: A) -> Self {
+        Self {
+            stick,
+            flick,
+            threshold: Self::DEFAULT_THRESHOLD,
+            rest_radius: Self::DEFAULT_REST_RADIUS,
+            max_duration: Self::DEFAULT_MAX_DURATION,
+            phase: FlickPhase::Rest,
+        }
+    }
+
+    /// Sets how far the stick must be pushed from its center
+    #[must_use]
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Sets how close to its center the stick must return for the flick to complete
+    #[must_use]
+    pub fn with_rest_radius(mut self, rest_radius: f32) -> Self {
+        self.rest_radius = rest_radius;
+        self
+    }
+
+    /// Sets how long the stick may spend away from its center during a flick
+    #[must_use]
+    pub fn with_max_duration(mut self, max_duration: Duration) -> Self {
+        self.max_duration = max_duration;
+        self
+    }
+
+    /// Advances the flick with the `position` of the stick, after `delta` has passed
+    ///
+    /// Returns the direction of the flick, if it was completed.
+    fn advance(&mut self, position: Vec2, delta: Duration) -> Option<Vec2> {
+        let at_rest = position.length() <= self.rest_radius;
+
+        match &mut self.phase {
+            FlickPhase::Rest if !at_rest => {
+                self.phase = FlickPhase::Pushed {
+                    elapsed: Duration::ZERO,
+                    peak: position,
+                };
+                None
+            }
+            FlickPhase::Pushed { elapsed, peak } => {
+                *elapsed += delta;
+                if position.length() > peak.length() {
+                    *peak = position;
+                }
+                let (elapsed, peak) = (*elapsed, *peak);
+
+                if at_rest {
+                    self.phase = FlickPhase::Rest;
+                    (elapsed <= self.max_duration && peak.length() >= self.threshold)
+                        .then(|| peak.normalize())
+                } else {
+                    if elapsed > self.max_duration {
+                        self.phase = FlickPhase::Held;
+                    }
+                    None
+                }
+            }
+            FlickPhase::Held if at_rest => {
+                self.phase = FlickPhase::Rest;
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Presses actions when sticks are flicked
+///
+/// As a resource, this affects the [`ActionState`] resource,
+/// and as a component, the [`ActionState`] of the same entity.
+/// This is applied by [`apply_stick_flicks`](crate::systems::apply_stick_flicks),
+/// as part of [`InputManagerSystem::ManualControl`](crate::plugin::InputManagerSystem::ManualControl).
+///
+/// A flick is reported once the stick returns to its center, as only then can it be told apart from the stick being held.
+/// Holding the stick past its [`StickFlick::max_duration`] never counts as a flick.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::stick_flicks::{StickFlick, StickFlicks};
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Look,
+///     QuickTurn,
+/// }
+///
+/// fn quick_turn(query: Query<&ActionState<Action>>) {
+///     for action_state in query.iter() {
+///         if let Some(direction) = action_state.axis_pair(Action::QuickTurn) {
+///             if action_state.just_pressed(Action::QuickTurn) {
+///                 println!("Turning towards {:?}", direction.xy());
+///             }
+///         }
+///     }
+/// }
+///
+/// let mut world = World::new();
+/// world
+///     .spawn(InputManagerBundle {
+///         input_map: InputMap::new([(DualAxis::right_stick(), Action::Look)]),
+///         ..Default::default()
+///     })
+///     .insert(StickFlicks::new([StickFlick::new(Action::Look, Action::QuickTurn)]));
+/// ```
+#[derive(Resource, Component, Debug, Clone, PartialEq)]
+pub struct StickFlicks<A: Actionlike> {
+    /// The flicks, which are detected independently of each other
+    pub flicks: Vec<StickFlick<A>>,
+}
+
+impl<A: Actionlike> Default for StickFlicks<A> {
+    fn default() -> Self {
+        Self { flicks: Vec::new() }
+    }
+}
+
+impl<A: Actionlike> StickFlicks<A> {
+    /// Creates [`StickFlicks`] from the provided `flicks`
+    #[must_use]
+    pub fn new(flicks: impl IntoIterator<Item = StickFlick<A>>) -> Self {
+        Self {
+            flicks: flicks.into_iter().collect(),
+        }
+    }
+
+    /// Adds a [`StickFlick`]
+    #[must_use]
+    pub fn with(mut self, flick: StickFlick<A>) -> Self {
+        self.flicks.push(flick);
+        self
+    }
+
+    /// Advances each flick with the axis pairs of the `action_state`, after `delta` has passed,
+    /// pressing the actions of completed flicks
+    pub fn apply(&mut self, action_state: &mut ActionState<A>, delta: Duration) {
+        for flick in &mut self.flicks {
+            let position = action_state
+                .axis_pair(flick.stick.clone())
+                .map_or(Vec2::ZERO, |axis_pair| axis_pair.xy());
+
+            if let Some(direction) = flick.advance(position, delta) {
+                action_state.press(flick.flick.clone());
+                action_state.action_data_mut(flick.flick.clone()).axis_pair =
+                    Some(DualAxisData::from_xy(direction));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as leafwing_input_manager;
+
+    #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    enum Action {
+        Look,
+        Flick,
+    }
+
+    const FRAME: Duration = Duration::from_millis(50);
+
+    /// Moves the stick through the `positions`, one per frame, returning the frames on which a flick was pressed
+    fn flicked_frames(positions: &[Vec2]) -> Vec<usize> {
+        let mut flicks = StickFlicks::new([StickFlick::new(Action::Look, Action::Flick)]);
+        let mut action_state = ActionState::<Action>::default();
+
+        let mut frames = Vec::new();
+        for (frame, &position) in positions.iter().enumerate() {
+            action_state.release(Action::Flick);
+            action_state.action_data_mut(Action::Look).axis_pair =
+                Some(DualAxisData::from_xy(position));
+            flicks.apply(&mut action_state, FRAME);
+            if action_state.pressed(Action::Flick) {
+                frames.push(frame);
+            }
+        }
+        frames
+    }
+
+    #[test]
+    fn quick_flicks_are_detected() {
+        let right = Vec2::X;
+        let positions = [Vec2::ZERO, right * 0.5, right, Vec2::ZERO, Vec2::ZERO];
+        assert_eq!(flicked_frames(&positions), [3]);
+    }
+
+    #[test]
+    fn flick_direction_is_reported() {
+        let mut flicks = StickFlicks::new([StickFlick::new(Action::Look, Action::Flick)]);
+        let mut action_state = ActionState::<Action>::default();
+
+        for position in [Vec2::new(0.0, -0.95), Vec2::ZERO] {
+            action_state.action_data_mut(Action::Look).axis_pair =
+                Some(DualAxisData::from_xy(position));
+            flicks.apply(&mut action_state, FRAME);
+        }
+        assert!(action_state.just_pressed(Action::Flick));
+        assert_eq!(
+            action_state.axis_pair(Action::Flick),
+            Some(DualAxisData::new(0.0, -1.0))
+        );
+    }
+
+    #[test]
+    fn small_and_held_movements_are_not_flicks() {
+        let up = Vec2::Y;
+        // Never reaches the threshold
+        assert!(flicked_frames(&[up * 0.6, up * 0.7, Vec2::ZERO]).is_empty());
+        // Held for longer than the maximum duration
+        let held = [up, up, up, up, up, up, Vec2::ZERO];
+        assert!(flicked_frames(&held).is_empty());
+    }
+}
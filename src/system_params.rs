@@ -0,0 +1,148 @@
+//! Custom [`SystemParam`]s for ergonomic access to the [`ActionState`] of a single player
+//!
+//! Most games only have one player, whose [`ActionState`] is stored either as a resource
+//! or as a component on the only entity with a marker such as `Player`.
+//! [`Actions`] and [`ActionsMut`] read from whichever of these exists,
+//! replacing the usual `Query<&ActionState<A>, With<Player>>` and `.single()` boilerplate.
+
+use bevy::ecs::prelude::*;
+use bevy::ecs::query::ReadOnlyWorldQuery;
+use bevy::ecs::system::SystemParam;
+
+use crate::action_state::ActionState;
+use crate::Actionlike;
+
+/// Read-only access to the [`ActionState<A>`] of the single player
+///
+/// The [`ActionState`] of the only entity matching the query filter `F` is used if there is exactly one,
+/// falling back to the [`ActionState<A>`] resource.
+/// Use `()` as the filter to match any entity with an [`ActionState<A>`].
+///
+/// For mutable access, such as to [consume](ActionState::consume) actions, use [`ActionsMut`] instead.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Jump,
+/// }
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// fn jump(actions: Actions<Action, With<Player>>) {
+///     if actions.single().just_pressed(Action::Jump) {
+///         println!("Jumped!");
+///     }
+/// }
+///
+/// // When there may not be a player, such as in menus, use the fallible variant instead
+/// fn jump_if_spawned(actions: Actions<Action, With<Player>>) {
+///     if let Some(action_state) = actions.get_single() {
+///         if action_state.just_pressed(Action::Jump) {
+///             println!("Jumped!");
+///         }
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugin(InputManagerPlugin::<Action>::default())
+///     .add_system(jump)
+///     .add_system(jump_if_spawned);
+/// ```
+#[derive(SystemParam)]
+pub struct Actions<'w, 's, A: Actionlike, F: ReadOnlyWorldQuery + 'static> {
+    resource: Option<Res<'w, ActionState<A>>>,
+    query: Query<'w, 's, &'static ActionState<A>, F>,
+}
+
+impl<'w, 's, A: Actionlike, F: ReadOnlyWorldQuery + 'static> Actions<'w, 's, A, F> {
+    /// The [`ActionState`] of the single player, if any
+    ///
+    /// Returns the [`ActionState`] of the only entity matching `F` if there is exactly one,
+    /// and otherwise the [`ActionState<A>`] resource if it exists.
+    #[must_use]
+    pub fn get_single(&self) -> Option<&ActionState<A>> {
+        self.query.get_single().ok().or(self.resource.as_deref())
+    }
+
+    /// The [`ActionState`] of the single player
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not exactly one entity matching `F` and there is no [`ActionState<A>`] resource.
+    /// Use [`Actions::get_single`] to handle this case instead.
+    #[must_use]
+    pub fn single(&self) -> &ActionState<A> {
+        self.get_single()
+            .unwrap_or_else(|| panic_no_single::<A>(self.query.iter().count()))
+    }
+}
+
+/// Mutable access to the [`ActionState<A>`] of the single player
+///
+/// This works like [`Actions`], but allows the [`ActionState`] to be modified,
+/// such as to [consume](ActionState::consume) actions.
+#[derive(SystemParam)]
+pub struct ActionsMut<'w, 's, A: Actionlike, F: ReadOnlyWorldQuery + 'static> {
+    resource: Option<ResMut<'w, ActionState<A>>>,
+    query: Query<'w, 's, &'static mut ActionState<A>, F>,
+}
+
+impl<'w, 's, A: Actionlike, F: ReadOnlyWorldQuery + 'static> ActionsMut<'w, 's, A, F> {
+    /// The [`ActionState`] of the single player, if any
+    ///
+    /// Returns the [`ActionState`] of the only entity matching `F` if there is exactly one,
+    /// and otherwise the [`ActionState<A>`] resource if it exists.
+    #[must_use]
+    pub fn get_single(&self) -> Option<&ActionState<A>> {
+        self.query.get_single().ok().or(self.resource.as_deref())
+    }
+
+    /// The [`ActionState`] of the single player, mutably, if any
+    ///
+    /// The same [`ActionState`] is returned as by [`ActionsMut::get_single`].
+    #[must_use]
+    pub fn get_single_mut(&mut self) -> Option<&mut ActionState<A>> {
+        match self.query.get_single_mut() {
+            Ok(action_state) => Some(action_state.into_inner()),
+            Err(_) => self.resource.as_deref_mut(),
+        }
+    }
+
+    /// The [`ActionState`] of the single player
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not exactly one entity matching `F` and there is no [`ActionState<A>`] resource.
+    /// Use [`ActionsMut::get_single`] to handle this case instead.
+    #[must_use]
+    pub fn single(&self) -> &ActionState<A> {
+        self.get_single()
+            .unwrap_or_else(|| panic_no_single::<A>(self.query.iter().count()))
+    }
+
+    /// The [`ActionState`] of the single player, mutably
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not exactly one entity matching `F` and there is no [`ActionState<A>`] resource.
+    /// Use [`ActionsMut::get_single_mut`] to handle this case instead.
+    #[must_use]
+    pub fn single_mut(&mut self) -> &mut ActionState<A> {
+        let matching = self.query.iter().count();
+        self.get_single_mut()
+            .unwrap_or_else(|| panic_no_single::<A>(matching))
+    }
+}
+
+/// Reports that no single [`ActionState<A>`] could be found, out of `matching` entities
+fn panic_no_single<A: Actionlike>(matching: usize) -> ! {
+    panic!(
+        "Expected a single `ActionState<{}>`, but found {matching} matching entities and no resource",
+        std::any::type_name::<A>(),
+    )
+}
@@ -1,65 +1,280 @@
 //! The systems that power each [`InputManagerPlugin`](crate::plugin::InputManagerPlugin).
 
 #[cfg(feature = "ui")]
-use crate::action_state::ActionStateDriver;
+use crate::action_state::{ActionStateDriver, ActionStateDrivers, DisabledDriver, DriverPressMode};
 use crate::{
+    action_aliases::ActionAliases,
     action_state::{ActionDiff, ActionState},
+    action_statistics::ActionStatistics,
+    action_usage::ActionUsage,
+    axis_ramp::AxisRamp,
+    axislike::{AxisType, DualAxisData, MouseWheelSettings},
+    binding_capture::BindingCapture,
+    buttonlike::GamepadButtonSettings,
     clashing_inputs::ClashStrategy,
-    input_map::InputMap,
+    combo_buttons::ComboButtons,
+    directional_repeat::DirectionalRepeat,
+    dynamic_actions::DynamicActions,
+    exclusive_actions::ExclusiveActions,
+    inhibit_input::{InhibitInput, InputDevice},
+    input_log::InputLog,
+    input_map::{InputMap, InputMapOverlay},
     input_streams::InputStreams,
-    plugin::ToggleActions,
+    key_sequences::KeySequences,
+    motion_controls::{GamepadMotion, MotionControls},
+    platform_bindings::{PlatformBindings, SchemeChanged},
+    plugin::{PauseTiming, TimeSource, ToggleActions},
     press_scheduler::PressScheduler,
+    stick_flicks::StickFlicks,
+    text_input::TextInputBuffer,
+    user_input::{InputKind, UserInput},
+    value_thresholds::{ValueThresholdCrossed, ValueThresholds},
+    virtual_controller::VirtualController,
     Actionlike,
 };
 
-use bevy::ecs::{prelude::*, schedule::ShouldRun};
+use bevy::ecs::{event::ManualEventReader, prelude::*, schedule::ShouldRun};
 use bevy::input::{
     gamepad::{GamepadAxis, GamepadButton, Gamepads},
-    keyboard::KeyCode,
+    keyboard::{KeyCode, KeyboardInput},
     mouse::{MouseButton, MouseMotion, MouseWheel},
-    Axis, Input,
+    touch::Touches,
+    Axis, ButtonState, Input,
 };
+use bevy::math::Vec2;
 use bevy::time::Time;
-use bevy::utils::Instant;
+use bevy::utils::{FloatOrd, HashMap, Instant};
+use bevy::window::{ReceivedCharacter, Windows};
 
+/// The number of entities handed to each parallel task by the systems in this module
+///
+/// Entities with an [`ActionState`] component are processed in parallel batches of this size,
+/// so that large numbers of AI-controlled or networked entities scale across cores.
+/// Smaller batches spread the work more evenly, at the cost of additional scheduling overhead.
+pub const ACTION_STATE_BATCH_SIZE: usize = 64;
+
+#[cfg(feature = "ui")]
+use bevy::transform::components::GlobalTransform;
 #[cfg(feature = "ui")]
-use bevy::ui::Interaction;
+use bevy::ui::{Interaction, Node};
 #[cfg(feature = "egui")]
 use bevy_egui::EguiContext;
 
+#[cfg(feature = "touchpad")]
+use crate::touchpad::GamepadTouchpads;
+
 /// Advances actions timer.
 ///
 /// Clears the just-pressed and just-released values of all [`ActionState`]s.
 /// Also resets the internal `pressed_this_tick` field, used to track whether or not to release an action.
+/// Durations follow the clock chosen by the [`TimeSource`] resource,
+/// and are held in place while the [`PauseTiming<A>`] resource is paused.
+///
+/// To tick and update a single [`ActionState`] outside of the plugin's schedule,
+/// call [`action_state::tick_action_state`](crate::action_state::tick_action_state) instead.
 pub fn tick_action_state<A: Actionlike>(
     mut query: Query<&mut ActionState<A>>,
     action_state: Option<ResMut<ActionState<A>>>,
     time: Res<Time>,
+    pause_timing: Option<Res<PauseTiming<A>>>,
+    time_source: Option<Res<TimeSource>>,
     mut stored_previous_instant: Local<Option<Instant>>,
 ) {
     // If this is the very first tick, measure from the start of the app
-    let current_instant = time.last_update().unwrap_or_else(|| time.startup());
     let previous_instant = stored_previous_instant.unwrap_or_else(|| time.startup());
+    let time_source = time_source.map(|res| *res).unwrap_or_default();
+    let current_instant = time_source.current_instant(&time, previous_instant);
+    let paused = pause_timing.is_some_and(|pause_timing| pause_timing.paused);
+
+    let tick = |action_state: &mut ActionState<A>| {
+        if paused {
+            action_state.tick_paused(current_instant, previous_instant);
+        } else {
+            action_state.tick(current_instant, previous_instant);
+        }
+    };
 
     // Only tick the ActionState resource if it exists
     if let Some(mut action_state) = action_state {
-        action_state.tick(current_instant, previous_instant);
+        tick(&mut action_state);
     }
 
     // Only tick the ActionState components if they exist
-    for mut action_state in query.iter_mut() {
+    query.par_for_each_mut(ACTION_STATE_BATCH_SIZE, |mut action_state| {
         // If `Time` has not ever been advanced, something has gone horribly wrong
         // and the user probably forgot to add the `core_plugin`.
-        action_state.tick(current_instant, previous_instant);
-    }
+        tick(&mut action_state);
+    });
 
     // Store the previous time in the system
-    *stored_previous_instant = time.last_update();
+    *stored_previous_instant = Some(current_instant);
+}
+
+/// Appends the typed characters to the [`TextInputBuffer`] resource, while it is active
+///
+/// Does nothing if the [`TextInputBuffer`] resource or the [`ReceivedCharacter`] events do not exist.
+pub fn capture_text_input(
+    received_characters: Option<Res<Events<ReceivedCharacter>>>,
+    mut text_input: Option<ResMut<TextInputBuffer>>,
+    mut reader: Local<ManualEventReader<ReceivedCharacter>>,
+) {
+    let Some(received_characters) = received_characters else {
+        return;
+    };
+
+    // Characters are read even without a buffer, so that they are not captured once it is added
+    for event in reader.iter(&received_characters) {
+        if let Some(text_input) = text_input.as_mut() {
+            text_input.receive(event.char);
+        }
+    }
+}
+
+/// Offers each newly pressed input to the [`BindingCapture`] resource, so that it can capture the next binding
+///
+/// Does nothing if the [`BindingCapture`] resource does not exist.
+/// Missing input resources and events are ignored, as if none of the corresponding inputs were pressed.
+pub fn capture_binding(
+    keyboard_input: Option<Res<Events<KeyboardInput>>>,
+    mouse_buttons: Option<Res<Input<MouseButton>>>,
+    mouse_wheel: Option<Res<Events<MouseWheel>>>,
+    mouse_motion: Option<Res<Events<MouseMotion>>>,
+    gamepad_buttons: Option<Res<Input<GamepadButton>>>,
+    mut capture: Option<ResMut<BindingCapture>>,
+    mut readers: Local<(
+        ManualEventReader<KeyboardInput>,
+        ManualEventReader<MouseWheel>,
+        ManualEventReader<MouseMotion>,
+    )>,
+) {
+    let (key_reader, wheel_reader, motion_reader) = &mut *readers;
+
+    // Keys are tracked even without a capture, so that keys held when it is added count as repeats
+    if let Some(keyboard_input) = keyboard_input {
+        for event in key_reader.iter(&keyboard_input) {
+            if let (Some(key), Some(capture)) = (event.key_code, capture.as_mut()) {
+                capture.receive_key(key, event.state == ButtonState::Pressed);
+            }
+        }
+    }
+
+    let Some(mut capture) = capture else {
+        return;
+    };
+    if let Some(mouse_buttons) = mouse_buttons {
+        for &button in mouse_buttons.get_just_pressed() {
+            capture.receive(InputKind::Mouse(button));
+        }
+    }
+    if let Some(gamepad_buttons) = gamepad_buttons {
+        for button in gamepad_buttons.get_just_pressed() {
+            capture.receive(InputKind::GamepadButton(button.button_type));
+        }
+    }
+    if let Some(mouse_wheel) = mouse_wheel {
+        for event in wheel_reader.iter(&mouse_wheel) {
+            capture.receive_mouse_wheel(Vec2::new(event.x, event.y));
+        }
+    }
+    if let Some(mouse_motion) = mouse_motion {
+        let motion = motion_reader
+            .iter(&mouse_motion)
+            .map(|event| event.delta)
+            .sum();
+        capture.receive_mouse_motion(motion);
+    }
+}
+
+/// Updates the [`DynamicActions`] resource and components from the inputs of every device
+///
+/// Missing resources will be ignored, and treated as if none of the corresponding inputs were pressed.
+#[allow(clippy::too_many_arguments)]
+pub fn update_dynamic_actions(
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    gamepad_button_axes: Res<Axis<GamepadButton>>,
+    gamepad_axes: Res<Axis<GamepadAxis>>,
+    gamepads: Res<Gamepads>,
+    keycodes: Option<Res<Input<KeyCode>>>,
+    mouse_buttons: Option<Res<Input<MouseButton>>>,
+    mouse_wheel: Option<Res<Events<MouseWheel>>>,
+    input_settings: (
+        Option<Res<MouseWheelSettings>>,
+        Option<Res<GamepadButtonSettings>>,
+    ),
+    mouse_motion: Res<Events<MouseMotion>>,
+    windows: Option<Res<Windows>>,
+    dynamic_actions: Option<ResMut<DynamicActions>>,
+    mut query: Query<&mut DynamicActions>,
+) {
+    let (mouse_wheel_settings, gamepad_button_settings) = input_settings;
+    let input_streams = InputStreams {
+        gamepad_buttons: &gamepad_buttons,
+        gamepad_button_axes: &gamepad_button_axes,
+        gamepad_button_settings: gamepad_button_settings
+            .map(|settings| *settings)
+            .unwrap_or_default(),
+        gamepad_axes: &gamepad_axes,
+        gamepads: &gamepads,
+        keycodes: keycodes.as_deref(),
+        mouse_buttons: mouse_buttons.as_deref(),
+        mouse_wheel: mouse_wheel.as_deref(),
+        mouse_wheel_settings: mouse_wheel_settings
+            .map(|settings| *settings)
+            .unwrap_or_default(),
+        mouse_motion: &mouse_motion,
+        windows: windows.as_deref(),
+        associated_gamepad: None,
+        gamepad_association: Default::default(),
+        associated_window: None,
+    };
+
+    let update = |dynamic_actions: &mut DynamicActions| {
+        let input_streams = InputStreams {
+            associated_gamepad: dynamic_actions.gamepad(),
+            ..input_streams.clone()
+        };
+        dynamic_actions.update(&input_streams);
+    };
+
+    if let Some(mut dynamic_actions) = dynamic_actions {
+        update(&mut dynamic_actions);
+    }
+
+    for mut dynamic_actions in query.iter_mut() {
+        update(&mut dynamic_actions);
+    }
+}
+
+/// Writes the [`GamepadTouchpads`] resource into the gamepad inputs, so that touchpads can be bound like other gamepad inputs
+///
+/// Does nothing if the [`GamepadTouchpads`] resource does not exist.
+#[cfg(feature = "touchpad")]
+pub fn update_touchpad_inputs(
+    touchpads: Option<ResMut<GamepadTouchpads>>,
+    mut gamepad_buttons: ResMut<Input<GamepadButton>>,
+    mut gamepad_button_axes: ResMut<Axis<GamepadButton>>,
+    mut gamepad_axes: ResMut<Axis<GamepadAxis>>,
+) {
+    if let Some(mut touchpads) = touchpads {
+        touchpads.write_inputs(
+            &mut gamepad_buttons,
+            &mut gamepad_button_axes,
+            &mut gamepad_axes,
+        );
+    }
 }
 
 /// Fetches all of the releveant [`Input`] resources to update [`ActionState`] according to the [`InputMap`]
 ///
-/// Missing resources will be ignored, and treated as if none of the corresponding inputs were pressed
+/// Missing resources will be ignored, and treated as if none of the corresponding inputs were pressed.
+///
+/// [`ActionState`] components are updated in parallel, in batches of [`ACTION_STATE_BATCH_SIZE`].
+/// Entities with an [`InputMapOverlay`] use the result of [`InputMapOverlay::apply`] instead of their base [`InputMap`],
+/// which is only recomputed when the overlay or the base map changes.
+/// Inputs from the devices of an [`InhibitInput`] are ignored, as if they were released,
+/// as are the keys suppressed by an active [`TextInputBuffer`].
+/// Pressed actions then pass through any [`ComboButtons`], which may hold them back or combine them,
+/// and are forwarded to other actions by any [`ActionAliases`].
 #[allow(clippy::too_many_arguments)]
 pub fn update_action_state<A: Actionlike>(
     gamepad_buttons: Res<Input<GamepadButton>>,
@@ -69,16 +284,39 @@ pub fn update_action_state<A: Actionlike>(
     keycodes: Option<Res<Input<KeyCode>>>,
     mouse_buttons: Option<Res<Input<MouseButton>>>,
     mouse_wheel: Option<Res<Events<MouseWheel>>>,
+    input_settings: (
+        Option<Res<MouseWheelSettings>>,
+        Option<Res<GamepadButtonSettings>>,
+        Option<Res<TextInputBuffer>>,
+    ),
     mouse_motion: Res<Events<MouseMotion>>,
+    windows: Option<Res<Windows>>,
     clash_strategy: Res<ClashStrategy>,
     #[cfg(feature = "egui")] maybe_egui: Option<ResMut<EguiContext>>,
     action_state: Option<ResMut<ActionState<A>>>,
     input_map: Option<Res<InputMap<A>>>,
-    press_scheduler: Option<ResMut<PressScheduler<A>>>,
+    resource_extras: (
+        Option<ResMut<PressScheduler<A>>>,
+        Option<Res<InhibitInput<A>>>,
+        Option<ResMut<ComboButtons<A>>>,
+        Option<Res<ActionAliases<A>>>,
+    ),
+    overlays: (
+        Query<
+            (Entity, &InputMap<A>, &InputMapOverlay<A>),
+            Or<(Changed<InputMap<A>>, Changed<InputMapOverlay<A>>)>,
+        >,
+        Query<(), With<InputMapOverlay<A>>>,
+        Local<HashMap<Entity, InputMap<A>>>,
+    ),
     mut query: Query<(
+        Entity,
         &mut ActionState<A>,
         &InputMap<A>,
         Option<&mut PressScheduler<A>>,
+        Option<&InhibitInput<A>>,
+        Option<&mut ComboButtons<A>>,
+        Option<&ActionAliases<A>>,
     )>,
 ) {
     let gamepad_buttons = gamepad_buttons.into_inner();
@@ -88,7 +326,21 @@ pub fn update_action_state<A: Actionlike>(
     let keycodes = keycodes.map(|keycodes| keycodes.into_inner());
     let mouse_buttons = mouse_buttons.map(|mouse_buttons| mouse_buttons.into_inner());
     let mouse_wheel = mouse_wheel.map(|mouse_wheel| mouse_wheel.into_inner());
+    let (mouse_wheel_settings, gamepad_button_settings, text_input) = input_settings;
+    let mouse_wheel_settings = mouse_wheel_settings
+        .map(|settings| *settings)
+        .unwrap_or_default();
+    let gamepad_button_settings = gamepad_button_settings
+        .map(|settings| *settings)
+        .unwrap_or_default();
     let mouse_motion = mouse_motion.into_inner();
+    let windows = windows.map(|windows| windows.into_inner());
+
+    // While text is being typed, only the passthrough keys can trigger actions
+    let text_input_keycodes = text_input
+        .zip(keycodes)
+        .and_then(|(text_input, keycodes)| text_input.filter_keycodes(keycodes));
+    let keycodes = text_input_keycodes.as_ref().or(keycodes);
 
     #[cfg(feature = "egui")]
     let (keycodes, mouse_buttons, mouse_wheel) = if let Some(mut egui) = maybe_egui {
@@ -108,58 +360,317 @@ pub fn update_action_state<A: Actionlike>(
         (keycodes, mouse_buttons, mouse_wheel)
     };
 
-    let resources = input_map
-        .zip(action_state)
-        .map(|(input_map, action_state)| {
-            (
-                Mut::from(action_state),
-                input_map.into_inner(),
-                press_scheduler.map(Mut::from),
-            )
-        });
-
-    for (mut action_state, input_map, press_scheduler) in query.iter_mut().chain(resources) {
+    let clash_strategy = *clash_strategy;
+
+    // Stand-ins for the gamepad and mouse motion streams, whose inputs are never pressed
+    let no_gamepad_buttons = Input::<GamepadButton>::default();
+    let no_gamepad_button_axes = Axis::<GamepadButton>::default();
+    let no_gamepad_axes = Axis::<GamepadAxis>::default();
+    let no_mouse_motion = Events::<MouseMotion>::default();
+
+    // Layering clones the whole base map, so the layered maps are cached until their inputs change
+    let (changed_overlays, overlaid, mut layered_maps) = overlays;
+    layered_maps.retain(|&entity, _| overlaid.contains(entity));
+    for (entity, input_map, overlay) in changed_overlays.iter() {
+        layered_maps.insert(entity, overlay.apply(input_map));
+    }
+
+    let update = |(
+        mut action_state,
+        input_map,
+        press_scheduler,
+        inhibit_input,
+        combo_buttons,
+        action_aliases,
+    ): (
+        Mut<ActionState<A>>,
+        &InputMap<A>,
+        Option<Mut<PressScheduler<A>>>,
+        Option<&InhibitInput<A>>,
+        Option<Mut<ComboButtons<A>>>,
+        Option<&ActionAliases<A>>,
+    )| {
+        let inhibited = |device| inhibit_input.is_some_and(|inhibit| inhibit.inhibits(device));
+        let (gamepad_buttons, gamepad_button_axes, gamepad_axes) =
+            if inhibited(InputDevice::Gamepad) {
+                (
+                    &no_gamepad_buttons,
+                    &no_gamepad_button_axes,
+                    &no_gamepad_axes,
+                )
+            } else {
+                (gamepad_buttons, gamepad_button_axes, gamepad_axes)
+            };
+        let mouse_inhibited = inhibited(InputDevice::Mouse);
+
         let input_streams = InputStreams {
             gamepad_buttons,
             gamepad_button_axes,
+            gamepad_button_settings,
             gamepad_axes,
             gamepads,
-            keycodes,
-            mouse_buttons,
-            mouse_wheel,
-            mouse_motion,
+            keycodes: keycodes.filter(|_| !inhibited(InputDevice::Keyboard)),
+            mouse_buttons: mouse_buttons.filter(|_| !mouse_inhibited),
+            mouse_wheel: mouse_wheel.filter(|_| !mouse_inhibited),
+            mouse_wheel_settings,
+            mouse_motion: if mouse_inhibited {
+                &no_mouse_motion
+            } else {
+                mouse_motion
+            },
+            windows,
             associated_gamepad: input_map.gamepad(),
+            gamepad_association: input_map.gamepad_association(),
+            associated_window: input_map.window(),
         };
 
-        action_state.update(input_map.which_pressed(&input_streams, *clash_strategy));
+        let mut action_data = input_map.which_pressed(&input_streams, clash_strategy);
+        if let Some(mut combo_buttons) = combo_buttons {
+            combo_buttons.apply(&mut action_data);
+        }
+        if let Some(action_aliases) = action_aliases {
+            action_aliases.apply(&mut action_data);
+        }
+        action_state.update(action_data);
         if let Some(mut press_scheduler) = press_scheduler {
             press_scheduler.apply(&mut action_state);
         }
+    };
+
+    let (press_scheduler, inhibit_input, combo_buttons, action_aliases) = resource_extras;
+    if let Some((input_map, action_state)) = input_map.zip(action_state) {
+        update((
+            Mut::from(action_state),
+            input_map.into_inner(),
+            press_scheduler.map(Mut::from),
+            inhibit_input.map(|inhibit_input| inhibit_input.into_inner()),
+            combo_buttons.map(Mut::from),
+            action_aliases.map(|action_aliases| action_aliases.into_inner()),
+        ));
     }
+
+    let layered_maps = &*layered_maps;
+    query.par_for_each_mut(
+        ACTION_STATE_BATCH_SIZE,
+        |(
+            entity,
+            action_state,
+            input_map,
+            press_scheduler,
+            inhibit_input,
+            combo_buttons,
+            action_aliases,
+        )| {
+            update((
+                action_state,
+                layered_maps.get(&entity).unwrap_or(input_map),
+                press_scheduler,
+                inhibit_input,
+                combo_buttons,
+                action_aliases,
+            ))
+        },
+    );
+}
+
+/// Samples mouse motion again, replacing the axis pairs of actions that only get axis pairs from mouse motion
+///
+/// Only actions whose every axis pair comes from a mouse motion [`DualAxis`](crate::axislike::DualAxis) binding are patched,
+/// and no action is pressed or released.
+/// Mice that are inhibited by an [`InhibitInput`], or not [allowed](InputMap::set_allowed_devices) by the [`InputMap`], are ignored.
+///
+/// This system is added by [`InputManagerConfig::with_late_mouse_sampling`](crate::plugin::InputManagerConfig::with_late_mouse_sampling).
+#[allow(clippy::too_many_arguments)]
+pub fn resample_mouse_motion<A: Actionlike>(
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    gamepad_button_axes: Res<Axis<GamepadButton>>,
+    gamepad_axes: Res<Axis<GamepadAxis>>,
+    gamepads: Res<Gamepads>,
+    mouse_motion: Res<Events<MouseMotion>>,
+    windows: Option<Res<Windows>>,
+    action_state: Option<ResMut<ActionState<A>>>,
+    input_map: Option<Res<InputMap<A>>>,
+    inhibit_input: Option<Res<InhibitInput<A>>>,
+    mut query: Query<(&mut ActionState<A>, &InputMap<A>, Option<&InhibitInput<A>>)>,
+) {
+    let input_streams = InputStreams {
+        gamepad_buttons: &gamepad_buttons,
+        gamepad_button_axes: &gamepad_button_axes,
+        gamepad_button_settings: GamepadButtonSettings::default(),
+        gamepad_axes: &gamepad_axes,
+        gamepads: &gamepads,
+        keycodes: None,
+        mouse_buttons: None,
+        mouse_wheel: None,
+        mouse_wheel_settings: MouseWheelSettings::default(),
+        mouse_motion: &mouse_motion,
+        windows: windows.as_deref(),
+        associated_gamepad: None,
+        gamepad_association: Default::default(),
+        associated_window: None,
+    };
+
+    let resample = |action_state: &mut ActionState<A>,
+                    input_map: &InputMap<A>,
+                    inhibit_input: Option<&InhibitInput<A>>| {
+        if !input_map.allows_device(InputDevice::Mouse)
+            || inhibit_input.is_some_and(|inhibit| inhibit.inhibits(InputDevice::Mouse))
+        {
+            return;
+        }
+
+        let input_streams = InputStreams {
+            associated_window: input_map.window(),
+            ..input_streams.clone()
+        };
+
+        for action in A::variants() {
+            let mut axis_pair: Option<DualAxisData> = None;
+            let mut mouse_only = true;
+            for input in input_map.get(action.clone()).iter() {
+                let Some(sample) = input_streams.input_axis_pair(input) else {
+                    continue;
+                };
+                if !is_mouse_motion(input) {
+                    mouse_only = false;
+                    break;
+                }
+                axis_pair =
+                    Some(axis_pair.map_or(sample, |axis_pair| axis_pair.merged_with(sample)));
+            }
+
+            if let Some(axis_pair) = axis_pair.filter(|_| mouse_only) {
+                action_state.action_data_mut(action).axis_pair = Some(axis_pair);
+            }
+        }
+    };
+
+    if let Some((mut action_state, input_map)) = action_state.zip(input_map) {
+        resample(&mut action_state, &input_map, inhibit_input.as_deref());
+    }
+
+    for (mut action_state, input_map, inhibit_input) in query.iter_mut() {
+        resample(&mut action_state, input_map, inhibit_input);
+    }
+}
+
+/// Is the `input` a [`DualAxis`](crate::axislike::DualAxis) of mouse motion?
+fn is_mouse_motion(input: &UserInput) -> bool {
+    matches!(
+        input,
+        UserInput::Single(InputKind::DualAxis(dual_axis))
+            if matches!(dual_axis.x.axis_type, AxisType::MouseMotion(_))
+                && matches!(dual_axis.y.axis_type, AxisType::MouseMotion(_))
+    )
 }
 
 /// When a button with a component of type `A` is clicked, press the corresponding action in the [`ActionState`]
 ///
 /// The action triggered is determined by the variant stored in your UI-defined button.
+/// The [`ActionStateDriver::hovered_action`] is pressed while the button is hovered,
+/// and any [`ActionStateDriver::value`] or [`ActionStateDriver::axis_pair`] is forwarded to the action.
+/// Each driver in an [`ActionStateDrivers`] component is handled in the same way.
+///
+/// The [`ActionStateDriver::activation`] controls which [`Interaction`]s press the action,
+/// and the drivers of entities with a [`DisabledDriver`] component are skipped.
+/// Drivers using [`DriverPressMode::WhilePointerOver`] only press their action while the cursor remains over the clicked node.
 #[cfg(feature = "ui")]
 pub fn update_action_state_from_interaction<A: Actionlike>(
-    ui_query: Query<(&Interaction, &ActionStateDriver<A>)>,
+    ui_query: Query<
+        (
+            &Interaction,
+            Option<&ActionStateDriver<A>>,
+            Option<&ActionStateDrivers<A>>,
+            Option<&Node>,
+            Option<&GlobalTransform>,
+        ),
+        Without<DisabledDriver>,
+    >,
     mut action_state_query: Query<&mut ActionState<A>>,
+    windows: Option<Res<Windows>>,
 ) {
-    for (&interaction, action_state_driver) in ui_query.iter() {
-        if interaction == Interaction::Clicked {
-            let mut action_state = action_state_query
-                .get_mut(action_state_driver.entity)
-                .expect("Entity does not exist, or does not have an `ActionState` component.");
-            action_state.press(action_state_driver.action.clone());
+    for (&interaction, driver, drivers, node, transform) in ui_query.iter() {
+        let drivers = driver
+            .into_iter()
+            .chain(drivers.into_iter().flat_map(ActionStateDrivers::iter));
+
+        for action_state_driver in drivers {
+            // The driven entity may have been despawned, or lost its `ActionState`
+            let target = action_state_driver.entity;
+            let Ok(mut action_state) = action_state_query.get_mut(target) else {
+                continue;
+            };
+            let action = action_state_driver.action.clone();
+
+            if let Some(value) = action_state_driver.value {
+                action_state.action_data_mut(action.clone()).value = value;
+                if value != 0.0 {
+                    action_state.press(action.clone());
+                }
+            }
+
+            if let Some(axis_pair) = action_state_driver.axis_pair {
+                action_state.action_data_mut(action.clone()).axis_pair = Some(axis_pair);
+                if axis_pair.xy() != Vec2::ZERO {
+                    action_state.press(action.clone());
+                }
+            }
+
+            let activation = action_state_driver.activation;
+            match interaction {
+                Interaction::Clicked => {
+                    let pointer_over = match (action_state_driver.press_mode, node, transform) {
+                        (DriverPressMode::WhilePointerOver, Some(node), Some(transform)) => windows
+                            .as_deref()
+                            .is_some_and(|windows| cursor_over_node(windows, node, transform)),
+                        _ => true,
+                    };
+
+                    if activation.clicked && pointer_over {
+                        action_state.press(action);
+                    }
+                }
+                Interaction::Hovered => {
+                    if activation.hovered {
+                        action_state.press(action);
+                    }
+                    if let Some(hovered_action) = action_state_driver.hovered_action.clone() {
+                        action_state.press(hovered_action);
+                    }
+                }
+                Interaction::None => {
+                    if activation.none {
+                        action_state.press(action);
+                    }
+                }
+            }
         }
     }
 }
 
+/// Is the cursor of a focused window within the bounds of the UI `node`?
+///
+/// Matches the hit test performed by `bevy_ui`:
+/// both the cursor position and the [`GlobalTransform`] of UI nodes are measured from the bottom-left corner of the window.
+#[cfg(feature = "ui")]
+fn cursor_over_node(windows: &Windows, node: &Node, transform: &GlobalTransform) -> bool {
+    let center = transform.translation().truncate();
+    let extents = node.size() / 2.0;
+
+    windows
+        .iter()
+        .filter(|window| window.is_focused())
+        .find_map(|window| window.cursor_position())
+        .is_some_and(|cursor| {
+            (center - extents).cmple(cursor).all() && cursor.cmplt(center + extents).all()
+        })
+}
+
 /// Generates an [`Events`](bevy::ecs::event::Events) stream of [`ActionDiff`] from [`ActionState`]
 ///
 /// The `ID` generic type should be a stable entity identifer,
 /// suitable to be sent across a network.
+/// Values and axis pairs are compared against those of the previous update.
 ///
 /// This system is not part of the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin) and must be added manually.
 pub fn generate_action_diffs<A: Actionlike, ID: Eq + Clone + Component>(
@@ -180,6 +691,26 @@ pub fn generate_action_diffs<A: Actionlike, ID: Eq + Clone + Component>(
                 id: id.clone(),
             });
         }
+
+        for action in A::variants() {
+            let action_data = action_state.action_data(action.clone());
+
+            if FloatOrd(action_data.value) != FloatOrd(action_data.previous_value) {
+                action_diffs.send(ActionDiff::ValueChanged {
+                    action: action.clone(),
+                    id: id.clone(),
+                    value: action_data.value,
+                });
+            }
+
+            if action_data.axis_pair != action_data.previous_axis_pair {
+                action_diffs.send(ActionDiff::AxisPairChanged {
+                    action,
+                    id: id.clone(),
+                    axis_pair: action_data.axis_pair,
+                });
+            }
+        }
     }
 }
 
@@ -196,30 +727,258 @@ pub fn process_action_diffs<A: Actionlike, ID: Eq + Component + Clone>(
     // PERF: This would probably be faster with an index, but is much more fussy
     for action_diff in action_diffs.iter() {
         for (mut action_state, id) in action_state_query.iter_mut() {
-            match action_diff {
-                ActionDiff::Pressed {
-                    action,
-                    id: event_id,
-                } => {
-                    if event_id == id {
-                        action_state.press(action.clone());
-                        continue;
-                    }
-                }
-                ActionDiff::Released {
-                    action,
-                    id: event_id,
-                } => {
-                    if event_id == id {
-                        action_state.release(action.clone());
-                        continue;
-                    }
-                }
-            };
+            if action_diff.id() == id {
+                action_state.apply_diff(action_diff);
+            }
         }
     }
 }
 
+/// Repeats and filters directional actions according to the [`DirectionalRepeat<A>`] resource and components
+///
+/// Runs after [`update_action_state`], so that the repeated presses are not overwritten.
+pub fn apply_directional_repeat<A: Actionlike>(
+    mut query: Query<(&mut ActionState<A>, &mut DirectionalRepeat<A>)>,
+    action_state: Option<ResMut<ActionState<A>>>,
+    directional_repeat: Option<ResMut<DirectionalRepeat<A>>>,
+    time: Res<Time>,
+) {
+    let delta = time.delta();
+
+    if let Some((mut action_state, mut directional_repeat)) = action_state.zip(directional_repeat) {
+        directional_repeat.apply(&mut action_state, delta);
+    }
+
+    for (mut action_state, mut directional_repeat) in query.iter_mut() {
+        directional_repeat.apply(&mut action_state, delta);
+    }
+}
+
+/// Matches the keys pressed this update against the [`KeySequences<A>`] resource and components
+///
+/// Runs after [`update_action_state`], so that the actions of completed sequences are not released again.
+/// Does nothing to the sequences if the [`Input<KeyCode>`] resource does not exist.
+pub fn apply_key_sequences<A: Actionlike>(
+    mut query: Query<(&mut ActionState<A>, &mut KeySequences<A>)>,
+    action_state: Option<ResMut<ActionState<A>>>,
+    key_sequences: Option<ResMut<KeySequences<A>>>,
+    keycodes: Option<Res<Input<KeyCode>>>,
+    time: Res<Time>,
+) {
+    let Some(keycodes) = keycodes else {
+        return;
+    };
+    let pressed: Vec<KeyCode> = keycodes.get_just_pressed().copied().collect();
+    let delta = time.delta();
+
+    if let Some((mut action_state, mut key_sequences)) = action_state.zip(key_sequences) {
+        key_sequences.apply(&mut action_state, &pressed, delta);
+    }
+
+    for (mut action_state, mut key_sequences) in query.iter_mut() {
+        key_sequences.apply(&mut action_state, &pressed, delta);
+    }
+}
+
+/// Detects flicks of sticks according to the [`StickFlicks<A>`] resource and components
+///
+/// Runs after [`update_action_state`], so that the flicks are detected from fresh axis pairs, and are not released again.
+pub fn apply_stick_flicks<A: Actionlike>(
+    mut query: Query<(&mut ActionState<A>, &mut StickFlicks<A>)>,
+    action_state: Option<ResMut<ActionState<A>>>,
+    stick_flicks: Option<ResMut<StickFlicks<A>>>,
+    time: Res<Time>,
+) {
+    let delta = time.delta();
+
+    if let Some((mut action_state, mut stick_flicks)) = action_state.zip(stick_flicks) {
+        stick_flicks.apply(&mut action_state, delta);
+    }
+
+    for (mut action_state, mut stick_flicks) in query.iter_mut() {
+        stick_flicks.apply(&mut action_state, delta);
+    }
+}
+
+/// Feeds the motion sensors of gamepads into actions according to the [`MotionControls<A>`] resource and components
+///
+/// Runs after [`update_action_state`], so that the motion is added to the axis pairs of the current update.
+/// Does nothing if the [`GamepadMotion`] resource does not exist.
+pub fn apply_motion_controls<A: Actionlike>(
+    mut query: Query<(
+        &mut ActionState<A>,
+        &MotionControls<A>,
+        Option<&InputMap<A>>,
+    )>,
+    action_state: Option<ResMut<ActionState<A>>>,
+    motion_controls: Option<Res<MotionControls<A>>>,
+    input_map: Option<Res<InputMap<A>>>,
+    gamepad_motion: Option<Res<GamepadMotion>>,
+    time: Res<Time>,
+) {
+    let Some(gamepad_motion) = gamepad_motion else {
+        return;
+    };
+    let delta = time.delta();
+
+    if let Some((mut action_state, motion_controls)) = action_state.zip(motion_controls) {
+        let gamepad = input_map.and_then(|input_map| input_map.gamepad());
+        if let Some(sample) = gamepad_motion.sample(gamepad) {
+            motion_controls.apply(&mut action_state, sample, delta);
+        }
+    }
+
+    for (mut action_state, motion_controls, input_map) in query.iter_mut() {
+        let gamepad = input_map.and_then(InputMap::gamepad);
+        if let Some(sample) = gamepad_motion.sample(gamepad) {
+            motion_controls.apply(&mut action_state, sample, delta);
+        }
+    }
+}
+
+/// Arbitrates between mutually exclusive actions according to the [`ExclusiveActions<A>`] resource and components
+///
+/// Runs after [`update_action_state`], so that the losing actions are not pressed again.
+/// The action priorities of the matching [`InputMap<A>`] are used for [`ExclusivePolicy::MapPriority`](crate::exclusive_actions::ExclusivePolicy::MapPriority) groups.
+pub fn apply_exclusive_actions<A: Actionlike>(
+    mut query: Query<(
+        &mut ActionState<A>,
+        &ExclusiveActions<A>,
+        Option<&InputMap<A>>,
+    )>,
+    action_state: Option<ResMut<ActionState<A>>>,
+    exclusive_actions: Option<Res<ExclusiveActions<A>>>,
+    input_map: Option<Res<InputMap<A>>>,
+) {
+    if let Some((mut action_state, exclusive_actions)) = action_state.zip(exclusive_actions) {
+        exclusive_actions.apply_prioritized(&mut action_state, input_map.as_deref());
+    }
+
+    for (mut action_state, exclusive_actions, input_map) in query.iter_mut() {
+        exclusive_actions.apply_prioritized(&mut action_state, input_map);
+    }
+}
+
+/// Plays the scheduled inputs of the [`VirtualController<A>`] resource and components
+///
+/// Runs after [`update_action_state`] and before [`apply_axis_ramp`], so that controlled values can be ramped.
+pub fn apply_virtual_controller<A: Actionlike>(
+    mut query: Query<(&mut ActionState<A>, &mut VirtualController<A>)>,
+    action_state: Option<ResMut<ActionState<A>>>,
+    virtual_controller: Option<ResMut<VirtualController<A>>>,
+    time: Res<Time>,
+) {
+    let delta = time.delta();
+
+    if let Some((mut action_state, mut virtual_controller)) = action_state.zip(virtual_controller) {
+        virtual_controller.apply(&mut action_state, delta);
+    }
+
+    for (mut action_state, mut virtual_controller) in query.iter_mut() {
+        virtual_controller.apply(&mut action_state, delta);
+    }
+}
+
+/// Ramps the values of actions according to the [`AxisRamp<A>`] resource and components
+///
+/// Runs after [`update_action_state`], so that the ramped values are not overwritten.
+pub fn apply_axis_ramp<A: Actionlike>(
+    mut query: Query<(&mut ActionState<A>, &mut AxisRamp<A>)>,
+    action_state: Option<ResMut<ActionState<A>>>,
+    axis_ramp: Option<ResMut<AxisRamp<A>>>,
+    time: Res<Time>,
+) {
+    let delta = time.delta();
+
+    if let Some((mut action_state, mut axis_ramp)) = action_state.zip(axis_ramp) {
+        axis_ramp.apply(&mut action_state, delta);
+    }
+
+    for (mut action_state, mut axis_ramp) in query.iter_mut() {
+        axis_ramp.apply(&mut action_state, delta);
+    }
+}
+
+/// Sends a [`ValueThresholdCrossed<A>`] event for each threshold of the [`ValueThresholds<A>`] resource and components that was crossed
+///
+/// Runs after every other system that modifies the [`ActionState<A>`], so that only the final values are compared.
+pub fn send_value_threshold_events<A: Actionlike>(
+    mut query: Query<(Entity, &ActionState<A>, &mut ValueThresholds<A>)>,
+    action_state: Option<Res<ActionState<A>>>,
+    value_thresholds: Option<ResMut<ValueThresholds<A>>>,
+    mut events: EventWriter<ValueThresholdCrossed<A>>,
+) {
+    if let Some((action_state, mut value_thresholds)) = action_state.zip(value_thresholds) {
+        events.send_batch(value_thresholds.crossings(&action_state));
+    }
+
+    for (entity, action_state, mut value_thresholds) in query.iter_mut() {
+        events.send_batch(
+            value_thresholds
+                .crossings(action_state)
+                .into_iter()
+                .map(|crossing| ValueThresholdCrossed {
+                    entity: Some(entity),
+                    ..crossing
+                }),
+        );
+    }
+}
+
+/// Records the actions triggered in the [`ActionState<A>`] resource and components in the matching [`ActionUsage<A>`]
+///
+/// Runs after every other system that modifies the [`ActionState<A>`], so that only the final presses are recorded.
+pub fn update_action_usage<A: Actionlike>(
+    mut query: Query<(&ActionState<A>, &mut ActionUsage<A>)>,
+    action_state: Option<Res<ActionState<A>>>,
+    action_usage: Option<ResMut<ActionUsage<A>>>,
+) {
+    if let Some((action_state, mut action_usage)) = action_state.zip(action_usage) {
+        action_usage.record(&action_state);
+    }
+
+    for (action_state, mut action_usage) in query.iter_mut() {
+        action_usage.record(action_state);
+    }
+}
+
+/// Records the presses and hold times of the [`ActionState<A>`] resource and components in the matching [`ActionStatistics<A>`]
+///
+/// Runs after every other system that modifies the [`ActionState<A>`], so that only the final presses are counted.
+pub fn update_action_statistics<A: Actionlike>(
+    mut query: Query<(&ActionState<A>, &mut ActionStatistics<A>)>,
+    action_state: Option<Res<ActionState<A>>>,
+    action_statistics: Option<ResMut<ActionStatistics<A>>>,
+) {
+    if let Some((action_state, mut action_statistics)) = action_state.zip(action_statistics) {
+        action_statistics.record(&action_state);
+    }
+
+    for (action_state, mut action_statistics) in query.iter_mut() {
+        action_statistics.record(action_state);
+    }
+}
+
+/// Records the presses and releases of the [`ActionState<A>`] resource and components in the matching [`InputLog<A>`]
+///
+/// Runs after every other system that modifies the [`ActionState<A>`], so that only the final changes are recorded.
+pub fn update_input_log<A: Actionlike>(
+    mut query: Query<(&ActionState<A>, &mut InputLog<A>)>,
+    action_state: Option<Res<ActionState<A>>>,
+    input_log: Option<ResMut<InputLog<A>>>,
+    time: Res<Time>,
+) {
+    let elapsed = time.elapsed();
+
+    if let Some((action_state, mut input_log)) = action_state.zip(input_log) {
+        input_log.record(&action_state, elapsed);
+    }
+
+    for (action_state, mut input_log) in query.iter_mut() {
+        input_log.record(action_state, elapsed);
+    }
+}
+
 /// Release all inputs if the [`ToggleActions<A>`] resource exists and its `enabled` field is false.
 pub fn release_on_disable<A: Actionlike>(
     mut query: Query<&mut ActionState<A>>,
@@ -236,6 +995,83 @@ pub fn release_on_disable<A: Actionlike>(
     }
 }
 
+/// Records which [`InputDevice`] the player used this frame in the [`PlatformBindings<A>`] resource and components,
+/// sending a [`SchemeChanged<A>`] event whenever this switches their active devices
+///
+/// Pressing a key counts as using [`InputDevice::Keyboard`], pressing a mouse button counts as using [`InputDevice::Mouse`],
+/// pressing a gamepad button counts as using [`InputDevice::Gamepad`],
+/// and touching the screen counts as using [`InputDevice::Touch`].
+pub fn switch_control_schemes<A: Actionlike>(
+    mut query: Query<(Entity, &mut PlatformBindings<A>)>,
+    platform_bindings: Option<ResMut<PlatformBindings<A>>>,
+    keycodes: Option<Res<Input<KeyCode>>>,
+    mouse_buttons: Option<Res<Input<MouseButton>>>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    touches: Option<Res<Touches>>,
+    mut events: EventWriter<SchemeChanged<A>>,
+) {
+    let used_device = if gamepad_buttons.get_just_pressed().next().is_some() {
+        InputDevice::Gamepad
+    } else if keycodes.is_some_and(|keycodes| keycodes.get_just_pressed().next().is_some()) {
+        InputDevice::Keyboard
+    } else if mouse_buttons.is_some_and(|buttons| buttons.get_just_pressed().next().is_some()) {
+        InputDevice::Mouse
+    } else if touches.is_some_and(|touches| touches.iter_just_pressed().next().is_some()) {
+        InputDevice::Touch
+    } else {
+        return;
+    };
+
+    if let Some(mut platform_bindings) = platform_bindings {
+        // Only mutate the bindings when switching, to avoid needlessly rebuilding the input map
+        if platform_bindings.switches_to(used_device) {
+            events.send_batch(platform_bindings.use_device(used_device));
+        }
+    }
+
+    for (entity, mut platform_bindings) in query.iter_mut() {
+        if platform_bindings.switches_to(used_device) {
+            events.send_batch(platform_bindings.use_device(used_device).map(|changed| {
+                SchemeChanged {
+                    entity: Some(entity),
+                    ..changed
+                }
+            }));
+        }
+    }
+}
+
+/// Replaces the [`InputMap<A>`] resource and components with the bindings of their [`PlatformBindings<A>`] whenever those change
+///
+/// Automatically selected devices are also re-detected whenever a gamepad is connected or disconnected.
+pub fn apply_platform_bindings<A: Actionlike>(
+    mut query: Query<(
+        &PlatformBindings<A>,
+        ChangeTrackers<PlatformBindings<A>>,
+        &mut InputMap<A>,
+    )>,
+    platform_bindings: Option<Res<PlatformBindings<A>>>,
+    input_map: Option<ResMut<InputMap<A>>>,
+    gamepads: Res<Gamepads>,
+) {
+    let detected = InputDevice::detect(&gamepads);
+    let needs_update = |changed: bool, platform_bindings: &PlatformBindings<A>| {
+        changed || (gamepads.is_changed() && platform_bindings.is_automatic())
+    };
+
+    if let Some((platform_bindings, mut input_map)) = platform_bindings.zip(input_map) {
+        if needs_update(platform_bindings.is_changed(), &platform_bindings) {
+            platform_bindings.apply(&mut input_map, &detected);
+        }
+    }
+
+    for (platform_bindings, change_trackers, mut input_map) in query.iter_mut() {
+        if needs_update(change_trackers.is_changed(), platform_bindings) {
+            platform_bindings.apply(&mut input_map, &detected);
+        }
+    }
+}
+
 /// Release all inputs when an [`InputMap<A>`] is removed to prevent them from being held forever.
 ///
 /// By default, [`InputManagerPlugin<A>`] will run this on [`CoreStage::PostUpdate`](bevy::prelude::CoreStage::PostUpdate).
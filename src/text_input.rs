@@ -0,0 +1,194 @@
+//! This module contains [`TextInputBuffer`] and its supporting methods and impls.
+//!
+//! The [`TextInputBuffer`] is an optional resource that gives games a sanctioned way to implement chat or console input
+//! alongside their actions: while it is active, typed characters are collected into a string,
+//! and keyboard bindings are suppressed so that typing does not also trigger gameplay actions.
+
+use bevy::ecs::prelude::*;
+use bevy::input::keyboard::KeyCode;
+use bevy::input::Input;
+
+/// Collects typed text from [`ReceivedCharacter`](bevy::window::ReceivedCharacter) events, while suppressing keyboard actions
+///
+/// Insert this as a resource, and [`activate`](Self::activate) it when a text field (such as a chat box) gains focus.
+/// While active:
+/// - [`capture_text_input`](crate::systems::capture_text_input) appends each typed character to the [`text`](Self::text),
+///   with backspace removing the last character and other control characters ignored
+/// - [`update_action_state`](crate::systems::update_action_state) ignores every key except the [`passthrough_keys`](Self::passthrough_keys),
+///   so that only keys such as `Escape` and `Return` can trigger actions (for example, to close or submit the chat)
+///
+/// Actions that were only held by suppressed keys are released.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::text_input::TextInputBuffer;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     OpenChat,
+///     SendMessage,
+/// }
+///
+/// fn chat(action_state: Res<ActionState<Action>>, mut text_input: ResMut<TextInputBuffer>) {
+///     if action_state.just_pressed(Action::OpenChat) {
+///         text_input.activate();
+///     } else if text_input.is_active() && action_state.just_pressed(Action::SendMessage) {
+///         let message = text_input.take_text();
+///         text_input.deactivate();
+///         println!("Sending {message}");
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugins(MinimalPlugins)
+///     .add_plugin(InputManagerPlugin::<Action>::default())
+///     .init_resource::<TextInputBuffer>()
+///     .add_system(chat);
+/// ```
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct TextInputBuffer {
+    /// Are characters currently being captured?
+    active: bool,
+    /// The text typed since the buffer was last cleared
+    text: String,
+    /// The keys that can still trigger actions while text is being captured
+    passthrough_keys: Vec<KeyCode>,
+}
+
+impl Default for TextInputBuffer {
+    fn default() -> Self {
+        Self {
+            active: false,
+            text: String::new(),
+            passthrough_keys: vec![KeyCode::Escape, KeyCode::Return, KeyCode::NumpadEnter],
+        }
+    }
+}
+
+impl TextInputBuffer {
+    /// The character sent by the backspace key, which removes the last captured character
+    pub const BACKSPACE: char = '\u{8}';
+
+    /// Replaces the keys that can still trigger actions while text is being captured
+    ///
+    /// By default, these are `Escape`, `Return` and `NumpadEnter`.
+    #[must_use]
+    pub fn with_passthrough_keys(mut self, keys: impl IntoIterator<Item = KeyCode>) -> Self {
+        self.passthrough_keys = keys.into_iter().collect();
+        self
+    }
+
+    /// Starts capturing typed characters, and suppressing keyboard actions
+    pub fn activate(&mut self) {
+        self.active = true;
+    }
+
+    /// Stops capturing typed characters, so that every key can trigger actions again
+    ///
+    /// The captured [`text`](Self::text) is kept until it is [taken](Self::take_text) or [cleared](Self::clear).
+    pub fn deactivate(&mut self) {
+        self.active = false;
+    }
+
+    /// Are typed characters currently being captured?
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// The text that has been captured so far
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the captured text, leaving the buffer empty
+    pub fn take_text(&mut self) -> String {
+        std::mem::take(&mut self.text)
+    }
+
+    /// Discards the captured text
+    pub fn clear(&mut self) {
+        self.text.clear();
+    }
+
+    /// The keys that can still trigger actions while text is being captured
+    #[must_use]
+    pub fn passthrough_keys(&self) -> &[KeyCode] {
+        &self.passthrough_keys
+    }
+
+    /// Records a typed `character`, if the buffer is active
+    ///
+    /// [`BACKSPACE`](Self::BACKSPACE) removes the last character, and other control characters are ignored.
+    pub fn receive(&mut self, character: char) {
+        if !self.active {
+            return;
+        }
+
+        if character == Self::BACKSPACE {
+            self.text.pop();
+        } else if !character.is_control() {
+            self.text.push(character);
+        }
+    }
+
+    /// The `keycodes` that may trigger actions
+    ///
+    /// While active, this only contains the pressed [`passthrough_keys`](Self::passthrough_keys).
+    /// Otherwise, it is [`None`], and the `keycodes` should be used unchanged.
+    #[must_use]
+    pub fn filter_keycodes(&self, keycodes: &Input<KeyCode>) -> Option<Input<KeyCode>> {
+        if !self.active {
+            return None;
+        }
+
+        let mut filtered = Input::default();
+        for &key in keycodes
+            .get_pressed()
+            .filter(|key| self.passthrough_keys.contains(key))
+        {
+            filtered.press(key);
+        }
+        Some(filtered)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TextInputBuffer;
+    use bevy::input::keyboard::KeyCode;
+    use bevy::input::Input;
+
+    #[test]
+    fn characters_are_captured_while_active() {
+        let mut text_input = TextInputBuffer::default();
+        text_input.receive('a');
+        assert_eq!(text_input.text(), "");
+
+        text_input.activate();
+        for character in ['h', 'i', 'x', TextInputBuffer::BACKSPACE, '!', '\r'] {
+            text_input.receive(character);
+        }
+        assert_eq!(text_input.text(), "hi!");
+        assert_eq!(text_input.take_text(), "hi!");
+        assert_eq!(text_input.text(), "");
+    }
+
+    #[test]
+    fn only_passthrough_keys_remain_pressed() {
+        let mut keycodes = Input::<KeyCode>::default();
+        keycodes.press(KeyCode::W);
+        keycodes.press(KeyCode::Escape);
+
+        let mut text_input = TextInputBuffer::default();
+        assert!(text_input.filter_keycodes(&keycodes).is_none());
+
+        text_input.activate();
+        let filtered = text_input.filter_keycodes(&keycodes).unwrap();
+        assert!(!filtered.pressed(KeyCode::W));
+        assert!(filtered.pressed(KeyCode::Escape));
+    }
+}
@@ -0,0 +1,175 @@
+//! This module contains [`GamepadTouchpads`] and its supporting methods and impls.
+//!
+//! The touchpads of PS4 and PS5 controllers are not read by Bevy,
+//! so the [`GamepadTouchpads`] resource must be filled in by the backend that does, such as an SDL or HID integration.
+//! The touchpads are then exposed as ordinary gamepad inputs, which can be bound in an [`InputMap`](crate::input_map::InputMap):
+//! the finger position as [`DualAxis::touchpad`](crate::axislike::DualAxis::touchpad),
+//! and the click of the touchpad as [`TOUCHPAD_CLICK`].
+//! This requires the `touchpad` feature.
+
+use bevy::ecs::prelude::*;
+use bevy::input::gamepad::{
+    Gamepad, GamepadAxis, GamepadAxisType, GamepadButton, GamepadButtonType,
+};
+use bevy::input::{Axis, Input};
+use bevy::math::Vec2;
+use bevy::utils::HashMap;
+
+/// The gamepad axis that reports the horizontal position of a finger on the touchpad
+///
+/// This is between `-1.0` on the left edge and `1.0` on the right edge, and `0.0` while the touchpad is not touched.
+pub const TOUCHPAD_X: GamepadAxisType = GamepadAxisType::Other(0xF0);
+
+/// The gamepad axis that reports the vertical position of a finger on the touchpad
+///
+/// This is between `-1.0` on the bottom edge and `1.0` on the top edge, and `0.0` while the touchpad is not touched.
+pub const TOUCHPAD_Y: GamepadAxisType = GamepadAxisType::Other(0xF1);
+
+/// The gamepad button that is pressed while the touchpad is clicked
+pub const TOUCHPAD_CLICK: GamepadButtonType = GamepadButtonType::Other(0xF0);
+
+/// The state of the touchpad of a single gamepad
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TouchpadState {
+    /// The position of the first finger on the touchpad, or [`None`] if it is not touched
+    ///
+    /// Both axes are between `-1.0` and `1.0`, with `x` increasing to the right and `y` increasing upwards.
+    pub position: Option<Vec2>,
+    /// Is the touchpad clicked?
+    pub clicked: bool,
+}
+
+/// The latest [`TouchpadState`] of each gamepad with a touchpad
+///
+/// This should be updated by the backend that reads the touchpads,
+/// and is written into the gamepad inputs by [`update_touchpad_inputs`](crate::systems::update_touchpad_inputs),
+/// before [`InputManagerSystem::Update`](crate::plugin::InputManagerSystem::Update).
+/// Gamepads without an entry are left untouched.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::touchpad::TOUCHPAD_CLICK;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Pan,
+///     Map,
+/// }
+///
+/// let input_map = InputMap::new([
+///     (UserInput::from(DualAxis::touchpad()), Action::Pan),
+///     (UserInput::from(TOUCHPAD_CLICK), Action::Map),
+/// ]);
+/// ```
+#[derive(Resource, Debug, Clone, PartialEq, Default)]
+pub struct GamepadTouchpads {
+    touchpads: HashMap<Gamepad, TouchpadState>,
+    /// The gamepads whose touchpad inputs must be released by the next update
+    removed: Vec<Gamepad>,
+}
+
+impl GamepadTouchpads {
+    /// Records the latest `state` of the touchpad of the `gamepad`
+    pub fn set(&mut self, gamepad: Gamepad, state: TouchpadState) {
+        self.touchpads.insert(gamepad, state);
+    }
+
+    /// Forgets the touchpad of the `gamepad`, such as when it is disconnected
+    ///
+    /// The touchpad inputs of the gamepad are released by the next update.
+    pub fn remove(&mut self, gamepad: Gamepad) {
+        if self.touchpads.remove(&gamepad).is_some() {
+            self.removed.push(gamepad);
+        }
+    }
+
+    /// The latest state of the touchpad of the `gamepad`, if it has one
+    #[must_use]
+    pub fn get(&self, gamepad: Gamepad) -> Option<TouchpadState> {
+        self.touchpads.get(&gamepad).copied()
+    }
+
+    /// Writes the touchpads into the gamepad inputs
+    pub fn write_inputs(
+        &mut self,
+        gamepad_buttons: &mut Input<GamepadButton>,
+        gamepad_button_axes: &mut Axis<GamepadButton>,
+        gamepad_axes: &mut Axis<GamepadAxis>,
+    ) {
+        let released = self
+            .removed
+            .drain(..)
+            .map(|gamepad| (gamepad, TouchpadState::default()));
+        let touchpads = self
+            .touchpads
+            .iter()
+            .map(|(&gamepad, &state)| (gamepad, state));
+
+        for (gamepad, state) in released.chain(touchpads) {
+            let position = state.position.unwrap_or(Vec2::ZERO);
+            gamepad_axes.set(GamepadAxis::new(gamepad, TOUCHPAD_X), position.x);
+            gamepad_axes.set(GamepadAxis::new(gamepad, TOUCHPAD_Y), position.y);
+
+            let click = GamepadButton::new(gamepad, TOUCHPAD_CLICK);
+            if state.clicked {
+                gamepad_buttons.press(click);
+                gamepad_button_axes.set(click, 1.0);
+            } else {
+                gamepad_buttons.release(click);
+                gamepad_button_axes.set(click, 0.0);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn touchpads_are_written_as_gamepad_inputs() {
+        let gamepad = Gamepad { id: 0 };
+        let mut touchpads = GamepadTouchpads::default();
+        let mut gamepad_buttons = Input::<GamepadButton>::default();
+        let mut gamepad_button_axes = Axis::<GamepadButton>::default();
+        let mut gamepad_axes = Axis::<GamepadAxis>::default();
+
+        touchpads.set(
+            gamepad,
+            TouchpadState {
+                position: Some(Vec2::new(0.5, -0.25)),
+                clicked: true,
+            },
+        );
+        touchpads.write_inputs(
+            &mut gamepad_buttons,
+            &mut gamepad_button_axes,
+            &mut gamepad_axes,
+        );
+        let click = GamepadButton::new(gamepad, TOUCHPAD_CLICK);
+        assert!(gamepad_buttons.just_pressed(click));
+        assert_eq!(
+            gamepad_axes.get(GamepadAxis::new(gamepad, TOUCHPAD_X)),
+            Some(0.5)
+        );
+        assert_eq!(
+            gamepad_axes.get(GamepadAxis::new(gamepad, TOUCHPAD_Y)),
+            Some(-0.25)
+        );
+
+        touchpads.remove(gamepad);
+        touchpads.write_inputs(
+            &mut gamepad_buttons,
+            &mut gamepad_button_axes,
+            &mut gamepad_axes,
+        );
+        assert!(!gamepad_buttons.pressed(click));
+        assert_eq!(
+            gamepad_axes.get(GamepadAxis::new(gamepad, TOUCHPAD_X)),
+            Some(0.0)
+        );
+        assert_eq!(touchpads.get(gamepad), None);
+    }
+}
@@ -7,14 +7,18 @@ use petitset::PetitSet;
 use serde::{Deserialize, Serialize};
 
 use crate::axislike::VirtualAxis;
+use crate::errors::InputManagerError;
+use crate::inhibit_input::InputDevice;
 use crate::{
-    axislike::{AxisType, DualAxis, SingleAxis, VirtualDPad},
+    axislike::{AxisSemantics, AxisType, DualAxis, SingleAxis, VirtualDPad},
     buttonlike::{MouseMotionDirection, MouseWheelDirection},
 };
 
 /// Some combination of user input, which may cross [`Input`]-mode boundaries
 ///
 /// Suitable for use in an [`InputMap`](crate::input_map::InputMap)
+// Boxing chords would break every pattern that matches on `UserInput::Chord`
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum UserInput {
     /// A single button
@@ -22,6 +26,8 @@ pub enum UserInput {
     /// A combination of buttons, pressed simultaneously
     ///
     /// Up to 8 (!!) buttons can be chorded together at once.
+    /// Each member can be any [`InputKind`], so chords can mix devices and input types,
+    /// such as `Shift + MouseWheelDirection::Up` or a trigger axis plus a face button.
     /// Chords are considered to belong to all of the [InputMode]s of their constituent buttons.
     Chord(PetitSet<InputKind, 8>),
     /// A virtual DPad that you can get an [`AxisPair`] from
@@ -46,7 +52,13 @@ impl UserInput {
 
     /// Creates a [`UserInput::Chord`] from an iterator of inputs of the same type that can be converted into an [`InputKind`]s
     ///
+    /// To chord together inputs from different devices, convert each of them into an [`InputKind`] first.
     /// If `inputs` has a length of 1, a [`UserInput::Single`] variant will be returned instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `inputs` contains more than 8 distinct inputs.
+    /// Use [`UserInput::try_chord`] to handle this case, and to reject empty chords.
     pub fn chord(inputs: impl IntoIterator<Item = impl Into<InputKind>>) -> Self {
         // We can't just check the length unless we add an ExactSizeIterator bound :(
         let mut length: u8 = 0;
@@ -63,6 +75,106 @@ impl UserInput {
         }
     }
 
+    /// Attempts to create a [`UserInput::Chord`] from an iterator of inputs that can be converted into [`InputKind`]s
+    ///
+    /// Returns an error if `inputs` is empty, as such a chord would always be pressed,
+    /// or if it contains more than 8 distinct inputs.
+    /// Duplicate inputs are ignored, and if only one distinct input remains, a [`UserInput::Single`] variant is returned instead.
+    pub fn try_chord(
+        inputs: impl IntoIterator<Item = impl Into<InputKind>>,
+    ) -> Result<Self, InputManagerError> {
+        const MAX_CHORD_LENGTH: usize = 8;
+
+        let mut set: PetitSet<InputKind, MAX_CHORD_LENGTH> = PetitSet::default();
+        for input in inputs {
+            let input = input.into();
+            if set.len() == MAX_CHORD_LENGTH && !set.contains(&input) {
+                return Err(InputManagerError::ChordTooLong {
+                    max: MAX_CHORD_LENGTH,
+                });
+            }
+            set.insert(input);
+        }
+
+        match set.len() {
+            0 => Err(InputManagerError::EmptyChord),
+            1 => Ok(UserInput::Single(set.into_iter().next().unwrap())),
+            _ => Ok(UserInput::Chord(set)),
+        }
+    }
+
+    /// Does this input report positions or changes in position?
+    ///
+    /// Inputs are [`AxisSemantics::Relative`] only if every axis or button that makes them up is relative,
+    /// such as mouse motion and mouse wheel inputs.
+    /// Buttons and chords are always [`AxisSemantics::Absolute`].
+    #[must_use]
+    pub fn axis_semantics(&self) -> AxisSemantics {
+        let is_relative = |input_kind: &InputKind| match input_kind {
+            InputKind::SingleAxis(axis) => axis.axis_type.semantics() == AxisSemantics::Relative,
+            InputKind::DualAxis(axis) => {
+                axis.x.axis_type.semantics() == AxisSemantics::Relative
+                    && axis.y.axis_type.semantics() == AxisSemantics::Relative
+            }
+            InputKind::MouseWheel(_) | InputKind::MouseMotion(_) => true,
+            _ => false,
+        };
+
+        let relative = match self {
+            UserInput::Single(input_kind) => is_relative(input_kind),
+            UserInput::Chord(_) => false,
+            UserInput::VirtualDPad(VirtualDPad {
+                up,
+                down,
+                left,
+                right,
+            }) => [up, down, left, right].into_iter().all(is_relative),
+            UserInput::VirtualAxis(VirtualAxis { negative, positive }) => {
+                is_relative(negative) && is_relative(positive)
+            }
+        };
+
+        if relative {
+            AxisSemantics::Relative
+        } else {
+            AxisSemantics::Absolute
+        }
+    }
+
+    /// The [`InputDevice`] this input is read from
+    ///
+    /// Inputs that mix devices, such as a chord of a gamepad button and a key, belong to [`InputDevice::Gamepad`],
+    /// while those that mix the keyboard and mouse belong to the device of their first part.
+    /// Returns [`None`] if every part of the input is a [`Wildcard::AnyInput`].
+    #[must_use]
+    pub fn device(&self) -> Option<InputDevice> {
+        let devices: Vec<InputDevice> = match self {
+            UserInput::Single(input_kind) => input_kind.device().into_iter().collect(),
+            UserInput::Chord(input_kinds) => {
+                input_kinds.iter().filter_map(InputKind::device).collect()
+            }
+            UserInput::VirtualDPad(VirtualDPad {
+                up,
+                down,
+                left,
+                right,
+            }) => [up, down, left, right]
+                .into_iter()
+                .filter_map(InputKind::device)
+                .collect(),
+            UserInput::VirtualAxis(VirtualAxis { negative, positive }) => [negative, positive]
+                .into_iter()
+                .filter_map(InputKind::device)
+                .collect(),
+        };
+
+        if devices.contains(&InputDevice::Gamepad) {
+            Some(InputDevice::Gamepad)
+        } else {
+            devices.first().copied()
+        }
+    }
+
     /// The number of logical inputs that make up the [`UserInput`].
     ///
     /// - A [`Single`][UserInput::Single] input returns 1
@@ -171,6 +283,7 @@ impl UserInput {
                 InputKind::Mouse(button) => raw_inputs.mouse_buttons.push(button),
                 InputKind::MouseWheel(button) => raw_inputs.mouse_wheel.push(button),
                 InputKind::MouseMotion(button) => raw_inputs.mouse_motion.push(button),
+                InputKind::Wildcard(_) => {}
             },
             UserInput::Chord(button_set) => {
                 for button in button_set.iter() {
@@ -196,6 +309,7 @@ impl UserInput {
                         InputKind::Mouse(button) => raw_inputs.mouse_buttons.push(button),
                         InputKind::MouseWheel(button) => raw_inputs.mouse_wheel.push(button),
                         InputKind::MouseMotion(button) => raw_inputs.mouse_motion.push(button),
+                        InputKind::Wildcard(_) => {}
                     }
                 }
             }
@@ -228,6 +342,7 @@ impl UserInput {
                         InputKind::Mouse(button) => raw_inputs.mouse_buttons.push(button),
                         InputKind::MouseWheel(button) => raw_inputs.mouse_wheel.push(button),
                         InputKind::MouseMotion(button) => raw_inputs.mouse_motion.push(button),
+                        InputKind::Wildcard(_) => {}
                     }
                 }
             }
@@ -256,6 +371,7 @@ impl UserInput {
                         InputKind::Mouse(button) => raw_inputs.mouse_buttons.push(button),
                         InputKind::MouseWheel(button) => raw_inputs.mouse_wheel.push(button),
                         InputKind::MouseMotion(button) => raw_inputs.mouse_motion.push(button),
+                        InputKind::Wildcard(_) => {}
                     }
                 }
             }
@@ -358,6 +474,8 @@ pub enum InputKind {
     MouseWheel(MouseWheelDirection),
     /// A discretized mouse movement
     MouseMotion(MouseMotionDirection),
+    /// Any input of a whole category, such as "any keyboard key"
+    Wildcard(Wildcard),
 }
 
 impl From<DualAxis> for InputKind {
@@ -408,6 +526,68 @@ impl From<Modifier> for InputKind {
     }
 }
 
+impl From<Wildcard> for InputKind {
+    fn from(input: Wildcard) -> Self {
+        InputKind::Wildcard(input)
+    }
+}
+
+impl From<Wildcard> for UserInput {
+    fn from(input: Wildcard) -> Self {
+        UserInput::Single(InputKind::Wildcard(input))
+    }
+}
+
+/// A buttonlike input that is triggered by any input of a whole category
+///
+/// Useful for "press any key to start" screens and skippable cutscenes.
+/// The concrete input that matched is reported by [`ActionState::triggering_input`](crate::action_state::ActionState::triggering_input).
+///
+/// Axes are never matched by wildcards, as analog sticks drift and the cursor moves without the user meaning to act.
+/// Wildcards have no [`RawInputs`], and so cannot be sent using [input mocking](crate::MockInput).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Wildcard {
+    /// Any [`KeyCode`]
+    AnyKey,
+    /// Any [`GamepadButtonType`] of the associated gamepad
+    AnyGamepadButton,
+    /// Any [`MouseButton`]
+    AnyMouseButton,
+    /// Any keyboard key, gamepad button or mouse button
+    AnyInput,
+}
+
+impl InputKind {
+    /// The kind of device that this input is read from
+    ///
+    /// Returns [`None`] for [`Wildcard::AnyInput`], which matches inputs from every device.
+    #[must_use]
+    pub fn device(&self) -> Option<InputDevice> {
+        let axis_device = |axis_type: AxisType| match axis_type {
+            AxisType::Gamepad(_) | AxisType::GamepadButton(_) => InputDevice::Gamepad,
+            AxisType::MouseWheel(_) | AxisType::MouseMotion(_) | AxisType::CursorPosition(_) => {
+                InputDevice::Mouse
+            }
+        };
+
+        match self {
+            InputKind::GamepadButton(_) | InputKind::Wildcard(Wildcard::AnyGamepadButton) => {
+                Some(InputDevice::Gamepad)
+            }
+            InputKind::SingleAxis(axis) => Some(axis_device(axis.axis_type)),
+            InputKind::DualAxis(axis) => Some(axis_device(axis.x.axis_type)),
+            InputKind::Keyboard(_)
+            | InputKind::Modifier(_)
+            | InputKind::Wildcard(Wildcard::AnyKey) => Some(InputDevice::Keyboard),
+            InputKind::Mouse(_)
+            | InputKind::MouseWheel(_)
+            | InputKind::MouseMotion(_)
+            | InputKind::Wildcard(Wildcard::AnyMouseButton) => Some(InputDevice::Mouse),
+            InputKind::Wildcard(Wildcard::AnyInput) => None,
+        }
+    }
+}
+
 /// A keyboard modifier that combines two [`KeyCode`] values into one representation.
 ///
 /// This buttonlike input is stored in [`InputKind`], and will be triggered whenever either of these buttons are pressed.
@@ -557,6 +737,37 @@ mod raw_input_tests {
         assert_eq!(expected, raw);
     }
 
+    #[test]
+    fn malformed_chords() {
+        use crate::errors::InputManagerError;
+        use bevy::input::keyboard::KeyCode;
+
+        assert_eq!(
+            UserInput::try_chord(Vec::<KeyCode>::new()),
+            Err(InputManagerError::EmptyChord)
+        );
+        assert_eq!(
+            UserInput::try_chord([KeyCode::A, KeyCode::A]),
+            Ok(UserInput::Single(InputKind::Keyboard(KeyCode::A)))
+        );
+
+        let keys = [
+            KeyCode::A,
+            KeyCode::B,
+            KeyCode::C,
+            KeyCode::D,
+            KeyCode::E,
+            KeyCode::F,
+            KeyCode::G,
+            KeyCode::H,
+        ];
+        assert_eq!(UserInput::try_chord(keys), Ok(UserInput::chord(keys)));
+        assert_eq!(
+            UserInput::try_chord(keys.into_iter().chain([KeyCode::I])),
+            Err(InputManagerError::ChordTooLong { max: 8 })
+        );
+    }
+
     mod gamepad {
         use crate::user_input::{RawInputs, UserInput};
 
@@ -0,0 +1,198 @@
+//! This module contains [`ValueThresholds`] and its supporting methods and impls.
+//!
+//! The [`ValueThresholds`] is an optional addition to an [`InputManagerBundle`](crate::InputManagerBundle),
+//! which sends a [`ValueThresholdCrossed`] event whenever the [`ActionState::value`] of an action
+//! crosses one of its thresholds, such as a throttle being pushed past half.
+//! This lets discrete gameplay logic react to analog inputs without polling them every frame.
+
+use bevy::ecs::prelude::*;
+use std::marker::PhantomData;
+
+use crate::action_state::ActionState;
+use crate::Actionlike;
+
+/// Which way the value of an action moved when it crossed a threshold
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CrossingDirection {
+    /// The value rose to or above the threshold
+    Rising,
+    /// The value fell below the threshold
+    Falling,
+}
+
+/// Sent when the [`ActionState::value`] of an action crosses one of the thresholds in its [`ValueThresholds`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValueThresholdCrossed<A: Actionlike> {
+    /// The entity whose [`ActionState`] changed, or [`None`] for the [`ActionState`] resource
+    pub entity: Option<Entity>,
+    /// The action whose value crossed the threshold
+    pub action: A,
+    /// The threshold that was crossed
+    pub threshold: f32,
+    /// Which way the value moved
+    pub direction: CrossingDirection,
+    /// The new value of the action
+    pub value: f32,
+}
+
+/// Thresholds on the values of actions, which send a [`ValueThresholdCrossed`] event when crossed
+///
+/// A threshold is crossed in the [`Rising`](CrossingDirection::Rising) direction when the value
+/// goes from below the threshold to at or above it, and in the [`Falling`](CrossingDirection::Falling) direction
+/// when it goes back below it.
+/// Values start at 0.0, so thresholds at or below 0.0 are only crossed once the value has risen above them.
+///
+/// Events are sent by [`send_value_threshold_events`](crate::systems::send_value_threshold_events),
+/// as part of [`InputManagerSystem::Activity`](crate::plugin::InputManagerSystem::Activity).
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::value_thresholds::ValueThresholds;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Throttle,
+/// }
+///
+/// let mut world = World::new();
+/// world.spawn(InputManagerBundle {
+///     input_map: InputMap::new([(
+///         SingleAxis::symmetric(GamepadAxisType::RightZ, 0.1),
+///         Action::Throttle,
+///     )]),
+///     ..Default::default()
+/// })
+/// .insert(ValueThresholds::default().with_threshold(Action::Throttle, 0.5));
+/// ```
+#[derive(Resource, Component, Debug, Clone, PartialEq)]
+pub struct ValueThresholds<A: Actionlike> {
+    /// The thresholds of each action
+    thresholds: Vec<Vec<f32>>,
+    /// The value of each action when the thresholds were last checked
+    previous_values: Vec<f32>,
+    _phantom: PhantomData<A>,
+}
+
+impl<A: Actionlike> Default for ValueThresholds<A> {
+    fn default() -> Self {
+        Self {
+            thresholds: vec![Vec::new(); A::N_VARIANTS],
+            previous_values: vec![0.0; A::N_VARIANTS],
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A: Actionlike> ValueThresholds<A> {
+    /// Adds a `threshold` to the `action`
+    #[must_use]
+    pub fn with_threshold(mut self, action: A, threshold: f32) -> Self {
+        self.add_threshold(action, threshold);
+        self
+    }
+
+    /// Adds a `threshold` to the `action`
+    pub fn add_threshold(&mut self, action: A, threshold: f32) {
+        self.thresholds[action.index()].push(threshold);
+    }
+
+    /// Removes every threshold of the `action`
+    pub fn clear_thresholds(&mut self, action: A) {
+        self.thresholds[action.index()].clear();
+    }
+
+    /// The thresholds of the `action`
+    #[must_use]
+    pub fn thresholds(&self, action: A) -> &[f32] {
+        &self.thresholds[action.index()]
+    }
+
+    /// Compares the values of the `action_state` to those seen by the previous call, returning every threshold that was crossed
+    ///
+    /// The returned events have no [`entity`](ValueThresholdCrossed::entity); this is filled in by the calling system.
+    #[must_use]
+    pub fn crossings(&mut self, action_state: &ActionState<A>) -> Vec<ValueThresholdCrossed<A>> {
+        let mut crossings = Vec::new();
+
+        for action in A::variants() {
+            let index = action.index();
+            let value = action_state.value(action.clone());
+            let previous = std::mem::replace(&mut self.previous_values[index], value);
+
+            for &threshold in &self.thresholds[index] {
+                let direction = if previous < threshold && value >= threshold {
+                    CrossingDirection::Rising
+                } else if previous >= threshold && value < threshold {
+                    CrossingDirection::Falling
+                } else {
+                    continue;
+                };
+
+                crossings.push(ValueThresholdCrossed {
+                    entity: None,
+                    action: action.clone(),
+                    threshold,
+                    direction,
+                    value,
+                });
+            }
+        }
+
+        crossings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CrossingDirection, ValueThresholds};
+    use crate as leafwing_input_manager;
+    use crate::action_state::ActionState;
+    use crate::Actionlike;
+
+    #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    enum Action {
+        Throttle,
+        Brake,
+    }
+
+    #[test]
+    fn thresholds_are_crossed_in_both_directions() {
+        let mut action_state = ActionState::<Action>::default();
+        let mut value_thresholds = ValueThresholds::default()
+            .with_threshold(Action::Throttle, 0.5)
+            .with_threshold(Action::Throttle, 0.9);
+
+        let mut crossings = |action_state: &mut ActionState<Action>, value: f32| {
+            action_state.action_data_mut(Action::Throttle).value = value;
+            action_state.action_data_mut(Action::Brake).value = value;
+            value_thresholds
+                .crossings(action_state)
+                .into_iter()
+                .map(|crossing| {
+                    assert_eq!(crossing.action, Action::Throttle);
+                    (crossing.threshold, crossing.direction)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(crossings(&mut action_state, 0.3), []);
+        assert_eq!(
+            crossings(&mut action_state, 0.5),
+            [(0.5, CrossingDirection::Rising)]
+        );
+        assert_eq!(crossings(&mut action_state, 0.7), []);
+        assert_eq!(
+            crossings(&mut action_state, 1.0),
+            [(0.9, CrossingDirection::Rising)]
+        );
+        assert_eq!(
+            crossings(&mut action_state, 0.0),
+            [
+                (0.5, CrossingDirection::Falling),
+                (0.9, CrossingDirection::Falling)
+            ]
+        );
+    }
+}
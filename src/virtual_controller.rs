@@ -0,0 +1,303 @@
+//! This module contains [`VirtualController`] and its supporting methods and impls.
+//!
+//! The [`VirtualController`] is an optional addition to an entity with an [`ActionState`],
+//! which lets AI or scripted sequences press, hold and release actions over time.
+//! As bots then produce the same [`ActionState`] that players do,
+//! every downstream gameplay system can be shared between them.
+
+use bevy::ecs::prelude::*;
+use bevy::utils::Duration;
+use std::marker::PhantomData;
+
+use crate::action_state::ActionState;
+use crate::axislike::DualAxisData;
+use crate::Actionlike;
+
+/// A single timed input, scheduled onto a [`VirtualController`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduledInput<A: Actionlike> {
+    /// The action to press
+    pub action: A,
+    /// How long to wait before pressing the action
+    pub delay: Duration,
+    /// How long the action stays pressed for
+    ///
+    /// Actions are always pressed for at least one frame, even if this is [`Duration::ZERO`].
+    pub duration: Duration,
+    /// The [`ActionState::value`] reported while the action is pressed
+    pub value: f32,
+    /// The [`ActionState::axis_pair`] reported while the action is pressed
+    pub axis_pair: Option<DualAxisData>,
+    /// How much time has passed since this input was scheduled
+    elapsed: Duration,
+    /// Has this input pressed its action yet?
+    started: bool,
+}
+
+impl<A: Actionlike> ScheduledInput<A> {
+    /// Presses the `action` for the given `duration`, starting immediately
+    #[must_use]
+    pub fn new(action: A, duration: Duration) -> Self {
+        Self {
+            action,
+            delay: Duration::ZERO,
+            duration,
+            value: 1.0,
+            axis_pair: None,
+            elapsed: Duration::ZERO,
+            started: false,
+        }
+    }
+
+    /// Waits for `delay` before pressing the action
+    #[must_use]
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Reports the `value` while the action is pressed
+    #[must_use]
+    pub fn with_value(mut self, value: f32) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Reports the `axis_pair` while the action is pressed
+    #[must_use]
+    pub fn with_axis_pair(mut self, axis_pair: DualAxisData) -> Self {
+        self.axis_pair = Some(axis_pair);
+        self
+    }
+
+    /// Has this input pressed its action for its whole duration?
+    fn finished(&self) -> bool {
+        self.started && self.elapsed >= self.delay + self.duration
+    }
+}
+
+/// Drives the [`ActionState`] of an AI-controlled or scripted entity from a queue of timed inputs
+///
+/// Each [`ScheduledInput`] presses its action once its delay has passed, and releases it once its duration is over.
+/// While several inputs press the same action, the one that was scheduled last decides its value.
+///
+/// Actions that are not driven by this controller are left untouched.
+/// This is intended for entities without an [`InputMap`](crate::input_map::InputMap):
+/// to inject presses into a player's [`ActionState`], use a [`PressScheduler`](crate::press_scheduler::PressScheduler) instead.
+///
+/// This is applied by [`apply_virtual_controller`](crate::systems::apply_virtual_controller),
+/// as part of [`InputManagerSystem::ManualControl`](crate::plugin::InputManagerSystem::ManualControl).
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy::utils::Duration;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::virtual_controller::VirtualController;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Jump,
+///     Charge,
+/// }
+///
+/// let mut controller = VirtualController::default();
+/// controller.tap(Action::Jump);
+/// controller.hold_after(Action::Charge, Duration::from_millis(500), Duration::from_secs(2));
+///
+/// let mut world = World::new();
+/// world.spawn((ActionState::<Action>::default(), controller));
+/// ```
+#[derive(Resource, Component, Debug, Clone, PartialEq)]
+pub struct VirtualController<A: Actionlike> {
+    /// The inputs that have not finished yet, in the order they were scheduled
+    inputs: Vec<ScheduledInput<A>>,
+    /// Was each action pressed by this controller during the previous call to [`VirtualController::apply`]?
+    controlled: Vec<bool>,
+    _phantom: PhantomData<A>,
+}
+
+impl<A: Actionlike> Default for VirtualController<A> {
+    fn default() -> Self {
+        Self {
+            inputs: Vec::new(),
+            controlled: vec![false; A::N_VARIANTS],
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A: Actionlike> VirtualController<A> {
+    /// Adds the `input` to the queue
+    pub fn schedule(&mut self, input: ScheduledInput<A>) {
+        self.inputs.push(input);
+    }
+
+    /// Presses the `action` for a single frame
+    pub fn tap(&mut self, action: A) {
+        self.schedule(ScheduledInput::new(action, Duration::ZERO));
+    }
+
+    /// Presses the `action` for the given `duration`, starting immediately
+    pub fn hold(&mut self, action: A, duration: Duration) {
+        self.schedule(ScheduledInput::new(action, duration));
+    }
+
+    /// Presses the `action` for the given `duration`, once `delay` has passed
+    pub fn hold_after(&mut self, action: A, delay: Duration, duration: Duration) {
+        self.schedule(ScheduledInput::new(action, duration).with_delay(delay));
+    }
+
+    /// Presses the `action` with the given `value` for the given `duration`, starting immediately
+    pub fn hold_value(&mut self, action: A, value: f32, duration: Duration) {
+        self.schedule(ScheduledInput::new(action, duration).with_value(value));
+    }
+
+    /// Presses the `action` with the given `axis_pair` for the given `duration`, starting immediately
+    ///
+    /// The value of the action is set to the length of the `axis_pair`.
+    pub fn hold_axis_pair(&mut self, action: A, axis_pair: DualAxisData, duration: Duration) {
+        self.schedule(
+            ScheduledInput::new(action, duration)
+                .with_value(axis_pair.length())
+                .with_axis_pair(axis_pair),
+        );
+    }
+
+    /// Cancels every scheduled input of the `action`, releasing it on the next call to [`VirtualController::apply`]
+    pub fn cancel(&mut self, action: A) {
+        self.inputs
+            .retain(|input| input.action.index() != action.index());
+    }
+
+    /// Cancels every scheduled input, releasing all controlled actions on the next call to [`VirtualController::apply`]
+    pub fn clear(&mut self) {
+        self.inputs.clear();
+    }
+
+    /// The inputs that have not finished yet, in the order they were scheduled
+    #[must_use]
+    pub fn scheduled(&self) -> &[ScheduledInput<A>] {
+        &self.inputs
+    }
+
+    /// Are there no inputs left to play?
+    #[must_use]
+    pub fn is_idle(&self) -> bool {
+        self.inputs.is_empty()
+    }
+
+    /// Presses and releases the actions of the `action_state` according to the scheduled inputs, then advances time by `delta`
+    ///
+    /// This should be called once per frame, after the `action_state` has been ticked.
+    pub fn apply(&mut self, action_state: &mut ActionState<A>, delta: Duration) {
+        let mut active: Vec<Option<&ScheduledInput<A>>> = vec![None; A::N_VARIANTS];
+        for input in &mut self.inputs {
+            if input.elapsed >= input.delay {
+                input.started = true;
+            }
+        }
+        for input in self.inputs.iter().filter(|input| input.started) {
+            active[input.action.index()] = Some(input);
+        }
+
+        for action in A::variants() {
+            let index = action.index();
+            match active[index] {
+                Some(input) => {
+                    action_state.press(action.clone());
+                    let action_data = action_state.action_data_mut(action);
+                    action_data.value = input.value;
+                    action_data.axis_pair = input.axis_pair;
+                    self.controlled[index] = true;
+                }
+                None if self.controlled[index] => {
+                    action_state.release(action.clone());
+                    let action_data = action_state.action_data_mut(action);
+                    action_data.value = 0.0;
+                    action_data.axis_pair = None;
+                    self.controlled[index] = false;
+                }
+                None => (),
+            }
+        }
+
+        for input in &mut self.inputs {
+            input.elapsed += delta;
+        }
+        self.inputs.retain(|input| !input.finished());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VirtualController;
+    use crate as leafwing_input_manager;
+    use crate::action_state::ActionState;
+    use crate::axislike::DualAxisData;
+    use crate::Actionlike;
+    use bevy::utils::{Duration, Instant};
+
+    #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    enum Action {
+        Jump,
+        Move,
+    }
+
+    #[test]
+    fn scheduled_inputs_press_and_release() {
+        let mut action_state = ActionState::<Action>::default();
+        let mut controller = VirtualController::default();
+        let frame = Duration::from_millis(100);
+
+        controller.tap(Action::Jump);
+        controller.hold_after(Action::Jump, frame * 2, frame * 2);
+        controller.hold_axis_pair(Action::Move, DualAxisData::new(0.0, 0.5), frame);
+
+        let mut step = |action_state: &mut ActionState<Action>| {
+            let now = Instant::now();
+            action_state.tick(now, now - frame);
+            controller.apply(action_state, frame);
+        };
+
+        step(&mut action_state);
+        assert!(action_state.just_pressed(Action::Jump));
+        assert!(action_state.pressed(Action::Move));
+        assert_eq!(action_state.value(Action::Move), 0.5);
+        assert_eq!(
+            action_state.axis_pair(Action::Move),
+            Some(DualAxisData::new(0.0, 0.5))
+        );
+
+        // Taps only last a single frame, and holds last for their duration
+        step(&mut action_state);
+        assert!(action_state.just_released(Action::Jump));
+        assert!(action_state.just_released(Action::Move));
+        assert_eq!(action_state.value(Action::Move), 0.0);
+
+        step(&mut action_state);
+        assert!(action_state.just_pressed(Action::Jump));
+        step(&mut action_state);
+        assert!(action_state.pressed(Action::Jump));
+        assert!(!action_state.just_pressed(Action::Jump));
+        step(&mut action_state);
+        assert!(action_state.just_released(Action::Jump));
+        assert!(controller.is_idle());
+    }
+
+    #[test]
+    fn cancelled_inputs_are_released() {
+        let mut action_state = ActionState::<Action>::default();
+        let mut controller = VirtualController::default();
+        let frame = Duration::from_millis(100);
+
+        controller.hold(Action::Jump, Duration::from_secs(10));
+        controller.apply(&mut action_state, frame);
+        assert!(action_state.pressed(Action::Jump));
+
+        controller.cancel(Action::Jump);
+        controller.apply(&mut action_state, frame);
+        assert!(action_state.released(Action::Jump));
+        assert!(controller.is_idle());
+    }
+}
@@ -1,7 +1,7 @@
 //! When debugging this file, `cargo expand` is invaluable.
 //! See: https://github.com/dtolnay/cargo-expand
 //! use `cargo expand --test actionlike_derive`
-use leafwing_input_manager::Actionlike;
+use leafwing_input_manager::{ActionMetadata, Actionlike};
 
 #[derive(Actionlike, Debug, Hash, PartialEq, Eq, Clone, Copy)]
 enum UnitAction {}
@@ -30,6 +30,19 @@ enum NamedFieldVariantsAction {
     Jump,
 }
 
+#[derive(Actionlike, Debug, Hash, PartialEq, Eq, Clone, Copy)]
+enum AnnotatedAction {
+    #[actionlike(name = "Jump", category = "Movement")]
+    Jump,
+    #[actionlike(description = "Fire the equipped weapon")]
+    #[actionlike(category = "Combat")]
+    Shoot(usize),
+    #[actionlike(fixed, name = "Take screenshot")]
+    Screenshot,
+    #[actionlike(alias = "Menu", alias = "Escape")]
+    Pause,
+}
+
 #[test]
 fn in_order_iteration() {
     let constructed_vec = vec![SimpleAction::Zero, SimpleAction::One, SimpleAction::Two];
@@ -55,3 +68,83 @@ fn index() {
     assert_eq!(SimpleAction::One.index(), 1);
     assert_eq!(SimpleAction::Two.index(), 2);
 }
+
+#[test]
+fn n_variants() {
+    assert_eq!(UnitAction::N_VARIANTS, 0);
+    assert_eq!(OneAction::N_VARIANTS, 1);
+    assert_eq!(NamedFieldVariantsAction::N_VARIANTS, 2);
+    assert_eq!(SimpleAction::n_variants(), SimpleAction::N_VARIANTS);
+
+    // The count is known at compile time, and so can size arrays
+    let mut presses = [0_u32; SimpleAction::N_VARIANTS];
+    presses[SimpleAction::Two.index()] += 1;
+    assert_eq!(presses, [0, 0, 1]);
+}
+
+#[test]
+fn metadata() {
+    assert_eq!(
+        AnnotatedAction::Jump.metadata(),
+        ActionMetadata {
+            name: "Jump",
+            identifier: "Jump",
+            aliases: &[],
+            description: None,
+            category: Some("Movement"),
+            fixed: false,
+        }
+    );
+    assert_eq!(
+        AnnotatedAction::Shoot(3).metadata(),
+        ActionMetadata {
+            name: "Shoot",
+            identifier: "Shoot",
+            aliases: &[],
+            description: Some("Fire the equipped weapon"),
+            category: Some("Combat"),
+            fixed: false,
+        }
+    );
+    assert_eq!(
+        AnnotatedAction::Screenshot.metadata(),
+        ActionMetadata {
+            name: "Take screenshot",
+            identifier: "Screenshot",
+            aliases: &[],
+            description: None,
+            category: None,
+            fixed: true,
+        }
+    );
+    assert_eq!(
+        AnnotatedAction::Pause.metadata(),
+        ActionMetadata {
+            name: "Pause",
+            identifier: "Pause",
+            aliases: &["Menu", "Escape"],
+            description: None,
+            category: None,
+            fixed: false,
+        }
+    );
+}
+
+#[test]
+fn from_identifier() {
+    assert_eq!(
+        AnnotatedAction::from_identifier("Jump"),
+        Some(AnnotatedAction::Jump)
+    );
+    assert_eq!(
+        AnnotatedAction::from_identifier("Screenshot"),
+        Some(AnnotatedAction::Screenshot)
+    );
+    // Display names are not identifiers
+    assert_eq!(AnnotatedAction::from_identifier("Take screenshot"), None);
+    assert_eq!(
+        AnnotatedAction::from_identifier("Escape"),
+        Some(AnnotatedAction::Pause)
+    );
+    assert_eq!(AnnotatedAction::from_identifier("Crouch"), None);
+}
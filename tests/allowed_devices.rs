@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+use leafwing_input_manager::inhibit_input::InputDevice;
+use leafwing_input_manager::input_mocking::test_app;
+use leafwing_input_manager::prelude::*;
+
+#[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+enum Action {
+    Jump,
+    Start,
+}
+
+fn keyboard_player() -> InputMap<Action> {
+    let mut input_map = InputMap::new([
+        (UserInput::from(KeyCode::Space), Action::Jump),
+        // A stray binding, left over from the shared defaults
+        (UserInput::from(GamepadButtonType::South), Action::Jump),
+        (UserInput::from(Wildcard::AnyInput), Action::Start),
+    ]);
+    input_map.set_allowed_devices([InputDevice::Keyboard]);
+    input_map
+}
+
+#[test]
+fn disallowed_devices_are_ignored() {
+    let mut app = test_app::<Action>();
+    app.insert_resource(keyboard_player());
+
+    app.send_input(GamepadButtonType::South);
+    app.send_input(MouseButton::Left);
+    app.update();
+    let action_state = app.world.resource::<ActionState<Action>>();
+    assert!(action_state.released(Action::Jump));
+    assert!(action_state.released(Action::Start));
+
+    app.send_input(KeyCode::Space);
+    app.update();
+    let action_state = app.world.resource::<ActionState<Action>>();
+    assert!(action_state.pressed(Action::Jump));
+    assert!(action_state.pressed(Action::Start));
+}
+
+#[test]
+fn every_device_is_allowed_by_default() {
+    let mut input_map = keyboard_player();
+    assert_eq!(input_map.allowed_devices(), [InputDevice::Keyboard]);
+
+    input_map.set_allowed_devices(InputDevice::ALL);
+    assert_eq!(
+        input_map.allowed_devices(),
+        InputMap::<Action>::default().allowed_devices()
+    );
+
+    let mut app = test_app::<Action>();
+    app.insert_resource(input_map);
+    app.send_input(GamepadButtonType::South);
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .pressed(Action::Jump));
+}
@@ -0,0 +1,50 @@
+use bevy::prelude::*;
+use leafwing_input_manager::binding_capture::BindingCapture;
+use leafwing_input_manager::inhibit_input::InputDevice;
+use leafwing_input_manager::input_mocking::test_app;
+use leafwing_input_manager::prelude::*;
+use leafwing_input_manager::user_input::InputKind;
+
+#[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+enum Action {
+    Jump,
+}
+
+#[test]
+fn next_pressed_input_is_captured() {
+    let mut app = test_app::<Action>();
+    app.init_resource::<BindingCapture>();
+    app.world.resource_mut::<BindingCapture>().start();
+
+    app.send_input(KeyCode::Escape);
+    app.update();
+    assert!(app.world.resource::<BindingCapture>().is_active());
+
+    app.send_input(KeyCode::LShift);
+    app.update();
+    let capture = app.world.resource::<BindingCapture>();
+    assert!(!capture.is_active());
+    assert_eq!(
+        capture.captured(),
+        Some(InputKind::Modifier(Modifier::Shift))
+    );
+}
+
+#[test]
+fn captures_can_require_a_gamepad() {
+    let mut app = test_app::<Action>();
+    app.insert_resource(BindingCapture::default().with_required_device(Some(InputDevice::Gamepad)));
+    app.world.resource_mut::<BindingCapture>().start();
+
+    app.send_input(MouseButton::Left);
+    app.send_input(KeyCode::Space);
+    app.update();
+    assert_eq!(app.world.resource::<BindingCapture>().captured(), None);
+
+    app.send_input(GamepadButtonType::North);
+    app.update();
+    assert_eq!(
+        app.world.resource::<BindingCapture>().captured(),
+        Some(InputKind::GamepadButton(GamepadButtonType::North))
+    );
+}
@@ -0,0 +1,232 @@
+//! Checks invariants of the binding matcher against every combination of pressed keys,
+//! for a large number of pseudo-randomly generated input maps.
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use leafwing_input_manager::action_set::ActionSet;
+use leafwing_input_manager::input_streams::InputStreams;
+use leafwing_input_manager::prelude::*;
+
+#[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum Action {
+    Zero,
+    One,
+    Two,
+    Three,
+}
+
+/// The keys that bindings are generated from
+const KEYS: [KeyCode; 5] = [
+    KeyCode::Key1,
+    KeyCode::Key2,
+    KeyCode::Key3,
+    KeyCode::LControl,
+    KeyCode::LShift,
+];
+
+/// The number of input maps generated by each test
+const N_MAPS: u64 = 500;
+
+/// A small xorshift generator, so that failures are reproducible from their seed
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0 % n
+    }
+}
+
+/// A set of keys, stored as one bit per entry of [`KEYS`]
+type KeyMask = u8;
+
+fn keys_of(mask: KeyMask) -> Vec<KeyCode> {
+    KEYS.iter()
+        .enumerate()
+        .filter(|(i, _)| mask & (1 << i) != 0)
+        .map(|(_, &key)| key)
+        .collect()
+}
+
+/// Generates between one and two bindings of one to three keys for each action
+fn random_bindings(seed: u64) -> Vec<(KeyMask, Action)> {
+    let mut rng = Rng::new(seed);
+    let mut bindings = Vec::new();
+
+    for action in Action::variants() {
+        for _ in 0..=rng.below(2) {
+            let len = 1 + rng.below(3);
+            let mut mask: KeyMask = 0;
+            while (mask.count_ones() as u64) < len {
+                mask |= 1 << rng.below(KEYS.len() as u64);
+            }
+            if !bindings.contains(&(mask, action)) {
+                bindings.push((mask, action));
+            }
+        }
+    }
+    bindings
+}
+
+fn input_map(bindings: &[(KeyMask, Action)]) -> InputMap<Action> {
+    let mut input_map = InputMap::default();
+    for &(mask, action) in bindings {
+        let keys = keys_of(mask);
+        let input = if keys.len() == 1 {
+            UserInput::from(keys[0])
+        } else {
+            UserInput::chord(keys)
+        };
+        input_map.insert(input, action);
+    }
+    input_map
+}
+
+/// Do two bindings clash, following the rules documented on [`ClashStrategy`]?
+fn bindings_clash(a: KeyMask, b: KeyMask) -> bool {
+    if a == b || (a.count_ones() == 1 && b.count_ones() == 1) {
+        return false;
+    }
+    a & b == a || a & b == b
+}
+
+/// Calls `check` with the bindings, the pressed keys and their evaluation under each strategy,
+/// for every generated input map and every combination of pressed keys
+fn for_each_case(
+    mut check: impl FnMut(&[(KeyMask, Action)], KeyMask, ClashStrategy, &ActionSet<Action>),
+) {
+    let mut app = App::new();
+    app.add_plugin(InputPlugin);
+
+    for seed in 0..N_MAPS {
+        let bindings = random_bindings(seed);
+        let input_map = input_map(&bindings);
+
+        for pressed_keys in 0..(1 << KEYS.len()) as KeyMask {
+            let mut keycodes = app.world.resource_mut::<Input<KeyCode>>();
+            keycodes.reset_all();
+            for key in keys_of(pressed_keys) {
+                keycodes.press(key);
+            }
+
+            let raw_state = InputStreams::from_world(&app.world, None);
+            for strategy in [
+                ClashStrategy::PressAll,
+                ClashStrategy::PrioritizeLongest,
+                ClashStrategy::UseActionOrder,
+            ] {
+                let pressed = input_map.evaluate(&raw_state, strategy);
+                check(&bindings, pressed_keys, strategy, &pressed);
+            }
+        }
+    }
+}
+
+/// The actions with at least one binding whose keys are all pressed
+fn matching_actions(bindings: &[(KeyMask, Action)], pressed_keys: KeyMask) -> ActionSet<Action> {
+    bindings
+        .iter()
+        .filter(|(mask, _)| mask & pressed_keys == *mask)
+        .map(|&(_, action)| action)
+        .collect()
+}
+
+#[test]
+fn press_all_matches_every_fully_pressed_binding() {
+    for_each_case(|bindings, pressed_keys, strategy, pressed| {
+        if strategy == ClashStrategy::PressAll {
+            assert_eq!(
+                *pressed,
+                matching_actions(bindings, pressed_keys),
+                "bindings: {bindings:?}, pressed keys: {pressed_keys:#b}"
+            );
+        }
+    });
+}
+
+#[test]
+fn chords_imply_their_members_are_pressed() {
+    for_each_case(|bindings, pressed_keys, strategy, pressed| {
+        for action in pressed.iter() {
+            assert!(
+                bindings
+                    .iter()
+                    .any(|&(mask, bound)| bound == action && mask & pressed_keys == mask),
+                "{action:?} was pressed under {strategy:?} without any binding being held, bindings: {bindings:?}, pressed keys: {pressed_keys:#b}"
+            );
+        }
+        // Resolving clashes can only release actions
+        let matching = matching_actions(bindings, pressed_keys);
+        assert!(pressed.difference(&matching).is_empty());
+    });
+}
+
+#[test]
+fn clash_strategies_are_respected() {
+    for_each_case(|bindings, pressed_keys, strategy, pressed| {
+        let held: Vec<(KeyMask, Action)> = bindings
+            .iter()
+            .copied()
+            .filter(|(mask, _)| mask & pressed_keys == *mask)
+            .collect();
+        let context = format!(
+            "strategy: {strategy:?}, bindings: {bindings:?}, pressed keys: {pressed_keys:#b}"
+        );
+
+        // Held bindings that do not clash with any held binding of another action are never overruled
+        for &(mask, action) in &held {
+            let challenged = held
+                .iter()
+                .any(|&(other_mask, other)| other != action && bindings_clash(mask, other_mask));
+            if !challenged {
+                assert!(
+                    pressed.contains(action),
+                    "{action:?} was overruled, {context}"
+                );
+            }
+        }
+
+        match strategy {
+            ClashStrategy::UseActionOrder => {
+                // The first matching action always wins
+                if let Some(&(_, first)) = held.iter().min_by_key(|(_, action)| action.index()) {
+                    assert!(pressed.contains(first), "{first:?} lost, {context}");
+                }
+            }
+            ClashStrategy::PrioritizeLongest => {
+                // An action held by a single chord is overruled by another action held by a single, longer chord containing it
+                let lone_binding = |action: Action| {
+                    let mut bindings = held.iter().filter(|(_, bound)| *bound == action);
+                    match (bindings.next(), bindings.next()) {
+                        (Some(&(mask, _)), None) => Some(mask),
+                        _ => None,
+                    }
+                };
+
+                for shorter in Action::variants() {
+                    for longer in Action::variants() {
+                        if let (Some(short_mask), Some(long_mask)) =
+                            (lone_binding(shorter), lone_binding(longer))
+                        {
+                            if shorter != longer
+                                && short_mask & long_mask == short_mask
+                                && bindings_clash(short_mask, long_mask)
+                            {
+                                assert!(
+                                    !pressed.contains(shorter),
+                                    "{shorter:?} was not overruled by {longer:?}, {context}"
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    });
+}
@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+use leafwing_input_manager::input_map::BindingTrigger;
+use leafwing_input_manager::input_mocking::test_app;
+use leafwing_input_manager::prelude::*;
+
+#[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+enum Action {
+    DrawBow,
+    FireArrow,
+    Special,
+}
+
+#[test]
+fn release_bindings_press_on_release() {
+    let mut app = test_app::<Action>();
+    let mut input_map = InputMap::new([(KeyCode::Space, Action::DrawBow)]);
+    input_map.insert_with_trigger(KeyCode::Space, Action::FireArrow, BindingTrigger::Release);
+    app.insert_resource(input_map);
+
+    app.send_input(KeyCode::Space);
+    app.update();
+    let action_state = app.world.resource::<ActionState<Action>>();
+    assert!(action_state.pressed(Action::DrawBow));
+    assert!(action_state.released(Action::FireArrow));
+
+    app.update();
+    let action_state = app.world.resource::<ActionState<Action>>();
+    assert!(action_state.released(Action::FireArrow));
+
+    app.release_input(KeyCode::Space);
+    app.update();
+    let action_state = app.world.resource::<ActionState<Action>>();
+    assert!(action_state.released(Action::DrawBow));
+    assert!(action_state.just_pressed(Action::FireArrow));
+    assert_eq!(action_state.value(Action::FireArrow), 1.0);
+
+    // Release bindings only trigger for a single update
+    app.update();
+    let action_state = app.world.resource::<ActionState<Action>>();
+    assert!(action_state.just_released(Action::FireArrow));
+}
+
+#[test]
+fn release_chords_trigger_once_broken() {
+    let mut app = test_app::<Action>();
+    let chord = UserInput::chord([KeyCode::Down, KeyCode::P]);
+    let mut input_map = InputMap::new([(chord.clone(), Action::Special)]);
+    input_map.set_trigger(Action::Special, chord.clone(), BindingTrigger::Release);
+    app.insert_resource(input_map);
+
+    // Releasing a key that was held alone does not count
+    app.send_input(KeyCode::Down);
+    app.update();
+    app.release_input(KeyCode::Down);
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .released(Action::Special));
+
+    app.send_input(chord);
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .released(Action::Special));
+
+    app.release_input(KeyCode::P);
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .just_pressed(Action::Special));
+}
+
+#[test]
+fn triggers_follow_their_bindings() {
+    let mut input_map = InputMap::<Action>::default();
+    // Unbound inputs cannot be given a trigger
+    input_map.set_trigger(Action::FireArrow, KeyCode::Space, BindingTrigger::Release);
+    assert_eq!(
+        input_map.trigger(Action::FireArrow, &KeyCode::Space.into()),
+        BindingTrigger::Press
+    );
+
+    input_map.insert_with_trigger(KeyCode::Space, Action::FireArrow, BindingTrigger::Release);
+    input_map.remove(Action::FireArrow, KeyCode::Space);
+    input_map.insert(KeyCode::Space, Action::FireArrow);
+    assert_eq!(
+        input_map.trigger(Action::FireArrow, &KeyCode::Space.into()),
+        BindingTrigger::Press
+    );
+
+    let mut other = InputMap::default();
+    other.insert_with_trigger(KeyCode::F, Action::FireArrow, BindingTrigger::Release);
+    input_map.merge(&other);
+    assert_eq!(
+        input_map.trigger(Action::FireArrow, &KeyCode::F.into()),
+        BindingTrigger::Release
+    );
+}
@@ -0,0 +1,137 @@
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use bevy::window::WindowId;
+use leafwing_input_manager::axislike::{
+    AxisSemantics, CursorPositionAxisType, CursorSpace, DualAxisData,
+};
+use leafwing_input_manager::prelude::*;
+
+#[derive(Actionlike, Clone, Copy, Debug)]
+enum AimAction {
+    Screen,
+    Ndc,
+}
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<AimAction>::default())
+        .init_resource::<ActionState<AimAction>>()
+        .insert_resource(InputMap::new([
+            (
+                DualAxis::cursor_position(CursorSpace::Screen),
+                AimAction::Screen,
+            ),
+            (DualAxis::cursor_position(CursorSpace::Ndc), AimAction::Ndc),
+        ]));
+
+    let mut windows = Windows::default();
+    windows.add(Window::new(
+        WindowId::primary(),
+        &WindowDescriptor {
+            width: 800.0,
+            height: 600.0,
+            ..Default::default()
+        },
+        800,
+        600,
+        1.0,
+        None,
+        None,
+    ));
+    app.insert_resource(windows);
+
+    app
+}
+
+#[test]
+fn cursor_outside_window() {
+    let mut app = test_app();
+    app.update();
+
+    let action_state = app.world.resource::<ActionState<AimAction>>();
+    assert!(action_state.released(AimAction::Screen));
+    assert_eq!(
+        action_state.axis_pair(AimAction::Screen),
+        Some(DualAxisData::new(0.0, 0.0))
+    );
+}
+
+#[test]
+fn cursor_position_screen_and_ndc() {
+    let mut app = test_app();
+
+    app.send_input(SingleAxis::from_value(
+        CursorPositionAxisType::X(CursorSpace::Screen),
+        600.0,
+    ));
+    app.send_input(SingleAxis::from_value(
+        CursorPositionAxisType::Y(CursorSpace::Screen),
+        150.0,
+    ));
+    app.update();
+
+    let action_state = app.world.resource::<ActionState<AimAction>>();
+    assert!(action_state.pressed(AimAction::Screen));
+    assert_eq!(
+        action_state.axis_pair(AimAction::Screen),
+        Some(DualAxisData::new(600.0, 150.0))
+    );
+    assert_eq!(
+        action_state.axis_pair(AimAction::Ndc),
+        Some(DualAxisData::new(0.5, -0.5))
+    );
+}
+
+#[test]
+fn cursor_position_ndc_mocking() {
+    let mut app = test_app();
+
+    app.send_input(DualAxis::from_value(
+        CursorPositionAxisType::X(CursorSpace::Ndc),
+        CursorPositionAxisType::Y(CursorSpace::Ndc),
+        -1.0,
+        1.0,
+    ));
+    app.update();
+
+    let action_state = app.world.resource::<ActionState<AimAction>>();
+    assert_eq!(
+        action_state.axis_pair(AimAction::Screen),
+        Some(DualAxisData::new(0.0, 600.0))
+    );
+}
+
+#[test]
+fn cursor_position_is_absolute() {
+    let mut app = test_app();
+
+    app.send_input(DualAxis::from_value(
+        CursorPositionAxisType::X(CursorSpace::Screen),
+        CursorPositionAxisType::Y(CursorSpace::Screen),
+        100.0,
+        100.0,
+    ));
+    app.update();
+
+    app.send_input(SingleAxis::from_value(
+        CursorPositionAxisType::X(CursorSpace::Screen),
+        130.0,
+    ));
+    app.update();
+
+    let action_state = app.world.resource::<ActionState<AimAction>>();
+    assert_eq!(
+        action_state.axis_semantics(AimAction::Screen),
+        AxisSemantics::Absolute
+    );
+    assert_eq!(
+        action_state.axis_pair(AimAction::Screen),
+        Some(DualAxisData::new(130.0, 100.0))
+    );
+    assert_eq!(
+        action_state.axis_delta(AimAction::Screen),
+        Some(DualAxisData::new(30.0, 0.0))
+    );
+}
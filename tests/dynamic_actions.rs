@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+use leafwing_input_manager::dynamic_actions::DynamicActions;
+use leafwing_input_manager::input_mocking::test_app;
+use leafwing_input_manager::prelude::*;
+
+#[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+enum Action {
+    Jump,
+}
+
+#[test]
+fn dynamic_actions_are_pressed_alongside_static_ones() {
+    let mut app = test_app::<Action>();
+    app.insert_resource(InputMap::new([(KeyCode::Space, Action::Jump)]));
+
+    let mut dynamic_actions = DynamicActions::default();
+    let teleport = dynamic_actions.register("my_mod:teleport");
+    dynamic_actions.insert(KeyCode::Space, teleport);
+    app.insert_resource(dynamic_actions);
+
+    app.send_input(KeyCode::Space);
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .just_pressed(Action::Jump));
+    assert!(app
+        .world
+        .resource::<DynamicActions>()
+        .just_pressed_named("my_mod:teleport"));
+
+    app.release_input(KeyCode::Space);
+    app.update();
+    assert!(app
+        .world
+        .resource::<DynamicActions>()
+        .just_released(teleport));
+}
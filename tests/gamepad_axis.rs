@@ -1,8 +1,10 @@
 use bevy::input::gamepad::{GamepadEventRaw, GamepadInfo};
 use bevy::input::InputPlugin;
 use bevy::prelude::*;
-use leafwing_input_manager::axislike::{AxisType, DualAxisData};
+use leafwing_input_manager::axislike::{AxisType, DeadZoneShape, DualAxisData, MagnitudeLimit};
+use leafwing_input_manager::buttonlike::GamepadButtonSettings;
 use leafwing_input_manager::prelude::*;
+use leafwing_input_manager::user_input::InputKind;
 
 #[derive(Actionlike, Clone, Copy, Debug)]
 enum ButtonlikeTestAction {
@@ -74,6 +76,8 @@ fn game_pad_single_axis_mocking() {
     let input = SingleAxis {
         axis_type: AxisType::Gamepad(GamepadAxisType::LeftStickX),
         value: Some(-1.),
+        range: None,
+        offset: 0.0,
         positive_low: 0.0,
         negative_low: 0.0,
     };
@@ -93,15 +97,21 @@ fn game_pad_dual_axis_mocking() {
         x: SingleAxis {
             axis_type: AxisType::Gamepad(GamepadAxisType::LeftStickX),
             value: Some(1.),
+            range: None,
+            offset: 0.0,
             positive_low: 0.0,
             negative_low: 0.0,
         },
         y: SingleAxis {
             axis_type: AxisType::Gamepad(GamepadAxisType::LeftStickY),
             value: Some(0.),
+            range: None,
+            offset: 0.0,
             positive_low: 0.0,
             negative_low: 0.0,
         },
+        deadzone_shape: DeadZoneShape::Cross,
+        magnitude: MagnitudeLimit::Raw,
     };
     app.send_input(input);
     let mut events = app.world.resource_mut::<Events<GamepadEventRaw>>();
@@ -127,6 +137,8 @@ fn game_pad_single_axis() {
     let input = SingleAxis {
         axis_type: AxisType::Gamepad(GamepadAxisType::LeftStickX),
         value: Some(1.),
+        range: None,
+        offset: 0.0,
         positive_low: 0.0,
         negative_low: 0.0,
     };
@@ -139,6 +151,8 @@ fn game_pad_single_axis() {
     let input = SingleAxis {
         axis_type: AxisType::Gamepad(GamepadAxisType::LeftStickX),
         value: Some(-1.),
+        range: None,
+        offset: 0.0,
         positive_low: 0.0,
         negative_low: 0.0,
     };
@@ -151,6 +165,8 @@ fn game_pad_single_axis() {
     let input = SingleAxis {
         axis_type: AxisType::Gamepad(GamepadAxisType::LeftStickY),
         value: Some(1.),
+        range: None,
+        offset: 0.0,
         positive_low: 0.0,
         negative_low: 0.0,
     };
@@ -163,6 +179,8 @@ fn game_pad_single_axis() {
     let input = SingleAxis {
         axis_type: AxisType::Gamepad(GamepadAxisType::LeftStickY),
         value: Some(-1.),
+        range: None,
+        offset: 0.0,
         positive_low: 0.0,
         negative_low: 0.0,
     };
@@ -175,6 +193,8 @@ fn game_pad_single_axis() {
     let input = SingleAxis {
         axis_type: AxisType::Gamepad(GamepadAxisType::LeftStickY),
         value: Some(0.0),
+        range: None,
+        offset: 0.0,
         // Usually a small deadzone threshold will be set
         positive_low: 0.1,
         negative_low: 0.1,
@@ -188,6 +208,8 @@ fn game_pad_single_axis() {
     let input = SingleAxis {
         axis_type: AxisType::Gamepad(GamepadAxisType::LeftStickY),
         value: None,
+        range: None,
+        offset: 0.0,
         positive_low: 0.0,
         negative_low: 0.0,
     };
@@ -216,12 +238,146 @@ fn game_pad_dual_axis() {
 
     let action_state = app.world.resource::<ActionState<AxislikeTestAction>>();
 
+    // Each axis is rescaled from the edge of its deadzone
+    let expected = (0.8 - 0.1) / (1.0 - 0.1);
     assert!(action_state.pressed(AxislikeTestAction::XY));
-    assert_eq!(action_state.value(AxislikeTestAction::XY), 0.8);
+    assert!((action_state.value(AxislikeTestAction::XY) - expected).abs() < 1e-5);
+    let axis_pair = action_state.axis_pair(AxislikeTestAction::XY).unwrap();
+    assert!((axis_pair.x() - expected).abs() < 1e-5);
+    assert_eq!(axis_pair.y(), 0.0);
+
+    // Full tilt still reaches the edge of the range, in both directions
+    app.send_input(DualAxis::from_value(
+        GamepadAxisType::LeftStickX,
+        GamepadAxisType::LeftStickY,
+        -1.0,
+        1.0,
+    ));
+    app.update();
+    let action_state = app.world.resource::<ActionState<AxislikeTestAction>>();
+    let axis_pair = action_state.axis_pair(AxislikeTestAction::XY).unwrap();
+    assert!((axis_pair.x() + 1.0).abs() < 1e-5);
+    assert!((axis_pair.y() - 1.0).abs() < 1e-5);
+}
+
+#[test]
+fn game_pad_dual_axis_deadzone_shapes() {
+    let mut app = test_app();
+    app.insert_resource(InputMap::new([(
+        DualAxis::left_stick().with_deadzone_shape(DeadZoneShape::Circle { radius: 0.2 }),
+        AxislikeTestAction::XY,
+    )]));
+
+    // Inside the circle, even though the x axis alone is outside of the default deadzone
+    app.send_input(DualAxis::from_value(
+        GamepadAxisType::LeftStickX,
+        GamepadAxisType::LeftStickY,
+        0.15,
+        0.1,
+    ));
+    app.update();
+    let action_state = app.world.resource::<ActionState<AxislikeTestAction>>();
+    assert!(action_state.released(AxislikeTestAction::XY));
+
+    // Diagonals keep their direction, and are rescaled from the edge of the deadzone
+    app.send_input(DualAxis::from_value(
+        GamepadAxisType::LeftStickX,
+        GamepadAxisType::LeftStickY,
+        0.36,
+        0.48,
+    ));
+    app.update();
+    let action_state = app.world.resource::<ActionState<AxislikeTestAction>>();
+    assert!(action_state.pressed(AxislikeTestAction::XY));
+    let axis_pair = action_state.axis_pair(AxislikeTestAction::XY).unwrap();
+    assert!((axis_pair.length() - 0.5).abs() < 1e-5);
+    assert!((axis_pair.x() / axis_pair.y() - 0.75).abs() < 1e-5);
+
+    app.insert_resource(InputMap::new([(
+        DualAxis::left_stick().with_deadzone_shape(DeadZoneShape::Square { half_size: 0.5 }),
+        AxislikeTestAction::XY,
+    )]));
+    app.update();
+    let action_state = app.world.resource::<ActionState<AxislikeTestAction>>();
+    assert!(action_state.released(AxislikeTestAction::XY));
+}
+
+#[test]
+fn full_size_deadzones_zero_every_input() {
     assert_eq!(
-        action_state.axis_pair(AxislikeTestAction::XY).unwrap(),
-        DualAxisData::new(0.8, 0.0)
+        DeadZoneShape::circle(1.5),
+        DeadZoneShape::Circle {
+            radius: DeadZoneShape::MAX_SIZE
+        }
     );
+    assert_eq!(
+        DeadZoneShape::square(-0.5),
+        DeadZoneShape::Square { half_size: 0.0 }
+    );
+
+    // Clamped deadzones still let full tilt through
+    let xy = DeadZoneShape::circle(1.0).apply(Vec2::X);
+    assert!(!xy.is_nan());
+    assert!((xy.x - 1.0).abs() < 1e-5);
+
+    // Deadzones built by hand that cover the whole range never produce NaN
+    for deadzone_shape in [
+        DeadZoneShape::Circle { radius: 1.0 },
+        DeadZoneShape::Square { half_size: 1.0 },
+    ] {
+        assert_eq!(deadzone_shape.apply(Vec2::X), Vec2::ZERO);
+        assert_eq!(deadzone_shape.apply(Vec2::ONE), Vec2::ZERO);
+    }
+}
+
+#[test]
+fn game_pad_dual_axis_magnitude_limits() {
+    let mut app = test_app();
+    app.insert_resource(InputMap::new([(
+        DualAxis::left_stick().with_magnitude_limit(MagnitudeLimit::ClampToUnit),
+        AxislikeTestAction::XY,
+    )]));
+
+    app.send_input(DualAxis::from_value(
+        GamepadAxisType::LeftStickX,
+        GamepadAxisType::LeftStickY,
+        0.9,
+        0.9,
+    ));
+    app.update();
+    let action_state = app.world.resource::<ActionState<AxislikeTestAction>>();
+    let axis_pair = action_state.axis_pair(AxislikeTestAction::XY).unwrap();
+    assert!((axis_pair.length() - 1.0).abs() < 1e-5);
+    assert!((axis_pair.x() - axis_pair.y()).abs() < 1e-5);
+
+    app.insert_resource(InputMap::new([(
+        DualAxis::left_stick()
+            .with_magnitude_limit(MagnitudeLimit::NormalizeAbove { threshold: 0.5 }),
+        AxislikeTestAction::XY,
+    )]));
+    app.send_input(DualAxis::from_value(
+        GamepadAxisType::LeftStickX,
+        GamepadAxisType::LeftStickY,
+        0.3,
+        0.3,
+    ));
+    app.update();
+    let action_state = app.world.resource::<ActionState<AxislikeTestAction>>();
+    let axis_pair = action_state.axis_pair(AxislikeTestAction::XY).unwrap();
+    let expected = (0.3 - 0.1) / (1.0 - 0.1);
+    assert!((axis_pair.x() - expected).abs() < 1e-5);
+    assert!((axis_pair.y() - expected).abs() < 1e-5);
+
+    app.send_input(DualAxis::from_value(
+        GamepadAxisType::LeftStickX,
+        GamepadAxisType::LeftStickY,
+        0.36,
+        0.48,
+    ));
+    app.update();
+    let action_state = app.world.resource::<ActionState<AxislikeTestAction>>();
+    let axis_pair = action_state.axis_pair(AxislikeTestAction::XY).unwrap();
+    assert!((axis_pair.length() - 1.0).abs() < 1e-5);
 }
 
 #[test]
@@ -246,3 +402,312 @@ fn game_pad_virtualdpad() {
         DualAxisData::new(-1.0, 0.0)
     );
 }
+
+#[test]
+fn gamepad_axis_and_button_chord() {
+    let mut app = test_app();
+    app.insert_resource(InputMap::new([(
+        UserInput::chord([
+            InputKind::SingleAxis(SingleAxis::positive_only(GamepadAxisType::LeftZ, 0.5)),
+            InputKind::GamepadButton(GamepadButtonType::South),
+        ]),
+        ButtonlikeTestAction::Up,
+    )]));
+
+    app.send_input(GamepadButtonType::South);
+    app.update();
+    let action_state = app.world.resource::<ActionState<ButtonlikeTestAction>>();
+    assert!(action_state.released(ButtonlikeTestAction::Up));
+
+    // Chords can mix analog axes and buttons
+    app.send_input(SingleAxis::from_value(GamepadAxisType::LeftZ, 1.0));
+    app.update();
+    let action_state = app.world.resource::<ActionState<ButtonlikeTestAction>>();
+    assert!(action_state.pressed(ButtonlikeTestAction::Up));
+}
+
+fn press_trigger(app: &mut App, value: f32) {
+    let mut events = app.world.resource_mut::<Events<GamepadEventRaw>>();
+    events.send(GamepadEventRaw {
+        gamepad: Gamepad { id: 1 },
+        event_type: GamepadEventType::ButtonChanged(GamepadButtonType::RightTrigger2, value),
+    });
+    app.update();
+}
+
+#[test]
+fn analog_button_thresholds() {
+    let mut app = test_app();
+    app.insert_resource(InputMap::new([
+        (
+            InputKind::GamepadButton(GamepadButtonType::RightTrigger2),
+            ButtonlikeTestAction::Up,
+        ),
+        (
+            InputKind::SingleAxis(SingleAxis::gamepad_button(
+                GamepadButtonType::RightTrigger2,
+                0.2,
+            )),
+            ButtonlikeTestAction::Down,
+        ),
+    ]));
+
+    // Bevy's default `GamepadSettings` require the trigger to be pulled further than this
+    press_trigger(&mut app, 0.3);
+    let action_state = app.world.resource::<ActionState<ButtonlikeTestAction>>();
+    assert!(action_state.released(ButtonlikeTestAction::Up));
+    // While the per-binding threshold does not
+    assert!(action_state.pressed(ButtonlikeTestAction::Down));
+    assert_eq!(action_state.value(ButtonlikeTestAction::Down), 0.3);
+
+    app.insert_resource(GamepadButtonSettings::with_threshold(0.25));
+    app.update();
+    let action_state = app.world.resource::<ActionState<ButtonlikeTestAction>>();
+    assert!(action_state.pressed(ButtonlikeTestAction::Up));
+
+    press_trigger(&mut app, 0.1);
+    let action_state = app.world.resource::<ActionState<ButtonlikeTestAction>>();
+    assert!(action_state.released(ButtonlikeTestAction::Up));
+    assert!(action_state.released(ButtonlikeTestAction::Down));
+}
+
+#[test]
+fn pressure_sensitive_button_values() {
+    let mut app = test_app();
+    app.insert_resource(InputMap::new([(
+        GamepadButtonType::South,
+        ButtonlikeTestAction::Up,
+    )]))
+    .insert_resource(GamepadButtonSettings::with_threshold(0.1));
+
+    // Partially pressing an analog face button reports its pressure
+    app.send_input(SingleAxis::from_value(GamepadButtonType::South, 0.4));
+    app.update();
+    let action_state = app.world.resource::<ActionState<ButtonlikeTestAction>>();
+    assert!(action_state.pressed(ButtonlikeTestAction::Up));
+    assert_eq!(action_state.value(ButtonlikeTestAction::Up), 0.4);
+
+    // Fully pressing it reports a value of 1.0
+    app.send_input(GamepadButtonType::South);
+    app.update();
+    let action_state = app.world.resource::<ActionState<ButtonlikeTestAction>>();
+    assert_eq!(action_state.value(ButtonlikeTestAction::Up), 1.0);
+}
+
+#[test]
+fn trigger_range_remapping() {
+    use leafwing_input_manager::axislike::AxisRange;
+
+    let mut app = test_app();
+    let mut input_map = InputMap::new([(
+        SingleAxis::gamepad_button(GamepadButtonType::RightTrigger2, 0.1),
+        AxislikeTestAction::X,
+    )]);
+    input_map.set_axis_range(GamepadButtonType::RightTrigger2, AxisRange::new(0.05, 0.92));
+    app.insert_resource(input_map);
+
+    // Fully pressed, even though the trigger never reports 1.0
+    press_trigger(&mut app, 0.92);
+    let action_state = app.world.resource::<ActionState<AxislikeTestAction>>();
+    assert_eq!(action_state.value(AxislikeTestAction::X), 1.0);
+
+    // The resting value is remapped to zero, so the action is released
+    press_trigger(&mut app, 0.05);
+    let action_state = app.world.resource::<ActionState<AxislikeTestAction>>();
+    assert!(action_state.released(AxislikeTestAction::X));
+}
+
+#[test]
+fn calibrated_one_sided_axis() {
+    use leafwing_input_manager::calibration::AxisCalibration;
+
+    let mut app = test_app();
+    let mut input_map = InputMap::new([(
+        SingleAxis::positive_only(GamepadAxisType::LeftStickX, 0.1),
+        AxislikeTestAction::X,
+    )]);
+    input_map.calibrate(
+        GamepadAxisType::LeftStickX,
+        AxisCalibration {
+            bias: 0.1,
+            deadzone: 0.05,
+        },
+    );
+    app.insert_resource(input_map);
+
+    let mut move_stick = |value: f32| {
+        let mut events = app.world.resource_mut::<Events<GamepadEventRaw>>();
+        events.send(GamepadEventRaw {
+            gamepad: Gamepad { id: 1 },
+            event_type: GamepadEventType::AxisChanged(GamepadAxisType::LeftStickX, value),
+        });
+        app.update();
+        app.world
+            .resource::<ActionState<AxislikeTestAction>>()
+            .clone()
+    };
+
+    // Pushing the stick the other way does not trigger a positive-only binding
+    let action_state = move_stick(-0.8);
+    assert!(action_state.released(AxislikeTestAction::X));
+    assert_eq!(action_state.value(AxislikeTestAction::X), 0.0);
+
+    // The resting bias is subtracted from the reported value
+    let action_state = move_stick(0.6);
+    assert!(action_state.pressed(AxislikeTestAction::X));
+    assert!((action_state.value(AxislikeTestAction::X) - 0.5).abs() < 1e-5);
+}
+
+#[test]
+fn action_output_ranges() {
+    use leafwing_input_manager::axislike::OutputRange;
+
+    let mut app = test_app();
+    let mut input_map = InputMap::new([
+        // Throttle
+        (
+            SingleAxis::symmetric(GamepadAxisType::LeftStickY, 0.1),
+            AxislikeTestAction::X,
+        ),
+        // Steering, in degrees
+        (
+            SingleAxis::symmetric(GamepadAxisType::LeftStickX, 0.1),
+            AxislikeTestAction::Y,
+        ),
+    ]);
+    input_map
+        .set_output_range(AxislikeTestAction::X, OutputRange::UNSIGNED)
+        .set_output_range(AxislikeTestAction::Y, OutputRange::new(-30.0, 30.0));
+    app.insert_resource(input_map);
+
+    app.send_input(SingleAxis::from_value(GamepadAxisType::LeftStickY, -0.5));
+    app.send_input(SingleAxis::from_value(GamepadAxisType::LeftStickX, -0.5));
+    app.update();
+
+    let action_state = app.world.resource::<ActionState<AxislikeTestAction>>();
+    // Pulling back on the throttle does not reverse
+    assert_eq!(action_state.value(AxislikeTestAction::X), 0.0);
+    assert_eq!(action_state.value(AxislikeTestAction::Y), -15.0);
+
+    app.send_input(SingleAxis::from_value(GamepadAxisType::LeftStickY, 0.5));
+    app.send_input(SingleAxis::from_value(GamepadAxisType::LeftStickX, 1.0));
+    app.update();
+
+    let action_state = app.world.resource::<ActionState<AxislikeTestAction>>();
+    assert_eq!(action_state.value(AxislikeTestAction::X), 0.5);
+    assert_eq!(action_state.value(AxislikeTestAction::Y), 30.0);
+}
+
+#[test]
+fn action_press_thresholds() {
+    let mut app = test_app();
+    let mut input_map = InputMap::new([
+        (
+            UserInput::from(SingleAxis::symmetric(GamepadAxisType::RightZ, 0.1)),
+            AxislikeTestAction::X,
+        ),
+        (
+            UserInput::from(GamepadButtonType::South),
+            AxislikeTestAction::X,
+        ),
+    ]);
+    input_map.set_press_threshold(AxislikeTestAction::X, 0.5);
+    app.insert_resource(input_map);
+
+    // The trigger is past its deadzone, but not yet past the threshold of the action
+    app.send_input(SingleAxis::from_value(GamepadAxisType::RightZ, 0.3));
+    app.update();
+    let action_state = app.world.resource::<ActionState<AxislikeTestAction>>();
+    assert!(action_state.released(AxislikeTestAction::X));
+    assert_eq!(action_state.value(AxislikeTestAction::X), 0.0);
+
+    app.send_input(SingleAxis::from_value(GamepadAxisType::RightZ, 0.7));
+    app.update();
+    let action_state = app.world.resource::<ActionState<AxislikeTestAction>>();
+    assert!(action_state.pressed(AxislikeTestAction::X));
+    assert_eq!(action_state.value(AxislikeTestAction::X), 0.7);
+
+    // Digital buttons always clear the threshold
+    app.send_input(SingleAxis::from_value(GamepadAxisType::RightZ, 0.0));
+    app.send_input(GamepadButtonType::South);
+    app.update();
+    let action_state = app.world.resource::<ActionState<AxislikeTestAction>>();
+    assert!(action_state.pressed(AxislikeTestAction::X));
+}
+
+#[test]
+fn value_threshold_events() {
+    use leafwing_input_manager::value_thresholds::{
+        CrossingDirection, ValueThresholdCrossed, ValueThresholds,
+    };
+
+    let mut app = test_app();
+    app.insert_resource(InputMap::new([(
+        SingleAxis::symmetric(GamepadAxisType::RightZ, 0.1),
+        AxislikeTestAction::X,
+    )]))
+    .insert_resource(ValueThresholds::default().with_threshold(AxislikeTestAction::X, 0.5));
+
+    app.send_input(SingleAxis::from_value(GamepadAxisType::RightZ, 0.8));
+    app.update();
+
+    let mut events = app
+        .world
+        .resource_mut::<Events<ValueThresholdCrossed<AxislikeTestAction>>>();
+    let crossings: Vec<_> = events.drain().collect();
+    assert_eq!(crossings.len(), 1);
+    assert_eq!(crossings[0].entity, None);
+    assert_eq!(crossings[0].threshold, 0.5);
+    assert_eq!(crossings[0].direction, CrossingDirection::Rising);
+}
+
+#[test]
+fn gamepad_association_modes() {
+    use leafwing_input_manager::inhibit_input::InputDevice;
+    use leafwing_input_manager::input_map::GamepadAssociation;
+
+    let mut app = test_app();
+    let second_gamepad = Gamepad { id: 2 };
+    app.world.send_event(GamepadEventRaw {
+        gamepad: second_gamepad,
+        event_type: GamepadEventType::Connected(GamepadInfo {
+            name: "SecondController".into(),
+        }),
+    });
+    app.update();
+    app.update();
+
+    app.insert_resource(InputMap::new([(
+        GamepadButtonType::South,
+        ButtonlikeTestAction::Up,
+    )]));
+
+    // By default, every gamepad is read from, so the second gamepad works too
+    app.send_input_as_gamepad(GamepadButtonType::South, Some(second_gamepad));
+    app.update();
+    let action_state = app.world.resource::<ActionState<ButtonlikeTestAction>>();
+    assert!(action_state.pressed(ButtonlikeTestAction::Up));
+    assert_eq!(
+        action_state.sources(ButtonlikeTestAction::Up),
+        [(InputDevice::Gamepad, Some(second_gamepad))]
+    );
+
+    // Strict maps ignore gamepads until one is associated
+    app.world
+        .resource_mut::<InputMap<ButtonlikeTestAction>>()
+        .set_gamepad_association(GamepadAssociation::Strict);
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<ButtonlikeTestAction>>()
+        .released(ButtonlikeTestAction::Up));
+
+    app.world
+        .resource_mut::<InputMap<ButtonlikeTestAction>>()
+        .set_gamepad(second_gamepad);
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<ButtonlikeTestAction>>()
+        .pressed(ButtonlikeTestAction::Up));
+}
@@ -0,0 +1,116 @@
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use bevy::time::TimePlugin;
+use bevy::utils::Duration;
+use leafwing_input_manager::prelude::*;
+
+#[derive(Actionlike, Clone, Copy, Debug)]
+enum Action {
+    Jump,
+}
+
+const IDLE_TIMEOUT: Duration = Duration::from_secs(1);
+
+fn test_app() -> App {
+    let mut app = App::new();
+    // Time is advanced manually by `update_after`, rather than by the wall clock
+    app.add_plugins(MinimalPlugins.build().disable::<TimePlugin>())
+        .init_resource::<Time>()
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .insert_resource(InputActivity::new(IDLE_TIMEOUT))
+        .init_resource::<ActionState<Action>>()
+        .insert_resource(InputMap::new([(KeyCode::Space, Action::Jump)]));
+
+    app
+}
+
+/// Advances the mocked [`Time`] by `duration`, then updates the `app`
+fn update_after(app: &mut App, duration: Duration) {
+    let mut time = app.world.resource_mut::<Time>();
+    let last_update = time.last_update().unwrap_or_else(|| time.startup());
+    time.update_with_instant(last_update + duration);
+    app.update();
+}
+
+fn idle_events(app: &mut App) -> Vec<InputIdle> {
+    app.world
+        .resource_mut::<Events<InputIdle>>()
+        .drain()
+        .collect()
+}
+
+#[test]
+fn idle_event_sent_once_after_timeout() {
+    let mut app = test_app();
+
+    update_after(&mut app, Duration::ZERO);
+    assert!(!app.world.resource::<InputActivity>().is_idle());
+    assert!(idle_events(&mut app).is_empty());
+
+    update_after(&mut app, 2 * IDLE_TIMEOUT);
+    assert!(app.world.resource::<InputActivity>().is_idle());
+    assert_eq!(idle_events(&mut app), vec![InputIdle { player: None }]);
+
+    // The event is not repeated while the app remains idle
+    update_after(&mut app, 2 * IDLE_TIMEOUT);
+    assert!(idle_events(&mut app).is_empty());
+}
+
+#[test]
+fn activity_resets_idle() {
+    let mut app = test_app();
+
+    update_after(&mut app, Duration::ZERO);
+    update_after(&mut app, 2 * IDLE_TIMEOUT);
+    assert!(app.world.resource::<InputActivity>().is_idle());
+    idle_events(&mut app);
+
+    app.send_input(KeyCode::Space);
+    update_after(&mut app, Duration::ZERO);
+    let activity = app.world.resource::<InputActivity>();
+    assert!(!activity.is_idle());
+    assert_eq!(activity.time_since_active(), Duration::ZERO);
+
+    app.release_input(KeyCode::Space);
+    update_after(&mut app, 2 * IDLE_TIMEOUT);
+    assert_eq!(idle_events(&mut app), vec![InputIdle { player: None }]);
+}
+
+#[test]
+fn players_are_tracked_separately() {
+    let mut app = test_app();
+    let active_player = app
+        .world
+        .spawn(InputManagerBundle::<Action> {
+            input_map: InputMap::new([(KeyCode::Space, Action::Jump)]),
+            ..Default::default()
+        })
+        .id();
+    let idle_player = app
+        .world
+        .spawn(InputManagerBundle::<Action> {
+            input_map: InputMap::new([(KeyCode::Return, Action::Jump)]),
+            ..Default::default()
+        })
+        .id();
+
+    update_after(&mut app, Duration::ZERO);
+    app.send_input(KeyCode::Space);
+    update_after(&mut app, 2 * IDLE_TIMEOUT);
+
+    let activity = app.world.resource::<InputActivity>();
+    assert!(!activity.is_idle());
+    assert!(!activity.is_player_idle(active_player));
+    assert!(activity.is_player_idle(idle_player));
+    assert_eq!(
+        activity.player_time_since_active(idle_player),
+        Some(2 * IDLE_TIMEOUT)
+    );
+    assert_eq!(
+        idle_events(&mut app),
+        vec![InputIdle {
+            player: Some(idle_player)
+        }]
+    );
+}
@@ -94,6 +94,47 @@ fn do_nothing() {
     }
 }
 
+#[test]
+fn single_player_system_params() {
+    use bevy::input::InputPlugin;
+
+    fn pay_respects_with_actions(
+        actions: Actions<Action, With<Player>>,
+        mut respect: ResMut<Respect>,
+    ) {
+        if actions.single().pressed(Action::PayRespects) {
+            respect.0 = true;
+        }
+    }
+
+    fn consume_respects(mut actions: ActionsMut<Action, With<Player>>) {
+        actions.single_mut().consume(Action::PayRespects);
+    }
+
+    #[derive(SystemLabel)]
+    struct PayRespects;
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .add_startup_system(spawn_player)
+        .init_resource::<Respect>()
+        .add_system(pay_respects_with_actions.label(PayRespects))
+        .add_system(consume_respects.after(PayRespects));
+
+    app.send_input(KeyCode::F);
+    app.update();
+    assert_eq!(*app.world.resource::<Respect>(), Respect(true));
+
+    // The player's action state was consumed through the mutable system param
+    let mut query = app
+        .world
+        .query_filtered::<&ActionState<Action>, With<Player>>();
+    let action_state = query.single(&app.world);
+    assert!(action_state.released(Action::PayRespects));
+}
+
 #[test]
 fn action_state_change_detection() {
     use bevy::input::InputPlugin;
@@ -168,6 +209,103 @@ fn disable_input() {
     assert_eq!(*respect, Respect(false));
 }
 
+#[test]
+fn pause_timing() {
+    use bevy::input::InputPlugin;
+    use bevy::utils::Duration;
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .init_resource::<ActionState<Action>>()
+        .insert_resource(InputMap::<Action>::new([(KeyCode::F, Action::PayRespects)]));
+
+    app.update();
+    app.send_input(KeyCode::F);
+    app.update();
+    app.update();
+
+    // Pausing timing freezes the duration without releasing the action
+    *app.world.resource_mut::<PauseTiming<Action>>() = PauseTiming::PAUSED;
+    app.update();
+    let frozen_duration = app
+        .world
+        .resource::<ActionState<Action>>()
+        .current_duration(Action::PayRespects);
+
+    for _ in 0..3 {
+        std::thread::sleep(Duration::from_millis(20));
+        app.update();
+        let action_state = app.world.resource::<ActionState<Action>>();
+        assert!(action_state.pressed(Action::PayRespects));
+        assert_eq!(
+            action_state.current_duration(Action::PayRespects),
+            frozen_duration
+        );
+    }
+
+    // Resuming picks up where the timer left off
+    *app.world.resource_mut::<PauseTiming<Action>>() = PauseTiming::RUNNING;
+    std::thread::sleep(Duration::from_millis(5));
+    app.update();
+    let action_state = app.world.resource::<ActionState<Action>>();
+    assert!(action_state.pressed(Action::PayRespects));
+    let resumed_duration = action_state.current_duration(Action::PayRespects);
+    assert!(resumed_duration > frozen_duration);
+    assert!(resumed_duration < frozen_duration + Duration::from_millis(60));
+}
+
+#[test]
+fn time_sources() {
+    use bevy::input::InputPlugin;
+    use bevy::utils::Duration;
+
+    const STEP: Duration = Duration::from_millis(100);
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .init_resource::<ActionState<Action>>()
+        .insert_resource(InputMap::<Action>::new([(KeyCode::F, Action::PayRespects)]))
+        .insert_resource(TimeSource::FixedStep(STEP));
+
+    app.update();
+    app.send_input(KeyCode::F);
+    app.update();
+
+    // Each tick advances the duration by exactly one step
+    for n_steps in 1..=3 {
+        app.update();
+        let action_state = app.world.resource::<ActionState<Action>>();
+        assert_eq!(
+            action_state.current_duration(Action::PayRespects),
+            STEP * n_steps
+        );
+    }
+
+    // Virtual time stops while `Time` is paused
+    *app.world.resource_mut::<TimeSource>() = TimeSource::Virtual;
+    app.world.resource_mut::<Time>().pause();
+    app.update();
+    let frozen_duration = app
+        .world
+        .resource::<ActionState<Action>>()
+        .current_duration(Action::PayRespects);
+
+    std::thread::sleep(Duration::from_millis(5));
+    app.update();
+    let action_state = app.world.resource::<ActionState<Action>>();
+    assert!(action_state.pressed(Action::PayRespects));
+    assert_eq!(
+        action_state.current_duration(Action::PayRespects),
+        frozen_duration
+    );
+}
+
 #[test]
 fn release_when_input_map_removed() {
     use bevy::input::InputPlugin;
@@ -233,10 +371,10 @@ fn action_state_driver() {
             .spawn_empty()
             .insert(ButtonMarker)
             .insert(Interaction::None)
-            .insert(ActionStateDriver::<Action> {
-                action: Action::PayRespects,
-                entity: player_entity,
-            });
+            .insert(ActionStateDriver::<Action>::new(
+                Action::PayRespects,
+                player_entity,
+            ));
     }
 
     app.add_plugins(MinimalPlugins)
@@ -280,6 +418,251 @@ fn action_state_driver() {
     assert_eq!(*respect, Respect(false));
 }
 
+#[test]
+#[cfg(feature = "ui")]
+fn action_state_driver_hover_and_value() {
+    use bevy::input::InputPlugin;
+    use bevy::ui::Interaction;
+
+    #[derive(Actionlike, Clone, Copy, Debug)]
+    enum SliderAction {
+        Throttle,
+        Highlight,
+    }
+
+    #[derive(Component)]
+    struct SliderMarker;
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<SliderAction>::default());
+
+    let ship = app
+        .world
+        .spawn(InputManagerBundle::<SliderAction>::default())
+        .id();
+    let slider = app
+        .world
+        .spawn((
+            SliderMarker,
+            Interaction::None,
+            ActionStateDriver::new(SliderAction::Throttle, ship)
+                .with_hovered_action(SliderAction::Highlight),
+        ))
+        .id();
+
+    // Hovering presses the hovered action only
+    app.hover_button::<SliderMarker>();
+    app.update();
+    let action_state = app.world.get::<ActionState<SliderAction>>(ship).unwrap();
+    assert!(action_state.pressed(SliderAction::Highlight));
+    assert!(action_state.released(SliderAction::Throttle));
+
+    // Forwarded values drive the action regardless of the interaction
+    app.world.entity_mut(slider).insert(Interaction::None);
+    app.world
+        .get_mut::<ActionStateDriver<SliderAction>>(slider)
+        .unwrap()
+        .value = Some(0.75);
+    app.update();
+    let action_state = app.world.get::<ActionState<SliderAction>>(ship).unwrap();
+    assert!(action_state.released(SliderAction::Highlight));
+    assert!(action_state.pressed(SliderAction::Throttle));
+    assert_eq!(action_state.value(SliderAction::Throttle), 0.75);
+
+    app.world
+        .get_mut::<ActionStateDriver<SliderAction>>(slider)
+        .unwrap()
+        .value = Some(0.0);
+    app.update();
+    let action_state = app.world.get::<ActionState<SliderAction>>(ship).unwrap();
+    assert!(action_state.released(SliderAction::Throttle));
+}
+
+#[test]
+#[cfg(feature = "ui")]
+fn action_state_driver_press_mode() {
+    use bevy::input::InputPlugin;
+    use bevy::math::DVec2;
+    use bevy::reflect::Struct;
+    use bevy::ui::{Interaction, Node};
+    use bevy::window::WindowId;
+    use leafwing_input_manager::action_state::DriverPressMode;
+
+    #[derive(Component)]
+    struct ButtonMarker;
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default());
+
+    let mut window = Window::new(
+        WindowId::primary(),
+        &WindowDescriptor::default(),
+        800,
+        600,
+        1.0,
+        None,
+        None,
+    );
+    window.update_cursor_physical_position_from_backend(Some(DVec2::new(400.0, 300.0)));
+    let mut windows = Windows::default();
+    windows.add(window);
+    app.insert_resource(windows);
+
+    let player = app
+        .world
+        .spawn(InputManagerBundle::<Action>::default())
+        .id();
+    // Nodes are laid out by `bevy_ui`, so this node has no size and can never be under the pointer
+    let button = app
+        .world
+        .spawn((
+            ButtonMarker,
+            Interaction::None,
+            Node::default(),
+            GlobalTransform::default(),
+            ActionStateDriver::new(Action::PayRespects, player),
+        ))
+        .id();
+
+    // By default, clicks press the action even once the pointer has left the node
+    app.click_button::<ButtonMarker>();
+    app.update();
+    let action_state = app.world.get::<ActionState<Action>>(player).unwrap();
+    assert!(action_state.pressed(Action::PayRespects));
+
+    app.world
+        .get_mut::<ActionStateDriver<Action>>(button)
+        .unwrap()
+        .press_mode = DriverPressMode::WhilePointerOver;
+    app.update();
+    let action_state = app.world.get::<ActionState<Action>>(player).unwrap();
+    assert!(action_state.released(Action::PayRespects));
+
+    // Both UI nodes and the cursor are measured from the bottom-left corner of the window,
+    // so this node sits in the top-right quarter of the window
+    let mut node = app.world.get_mut::<Node>(button).unwrap();
+    *node.get_field_mut::<Vec2>("calculated_size").unwrap() = Vec2::new(100.0, 50.0);
+    *app.world.get_mut::<GlobalTransform>(button).unwrap() =
+        GlobalTransform::from_xyz(600.0, 450.0, 0.0);
+    let mut windows = app.world.resource_mut::<Windows>();
+    let window = windows.primary_mut();
+    window.update_cursor_physical_position_from_backend(Some(DVec2::new(610.0, 460.0)));
+    app.update();
+    let action_state = app.world.get::<ActionState<Action>>(player).unwrap();
+    assert!(action_state.pressed(Action::PayRespects));
+
+    // The mirrored position in the bottom-right quarter of the window is not over the node
+    let mut windows = app.world.resource_mut::<Windows>();
+    let window = windows.primary_mut();
+    window.update_cursor_physical_position_from_backend(Some(DVec2::new(610.0, 140.0)));
+    app.update();
+    let action_state = app.world.get::<ActionState<Action>>(player).unwrap();
+    assert!(action_state.released(Action::PayRespects));
+}
+
+#[test]
+#[cfg(feature = "ui")]
+fn multiple_drivers_on_one_button() {
+    use bevy::input::InputPlugin;
+    use bevy::ui::Interaction;
+    use leafwing_input_manager::action_state::ActionStateDrivers;
+
+    #[derive(Actionlike, Clone, Copy, Debug)]
+    enum MenuAction {
+        Open,
+        Highlight,
+    }
+
+    #[derive(Component)]
+    struct ButtonMarker;
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .add_plugin(InputManagerPlugin::<MenuAction>::default());
+
+    let player = app
+        .world
+        .spawn(InputManagerBundle::<Action>::default())
+        .id();
+    let menu = app
+        .world
+        .spawn(InputManagerBundle::<MenuAction>::default())
+        .id();
+    app.world.spawn((
+        ButtonMarker,
+        Interaction::None,
+        ActionStateDriver::new(Action::PayRespects, player),
+        ActionStateDrivers::new([
+            ActionStateDriver::new(MenuAction::Open, menu),
+            ActionStateDriver::new(MenuAction::Highlight, menu),
+        ]),
+    ));
+
+    app.click_button::<ButtonMarker>();
+    app.update();
+
+    let action_state = app.world.get::<ActionState<Action>>(player).unwrap();
+    assert!(action_state.pressed(Action::PayRespects));
+    let menu_state = app.world.get::<ActionState<MenuAction>>(menu).unwrap();
+    assert!(menu_state.pressed(MenuAction::Open));
+    assert!(menu_state.pressed(MenuAction::Highlight));
+}
+
+#[test]
+#[cfg(feature = "ui")]
+fn driver_activation_and_disabling() {
+    use bevy::input::InputPlugin;
+    use bevy::ui::Interaction;
+    use leafwing_input_manager::action_state::{DisabledDriver, DriverActivation};
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default());
+
+    let player = app
+        .world
+        .spawn(InputManagerBundle::<Action>::default())
+        .id();
+    let button = app
+        .world
+        .spawn((
+            Interaction::Hovered,
+            ActionStateDriver::new(Action::PayRespects, player)
+                .with_activation(DriverActivation::HOVERED),
+        ))
+        .id();
+
+    // Hovering presses the action
+    app.update();
+    let action_state = app.world.get::<ActionState<Action>>(player).unwrap();
+    assert!(action_state.pressed(Action::PayRespects));
+
+    // Clicking does not
+    *app.world.get_mut::<Interaction>(button).unwrap() = Interaction::Clicked;
+    app.update();
+    let action_state = app.world.get::<ActionState<Action>>(player).unwrap();
+    assert!(action_state.released(Action::PayRespects));
+
+    // Disabled drivers do nothing
+    *app.world.get_mut::<Interaction>(button).unwrap() = Interaction::Hovered;
+    app.world.entity_mut(button).insert(DisabledDriver);
+    app.update();
+    let action_state = app.world.get::<ActionState<Action>>(player).unwrap();
+    assert!(action_state.released(Action::PayRespects));
+
+    // Drivers whose target was despawned are skipped
+    app.world.entity_mut(button).remove::<DisabledDriver>();
+    app.world.despawn(player);
+    app.update();
+}
+
 #[test]
 fn duration() {
     use bevy::input::InputPlugin;
@@ -366,3 +749,550 @@ fn schedule_presses() {
         .resource::<ActionState<Action>>()
         .just_released(Action::PayRespects));
 }
+
+#[test]
+fn late_update_reads_inputs_sent_during_update() {
+    use bevy::input::InputPlugin;
+    use leafwing_input_manager::plugin::InputManagerSystem;
+
+    // Whether the action was pressed during `Update` and at the end of the frame
+    #[derive(Resource, Default)]
+    struct Observed {
+        update: bool,
+        post_update: bool,
+    }
+
+    fn observe_update(action_state: Res<ActionState<Action>>, mut observed: ResMut<Observed>) {
+        observed.update = action_state.pressed(Action::PayRespects);
+    }
+
+    fn press_f(mut keycodes: ResMut<Input<KeyCode>>) {
+        keycodes.press(KeyCode::F);
+    }
+
+    fn observe_post_update(action_state: Res<ActionState<Action>>, mut observed: ResMut<Observed>) {
+        observed.post_update = action_state.just_pressed(Action::PayRespects);
+    }
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default().with_late_update())
+        .init_resource::<ActionState<Action>>()
+        .insert_resource(InputMap::<Action>::new([(KeyCode::F, Action::PayRespects)]))
+        .init_resource::<Observed>()
+        .add_system(observe_update.before(press_f))
+        .add_system(press_f)
+        .add_system_to_stage(
+            CoreStage::PostUpdate,
+            observe_post_update.after(InputManagerSystem::LateUpdate),
+        );
+
+    app.update();
+
+    // The key was pressed after the systems in `Update` read the action state,
+    // but is seen by the systems that run after the late update
+    let observed = app.world.resource::<Observed>();
+    assert!(!observed.update);
+    assert!(observed.post_update);
+}
+
+#[test]
+fn inhibited_devices_are_ignored() {
+    use bevy::input::InputPlugin;
+    use leafwing_input_manager::inhibit_input::{InhibitInput, InputDevice};
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .init_resource::<ActionState<Action>>()
+        .insert_resource(InputMap::<Action>::new([
+            (UserInput::from(KeyCode::F), Action::PayRespects),
+            (
+                UserInput::from(GamepadButtonType::South),
+                Action::PayRespects,
+            ),
+        ]));
+
+    app.update();
+    app.send_input(KeyCode::F);
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .pressed(Action::PayRespects));
+
+    // Inhibiting the keyboard releases the action, even though the key is still held
+    app.insert_resource(InhibitInput::<Action>::new([InputDevice::Keyboard]));
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .just_released(Action::PayRespects));
+
+    // Other devices still work
+    let gamepad = Gamepad { id: 0 };
+    app.world
+        .resource_mut::<InputMap<Action>>()
+        .set_gamepad(gamepad);
+    app.send_input_as_gamepad(GamepadButtonType::South, Some(gamepad));
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .pressed(Action::PayRespects));
+
+    app.world
+        .resource_mut::<InhibitInput<Action>>()
+        .insert(InputDevice::Gamepad);
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .released(Action::PayRespects));
+}
+
+#[test]
+fn many_entities_are_updated() {
+    use bevy::input::InputPlugin;
+    use leafwing_input_manager::systems::ACTION_STATE_BATCH_SIZE;
+
+    fn spawn_crowd(mut commands: Commands) {
+        // Spans several parallel batches, with a partial batch at the end
+        for _ in 0..(3 * ACTION_STATE_BATCH_SIZE + 1) {
+            commands.spawn(InputManagerBundle::<Action> {
+                input_map: InputMap::<Action>::new([(KeyCode::F, Action::PayRespects)]),
+                ..Default::default()
+            });
+        }
+    }
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .add_startup_system(spawn_crowd);
+
+    app.update();
+
+    // Press
+    app.send_input(KeyCode::F);
+    app.update();
+
+    let mut action_state_query = app.world.query::<&ActionState<Action>>();
+    assert_eq!(
+        action_state_query.iter(&app.world).count(),
+        3 * ACTION_STATE_BATCH_SIZE + 1
+    );
+    for action_state in action_state_query.iter(&app.world) {
+        assert!(action_state.just_pressed(Action::PayRespects));
+    }
+
+    // Hold
+    app.update();
+
+    for action_state in action_state_query.iter(&app.world) {
+        assert!(action_state.pressed(Action::PayRespects));
+        assert!(!action_state.just_pressed(Action::PayRespects));
+    }
+}
+
+#[test]
+fn multiple_action_types() {
+    use bevy::input::InputPlugin;
+    use leafwing_input_manager::plugin::{InputManagerSystem, InputManagerSystemFor};
+
+    #[derive(Actionlike, Clone, Copy, Debug)]
+    enum MenuAction {
+        Select,
+    }
+
+    fn select_requires_respect(
+        action_state: Res<ActionState<Action>>,
+        menu_action_state: Res<ActionState<MenuAction>>,
+    ) {
+        // Both action types have been updated by the time this system runs
+        assert_eq!(
+            action_state.pressed(Action::PayRespects),
+            menu_action_state.pressed(MenuAction::Select)
+        );
+    }
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .add_plugin(InputManagerPlugin::<MenuAction>::default())
+        .init_resource::<ActionState<Action>>()
+        .insert_resource(InputMap::<Action>::new([(KeyCode::F, Action::PayRespects)]))
+        .init_resource::<ActionState<MenuAction>>()
+        .insert_resource(InputMap::<MenuAction>::new([(
+            KeyCode::F,
+            MenuAction::Select,
+        )]))
+        .add_system_to_stage(
+            CoreStage::PreUpdate,
+            select_requires_respect.after(InputManagerSystemFor::<MenuAction>::new(
+                InputManagerSystem::Update,
+            )),
+        );
+
+    app.update();
+
+    app.send_input(KeyCode::F);
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<MenuAction>>()
+        .just_pressed(MenuAction::Select));
+}
+
+#[test]
+fn duplicate_plugins_are_ignored() {
+    use bevy::input::InputPlugin;
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .init_resource::<ActionState<Action>>()
+        .insert_resource(InputMap::<Action>::new([(KeyCode::F, Action::PayRespects)]));
+
+    app.update();
+
+    // The extra copy of the plugin must not add a second set of systems
+    app.send_input(KeyCode::F);
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .just_pressed(Action::PayRespects));
+}
+
+#[test]
+fn state_scoped_actions() {
+    use bevy::input::InputPlugin;
+    use leafwing_input_manager::state_scoped_actions::{
+        StateScopedActions, StateScopedActionsPlugin,
+    };
+
+    #[derive(Actionlike, Clone, Copy, Debug)]
+    enum GameAction {
+        Jump,
+        Pause,
+    }
+
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+    enum GameState {
+        Playing,
+        Paused,
+    }
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<GameAction>::default())
+        .add_plugin(StateScopedActionsPlugin::<GameAction, GameState>::default())
+        .add_state(GameState::Paused)
+        .insert_resource(
+            StateScopedActions::default().with_scope(GameState::Paused, [GameAction::Pause]),
+        )
+        .init_resource::<ActionState<GameAction>>()
+        .insert_resource(InputMap::new([
+            (KeyCode::Space, GameAction::Jump),
+            (KeyCode::Escape, GameAction::Pause),
+        ]));
+
+    app.update();
+
+    // Only pausing is available while paused
+    app.send_input(KeyCode::Space);
+    app.send_input(KeyCode::Escape);
+    app.update();
+    let action_state = app.world.resource::<ActionState<GameAction>>();
+    assert!(action_state.released(GameAction::Jump));
+    assert!(action_state.just_pressed(GameAction::Pause));
+
+    // Holding jump through the transition does not press it
+    app.world
+        .resource_mut::<State<GameState>>()
+        .set(GameState::Playing)
+        .unwrap();
+    app.update();
+    app.update();
+    let action_state = app.world.resource::<ActionState<GameAction>>();
+    assert!(action_state.released(GameAction::Jump));
+
+    // Every action is available while playing
+    app.release_input(KeyCode::Space);
+    app.update();
+    app.send_input(KeyCode::Space);
+    app.update();
+    let action_state = app.world.resource::<ActionState<GameAction>>();
+    assert!(action_state.just_pressed(GameAction::Jump));
+}
+
+#[test]
+fn overlay_shadows_base_map() {
+    use bevy::input::InputPlugin;
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .add_startup_system(spawn_player)
+        .init_resource::<Respect>()
+        .add_system(pay_respects)
+        .add_system_to_stage(CoreStage::PreUpdate, respect_fades);
+
+    app.update();
+    let player = app
+        .world
+        .query_filtered::<Entity, With<Player>>()
+        .single(&app.world);
+    app.world.entity_mut(player).insert(InputMapOverlay::new(
+        InputMap::new([(KeyCode::G, Action::PayRespects)]),
+        LayerMode::Shadow,
+    ));
+
+    // The base binding is shadowed by the overlay
+    app.send_input(KeyCode::F);
+    app.update();
+    assert_eq!(*app.world.resource::<Respect>(), Respect(false));
+
+    app.send_input(KeyCode::G);
+    app.update();
+    assert_eq!(*app.world.resource::<Respect>(), Respect(true));
+
+    // Changes to the overlay are picked up
+    app.release_input(KeyCode::G);
+    app.world
+        .get_mut::<InputMapOverlay<Action>>(player)
+        .unwrap()
+        .map = InputMap::new([(KeyCode::H, Action::PayRespects)]);
+    app.update();
+    assert_eq!(*app.world.resource::<Respect>(), Respect(false));
+
+    app.send_input(KeyCode::H);
+    app.update();
+    assert_eq!(*app.world.resource::<Respect>(), Respect(true));
+
+    // Removing the overlay restores the base bindings
+    app.release_input(KeyCode::H);
+    app.world
+        .entity_mut(player)
+        .remove::<InputMapOverlay<Action>>();
+    app.update();
+    assert_eq!(*app.world.resource::<Respect>(), Respect(true));
+}
+
+#[test]
+fn platform_bindings_switch_devices() {
+    use bevy::input::InputPlugin;
+    use leafwing_input_manager::inhibit_input::InputDevice;
+    use leafwing_input_manager::platform_bindings::{
+        PlatformBindings, SchemeChanged, SchemeSelection,
+    };
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .init_resource::<ActionState<Action>>()
+        .init_resource::<InputMap<Action>>()
+        .insert_resource(
+            PlatformBindings::default()
+                .with_device(
+                    InputDevice::Keyboard,
+                    InputMap::new([(KeyCode::F, Action::PayRespects)]),
+                )
+                .with_device(
+                    InputDevice::Gamepad,
+                    InputMap::new([(GamepadButtonType::North, Action::PayRespects)]),
+                ),
+        );
+
+    // Only the keyboard is detected, since no gamepads are connected
+    app.update();
+    let input_map = app.world.resource::<InputMap<Action>>();
+    assert_eq!(
+        input_map
+            .get(Action::PayRespects)
+            .iter()
+            .collect::<Vec<_>>(),
+        [&KeyCode::F.into()]
+    );
+
+    app.world
+        .resource_mut::<PlatformBindings<Action>>()
+        .set_active_devices([InputDevice::Gamepad]);
+    app.update();
+    let input_map = app.world.resource::<InputMap<Action>>();
+    assert_eq!(
+        input_map
+            .get(Action::PayRespects)
+            .iter()
+            .collect::<Vec<_>>(),
+        [&GamepadButtonType::North.into()]
+    );
+
+    // Using the keyboard switches back to its bindings
+    app.world
+        .resource_mut::<PlatformBindings<Action>>()
+        .set_selection(SchemeSelection::LastUsed);
+    app.send_input(KeyCode::F);
+    app.update();
+    let input_map = app.world.resource::<InputMap<Action>>();
+    assert_eq!(
+        input_map
+            .get(Action::PayRespects)
+            .iter()
+            .collect::<Vec<_>>(),
+        [&KeyCode::F.into()]
+    );
+    let events = app.world.resource::<Events<SchemeChanged<Action>>>();
+    let changes: Vec<_> = events.get_reader().iter(events).cloned().collect();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].previous, None);
+    assert_eq!(changes[0].current, InputDevice::Keyboard);
+}
+
+#[test]
+fn text_input_suppresses_key_actions() {
+    use bevy::input::InputPlugin;
+    use bevy::window::ReceivedCharacter;
+    use leafwing_input_manager::text_input::TextInputBuffer;
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .add_event::<ReceivedCharacter>()
+        .init_resource::<ActionState<Action>>()
+        .insert_resource(InputMap::<Action>::new([(KeyCode::F, Action::PayRespects)]));
+
+    let mut text_input = TextInputBuffer::default();
+    text_input.activate();
+    app.insert_resource(text_input);
+
+    app.update();
+    app.send_input(KeyCode::F);
+    app.world.send_event(ReceivedCharacter {
+        id: bevy::window::WindowId::primary(),
+        char: 'f',
+    });
+    app.update();
+
+    assert_eq!(app.world.resource::<TextInputBuffer>().text(), "f");
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .released(Action::PayRespects));
+
+    // Once typing is done, the key triggers its action again
+    app.world.resource_mut::<TextInputBuffer>().deactivate();
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .pressed(Action::PayRespects));
+}
+
+#[test]
+fn sources_report_the_pressing_devices() {
+    use bevy::input::InputPlugin;
+    use leafwing_input_manager::inhibit_input::InputDevice;
+
+    let gamepad = Gamepad { id: 1 };
+    let mut input_map = InputMap::<Action>::new([
+        (UserInput::from(KeyCode::F), Action::PayRespects),
+        (
+            UserInput::from(GamepadButtonType::South),
+            Action::PayRespects,
+        ),
+    ]);
+    input_map.set_gamepad(gamepad);
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .init_resource::<ActionState<Action>>()
+        .insert_resource(input_map);
+
+    app.update();
+    app.send_input_as_gamepad(GamepadButtonType::South, Some(gamepad));
+    app.update();
+    let action_state = app.world.resource::<ActionState<Action>>();
+    assert_eq!(
+        action_state.sources(Action::PayRespects),
+        [(InputDevice::Gamepad, Some(gamepad))]
+    );
+
+    app.send_input(KeyCode::F);
+    app.update();
+    let action_state = app.world.resource::<ActionState<Action>>();
+    assert_eq!(
+        action_state.sources(Action::PayRespects),
+        [
+            (InputDevice::Keyboard, None),
+            (InputDevice::Gamepad, Some(gamepad))
+        ]
+    );
+
+    app.reset_inputs();
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .sources(Action::PayRespects)
+        .is_empty());
+}
+
+#[test]
+fn plugin_config_sets_up_shared_resources() {
+    use bevy::input::InputPlugin;
+    use leafwing_input_manager::inhibit_input::{InhibitInput, InputDevice};
+    use leafwing_input_manager::plugin::InputManagerConfig;
+
+    let config = InputManagerConfig::default()
+        .with_clash_strategy(ClashStrategy::PressAll)
+        .with_time_source(TimeSource::Virtual)
+        .with_inhibited_devices([InputDevice::Keyboard]);
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::new(config))
+        .init_resource::<ActionState<Action>>()
+        .insert_resource(InputMap::<Action>::new([(KeyCode::F, Action::PayRespects)]));
+
+    assert_eq!(
+        *app.world.resource::<ClashStrategy>(),
+        ClashStrategy::PressAll
+    );
+    assert_eq!(*app.world.resource::<TimeSource>(), TimeSource::Virtual);
+    assert!(app
+        .world
+        .resource::<InhibitInput<Action>>()
+        .inhibits(InputDevice::Keyboard));
+
+    app.update();
+    app.send_input(KeyCode::F);
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .released(Action::PayRespects));
+}
@@ -0,0 +1,57 @@
+use bevy::prelude::*;
+use bevy::utils::Duration;
+use leafwing_input_manager::input_mocking::test_app;
+use leafwing_input_manager::key_sequences::{KeySequence, KeySequences};
+use leafwing_input_manager::prelude::*;
+
+#[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+enum Action {
+    Jump,
+    GodMode,
+}
+
+fn tap(app: &mut App, key: KeyCode) {
+    app.send_input(key);
+    app.update();
+    app.release_input(key);
+    app.update();
+}
+
+#[test]
+fn typed_sequences_press_their_action() {
+    let mut app = test_app::<Action>();
+    app.insert_resource(InputMap::new([(KeyCode::Space, Action::Jump)]));
+    app.insert_resource(KeySequences::new([KeySequence::new(
+        [KeyCode::I, KeyCode::D, KeyCode::D, KeyCode::Q, KeyCode::D],
+        Action::GodMode,
+        Duration::from_secs(60),
+    )]));
+
+    for key in [KeyCode::I, KeyCode::D, KeyCode::D, KeyCode::Q] {
+        tap(&mut app, key);
+        assert!(app
+            .world
+            .resource::<ActionState<Action>>()
+            .released(Action::GodMode));
+    }
+    assert_eq!(
+        app.world
+            .resource::<KeySequences<Action>>()
+            .progress(Action::GodMode),
+        0.8
+    );
+
+    app.send_input(KeyCode::D);
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .just_pressed(Action::GodMode));
+
+    // Only pressed for a single update
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .released(Action::GodMode));
+}
@@ -0,0 +1,163 @@
+#![cfg(feature = "ui")]
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use leafwing_input_manager::menu_navigation::{Focusable, Focused, MenuNavigationPlugin};
+use leafwing_input_manager::prelude::*;
+
+#[derive(Component, Debug, PartialEq, Eq, Clone, Copy)]
+enum Slot {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+}
+
+fn spawn_menu(mut commands: Commands) {
+    // UI nodes are positioned from the bottom-left corner of the window, with `y` increasing upwards
+    for (slot, x, y) in [
+        (Slot::TopLeft, 100.0, 500.0),
+        (Slot::TopRight, 200.0, 500.0),
+        (Slot::BottomLeft, 100.0, 400.0),
+    ] {
+        commands.spawn((
+            slot,
+            Focusable,
+            Interaction::None,
+            GlobalTransform::from_xyz(x, y, 0.0),
+        ));
+    }
+}
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(MenuNavigationPlugin)
+        .add_startup_system(spawn_menu);
+
+    app.update();
+    app
+}
+
+fn focused_slot(app: &mut App) -> Option<Slot> {
+    let mut query = app.world.query_filtered::<&Slot, With<Focused>>();
+    let slots: Vec<Slot> = query.iter(&app.world).copied().collect();
+    assert!(slots.len() <= 1);
+    slots.first().copied()
+}
+
+fn tap(app: &mut App, key: KeyCode) {
+    app.send_input(key);
+    app.update();
+    app.release_input(key);
+    app.update();
+}
+
+#[test]
+fn first_direction_focuses_top_left() {
+    let mut app = test_app();
+    assert_eq!(focused_slot(&mut app), None);
+
+    tap(&mut app, KeyCode::Right);
+    assert_eq!(focused_slot(&mut app), Some(Slot::TopLeft));
+}
+
+#[test]
+fn focus_moves_in_direction() {
+    let mut app = test_app();
+    tap(&mut app, KeyCode::Right);
+
+    tap(&mut app, KeyCode::Right);
+    assert_eq!(focused_slot(&mut app), Some(Slot::TopRight));
+
+    // Nothing further right
+    tap(&mut app, KeyCode::Right);
+    assert_eq!(focused_slot(&mut app), Some(Slot::TopRight));
+
+    tap(&mut app, KeyCode::Left);
+    tap(&mut app, KeyCode::Down);
+    assert_eq!(focused_slot(&mut app), Some(Slot::BottomLeft));
+
+    // Nothing further down
+    tap(&mut app, KeyCode::Down);
+    assert_eq!(focused_slot(&mut app), Some(Slot::BottomLeft));
+
+    tap(&mut app, KeyCode::Up);
+    assert_eq!(focused_slot(&mut app), Some(Slot::TopLeft));
+}
+
+#[test]
+fn nan_transforms_are_never_focused() {
+    let mut app = test_app();
+    app.world.spawn((
+        Focusable,
+        Interaction::None,
+        GlobalTransform::from_xyz(f32::NAN, 600.0, 0.0),
+    ));
+
+    tap(&mut app, KeyCode::Right);
+    assert_eq!(focused_slot(&mut app), Some(Slot::TopLeft));
+
+    tap(&mut app, KeyCode::Right);
+    assert_eq!(focused_slot(&mut app), Some(Slot::TopRight));
+}
+
+#[test]
+fn select_clicks_focused_node() {
+    let mut app = test_app();
+    tap(&mut app, KeyCode::Down);
+
+    app.send_input(KeyCode::Return);
+    app.update();
+
+    let mut query = app.world.query::<(&Slot, &Interaction)>();
+    for (slot, interaction) in query.iter(&app.world) {
+        match slot {
+            Slot::TopLeft => assert_eq!(*interaction, Interaction::Clicked),
+            _ => assert_eq!(*interaction, Interaction::None),
+        }
+    }
+
+    // Each press only clicks once
+    app.update();
+    for (_, interaction) in query.iter(&app.world) {
+        assert_eq!(*interaction, Interaction::None);
+    }
+}
+
+#[test]
+fn custom_action_clicks_focused_node() {
+    use leafwing_input_manager::menu_navigation::ClickFocusedPlugin;
+
+    #[derive(Actionlike, Clone, Copy, Debug)]
+    enum Action {
+        Submit,
+    }
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .add_plugin(ClickFocusedPlugin::new(Action::Submit))
+        .init_resource::<ActionState<Action>>()
+        .insert_resource(InputMap::new([(KeyCode::Space, Action::Submit)]));
+
+    let focused = app
+        .world
+        .spawn((Focusable, Focused, Interaction::None))
+        .id();
+    app.update();
+
+    app.send_input(KeyCode::Space);
+    app.update();
+    assert_eq!(
+        app.world.get::<Interaction>(focused),
+        Some(&Interaction::Clicked)
+    );
+
+    // Each press only clicks once
+    app.update();
+    assert_eq!(
+        app.world.get::<Interaction>(focused),
+        Some(&Interaction::None)
+    );
+}
@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+use leafwing_input_manager::input_mocking::test_app;
+use leafwing_input_manager::prelude::*;
+
+#[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+enum Action {
+    Aim,
+    Fire,
+    AltFire,
+}
+
+fn input_map() -> InputMap<Action> {
+    let mut input_map = InputMap::new([
+        (KeyCode::LShift, Action::Aim),
+        (KeyCode::Space, Action::Fire),
+    ]);
+    input_map.insert_with_modifier_action(KeyCode::Space, Action::AltFire, Action::Aim);
+    input_map
+}
+
+fn pressed(app: &App) -> Vec<Action> {
+    app.world.resource::<ActionState<Action>>().get_pressed()
+}
+
+#[test]
+fn modifier_actions_are_required() {
+    let mut app = test_app::<Action>();
+    app.insert_resource(input_map());
+
+    app.send_input(KeyCode::Space);
+    app.update();
+    assert_eq!(pressed(&app), [Action::Fire]);
+
+    // The modified binding replaces the plain one while the modifier action is active
+    app.send_input(KeyCode::LShift);
+    app.update();
+    assert_eq!(pressed(&app), [Action::Aim, Action::AltFire]);
+
+    app.release_input(KeyCode::LShift);
+    app.update();
+    assert_eq!(pressed(&app), [Action::Fire]);
+}
+
+#[test]
+fn rebinding_the_modifier_action_affects_modified_bindings() {
+    let mut app = test_app::<Action>();
+    let mut input_map = input_map();
+    input_map.clear_action(Action::Aim);
+    input_map.insert(MouseButton::Right, Action::Aim);
+    app.insert_resource(input_map);
+
+    app.send_input(KeyCode::LShift);
+    app.send_input(KeyCode::Space);
+    app.update();
+    assert_eq!(pressed(&app), [Action::Fire]);
+
+    app.send_input(MouseButton::Right);
+    app.update();
+    assert_eq!(pressed(&app), [Action::Aim, Action::AltFire]);
+}
+
+#[test]
+fn press_all_presses_both_bindings() {
+    let mut app = test_app::<Action>();
+    app.insert_resource(input_map());
+    app.insert_resource(ClashStrategy::PressAll);
+
+    app.send_input(KeyCode::LShift);
+    app.send_input(KeyCode::Space);
+    app.update();
+    assert_eq!(pressed(&app), [Action::Aim, Action::Fire, Action::AltFire]);
+}
+
+#[test]
+fn removing_a_binding_forgets_its_modifier_action() {
+    let mut input_map = input_map();
+    input_map.remove(Action::AltFire, KeyCode::Space);
+    input_map.insert(KeyCode::Space, Action::AltFire);
+    assert_eq!(
+        input_map.modifier_action(Action::AltFire, &KeyCode::Space.into()),
+        None
+    );
+}
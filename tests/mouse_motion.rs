@@ -1,7 +1,9 @@
 use bevy::input::mouse::MouseMotion;
 use bevy::input::InputPlugin;
 use bevy::prelude::*;
-use leafwing_input_manager::axislike::{AxisType, DualAxisData, MouseMotionAxisType};
+use leafwing_input_manager::axislike::{
+    AxisSemantics, AxisType, DeadZoneShape, DualAxisData, MagnitudeLimit, MouseMotionAxisType,
+};
 use leafwing_input_manager::buttonlike::MouseMotionDirection;
 use leafwing_input_manager::prelude::*;
 
@@ -29,6 +31,27 @@ fn test_app() -> App {
         .init_resource::<ActionState<ButtonlikeTestAction>>()
         .init_resource::<ActionState<AxislikeTestAction>>();
 
+    // Browsers only report mouse motion under pointer lock
+    #[cfg(feature = "web")]
+    {
+        use bevy::window::{CursorGrabMode, WindowId};
+
+        let mut windows = Windows::default();
+        windows.add(Window::new(
+            WindowId::primary(),
+            &WindowDescriptor {
+                cursor_grab_mode: CursorGrabMode::Locked,
+                ..Default::default()
+            },
+            800,
+            600,
+            1.0,
+            None,
+            None,
+        ));
+        app.insert_resource(windows);
+    }
+
     app
 }
 
@@ -71,6 +94,8 @@ fn mouse_motion_single_axis_mocking() {
     let input = SingleAxis {
         axis_type: AxisType::MouseMotion(MouseMotionAxisType::X),
         value: Some(-1.),
+        range: None,
+        offset: 0.0,
         positive_low: 0.0,
         negative_low: 0.0,
     };
@@ -90,15 +115,21 @@ fn mouse_motion_dual_axis_mocking() {
         x: SingleAxis {
             axis_type: AxisType::MouseMotion(MouseMotionAxisType::X),
             value: Some(1.),
+            range: None,
+            offset: 0.0,
             positive_low: 0.0,
             negative_low: 0.0,
         },
         y: SingleAxis {
             axis_type: AxisType::MouseMotion(MouseMotionAxisType::Y),
             value: Some(0.),
+            range: None,
+            offset: 0.0,
             positive_low: 0.0,
             negative_low: 0.0,
         },
+        deadzone_shape: DeadZoneShape::Cross,
+        magnitude: MagnitudeLimit::Raw,
     };
     app.send_input(input);
     let mut events = app.world.resource_mut::<Events<MouseMotion>>();
@@ -163,6 +194,8 @@ fn mouse_motion_single_axis() {
     let input = SingleAxis {
         axis_type: AxisType::MouseMotion(MouseMotionAxisType::X),
         value: Some(1.),
+        range: None,
+        offset: 0.0,
         positive_low: 0.0,
         negative_low: 0.0,
     };
@@ -175,6 +208,8 @@ fn mouse_motion_single_axis() {
     let input = SingleAxis {
         axis_type: AxisType::MouseMotion(MouseMotionAxisType::X),
         value: Some(-1.),
+        range: None,
+        offset: 0.0,
         positive_low: 0.0,
         negative_low: 0.0,
     };
@@ -187,6 +222,8 @@ fn mouse_motion_single_axis() {
     let input = SingleAxis {
         axis_type: AxisType::MouseMotion(MouseMotionAxisType::Y),
         value: Some(1.),
+        range: None,
+        offset: 0.0,
         positive_low: 0.0,
         negative_low: 0.0,
     };
@@ -199,6 +236,8 @@ fn mouse_motion_single_axis() {
     let input = SingleAxis {
         axis_type: AxisType::MouseMotion(MouseMotionAxisType::Y),
         value: Some(-1.),
+        range: None,
+        offset: 0.0,
         positive_low: 0.0,
         negative_low: 0.0,
     };
@@ -211,6 +250,8 @@ fn mouse_motion_single_axis() {
     let input = SingleAxis {
         axis_type: AxisType::MouseMotion(MouseMotionAxisType::Y),
         value: Some(0.0),
+        range: None,
+        offset: 0.0,
         // Usually a small deadzone threshold will be set
         positive_low: 0.1,
         negative_low: 0.1,
@@ -224,6 +265,8 @@ fn mouse_motion_single_axis() {
     let input = SingleAxis {
         axis_type: AxisType::MouseMotion(MouseMotionAxisType::Y),
         value: None,
+        range: None,
+        offset: 0.0,
         positive_low: 0.0,
         negative_low: 0.0,
     };
@@ -287,3 +330,121 @@ fn mouse_motion_virtualdpad() {
         DualAxisData::new(0.0, -1.0)
     );
 }
+
+#[test]
+fn mouse_motion_is_relative() {
+    let mut app = test_app();
+    app.insert_resource(InputMap::new([(
+        DualAxis::mouse_motion(),
+        AxislikeTestAction::XY,
+    )]));
+
+    app.send_input(DualAxis::from_value(
+        MouseMotionAxisType::X,
+        MouseMotionAxisType::Y,
+        5.0,
+        0.0,
+    ));
+    app.update();
+
+    let action_state = app.world.resource::<ActionState<AxislikeTestAction>>();
+    assert_eq!(
+        action_state.axis_semantics(AxislikeTestAction::XY),
+        AxisSemantics::Relative
+    );
+    // Relative inputs already report their change since the last update
+    assert_eq!(
+        action_state.axis_delta(AxislikeTestAction::XY),
+        action_state.axis_pair(AxislikeTestAction::XY)
+    );
+}
+
+#[test]
+#[cfg(feature = "web")]
+fn mouse_motion_requires_pointer_lock_on_web() {
+    use bevy::window::CursorGrabMode;
+
+    let mut app = test_app();
+    app.insert_resource(InputMap::new([(
+        MouseMotionDirection::Up,
+        ButtonlikeTestAction::Up,
+    )]));
+    app.world
+        .resource_mut::<Windows>()
+        .get_primary_mut()
+        .unwrap()
+        .set_cursor_grab_mode(CursorGrabMode::None);
+
+    let send_motion = |app: &mut App| {
+        app.world
+            .resource_mut::<Events<MouseMotion>>()
+            .send(MouseMotion {
+                delta: Vec2::new(0.0, 1.0),
+            });
+    };
+
+    // Browsers do not report meaningful motion without pointer lock
+    send_motion(&mut app);
+    app.update();
+    let action_state = app.world.resource::<ActionState<ButtonlikeTestAction>>();
+    assert!(action_state.released(ButtonlikeTestAction::Up));
+
+    app.world
+        .resource_mut::<Windows>()
+        .get_primary_mut()
+        .unwrap()
+        .set_cursor_grab_mode(CursorGrabMode::Locked);
+    send_motion(&mut app);
+    app.update();
+    let action_state = app.world.resource::<ActionState<ButtonlikeTestAction>>();
+    assert!(action_state.pressed(ButtonlikeTestAction::Up));
+}
+
+#[test]
+#[cfg(not(feature = "web"))]
+fn late_mouse_sampling_patches_axis_pairs() {
+    use leafwing_input_manager::plugin::{InputManagerConfig, InputManagerSystem};
+
+    // The axis pair seen by the camera, which renders using the latest mouse motion
+    #[derive(Resource, Default)]
+    struct Observed(Option<DualAxisData>);
+
+    fn move_mouse(mut mouse_motion: EventWriter<MouseMotion>) {
+        mouse_motion.send(MouseMotion {
+            delta: Vec2::new(3.0, 4.0),
+        });
+    }
+
+    fn observe(action_state: Res<ActionState<AxislikeTestAction>>, mut observed: ResMut<Observed>) {
+        observed.0 = action_state.axis_pair(AxislikeTestAction::XY);
+    }
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<AxislikeTestAction>::new(
+            InputManagerConfig::default().with_late_mouse_sampling(),
+        ))
+        .init_resource::<ActionState<AxislikeTestAction>>()
+        .insert_resource(InputMap::new([(
+            DualAxis::mouse_motion(),
+            AxislikeTestAction::XY,
+        )]))
+        .init_resource::<Observed>()
+        .add_system(move_mouse)
+        .add_system_to_stage(
+            CoreStage::PostUpdate,
+            observe.after(InputManagerSystem::LateMouseSampling),
+        );
+
+    app.update();
+
+    // The motion was sent after the action state was updated, but is seen by the camera
+    assert_eq!(
+        app.world.resource::<Observed>().0,
+        Some(DualAxisData::new(3.0, 4.0))
+    );
+    // Nothing is pressed by the late sample
+    let action_state = app.world.resource::<ActionState<AxislikeTestAction>>();
+    assert!(action_state.released(AxislikeTestAction::XY));
+}
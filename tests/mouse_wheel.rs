@@ -1,7 +1,9 @@
 use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
 use bevy::input::InputPlugin;
 use bevy::prelude::*;
-use leafwing_input_manager::axislike::{AxisType, DualAxisData, MouseWheelAxisType};
+use leafwing_input_manager::axislike::{
+    AxisType, DeadZoneShape, DualAxisData, MagnitudeLimit, MouseWheelAxisType, MouseWheelSettings,
+};
 use leafwing_input_manager::prelude::*;
 
 #[derive(Actionlike, Clone, Copy, Debug)]
@@ -72,6 +74,8 @@ fn mouse_wheel_single_axis_mocking() {
     let input = SingleAxis {
         axis_type: AxisType::MouseWheel(MouseWheelAxisType::X),
         value: Some(-1.),
+        range: None,
+        offset: 0.0,
         positive_low: 0.0,
         negative_low: 0.0,
     };
@@ -91,15 +95,21 @@ fn mouse_wheel_dual_axis_mocking() {
         x: SingleAxis {
             axis_type: AxisType::MouseWheel(MouseWheelAxisType::X),
             value: Some(1.),
+            range: None,
+            offset: 0.0,
             positive_low: 0.0,
             negative_low: 0.0,
         },
         y: SingleAxis {
             axis_type: AxisType::MouseWheel(MouseWheelAxisType::Y),
             value: Some(0.),
+            range: None,
+            offset: 0.0,
             positive_low: 0.0,
             negative_low: 0.0,
         },
+        deadzone_shape: DeadZoneShape::Cross,
+        magnitude: MagnitudeLimit::Raw,
     };
     app.send_input(input);
     let mut events = app.world.resource_mut::<Events<MouseWheel>>();
@@ -164,6 +174,8 @@ fn mouse_wheel_single_axis() {
     let input = SingleAxis {
         axis_type: AxisType::MouseWheel(MouseWheelAxisType::X),
         value: Some(1.),
+        range: None,
+        offset: 0.0,
         positive_low: 0.0,
         negative_low: 0.0,
     };
@@ -176,6 +188,8 @@ fn mouse_wheel_single_axis() {
     let input = SingleAxis {
         axis_type: AxisType::MouseWheel(MouseWheelAxisType::X),
         value: Some(-1.),
+        range: None,
+        offset: 0.0,
         positive_low: 0.0,
         negative_low: 0.0,
     };
@@ -188,6 +202,8 @@ fn mouse_wheel_single_axis() {
     let input = SingleAxis {
         axis_type: AxisType::MouseWheel(MouseWheelAxisType::Y),
         value: Some(1.),
+        range: None,
+        offset: 0.0,
         positive_low: 0.0,
         negative_low: 0.0,
     };
@@ -200,6 +216,8 @@ fn mouse_wheel_single_axis() {
     let input = SingleAxis {
         axis_type: AxisType::MouseWheel(MouseWheelAxisType::Y),
         value: Some(-1.),
+        range: None,
+        offset: 0.0,
         positive_low: 0.0,
         negative_low: 0.0,
     };
@@ -212,6 +230,8 @@ fn mouse_wheel_single_axis() {
     let input = SingleAxis {
         axis_type: AxisType::MouseWheel(MouseWheelAxisType::Y),
         value: Some(0.0),
+        range: None,
+        offset: 0.0,
         // Usually a small deadzone threshold will be set
         positive_low: 0.1,
         negative_low: 0.1,
@@ -225,6 +245,8 @@ fn mouse_wheel_single_axis() {
     let input = SingleAxis {
         axis_type: AxisType::MouseWheel(MouseWheelAxisType::Y),
         value: None,
+        range: None,
+        offset: 0.0,
         positive_low: 0.0,
         negative_low: 0.0,
     };
@@ -288,3 +310,66 @@ fn mouse_wheel_virtualdpad() {
         DualAxisData::new(0.0, -1.0)
     );
 }
+
+#[test]
+fn mouse_wheel_modified_chord() {
+    let mut app = test_app();
+    app.insert_resource(InputMap::new([(
+        UserInput::modified(Modifier::Shift, MouseWheelDirection::Up),
+        ButtonlikeTestAction::Up,
+    )]));
+
+    // Scrolling alone does not trigger the chord
+    app.send_input(MouseWheelDirection::Up);
+    app.update();
+    let action_state = app.world.resource::<ActionState<ButtonlikeTestAction>>();
+    assert!(action_state.released(ButtonlikeTestAction::Up));
+
+    // Chords can freely mix keyboard and mouse wheel inputs
+    app.send_input(KeyCode::LShift);
+    app.send_input(MouseWheelDirection::Up);
+    app.update();
+    let action_state = app.world.resource::<ActionState<ButtonlikeTestAction>>();
+    assert!(action_state.pressed(ButtonlikeTestAction::Up));
+}
+
+#[test]
+fn mouse_wheel_scroll_units_are_scaled() {
+    let mut app = test_app();
+    app.insert_resource(InputMap::new([(
+        DualAxis::mouse_wheel(),
+        AxislikeTestAction::XY,
+    )]))
+    .insert_resource(MouseWheelSettings {
+        line_scale: Vec2::new(1.0, -1.0),
+        pixel_scale: Vec2::splat(0.1),
+    });
+
+    // Touchpads report precise, pixel-based deltas, including horizontal scrolling
+    let mut events = app.world.resource_mut::<Events<MouseWheel>>();
+    events.send(MouseWheel {
+        unit: MouseScrollUnit::Pixel,
+        x: 5.0,
+        y: 10.0,
+    });
+    app.update();
+    let action_state = app.world.resource::<ActionState<AxislikeTestAction>>();
+    assert_eq!(
+        action_state.axis_pair(AxislikeTestAction::XY),
+        Some(DualAxisData::new(0.5, 1.0))
+    );
+
+    // Line-based deltas use their own scale, which can be used to invert scrolling
+    let mut events = app.world.resource_mut::<Events<MouseWheel>>();
+    events.send(MouseWheel {
+        unit: MouseScrollUnit::Line,
+        x: 1.0,
+        y: 1.0,
+    });
+    app.update();
+    let action_state = app.world.resource::<ActionState<AxislikeTestAction>>();
+    assert_eq!(
+        action_state.axis_pair(AxislikeTestAction::XY),
+        Some(DualAxisData::new(1.0, -1.0))
+    );
+}
@@ -0,0 +1,109 @@
+use bevy::input::InputPlugin;
+use bevy::math::DVec2;
+use bevy::prelude::*;
+use bevy::window::WindowId;
+use leafwing_input_manager::prelude::*;
+
+#[derive(Actionlike, Clone, Copy, Debug)]
+enum EditorAction {
+    Save,
+    Select,
+}
+
+fn window(id: WindowId) -> Window {
+    Window::new(id, &WindowDescriptor::default(), 800, 600, 1.0, None, None)
+}
+
+/// Creates an app with a primary window, and a second window whose input drives the returned entity
+fn test_app() -> (App, WindowId, Entity) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<EditorAction>::default());
+
+    let secondary = WindowId::new();
+    let mut windows = Windows::default();
+    windows.add(window(WindowId::primary()));
+    windows.add(window(secondary));
+    app.insert_resource(windows);
+
+    let mut input_map = InputMap::new([
+        (UserInput::from(KeyCode::S), EditorAction::Save),
+        (UserInput::from(MouseButton::Left), EditorAction::Select),
+    ]);
+    input_map.set_window(secondary);
+
+    let entity = app
+        .world
+        .spawn(InputManagerBundle {
+            input_map,
+            ..Default::default()
+        })
+        .id();
+
+    (app, secondary, entity)
+}
+
+fn set_focus(app: &mut App, focused: WindowId) {
+    let mut windows = app.world.resource_mut::<Windows>();
+    for window in windows.iter_mut() {
+        let is_focused = window.id() == focused;
+        window.update_focused_status_from_backend(is_focused);
+    }
+}
+
+fn set_cursor(app: &mut App, window: WindowId, position: Option<DVec2>) {
+    let mut windows = app.world.resource_mut::<Windows>();
+    windows
+        .get_mut(window)
+        .unwrap()
+        .update_cursor_physical_position_from_backend(position);
+}
+
+#[test]
+fn keyboard_is_scoped_to_focused_window() {
+    let (mut app, secondary, entity) = test_app();
+
+    set_focus(&mut app, WindowId::primary());
+    app.send_input(KeyCode::S);
+    app.update();
+    let action_state = app.world.get::<ActionState<EditorAction>>(entity).unwrap();
+    assert!(action_state.released(EditorAction::Save));
+
+    set_focus(&mut app, secondary);
+    app.update();
+    let action_state = app.world.get::<ActionState<EditorAction>>(entity).unwrap();
+    assert!(action_state.pressed(EditorAction::Save));
+}
+
+#[test]
+fn mouse_is_scoped_to_hovered_window() {
+    let (mut app, secondary, entity) = test_app();
+
+    set_cursor(&mut app, WindowId::primary(), Some(DVec2::new(10.0, 10.0)));
+    app.send_input(MouseButton::Left);
+    app.update();
+    let action_state = app.world.get::<ActionState<EditorAction>>(entity).unwrap();
+    assert!(action_state.released(EditorAction::Select));
+
+    set_cursor(&mut app, WindowId::primary(), None);
+    set_cursor(&mut app, secondary, Some(DVec2::new(10.0, 10.0)));
+    app.update();
+    let action_state = app.world.get::<ActionState<EditorAction>>(entity).unwrap();
+    assert!(action_state.pressed(EditorAction::Select));
+}
+
+#[test]
+fn unscoped_maps_read_every_window() {
+    let (mut app, _, entity) = test_app();
+    app.world
+        .get_mut::<InputMap<EditorAction>>(entity)
+        .unwrap()
+        .clear_window();
+
+    set_focus(&mut app, WindowId::primary());
+    app.send_input(KeyCode::S);
+    app.update();
+    let action_state = app.world.get::<ActionState<EditorAction>>(entity).unwrap();
+    assert!(action_state.pressed(EditorAction::Save));
+}
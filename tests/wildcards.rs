@@ -0,0 +1,89 @@
+use bevy::input::gamepad::{GamepadEventRaw, GamepadInfo};
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::*;
+use leafwing_input_manager::user_input::InputKind;
+
+#[derive(Actionlike, Clone, Copy, Debug)]
+enum Action {
+    Start,
+    SkipCutscene,
+}
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .init_resource::<ActionState<Action>>()
+        .insert_resource(InputMap::new([
+            (Wildcard::AnyInput, Action::Start),
+            (Wildcard::AnyKey, Action::SkipCutscene),
+        ]));
+
+    let mut gamepad_events = app.world.resource_mut::<Events<GamepadEventRaw>>();
+    gamepad_events.send(GamepadEventRaw {
+        gamepad: Gamepad { id: 1 },
+        event_type: GamepadEventType::Connected(GamepadInfo {
+            name: "TestController".into(),
+        }),
+    });
+    app.update();
+    app.update();
+
+    app
+}
+
+#[test]
+fn any_key_reports_the_pressed_key() {
+    let mut app = test_app();
+
+    app.update();
+    let action_state = app.world.resource::<ActionState<Action>>();
+    assert!(action_state.released(Action::Start));
+    assert!(action_state.released(Action::SkipCutscene));
+    assert_eq!(action_state.triggering_input(Action::SkipCutscene), None);
+
+    app.send_input(KeyCode::Escape);
+    app.update();
+    let action_state = app.world.resource::<ActionState<Action>>();
+    assert!(action_state.just_pressed(Action::Start));
+    assert!(action_state.just_pressed(Action::SkipCutscene));
+    assert_eq!(
+        action_state.triggering_input(Action::SkipCutscene),
+        Some(&UserInput::Single(InputKind::Keyboard(KeyCode::Escape)))
+    );
+
+    app.release_input(KeyCode::Escape);
+    app.update();
+    let action_state = app.world.resource::<ActionState<Action>>();
+    assert!(action_state.just_released(Action::SkipCutscene));
+    assert_eq!(action_state.triggering_input(Action::SkipCutscene), None);
+}
+
+#[test]
+fn any_input_matches_gamepad_and_mouse_buttons() {
+    let mut app = test_app();
+
+    app.send_input(GamepadButtonType::South);
+    app.update();
+    let action_state = app.world.resource::<ActionState<Action>>();
+    assert!(action_state.pressed(Action::Start));
+    assert!(action_state.released(Action::SkipCutscene));
+    assert_eq!(
+        action_state.triggering_input(Action::Start),
+        Some(&UserInput::Single(InputKind::GamepadButton(
+            GamepadButtonType::South
+        )))
+    );
+
+    app.release_input(GamepadButtonType::South);
+    app.send_input(MouseButton::Left);
+    app.update();
+    let action_state = app.world.resource::<ActionState<Action>>();
+    assert!(action_state.pressed(Action::Start));
+    assert_eq!(
+        action_state.triggering_input(Action::Start),
+        Some(&UserInput::Single(InputKind::Mouse(MouseButton::Left)))
+    );
+}